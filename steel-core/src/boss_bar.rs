@@ -0,0 +1,222 @@
+//! Boss bars made with `/bossbar`, vanilla's `CustomBossEvents`.
+use rustc_hash::{FxHashMap, FxHashSet};
+use steel_protocol::packets::game::{BossBarColor, BossBarOverlay, BossEventAction, CBossEvent};
+use steel_utils::Identifier;
+use text_components::{Modifier, TextComponent, format::Color};
+use uuid::Uuid;
+
+/// A boss bar made with `/bossbar`, vanilla's `CustomBossEvent`.
+#[derive(Clone, Debug)]
+pub struct CustomBossBar {
+    /// The id commands refer to the bar by.
+    pub id: Identifier,
+    /// The id packets refer to the bar by.
+    pub uuid: Uuid,
+    /// The text shown above the bar.
+    pub name: TextComponent,
+    /// The color of the bar.
+    pub color: BossBarColor,
+    /// How the bar is divided.
+    pub overlay: BossBarOverlay,
+    /// How full the bar is, out of `max`.
+    pub value: i32,
+    /// The value of a full bar.
+    pub max: i32,
+    /// Whether the players see the bar.
+    pub visible: bool,
+    /// The players that see the bar, kept while they are offline.
+    pub players: FxHashSet<Uuid>,
+}
+
+impl CustomBossBar {
+    /// A bar with vanilla's defaults: white, undivided, empty and visible to nobody.
+    #[must_use]
+    pub fn new(id: Identifier, name: TextComponent) -> Self {
+        Self {
+            id,
+            uuid: Uuid::new_v4(),
+            name,
+            color: BossBarColor::White,
+            overlay: BossBarOverlay::Progress,
+            value: 0,
+            max: 100,
+            visible: true,
+            players: FxHashSet::default(),
+        }
+    }
+
+    /// How full the bar is, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        (self.value as f32 / self.max.max(1) as f32).clamp(0.0, 1.0)
+    }
+
+    /// The name in square brackets and the bar's color, as command feedback shows it.
+    // TODO: vanilla also shows the id on hover and inserts it on shift-click
+    #[must_use]
+    pub fn display_name(&self) -> TextComponent {
+        TextComponent::plain("[")
+            .add_child(self.name.clone())
+            .add_child(TextComponent::plain("]"))
+            .color(text_color(self.color))
+    }
+
+    /// The packet showing the bar.
+    #[must_use]
+    pub fn add_packet(&self) -> CBossEvent {
+        self.packet(BossEventAction::Add {
+            name: self.name.clone(),
+            progress: self.progress(),
+            color: self.color,
+            overlay: self.overlay,
+            flags: 0,
+        })
+    }
+
+    /// A packet changing the bar.
+    #[must_use]
+    pub const fn packet(&self, action: BossEventAction) -> CBossEvent {
+        CBossEvent {
+            id: self.uuid,
+            action,
+        }
+    }
+
+    /// Whether `player` sees the bar.
+    #[must_use]
+    pub fn is_seen_by(&self, player: Uuid) -> bool {
+        self.visible && self.players.contains(&player)
+    }
+
+    /// The packets bringing the players who saw `old` up to date with this bar.
+    #[must_use]
+    pub fn update_packets(&self, old: &Self) -> Vec<(Uuid, CBossEvent)> {
+        let mut packets = Vec::new();
+        for &player in &old.players {
+            if old.is_seen_by(player) && !self.is_seen_by(player) {
+                packets.push((player, self.packet(BossEventAction::Remove)));
+            }
+        }
+        for &player in &self.players {
+            if !self.is_seen_by(player) {
+                continue;
+            }
+            if !old.is_seen_by(player) {
+                packets.push((player, self.add_packet()));
+                continue;
+            }
+            if self.name.to_nbt_tag() != old.name.to_nbt_tag() {
+                packets.push((
+                    player,
+                    self.packet(BossEventAction::UpdateName(self.name.clone())),
+                ));
+            }
+            if self.value != old.value || self.max != old.max {
+                packets.push((
+                    player,
+                    self.packet(BossEventAction::UpdateProgress(self.progress())),
+                ));
+            }
+            if self.color != old.color || self.overlay != old.overlay {
+                packets.push((
+                    player,
+                    self.packet(BossEventAction::UpdateStyle {
+                        color: self.color,
+                        overlay: self.overlay,
+                    }),
+                ));
+            }
+        }
+        packets
+    }
+
+    /// The packets hiding the bar from everyone who sees it.
+    #[must_use]
+    pub fn remove_packets(&self) -> Vec<(Uuid, CBossEvent)> {
+        self.players
+            .iter()
+            .filter(|&&player| self.is_seen_by(player))
+            .map(|&player| (player, self.packet(BossEventAction::Remove)))
+            .collect()
+    }
+}
+
+/// The chat color matching a bar color, vanilla's `BossBarColor.getFormatting`.
+const fn text_color(color: BossBarColor) -> Color {
+    match color {
+        BossBarColor::Pink => Color::LightPurple,
+        BossBarColor::Blue => Color::Blue,
+        BossBarColor::Red => Color::Red,
+        BossBarColor::Green => Color::Green,
+        BossBarColor::Yellow => Color::Yellow,
+        BossBarColor::Purple => Color::DarkPurple,
+        BossBarColor::White => Color::White,
+    }
+}
+
+/// Every custom boss bar, by id.
+// TODO: persist the bars to level.dat's CustomBossEvents
+#[derive(Default)]
+pub struct BossBars {
+    bars: FxHashMap<Identifier, CustomBossBar>,
+}
+
+impl BossBars {
+    /// The bar with `id`.
+    #[must_use]
+    pub fn get(&self, id: &Identifier) -> Option<&CustomBossBar> {
+        self.bars.get(id)
+    }
+
+    /// The bar with `id`, for changing it.
+    pub fn get_mut(&mut self, id: &Identifier) -> Option<&mut CustomBossBar> {
+        self.bars.get_mut(id)
+    }
+
+    /// Every bar, sorted by id.
+    #[must_use]
+    pub fn sorted(&self) -> Vec<&CustomBossBar> {
+        let mut bars: Vec<_> = self.bars.values().collect();
+        bars.sort_by_key(|bar| bar.id.to_string());
+        bars
+    }
+
+    /// How many bars there are.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bars.len()
+    }
+
+    /// Whether there are no bars.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bars.is_empty()
+    }
+
+    /// Creates a bar, or returns `None` if `id` is taken.
+    pub fn create(&mut self, id: Identifier, name: TextComponent) -> Option<&CustomBossBar> {
+        if self.bars.contains_key(&id) {
+            return None;
+        }
+        Some(
+            self.bars
+                .entry(id.clone())
+                .or_insert_with(|| CustomBossBar::new(id, name)),
+        )
+    }
+
+    /// Removes the bar with `id`.
+    pub fn remove(&mut self, id: &Identifier) -> Option<CustomBossBar> {
+        self.bars.remove(id)
+    }
+
+    /// The packets showing `player` the visible bars they were added to.
+    #[must_use]
+    pub fn join_packets(&self, player: Uuid) -> Vec<CBossEvent> {
+        self.sorted()
+            .into_iter()
+            .filter(|bar| bar.visible && bar.players.contains(&player))
+            .map(CustomBossBar::add_packet)
+            .collect()
+    }
+}
@@ -1530,9 +1530,14 @@ impl ChunkMap {
     /// Iterates over `unloading_chunks`. For each chunk with `strong_count == 1`:
     /// - If dirty: spawn save task (keep until saved and clean)
     /// - If not dirty: release region handle and remove
+    ///
+    /// Nothing is unloaded while the world has saving turned off, as in vanilla `ChunkMap.tick`.
     #[instrument(level = "trace", skip(self))]
     pub fn process_unloads(self: &Arc<Self>) {
         self.propagate_queued_light_changes();
+        if self.world_gen_context.world().no_save() {
+            return;
+        }
 
         let light_updates = self.light_updates.lock();
         self.unloading_chunks.retain_sync(|pos, holder| {
@@ -1696,6 +1701,20 @@ impl ChunkMap {
     /// Returns the number of chunks saved.
     #[instrument(level = "info", skip(self), name = "save_all_chunks")]
     pub async fn save_all_chunks(self: &Arc<Self>) -> io::Result<usize> {
+        let saved_count = self.save_dirty_chunks().await?;
+
+        // Close all region files (flushes headers and releases file handles)
+        if let Err(e) = self.storage.close_all().await {
+            tracing::error!("Failed to close region files: {e}");
+        }
+        Ok(saved_count)
+    }
+
+    /// Saves all dirty chunks while keeping region files open, for saves while the world runs.
+    ///
+    /// Region headers stay in memory until the next flush; see [`ChunkStorage::flush_all`].
+    /// Returns the number of chunks saved.
+    pub async fn save_dirty_chunks(self: &Arc<Self>) -> io::Result<usize> {
         let mut saved_count = 0;
 
         self.flush_queued_light_changes_for_save().await;
@@ -1796,11 +1815,6 @@ impl ChunkMap {
             );
         }
 
-        // Close all region files (flushes headers and releases file handles)
-        if let Err(e) = self.storage.close_all().await {
-            tracing::error!("Failed to close region files: {e}");
-        }
-
         tracing::info!(
             saved_count,
             total_checked = all_chunks.len(),
@@ -1821,10 +1835,12 @@ mod tests {
     use crate::chunk::proto_chunk::ProtoChunk;
     use crate::chunk::section::{ChunkSection, Sections};
     use crate::chunk_saver::RamOnlyStorage;
+    use crate::test_support::server::{enter_runtime, test_server};
     use crate::world::tick_scheduler::{BlockTickList, FluidTickList};
     use crate::worldgen::EmptyChunkGenerator;
     use steel_registry::{test_support::init_test_registry, vanilla_dimension_types::OVERWORLD};
     use steel_worldgen::structure::{StructureReferenceMap, StructureStartMap};
+    use tokio::runtime::Handle;
 
     #[test]
     fn generation_priority_prefers_simulation_tickets() {
@@ -1999,6 +2015,42 @@ mod tests {
         assert!(!holder.has_changes_to_broadcast());
     }
 
+    #[test]
+    fn dirty_chunks_stay_unsaved_while_saving_is_off() {
+        let server = test_server();
+        let world = server.overworld();
+        let chunk_map = world.chunk_map.clone();
+        let pos = ChunkPos::new(100, 100);
+        let holder = unloaded_full_holder(pos);
+        holder
+            .try_chunk(ChunkStatus::Full)
+            .expect("test holder should contain a full chunk")
+            .mark_dirty();
+        let _ = chunk_map.unloading_chunks.insert_sync(pos, holder);
+        let _runtime = enter_runtime();
+        let is_dirty = || {
+            chunk_map.process_unloads();
+            chunk_map.task_tracker.close();
+            Handle::current().block_on(chunk_map.task_tracker.wait());
+            chunk_map.task_tracker.reopen();
+            chunk_map.unloading_chunks.read_sync(&pos, |_, holder| {
+                holder
+                    .try_chunk(ChunkStatus::Full)
+                    .is_some_and(|chunk| chunk.is_dirty())
+            })
+        };
+
+        world.set_no_save(true);
+        assert_eq!(is_dirty(), Some(true));
+
+        world.set_no_save(false);
+        assert_eq!(
+            is_dirty(),
+            Some(false),
+            "the chunk is saved once saving is on"
+        );
+    }
+
     #[test]
     fn save_retry_marks_same_unloading_holder_dirty() {
         let _chunk_map = test_chunk_map();
@@ -0,0 +1,93 @@
+//! An angle argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+
+/// A single yaw angle, optionally relative to the source's yaw.
+pub struct AngleArgument;
+
+impl CommandArgument for AngleArgument {
+    type Output = f32;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let angle = SingleAngle::parse(arg.first()?)?;
+        let (origin_yaw, _) = context.rotation.unwrap_or((0.0, 0.0));
+
+        Some((&arg[1..], angle.resolve(origin_yaw)))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Angle, None)
+    }
+}
+
+/// Vanilla `AngleArgument.SingleAngle`.
+#[derive(Debug, PartialEq)]
+struct SingleAngle {
+    angle: f32,
+    relative: bool,
+}
+
+impl SingleAngle {
+    fn parse(value: &str) -> Option<Self> {
+        let (value, relative) = match value.strip_prefix('~') {
+            Some(offset) => (offset, true),
+            None => (value, false),
+        };
+        let angle = if relative && value.is_empty() {
+            0.0
+        } else {
+            value.parse::<f32>().ok()?
+        };
+        angle.is_finite().then_some(Self { angle, relative })
+    }
+
+    /// Resolves against `origin_yaw` and wraps into `[-180, 180)`.
+    fn resolve(&self, origin_yaw: f32) -> f32 {
+        let angle = if self.relative {
+            origin_yaw + self.angle
+        } else {
+            self.angle
+        };
+        let wrapped = angle.rem_euclid(360.0);
+        if wrapped >= 180.0 {
+            wrapped - 360.0
+        } else {
+            wrapped
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SingleAngle;
+
+    #[test]
+    fn tilde_angles_are_marked_relative() {
+        let angle = SingleAngle::parse("~90").expect("relative angle should parse");
+
+        assert_eq!(
+            angle,
+            SingleAngle {
+                angle: 90.0,
+                relative: true
+            }
+        );
+        assert!((angle.resolve(120.0) - -150.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn absolute_angles_ignore_the_source_yaw() {
+        let angle = SingleAngle::parse("270").expect("absolute angle should parse");
+
+        assert!(!angle.relative);
+        assert!((angle.resolve(45.0) - -90.0).abs() < f32::EPSILON);
+        assert!(SingleAngle::parse("north").is_none());
+        assert!(SingleAngle::parse("NaN").is_none());
+    }
+}
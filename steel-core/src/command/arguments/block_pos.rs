@@ -1,5 +1,6 @@
 //! A block position argument.
 
+use glam::DVec3;
 use steel_protocol::packets::game::{ArgumentType, SuggestionType};
 use steel_utils::BlockPos;
 
@@ -22,11 +23,8 @@ impl CommandArgument for BlockPosArgument {
             return Some((&arg[3..], BlockPos::containing(pos.x, pos.y, pos.z)));
         }
 
-        let x = parse_coordinate(arg.first()?, context.position.x)?;
-        let y = parse_coordinate(arg.get(1)?, context.position.y)?;
-        let z = parse_coordinate(arg.get(2)?, context.position.z)?;
-
-        Some((&arg[3..], BlockPos::containing(x, y, z)))
+        let pos = parse_world_coordinates(arg.get(..3)?, context.position)?;
+        Some((&arg[3..], pos))
     }
 
     fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
@@ -34,7 +32,15 @@ impl CommandArgument for BlockPosArgument {
     }
 }
 
-fn parse_coordinate(value: &str, origin: f64) -> Option<f64> {
+/// Resolves three world coordinates, relative ones against `origin`, to the containing block.
+fn parse_world_coordinates(arg: &[&str], origin: DVec3) -> Option<BlockPos> {
+    let x = parse_coordinate(arg.first()?, origin.x)?;
+    let y = parse_coordinate(arg.get(1)?, origin.y)?;
+    let z = parse_coordinate(arg.get(2)?, origin.z)?;
+    Some(BlockPos::containing(x, y, z))
+}
+
+pub(super) fn parse_coordinate(value: &str, origin: f64) -> Option<f64> {
     if value.starts_with('^') {
         return None;
     }
@@ -52,7 +58,24 @@ fn parse_coordinate(value: &str, origin: f64) -> Option<f64> {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_coordinate;
+    use glam::DVec3;
+    use steel_utils::BlockPos;
+
+    use super::{parse_coordinate, parse_world_coordinates};
+
+    #[test]
+    fn relative_coordinates_resolve_against_the_sender() {
+        let origin = DVec3::new(10.7, 64.2, -3.5);
+
+        assert_eq!(
+            parse_world_coordinates(&["~", "~", "~"], origin),
+            Some(BlockPos::new(10, 64, -4))
+        );
+        assert_eq!(
+            parse_world_coordinates(&["~1", "70", "~-0.5"], origin),
+            Some(BlockPos::new(11, 70, -4))
+        );
+    }
 
     #[test]
     fn rejects_mixed_local_and_world_coordinates() {
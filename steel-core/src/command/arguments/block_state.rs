@@ -0,0 +1,158 @@
+//! A block state argument.
+use simdnbt::owned::NbtCompound;
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+use steel_registry::{REGISTRY, RegistryExt};
+use steel_utils::BlockStateId;
+
+use crate::command::{
+    arguments::{
+        CommandArgument, SuggestionContext, nbt_compound::read_compound_from,
+        resource_location::parse_identifier,
+    },
+    context::CommandContext,
+};
+
+/// A vanilla `BlockStateArgument`, parsing a block such as `chest[facing=east]{Lock:"key"}`.
+///
+/// Properties that aren't given keep the block's default value.
+pub struct BlockStateArgument;
+
+/// Vanilla `BlockInput`, a block state with the NBT for its block entity.
+pub struct BlockInput {
+    /// The parsed block state.
+    pub state: BlockStateId,
+    /// The block entity data, if any was given.
+    pub nbt: Option<NbtCompound>,
+}
+
+impl CommandArgument for BlockStateArgument {
+    type Output = BlockInput;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        parse_block_input(arg)
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::BlockState, None)
+    }
+
+    fn suggest(&self, prefix: &str, _suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        let stripped_prefix = prefix.strip_prefix("minecraft:").unwrap_or(prefix);
+        REGISTRY
+            .blocks
+            .iter()
+            .map(|(_, block)| SuggestionEntry::new(block.key.to_string()))
+            .filter(|suggestion| {
+                suggestion
+                    .text
+                    .strip_prefix("minecraft:")
+                    .unwrap_or(&suggestion.text)
+                    .starts_with(stripped_prefix)
+            })
+            .collect()
+    }
+}
+
+fn parse_block_input<'a>(arg: &'a [&'a str]) -> Option<(&'a [&'a str], BlockInput)> {
+    let first = arg.first()?;
+    let Some(nbt_start) = first.find('{') else {
+        let state = parse_block_state(first)?;
+        return Some((&arg[1..], BlockInput { state, nbt: None }));
+    };
+
+    let state = parse_block_state(&first[..nbt_start])?;
+    let (rest, nbt) = read_compound_from(arg, nbt_start)?;
+    Some((
+        rest,
+        BlockInput {
+            state,
+            nbt: Some(nbt),
+        },
+    ))
+}
+
+fn parse_block_state(input: &str) -> Option<BlockStateId> {
+    let (name, properties) = match input.split_once('[') {
+        Some((name, rest)) => (name, Some(rest.strip_suffix(']')?)),
+        None => (input, None),
+    };
+    let block = REGISTRY.blocks.by_key(&parse_identifier(name)?)?;
+
+    let mut parsed = Vec::new();
+    for property in properties.into_iter().flat_map(|p| p.split(',')) {
+        if property.is_empty() {
+            continue;
+        }
+        parsed.push(property.split_once('=')?);
+    }
+
+    REGISTRY
+        .blocks
+        .state_id_from_block_defaulted_properties(block, parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::blocks::block_state_ext::BlockStateExt;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{REGISTRY, vanilla_blocks};
+
+    use simdnbt::owned::NbtTag;
+
+    use super::{parse_block_input, parse_block_state};
+
+    #[test]
+    fn block_without_properties_uses_its_default_state() {
+        init_test_registry();
+
+        assert_eq!(
+            parse_block_state("stone"),
+            Some(REGISTRY.blocks.get_default_state_id(&vanilla_blocks::STONE))
+        );
+        assert_eq!(
+            parse_block_state("minecraft:stone[]"),
+            Some(REGISTRY.blocks.get_default_state_id(&vanilla_blocks::STONE))
+        );
+    }
+
+    #[test]
+    fn given_properties_override_the_default_state() {
+        init_test_registry();
+
+        let state = parse_block_state("oak_stairs[facing=east,half=top]")
+            .expect("stairs state should parse");
+
+        assert_eq!(state.get_block(), &vanilla_blocks::OAK_STAIRS);
+        assert_eq!(state.get_property_str("facing").as_deref(), Some("east"));
+        assert_eq!(state.get_property_str("half").as_deref(), Some("top"));
+        assert_eq!(state.get_property_str("shape").as_deref(), Some("straight"));
+    }
+
+    #[test]
+    fn unknown_blocks_and_properties_are_rejected() {
+        init_test_registry();
+
+        assert!(parse_block_state("not_a_block").is_none());
+        assert!(parse_block_state("stone[facing=east]").is_none());
+        assert!(parse_block_state("oak_stairs[facing=east").is_none());
+    }
+
+    #[test]
+    fn block_entity_nbt_follows_the_properties() {
+        init_test_registry();
+
+        let args = ["chest[facing=north]{Lock:\"two", "words\"}", "replace"];
+        let (rest, input) = parse_block_input(&args).expect("chest with nbt should parse");
+
+        assert_eq!(rest, ["replace"]);
+        assert_eq!(input.state.get_block(), &vanilla_blocks::CHEST);
+        assert_eq!(
+            input.nbt.and_then(|nbt| nbt.get("Lock").cloned()),
+            Some(NbtTag::String("two words".into()))
+        );
+    }
+}
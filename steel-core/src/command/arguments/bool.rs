@@ -7,6 +7,15 @@ use crate::command::context::CommandContext;
 /// A boolean argument that parses "true" or "false".
 pub struct BoolArgument;
 
+/// Parses `true` or `false`, case-sensitively like Brigadier's `BoolArgumentType`.
+fn parse_bool(s: &str) -> Option<bool> {
+    match s {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
 impl CommandArgument for BoolArgument {
     type Output = bool;
 
@@ -15,14 +24,7 @@ impl CommandArgument for BoolArgument {
         arg: &'a [&'a str],
         _context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        let s = arg.first()?;
-
-        let value = match s.to_lowercase().as_str() {
-            "true" => true,
-            "false" => false,
-            _ => return None,
-        };
-
+        let value = parse_bool(arg.first()?)?;
         Some((&arg[1..], value))
     }
 
@@ -44,3 +46,20 @@ impl CommandArgument for BoolArgument {
         suggestions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_bool;
+
+    #[test]
+    fn parses_lowercase_literals() {
+        assert_eq!(parse_bool("true"), Some(true));
+        assert_eq!(parse_bool("false"), Some(false));
+    }
+
+    #[test]
+    fn rejects_other_casing() {
+        assert_eq!(parse_bool("True"), None);
+        assert_eq!(parse_bool("FALSE"), None);
+    }
+}
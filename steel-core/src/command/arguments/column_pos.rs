@@ -0,0 +1,27 @@
+//! A column position argument.
+use glam::IVec2;
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+
+use crate::command::arguments::{CommandArgument, block_pos::parse_coordinate};
+use crate::command::context::CommandContext;
+
+/// A block column position, the x and z of a block position.
+pub struct ColumnPosArgument;
+
+impl CommandArgument for ColumnPosArgument {
+    type Output = IVec2;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let x = parse_coordinate(arg.first()?, context.position.x)?;
+        let z = parse_coordinate(arg.get(1)?, context.position.z)?;
+        Some((&arg[2..], IVec2::new(x.floor() as i32, z.floor() as i32)))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::ColumnPos, None)
+    }
+}
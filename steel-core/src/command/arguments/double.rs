@@ -0,0 +1,104 @@
+//! A double argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+
+use crate::command::arguments::CommandArgument;
+use crate::command::arguments::float::is_decimal_number;
+use crate::command::context::CommandContext;
+
+/// A double argument that parses a 64-bit floating point number.
+///
+/// Can optionally have minimum and maximum bounds.
+pub struct DoubleArgument {
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl DoubleArgument {
+    /// Creates a new unbounded double argument.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Creates a new double argument with bounds.
+    #[must_use]
+    pub const fn bounded(min: Option<f64>, max: Option<f64>) -> Self {
+        Self { min, max }
+    }
+
+    /// Parses a single token, rejecting values outside the bounds.
+    fn parse_value(&self, s: &str) -> Option<f64> {
+        if !is_decimal_number(s) {
+            return None;
+        }
+        let value: f64 = s.parse().ok()?;
+
+        // Check bounds
+        if let Some(min) = self.min
+            && value < min
+        {
+            return None;
+        }
+        if let Some(max) = self.max
+            && value > max
+        {
+            return None;
+        }
+
+        Some(value)
+    }
+}
+
+impl Default for DoubleArgument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandArgument for DoubleArgument {
+    type Output = f64;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let value = self.parse_value(arg.first()?)?;
+        Some((&arg[1..], value))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (
+            ArgumentType::Double {
+                min: self.min,
+                max: self.max,
+            },
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DoubleArgument;
+
+    #[test]
+    fn parses_decimal_doubles() {
+        let argument = DoubleArgument::new();
+
+        assert_eq!(argument.parse_value("0.125"), Some(0.125));
+        assert_eq!(argument.parse_value("-2"), Some(-2.0));
+    }
+
+    #[test]
+    fn rejects_scientific_notation_and_non_finite_values() {
+        let argument = DoubleArgument::bounded(Some(-10.0), Some(10.0));
+
+        assert_eq!(argument.parse_value("1e3"), None);
+        assert_eq!(argument.parse_value("-inf"), None);
+        assert_eq!(argument.parse_value("11"), None);
+    }
+}
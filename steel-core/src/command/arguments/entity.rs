@@ -1,9 +1,8 @@
 //! A entity argument.
 use crate::command::arguments::SuggestionContext;
+use crate::command::arguments::selector::resolve_players;
 use crate::command::context::CommandContext;
-use crate::entity::Entity;
 use crate::{command::arguments::CommandArgument, entity::LivingEntity};
-use rand::seq::IteratorRandom;
 use std::sync::Arc;
 use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
 use steel_utils::translations::{
@@ -11,7 +10,6 @@ use steel_utils::translations::{
     ARGUMENT_ENTITY_SELECTOR_NEAREST_ENTITY, ARGUMENT_ENTITY_SELECTOR_NEAREST_PLAYER,
     ARGUMENT_ENTITY_SELECTOR_RANDOM_PLAYER, ARGUMENT_ENTITY_SELECTOR_SELF,
 };
-use uuid::Uuid;
 
 /// A entity argument.
 #[derive(Default)]
@@ -40,55 +38,12 @@ impl CommandArgument for EntityArgument {
         arg: &'a [&'a str],
         context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        let players = context.server.get_players();
-        if players.is_empty() {
-            return Some((&arg[1..], vec![]));
-        }
-        let entities = match arg[0] {
-            // TODO: Add getting entities
-            "@a" | "@e" => players
-                .into_iter()
-                .map(|p| p as Arc<dyn LivingEntity + Send + Sync>)
-                .collect(),
-            "@n" | "@p" => {
-                let position = context.position;
-                let mut near_dist = (f64::MAX, players[0].clone());
-                for player in players {
-                    let dist = player.position().distance_squared(position);
-                    if dist < near_dist.0 {
-                        near_dist = (dist, player);
-                    }
-                }
-                vec![near_dist.1 as Arc<dyn LivingEntity + Send + Sync>]
-            }
-            "@r" => {
-                vec![players.into_iter().choose(&mut rand::rng())?
-                    as Arc<dyn LivingEntity + Send + Sync>]
-            }
-            "@s" => {
-                if let Some(player) = &context.player {
-                    vec![player.clone() as Arc<dyn LivingEntity + Send + Sync>]
-                } else {
-                    vec![]
-                }
-            }
-            name => {
-                let uuid = if let Ok(uuid) = Uuid::parse_str(name) {
-                    uuid
-                } else {
-                    Uuid::nil()
-                };
-                let player = players.into_iter().find_map(|p| {
-                    if p.gameprofile.name == name || p.uuid() == uuid {
-                        Some(p)
-                    } else {
-                        None
-                    }
-                })?;
-                vec![player as Arc<dyn LivingEntity + Send + Sync>]
-            }
-        };
-        // TODO: Add entity argiments. (e.g. @e[limit=1])
+        // TODO: Select non-player entities once they can be looked up from the world
+        let players = resolve_players(arg.first()?, context, self.one, false)?;
+        let entities = players
+            .into_iter()
+            .map(|p| p as Arc<dyn LivingEntity + Send + Sync>)
+            .collect();
         Some((&arg[1..], entities))
     }
 
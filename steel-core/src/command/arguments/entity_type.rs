@@ -1,10 +1,9 @@
 //! Entity type command arguments.
 use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
 use steel_registry::{REGISTRY, RegistryExt, entity_type::EntityTypeRef};
-use steel_utils::Identifier;
 
 use crate::command::{
-    arguments::{CommandArgument, SuggestionContext},
+    arguments::{CommandArgument, SuggestionContext, resource_location::parse_identifier},
     context::CommandContext,
 };
 use crate::entity::ENTITIES;
@@ -13,18 +12,8 @@ use crate::entity::ENTITIES;
 pub struct EntitySummonArgument;
 
 impl EntitySummonArgument {
-    fn parse_identifier(input: &str) -> Option<Identifier> {
-        let (namespace, path) = input.split_once(':').map_or(
-            (Identifier::VANILLA_NAMESPACE, input),
-            |(namespace, path)| (namespace, path),
-        );
-
-        Identifier::validate(namespace, path)
-            .then(|| Identifier::new(namespace.to_owned(), path.to_owned()))
-    }
-
     fn resolve(input: &str) -> Option<EntityTypeRef> {
-        let key = Self::parse_identifier(input)?;
+        let key = parse_identifier(input)?;
         REGISTRY
             .entity_types
             .by_key(&key)
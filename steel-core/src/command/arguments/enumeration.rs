@@ -0,0 +1,87 @@
+//! An argument accepting one of a fixed set of keywords.
+use steel_protocol::packets::game::{
+    ArgumentStringTypeBehavior, ArgumentType, SuggestionEntry, SuggestionType,
+};
+
+use crate::command::arguments::{CommandArgument, SuggestionContext};
+use crate::command::context::CommandContext;
+
+/// Parses a keyword from a static table into its value, like vanilla's `StringRepresentableArgument`.
+///
+/// The client sees a single word and asks the server for suggestions, which list the keywords.
+pub struct EnumArgument<T: 'static> {
+    values: &'static [(&'static str, T)],
+}
+
+impl<T> EnumArgument<T> {
+    /// Creates an argument accepting the keywords in `values`.
+    #[must_use]
+    pub const fn new(values: &'static [(&'static str, T)]) -> Self {
+        Self { values }
+    }
+
+    /// Returns the value of `keyword`, if it is one of the accepted ones.
+    fn lookup(&self, keyword: &str) -> Option<&'static T> {
+        self.values
+            .iter()
+            .find(|(name, _)| *name == keyword)
+            .map(|(_, value)| value)
+    }
+}
+
+impl<T: Copy + Send + Sync> CommandArgument for EnumArgument<T> {
+    type Output = T;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let value = self.lookup(arg.first()?)?;
+        Some((&arg[1..], *value))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (
+            ArgumentType::String {
+                behavior: ArgumentStringTypeBehavior::SingleWord,
+            },
+            Some(SuggestionType::AskServer),
+        )
+    }
+
+    fn suggest(&self, prefix: &str, _suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        self.values
+            .iter()
+            .filter(|(name, _)| name.starts_with(prefix))
+            .map(|(name, _)| SuggestionEntry::new(*name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnumArgument;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Sort {
+        Nearest,
+        Furthest,
+    }
+
+    const SORTS: EnumArgument<Sort> =
+        EnumArgument::new(&[("nearest", Sort::Nearest), ("furthest", Sort::Furthest)]);
+
+    #[test]
+    fn keywords_map_to_their_values() {
+        assert_eq!(SORTS.lookup("nearest"), Some(&Sort::Nearest));
+        assert_eq!(SORTS.lookup("furthest"), Some(&Sort::Furthest));
+    }
+
+    #[test]
+    fn unknown_keywords_are_rejected() {
+        assert_eq!(SORTS.lookup("random"), None);
+        assert_eq!(SORTS.lookup("Nearest"), None);
+        assert_eq!(SORTS.lookup(""), None);
+    }
+}
@@ -27,6 +27,37 @@ impl FloatArgument {
     pub const fn bounded(min: Option<f32>, max: Option<f32>) -> Self {
         Self { min, max }
     }
+
+    /// Parses a single token, rejecting values outside the bounds.
+    fn parse_value(&self, s: &str) -> Option<f32> {
+        if !is_decimal_number(s) {
+            return None;
+        }
+        let value: f32 = s.parse().ok()?;
+
+        // Check bounds
+        if let Some(min) = self.min
+            && value < min
+        {
+            return None;
+        }
+        if let Some(max) = self.max
+            && value > max
+        {
+            return None;
+        }
+
+        Some(value)
+    }
+}
+
+/// Whether `s` only uses the characters Brigadier accepts in a number (`0-9`, `.` and `-`).
+///
+/// Rust's float parsing also accepts exponents, `inf` and `NaN`, which vanilla rejects.
+pub(super) fn is_decimal_number(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_digit() || b == b'.' || b == b'-')
 }
 
 impl Default for FloatArgument {
@@ -43,21 +74,7 @@ impl CommandArgument for FloatArgument {
         arg: &'a [&'a str],
         _context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        let s = arg.first()?;
-        let value: f32 = s.parse().ok()?;
-
-        // Check bounds
-        if let Some(min) = self.min
-            && value < min
-        {
-            return None;
-        }
-        if let Some(max) = self.max
-            && value > max
-        {
-            return None;
-        }
-
+        let value = self.parse_value(arg.first()?)?;
         Some((&arg[1..], value))
     }
 
@@ -71,3 +88,35 @@ impl CommandArgument for FloatArgument {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FloatArgument;
+
+    #[test]
+    fn parses_decimal_floats() {
+        let argument = FloatArgument::new();
+
+        assert_eq!(argument.parse_value("1.5"), Some(1.5));
+        assert_eq!(argument.parse_value("-.5"), Some(-0.5));
+        assert_eq!(argument.parse_value("3"), Some(3.0));
+    }
+
+    #[test]
+    fn rejects_scientific_notation_and_non_finite_values() {
+        let argument = FloatArgument::new();
+
+        assert_eq!(argument.parse_value("1e3"), None);
+        assert_eq!(argument.parse_value("inf"), None);
+        assert_eq!(argument.parse_value("NaN"), None);
+    }
+
+    #[test]
+    fn rejects_out_of_range_floats() {
+        let argument = FloatArgument::bounded(Some(0.0), Some(1.0));
+
+        assert_eq!(argument.parse_value("1"), Some(1.0));
+        assert_eq!(argument.parse_value("1.01"), None);
+        assert_eq!(argument.parse_value("-0.1"), None);
+    }
+}
@@ -8,6 +8,17 @@ use crate::command::context::CommandContext;
 /// A gamemode argument.
 pub struct GameModeArgument;
 
+/// Parses a game mode name or its numeric id.
+fn parse_gamemode(s: &str) -> Option<GameType> {
+    Some(match s.to_lowercase().as_str() {
+        "survival" | "0" => GameType::Survival,
+        "creative" | "1" => GameType::Creative,
+        "adventure" | "2" => GameType::Adventure,
+        "spectator" | "3" => GameType::Spectator,
+        _ => return None,
+    })
+}
+
 impl CommandArgument for GameModeArgument {
     type Output = GameType;
 
@@ -16,16 +27,7 @@ impl CommandArgument for GameModeArgument {
         arg: &'a [&'a str],
         _context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        let s = arg.first()?;
-
-        let gamemode = match s.to_lowercase().as_str() {
-            "survival" | "0" => GameType::Survival,
-            "creative" | "1" => GameType::Creative,
-            "adventure" | "2" => GameType::Adventure,
-            "spectator" | "3" => GameType::Spectator,
-            _ => return None,
-        };
-
+        let gamemode = parse_gamemode(arg.first()?)?;
         Some((&arg[1..], gamemode))
     }
 
@@ -52,3 +54,30 @@ impl CommandArgument for GameModeArgument {
         suggestions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::types::GameType;
+
+    use super::parse_gamemode;
+
+    #[test]
+    fn parses_names_and_numeric_ids() {
+        for (name, id, expected) in [
+            ("survival", "0", GameType::Survival),
+            ("creative", "1", GameType::Creative),
+            ("adventure", "2", GameType::Adventure),
+            ("spectator", "3", GameType::Spectator),
+        ] {
+            assert_eq!(parse_gamemode(name), Some(expected));
+            assert_eq!(parse_gamemode(id), Some(expected));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_values() {
+        assert_eq!(parse_gamemode("s"), None);
+        assert_eq!(parse_gamemode("4"), None);
+        assert_eq!(parse_gamemode("hardcore"), None);
+    }
+}
@@ -27,6 +27,25 @@ impl IntegerArgument {
     pub const fn bounded(min: Option<i32>, max: Option<i32>) -> Self {
         Self { min, max }
     }
+
+    /// Parses a single token, rejecting values outside the bounds.
+    fn parse_value(&self, s: &str) -> Option<i32> {
+        let value: i32 = s.parse().ok()?;
+
+        // Check bounds
+        if let Some(min) = self.min
+            && value < min
+        {
+            return None;
+        }
+        if let Some(max) = self.max
+            && value > max
+        {
+            return None;
+        }
+
+        Some(value)
+    }
 }
 
 impl Default for IntegerArgument {
@@ -43,21 +62,7 @@ impl CommandArgument for IntegerArgument {
         arg: &'a [&'a str],
         _context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        let s = arg.first()?;
-        let value: i32 = s.parse().ok()?;
-
-        // Check bounds
-        if let Some(min) = self.min
-            && value < min
-        {
-            return None;
-        }
-        if let Some(max) = self.max
-            && value > max
-        {
-            return None;
-        }
-
+        let value = self.parse_value(arg.first()?)?;
         Some((&arg[1..], value))
     }
 
@@ -71,3 +76,36 @@ impl CommandArgument for IntegerArgument {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::IntegerArgument;
+
+    #[test]
+    fn parses_positive_and_negative_integers() {
+        let argument = IntegerArgument::new();
+
+        assert_eq!(argument.parse_value("5"), Some(5));
+        assert_eq!(argument.parse_value("-3"), Some(-3));
+        assert_eq!(argument.parse_value("five"), None);
+    }
+
+    #[test]
+    fn rejects_overflowing_integers() {
+        let argument = IntegerArgument::new();
+
+        assert_eq!(argument.parse_value("2147483647"), Some(i32::MAX));
+        assert_eq!(argument.parse_value("2147483648"), None);
+        assert_eq!(argument.parse_value("-2147483649"), None);
+    }
+
+    #[test]
+    fn rejects_out_of_range_integers() {
+        let argument = IntegerArgument::bounded(Some(0), Some(10));
+
+        assert_eq!(argument.parse_value("0"), Some(0));
+        assert_eq!(argument.parse_value("10"), Some(10));
+        assert_eq!(argument.parse_value("-1"), None);
+        assert_eq!(argument.parse_value("11"), None);
+    }
+}
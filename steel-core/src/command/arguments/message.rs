@@ -0,0 +1,27 @@
+//! A message argument.
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+
+/// A greedy message argument that consumes the rest of the command.
+pub struct MessageArgument;
+
+impl CommandArgument for MessageArgument {
+    type Output = String;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        // TODO: Expand entity selectors like vanilla's MessageArgument
+        if arg.is_empty() {
+            return None;
+        }
+        Some((&[], arg.join(" ")))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Message, None)
+    }
+}
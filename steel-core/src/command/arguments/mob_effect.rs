@@ -0,0 +1,54 @@
+//! A mob effect argument
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+use steel_registry::{REGISTRY, RegistryExt, mob_effect::MobEffectRef};
+use steel_utils::Identifier;
+
+use crate::command::{
+    arguments::{CommandArgument, SuggestionContext},
+    context::CommandContext,
+};
+
+/// A mob effect argument that resolves to a `MobEffectRef`.
+pub struct MobEffectArgument;
+
+impl CommandArgument for MobEffectArgument {
+    type Output = MobEffectRef;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let s = arg.first()?;
+        let key = s.strip_prefix("minecraft:").unwrap_or(s).to_owned();
+
+        REGISTRY
+            .mob_effects
+            .by_key(&Identifier::vanilla(key))
+            .map(|e| (&arg[1..], e))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (
+            ArgumentType::Resource {
+                identifier: "minecraft:mob_effect",
+            },
+            Some(SuggestionType::AskServer),
+        )
+    }
+
+    fn suggest(&self, prefix: &str, _suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        let stripped_prefix = prefix.strip_prefix("minecraft:").unwrap_or(prefix);
+        REGISTRY
+            .mob_effects
+            .iter()
+            .map(|(_, e)| SuggestionEntry::new(e.key.to_string()))
+            .filter(|s| {
+                s.text
+                    .strip_prefix("minecraft:")
+                    .unwrap_or(&s.text)
+                    .starts_with(stripped_prefix)
+            })
+            .collect()
+    }
+}
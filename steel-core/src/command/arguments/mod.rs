@@ -1,20 +1,44 @@
 //! This module contains types and utilities for parsing command arguments.
 pub mod anchor;
+pub mod angle;
 pub mod block_pos;
+pub mod block_state;
 pub mod bool;
+pub mod color;
+pub mod column_pos;
 pub mod domain;
+pub mod double;
 pub mod enchantment;
 pub mod entity;
 pub mod entity_type;
+pub mod enumeration;
 pub mod float;
 pub mod gamemode;
 pub mod integer;
 pub mod item;
+pub mod message;
+pub mod mob_effect;
+pub mod nbt_compound;
+pub mod nbt_path;
+pub mod nbt_tag;
+pub mod objective;
+pub mod objective_criteria;
+pub mod particle;
 pub mod player;
+pub mod range;
+pub mod resource_location;
+pub mod resource_or_tag;
 pub mod rotation;
+pub mod score_holder;
+pub mod scoreboard_slot;
+pub mod selector;
+pub mod sound;
+pub mod string;
 pub mod structure;
+pub mod team;
 pub mod text_component;
 pub mod time;
+pub mod uuid;
 pub mod vector2;
 pub mod vector3;
 pub mod world;
@@ -116,7 +140,7 @@ pub trait CommandArgument: Send + Sync {
     }
 }
 
-struct Helper;
+pub(crate) struct Helper;
 
 impl Helper {
     pub fn parse_relative_coordinate<const IS_Y: bool>(
@@ -246,6 +270,29 @@ mod tests {
         assert!(Helper::parse_local_coordinate_triplet(&["^", "~", "^"]).is_none());
     }
 
+    #[test]
+    fn world_coordinates_reject_local_prefix() {
+        assert!(Helper::parse_relative_coordinate::<false>("^", Some(0.0)).is_none());
+        assert!(Helper::parse_relative_coordinate::<true>("^1", Some(0.0)).is_none());
+    }
+
+    #[test]
+    fn world_coordinates_resolve_relative_and_absolute() {
+        assert_eq!(
+            Helper::parse_relative_coordinate::<false>("~2", Some(10.0)),
+            Some(12.0)
+        );
+        assert_eq!(
+            Helper::parse_relative_coordinate::<false>("3", None),
+            Some(3.5)
+        );
+        assert_eq!(
+            Helper::parse_relative_coordinate::<true>("3", None),
+            Some(3.0)
+        );
+        assert!(Helper::parse_relative_coordinate::<false>("~", None).is_none());
+    }
+
     #[test]
     fn local_coordinates_use_vanilla_yaw_axes() {
         let position = DVec3::new(10.0, 64.0, 20.0);
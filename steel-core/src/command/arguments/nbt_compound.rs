@@ -0,0 +1,100 @@
+//! An NBT compound argument.
+use simdnbt::owned::NbtCompound;
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+use steel_utils::snbt;
+
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+
+/// A vanilla `CompoundTagArgument`, parsing an SNBT compound such as `{Count:1b}`.
+pub struct NbtCompoundArgument;
+
+impl CommandArgument for NbtCompoundArgument {
+    type Output = NbtCompound;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        read_compound(arg)
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Nbt, None)
+    }
+}
+
+/// Parses the compound at the start of `arg`, which may span several tokens. The compound has to
+/// end on a token boundary, the tokens after it are returned.
+fn read_compound<'a>(arg: &'a [&'a str]) -> Option<(&'a [&'a str], NbtCompound)> {
+    read_compound_from(arg, 0)
+}
+
+/// Like [`read_compound`], but the compound starts `start` bytes into the first token.
+pub(super) fn read_compound_from<'a>(
+    arg: &'a [&'a str],
+    start: usize,
+) -> Option<(&'a [&'a str], NbtCompound)> {
+    read_spanning(arg, start, snbt::parse_compound_prefix)
+}
+
+/// Runs `parse` over the tokens joined back together from `start` bytes into the first one. What
+/// `parse` reads has to end on a token boundary, the tokens after it are returned.
+pub(super) fn read_spanning<'a, T>(
+    arg: &'a [&'a str],
+    start: usize,
+    parse: impl FnOnce(&str) -> Option<(T, usize)>,
+) -> Option<(&'a [&'a str], T)> {
+    let joined = arg.join(" ");
+    let (value, consumed) = parse(joined.get(start..)?)?;
+    let consumed = start + consumed;
+
+    let mut end = 0;
+    for (i, token) in arg.iter().enumerate() {
+        end += token.len();
+        if end == consumed {
+            return Some((&arg[i + 1..], value));
+        }
+        if end > consumed {
+            return None;
+        }
+        end += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use simdnbt::owned::NbtTag;
+
+    use super::read_compound;
+
+    #[test]
+    fn parses_nested_compound() {
+        let args = ["{Count:1b,tag:{display:{Name:\"x\"}}}"];
+        let (rest, compound) = read_compound(&args).expect("valid compound");
+
+        assert!(rest.is_empty());
+        assert_eq!(compound.get("Count"), Some(&NbtTag::Byte(1)));
+        assert!(compound.compound("tag").is_some());
+    }
+
+    #[test]
+    fn leaves_trailing_tokens() {
+        let args = ["{Name:\"two", "words\"}", "5"];
+        let (rest, compound) = read_compound(&args).expect("valid compound");
+
+        assert_eq!(rest, ["5"]);
+        assert_eq!(
+            compound.get("Name"),
+            Some(&NbtTag::String("two words".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_compound_ending_mid_token() {
+        assert!(read_compound(&["{a:1}5"]).is_none());
+        assert!(read_compound(&["{a:1"]).is_none());
+    }
+}
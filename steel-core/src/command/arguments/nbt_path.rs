@@ -0,0 +1,654 @@
+//! An NBT path argument.
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+use steel_utils::snbt;
+
+use crate::command::arguments::CommandArgument;
+use crate::command::arguments::nbt_compound::read_spanning;
+use crate::command::context::CommandContext;
+
+/// A vanilla `NbtPathArgument`, such as `Inventory[{Slot:0b}].components` or `"custom name"[]`.
+pub struct NbtPathArgument;
+
+impl CommandArgument for NbtPathArgument {
+    type Output = NbtPath;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        read_spanning(arg, 0, NbtPath::parse_prefix)
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::NbtPath, None)
+    }
+}
+
+/// A parsed NBT path, selecting tags below a compound.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NbtPath {
+    original: String,
+    nodes: Vec<NbtPathNode>,
+}
+
+/// One step of an [`NbtPath`].
+#[derive(Clone, Debug, PartialEq)]
+enum NbtPathNode {
+    /// `{pattern}` at the start, the root if it matches the pattern.
+    MatchRootObject(NbtCompound),
+    /// `name` or `"name"`, the field of a compound.
+    Named(String),
+    /// `name{pattern}`, the field of a compound if it matches the pattern.
+    MatchObject(String, NbtCompound),
+    /// `[index]`, an element of a list or array, counting from the end when negative.
+    Index(i32),
+    /// `[{pattern}]`, the elements of a list that match the pattern.
+    MatchElement(NbtCompound),
+    /// `[]`, every element of a list or array.
+    AllElements,
+}
+
+impl NbtPath {
+    /// Parses a path that spans all of `input`, or returns `None` when it is malformed.
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        let (path, len) = Self::parse_prefix(input)?;
+        (len == input.len()).then_some(path)
+    }
+
+    /// Parses the path at the start of `input`, which ends at the first space between nodes.
+    /// Returns it with the number of bytes it spans.
+    #[must_use]
+    pub fn parse_prefix(input: &str) -> Option<(Self, usize)> {
+        let mut nodes = Vec::new();
+        let mut pos = 0;
+        while pos < input.len() && !input[pos..].starts_with(' ') {
+            let (node, len) = NbtPathNode::parse(&input[pos..], nodes.is_empty())?;
+            nodes.push(node);
+            pos += len;
+            let rest = &input[pos..];
+            if !rest.is_empty() && !rest.starts_with([' ', '[', '{']) {
+                if !rest.starts_with('.') {
+                    return None;
+                }
+                pos += 1;
+            }
+        }
+        if nodes.is_empty() {
+            return None;
+        }
+        let path = Self {
+            original: input[..pos].to_owned(),
+            nodes,
+        };
+        Some((path, pos))
+    }
+
+    /// The tags the path leads to in `root`.
+    #[must_use]
+    pub fn get(&self, root: &NbtCompound) -> Vec<NbtTag> {
+        let mut tags = vec![NbtTag::Compound(root.clone())];
+        for node in &self.nodes {
+            tags = tags.iter().flat_map(|tag| node.get(tag)).collect();
+        }
+        tags
+    }
+
+    /// Vanilla `NbtPath.set`, writes `source` wherever the path leads, creating missing parents.
+    /// Returns how many tags changed.
+    pub fn set(&self, root: &mut NbtCompound, source: &NbtTag) -> usize {
+        let (last, parents) = self.split_last();
+        unwrap_infallible(with_root(root, |tag| {
+            walk(
+                parents,
+                tag,
+                Some(&|| last.preferred_parent()),
+                &mut |parent| Ok(last.set(parent, source)),
+            )
+        }))
+    }
+
+    /// Vanilla `NbtPath.remove`, returning how many tags were removed.
+    pub fn remove(&self, root: &mut NbtCompound) -> usize {
+        let (last, parents) = self.split_last();
+        unwrap_infallible(with_root(root, |tag| {
+            walk(parents, tag, None, &mut |parent| Ok(last.remove(parent)))
+        }))
+    }
+
+    /// Vanilla `NbtPath.getOrCreate`, runs `apply` on every tag the path leads to, inserting
+    /// `create()` where the last step is missing. Returns the sum of what `apply` returned.
+    pub fn get_or_create<E>(
+        &self,
+        root: &mut NbtCompound,
+        create: impl Fn() -> NbtTag,
+        mut apply: impl FnMut(&mut NbtTag) -> Result<usize, E>,
+    ) -> Result<usize, E> {
+        with_root(root, |tag| {
+            walk(&self.nodes, tag, Some(&create), &mut apply)
+        })
+    }
+
+    fn split_last(&self) -> (&NbtPathNode, &[NbtPathNode]) {
+        match self.nodes.split_last() {
+            Some(split) => split,
+            None => unreachable!("parsed paths have at least one node"),
+        }
+    }
+}
+
+impl Display for NbtPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.original)
+    }
+}
+
+/// Characters that end an unquoted name, vanilla's `isAllowedInUnquotedName`.
+const fn ends_unquoted_name(c: char) -> bool {
+    matches!(c, ' ' | '"' | '\'' | '[' | ']' | '.' | '{' | '}')
+}
+
+impl NbtPathNode {
+    /// Parses the node at the start of `input`, returning it and how many bytes it spans. Root
+    /// patterns are only allowed as the first node.
+    fn parse(input: &str, first: bool) -> Option<(Self, usize)> {
+        if input.starts_with('{') {
+            if !first {
+                return None;
+            }
+            let (pattern, len) = snbt::parse_compound_prefix(input)?;
+            return Some((Self::MatchRootObject(pattern), len));
+        }
+
+        if let Some(inner) = input.strip_prefix('[') {
+            if inner.starts_with('{') {
+                let (pattern, len) = snbt::parse_compound_prefix(inner)?;
+                if !inner[len..].starts_with(']') {
+                    return None;
+                }
+                return Some((Self::MatchElement(pattern), len + 2));
+            }
+            let close = inner.find(']')?;
+            let index = &inner[..close];
+            let node = if index.is_empty() {
+                Self::AllElements
+            } else {
+                Self::Index(index.parse().ok()?)
+            };
+            return Some((node, close + 2));
+        }
+
+        let (name, len) = if input.starts_with(['"', '\'']) {
+            let (NbtTag::String(name), len) = snbt::parse_tag_prefix(input)? else {
+                return None;
+            };
+            (name.to_str().into_owned(), len)
+        } else {
+            let len = input.find(ends_unquoted_name).unwrap_or(input.len());
+            if len == 0 {
+                return None;
+            }
+            (input[..len].to_owned(), len)
+        };
+
+        if input[len..].starts_with('{') {
+            let (pattern, pattern_len) = snbt::parse_compound_prefix(&input[len..])?;
+            return Some((Self::MatchObject(name, pattern), len + pattern_len));
+        }
+        Some((Self::Named(name), len))
+    }
+
+    /// The children of `tag` this node matches.
+    fn get(&self, tag: &NbtTag) -> Vec<NbtTag> {
+        match self {
+            Self::MatchRootObject(pattern) => {
+                if matches_pattern(pattern, tag) {
+                    vec![tag.clone()]
+                } else {
+                    Vec::new()
+                }
+            }
+            Self::Named(name) => child(tag, name).cloned().into_iter().collect(),
+            Self::MatchObject(name, pattern) => child(tag, name)
+                .filter(|child| matches_pattern(pattern, child))
+                .cloned()
+                .into_iter()
+                .collect(),
+            Self::Index(index) => {
+                let Some(elements) = elements(tag) else {
+                    return Vec::new();
+                };
+                resolve_index(*index, elements.len())
+                    .map(|i| elements[i].clone())
+                    .into_iter()
+                    .collect()
+            }
+            Self::MatchElement(pattern) => elements(tag)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|element| matches_pattern(pattern, element))
+                .collect(),
+            Self::AllElements => elements(tag).unwrap_or_default(),
+        }
+    }
+
+    /// Runs `apply` on the children of `tag` this node matches, adding `create` as the child
+    /// when none matches and the node can name one.
+    fn visit<E>(
+        &self,
+        tag: &mut NbtTag,
+        create: Option<NbtTag>,
+        apply: &mut dyn FnMut(&mut NbtTag) -> Result<usize, E>,
+    ) -> Result<usize, E> {
+        match self {
+            Self::MatchRootObject(pattern) => {
+                if matches_pattern(pattern, tag) {
+                    apply(tag)
+                } else {
+                    Ok(0)
+                }
+            }
+            Self::Named(name) => {
+                let NbtTag::Compound(compound) = tag else {
+                    return Ok(0);
+                };
+                if compound.get(name).is_none() {
+                    let Some(create) = create else {
+                        return Ok(0);
+                    };
+                    compound.insert(name.as_str(), create);
+                }
+                match compound.get_mut(name) {
+                    Some(child) => apply(child),
+                    None => Ok(0),
+                }
+            }
+            Self::MatchObject(name, pattern) => {
+                let NbtTag::Compound(compound) = tag else {
+                    return Ok(0);
+                };
+                if compound.get(name).is_none() {
+                    if create.is_none() {
+                        return Ok(0);
+                    }
+                    compound.insert(name.as_str(), NbtTag::Compound(pattern.clone()));
+                }
+                match compound.get_mut(name) {
+                    Some(child) if matches_pattern(pattern, child) => apply(child),
+                    _ => Ok(0),
+                }
+            }
+            Self::Index(index) => with_elements(tag, |elements| {
+                match resolve_index(*index, elements.len()) {
+                    Some(i) => apply(&mut elements[i]),
+                    None => Ok(0),
+                }
+            }),
+            Self::MatchElement(pattern) => with_elements(tag, |elements| {
+                if create.is_some()
+                    && !elements
+                        .iter()
+                        .any(|element| matches_pattern(pattern, element))
+                {
+                    elements.push(NbtTag::Compound(pattern.clone()));
+                }
+                let mut changed = 0;
+                for element in elements {
+                    if matches_pattern(pattern, element) {
+                        changed += apply(element)?;
+                    }
+                }
+                Ok(changed)
+            }),
+            Self::AllElements => with_elements(tag, |elements| {
+                if elements.is_empty()
+                    && let Some(create) = create
+                {
+                    elements.push(create);
+                }
+                let mut changed = 0;
+                for element in elements {
+                    changed += apply(element)?;
+                }
+                Ok(changed)
+            }),
+        }
+    }
+
+    /// Writes `source` as the children of `parent` this node matches.
+    fn set(&self, parent: &mut NbtTag, source: &NbtTag) -> usize {
+        match self {
+            Self::MatchRootObject(_) => 0,
+            Self::Named(name) => {
+                let NbtTag::Compound(compound) = parent else {
+                    return 0;
+                };
+                if compound.get(name) == Some(source) {
+                    return 0;
+                }
+                let _ = compound.remove(name);
+                compound.insert(name.as_str(), source.clone());
+                1
+            }
+            Self::MatchObject(name, pattern) => {
+                let NbtTag::Compound(compound) = parent else {
+                    return 0;
+                };
+                match compound.get(name) {
+                    Some(child) if matches_pattern(pattern, child) && child != source => {}
+                    _ => return 0,
+                }
+                let _ = compound.remove(name);
+                compound.insert(name.as_str(), source.clone());
+                1
+            }
+            Self::Index(index) => unwrap_infallible(with_elements(parent, |elements| {
+                Ok(match resolve_index(*index, elements.len()) {
+                    Some(i) if elements[i] != *source => {
+                        elements[i] = source.clone();
+                        1
+                    }
+                    _ => 0,
+                })
+            })),
+            Self::MatchElement(pattern) => unwrap_infallible(with_elements(parent, |elements| {
+                let mut changed = 0;
+                for element in elements
+                    .iter_mut()
+                    .filter(|element| matches_pattern(pattern, element))
+                {
+                    if *element != *source {
+                        *element = source.clone();
+                        changed += 1;
+                    }
+                }
+                Ok(changed)
+            })),
+            Self::AllElements => unwrap_infallible(with_elements(parent, |elements| {
+                if elements.is_empty() {
+                    elements.push(source.clone());
+                    return Ok(1);
+                }
+                let changed = elements.iter().filter(|element| *element != source).count();
+                elements.fill(source.clone());
+                Ok(changed)
+            })),
+        }
+    }
+
+    /// Removes the children of `parent` this node matches, returning how many.
+    fn remove(&self, parent: &mut NbtTag) -> usize {
+        match self {
+            Self::MatchRootObject(_) => 0,
+            Self::Named(name) => match parent {
+                NbtTag::Compound(compound) => usize::from(compound.remove(name).is_some()),
+                _ => 0,
+            },
+            Self::MatchObject(name, pattern) => match parent {
+                NbtTag::Compound(compound)
+                    if compound
+                        .get(name)
+                        .is_some_and(|child| matches_pattern(pattern, child)) =>
+                {
+                    usize::from(compound.remove(name).is_some())
+                }
+                _ => 0,
+            },
+            Self::Index(index) => unwrap_infallible(with_elements(parent, |elements| {
+                Ok(match resolve_index(*index, elements.len()) {
+                    Some(i) => {
+                        elements.remove(i);
+                        1
+                    }
+                    None => 0,
+                })
+            })),
+            Self::MatchElement(pattern) => unwrap_infallible(with_elements(parent, |elements| {
+                let before = elements.len();
+                elements.retain(|element| !matches_pattern(pattern, element));
+                Ok(before - elements.len())
+            })),
+            Self::AllElements => unwrap_infallible(with_elements(parent, |elements| {
+                let removed = elements.len();
+                elements.clear();
+                Ok(removed)
+            })),
+        }
+    }
+
+    /// The tag to create when this node needs a missing parent.
+    fn preferred_parent(&self) -> NbtTag {
+        match self {
+            Self::MatchRootObject(_) | Self::Named(_) | Self::MatchObject(..) => {
+                NbtTag::Compound(NbtCompound::new())
+            }
+            Self::Index(_) | Self::MatchElement(_) | Self::AllElements => {
+                NbtTag::List(NbtList::Empty)
+            }
+        }
+    }
+}
+
+/// The field `name` of `tag`, if it is a compound.
+fn child<'a>(tag: &'a NbtTag, name: &str) -> Option<&'a NbtTag> {
+    match tag {
+        NbtTag::Compound(compound) => compound.get(name),
+        _ => None,
+    }
+}
+
+/// Vanilla `NbtUtils.compareNbt` with partial lists: every field of the pattern has to match,
+/// and every element of a pattern list has to match some element of the list.
+fn matches_pattern(pattern: &NbtCompound, tag: &NbtTag) -> bool {
+    let NbtTag::Compound(compound) = tag else {
+        return false;
+    };
+    pattern.iter().all(|(key, expected)| {
+        compound
+            .get(&key.to_str())
+            .is_some_and(|actual| tag_matches(expected, actual))
+    })
+}
+
+fn tag_matches(expected: &NbtTag, actual: &NbtTag) -> bool {
+    match (expected, actual) {
+        (NbtTag::Compound(pattern), _) => matches_pattern(pattern, actual),
+        (NbtTag::List(expected), NbtTag::List(actual)) => {
+            let expected = snbt::tags_from_list(expected.clone());
+            let actual = snbt::tags_from_list(actual.clone());
+            if expected.is_empty() {
+                return actual.is_empty();
+            }
+            expected
+                .iter()
+                .all(|expected| actual.iter().any(|actual| tag_matches(expected, actual)))
+        }
+        _ => expected == actual,
+    }
+}
+
+/// Follows `nodes` down from `tag` and runs `apply` where they end. With `create`, missing
+/// children are added on the way, calling `create` for the last one.
+fn walk<E>(
+    nodes: &[NbtPathNode],
+    tag: &mut NbtTag,
+    create: Option<&dyn Fn() -> NbtTag>,
+    apply: &mut dyn FnMut(&mut NbtTag) -> Result<usize, E>,
+) -> Result<usize, E> {
+    let Some((node, rest)) = nodes.split_first() else {
+        return apply(tag);
+    };
+    let child = create.map(|create| match rest.first() {
+        Some(next) => next.preferred_parent(),
+        None => create(),
+    });
+    node.visit(tag, child, &mut |child| walk(rest, child, create, apply))
+}
+
+/// Runs `f` with `root` as a tag, putting it back afterwards.
+fn with_root<E>(
+    root: &mut NbtCompound,
+    f: impl FnOnce(&mut NbtTag) -> Result<usize, E>,
+) -> Result<usize, E> {
+    let mut tag = NbtTag::Compound(mem::take(root));
+    let result = f(&mut tag);
+    if let NbtTag::Compound(compound) = tag {
+        *root = compound;
+    }
+    result
+}
+
+fn unwrap_infallible(result: Result<usize, Infallible>) -> usize {
+    let Ok(value) = result;
+    value
+}
+
+/// The position `index` points at in a collection of `len` elements.
+fn resolve_index(index: i32, len: usize) -> Option<usize> {
+    let len = i64::try_from(len).ok()?;
+    let index = if index < 0 {
+        len + i64::from(index)
+    } else {
+        i64::from(index)
+    };
+    usize::try_from(index).ok().filter(|_| index < len)
+}
+
+/// The elements of a list or array.
+fn elements(tag: &NbtTag) -> Option<Vec<NbtTag>> {
+    Some(match tag {
+        NbtTag::List(list) => snbt::tags_from_list(list.clone()),
+        NbtTag::ByteArray(bytes) => bytes
+            .iter()
+            .map(|byte| NbtTag::Byte(byte.cast_signed()))
+            .collect(),
+        NbtTag::IntArray(ints) => ints.iter().copied().map(NbtTag::Int).collect(),
+        NbtTag::LongArray(longs) => longs.iter().copied().map(NbtTag::Long).collect(),
+        _ => return None,
+    })
+}
+
+/// Runs `f` over the elements of the list or array `tag` and writes them back when it reports a
+/// change. Nothing changes if an element no longer fits the collection's type.
+pub(crate) fn with_elements<E>(
+    tag: &mut NbtTag,
+    f: impl FnOnce(&mut Vec<NbtTag>) -> Result<usize, E>,
+) -> Result<usize, E> {
+    let Some(mut elements) = elements(tag) else {
+        return Ok(0);
+    };
+    let changed = f(&mut elements)?;
+    if changed == 0 {
+        return Ok(0);
+    }
+
+    let rebuilt = match tag {
+        NbtTag::ByteArray(_) => elements
+            .into_iter()
+            .map(|element| match element {
+                NbtTag::Byte(byte) => Some(byte.cast_unsigned()),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(NbtTag::ByteArray),
+        NbtTag::IntArray(_) => elements
+            .into_iter()
+            .map(|element| match element {
+                NbtTag::Int(int) => Some(int),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(NbtTag::IntArray),
+        NbtTag::LongArray(_) => elements
+            .into_iter()
+            .map(|element| match element {
+                NbtTag::Long(long) => Some(long),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(NbtTag::LongArray),
+        _ => snbt::list_from_tags(elements).map(NbtTag::List),
+    };
+    Ok(match rebuilt {
+        Some(rebuilt) => {
+            *tag = rebuilt;
+            changed
+        }
+        None => 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use simdnbt::owned::NbtTag;
+    use steel_utils::snbt::parse_compound;
+
+    use super::NbtPath;
+
+    fn path(input: &str) -> NbtPath {
+        NbtPath::parse(input).expect("valid path")
+    }
+
+    #[test]
+    fn indexes_into_lists() {
+        let data = parse_compound("{foo:{bar:[{baz:1},{baz:2},{baz:3}]}}").expect("valid snbt");
+
+        assert_eq!(path("foo.bar[0].baz").get(&data), [NbtTag::Int(1)]);
+        assert_eq!(path("foo.bar[-1].baz").get(&data), [NbtTag::Int(3)]);
+        assert_eq!(path("foo.bar[].baz").get(&data).len(), 3);
+        assert!(path("foo.bar[3]").get(&data).is_empty());
+    }
+
+    #[test]
+    fn filters_by_compound_match() {
+        let data =
+            parse_compound("{list:[{id:\"x\",n:1},{id:\"y\",n:2},{id:\"x\",n:3}],item:{id:\"y\"}}")
+                .expect("valid snbt");
+
+        assert_eq!(
+            path("list[{id:\"x\"}].n").get(&data),
+            [NbtTag::Int(1), NbtTag::Int(3)]
+        );
+        assert_eq!(path("item{id:\"y\"}.id").get(&data).len(), 1);
+        assert!(path("item{id:\"x\"}").get(&data).is_empty());
+        assert_eq!(
+            path("{item:{id:\"y\"}}.list[1].n").get(&data),
+            [NbtTag::Int(2)]
+        );
+        assert!(path("{item:{id:\"x\"}}.list").get(&data).is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        for input in [
+            "foo[0",
+            "foo]",
+            "foo[{id:1}",
+            "foo..bar",
+            "foo{a:1",
+            "a.{b:1}",
+            "[x]",
+        ] {
+            assert!(NbtPath::parse(input).is_none(), "{input} should not parse");
+        }
+    }
+
+    #[test]
+    fn sets_and_removes_matching_elements() {
+        let mut data = parse_compound("{list:[{id:\"x\"},{id:\"y\"}]}").expect("valid snbt");
+
+        assert_eq!(
+            path("list[{id:\"y\"}].n").set(&mut data, &NbtTag::Int(5)),
+            1
+        );
+        assert_eq!(path("list[1].n").get(&data), [NbtTag::Int(5)]);
+        assert_eq!(path("list[{id:\"x\"}]").remove(&mut data), 1);
+        assert_eq!(path("list[]").get(&data).len(), 1);
+    }
+}
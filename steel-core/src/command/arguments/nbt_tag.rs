@@ -0,0 +1,27 @@
+//! An NBT tag argument.
+use simdnbt::owned::NbtTag;
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+use steel_utils::snbt;
+
+use crate::command::arguments::CommandArgument;
+use crate::command::arguments::nbt_compound::read_spanning;
+use crate::command::context::CommandContext;
+
+/// A vanilla `NbtTagArgument`, parsing any SNBT value such as `5b`, `"text"` or `[1,2]`.
+pub struct NbtTagArgument;
+
+impl CommandArgument for NbtTagArgument {
+    type Output = NbtTag;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        read_spanning(arg, 0, snbt::parse_tag_prefix)
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::NbtTag, None)
+    }
+}
@@ -0,0 +1,115 @@
+//! A scoreboard objective argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+
+use crate::command::arguments::{CommandArgument, SuggestionContext};
+use crate::command::context::CommandContext;
+use crate::scoreboard::Scoreboard;
+
+/// The longest objective name.
+const MAX_LENGTH: usize = 16;
+
+/// The name of a scoreboard objective, vanilla's `ObjectiveArgument`.
+pub struct ObjectiveArgument {
+    /// Whether the objective must already exist.
+    existing: bool,
+}
+
+impl ObjectiveArgument {
+    /// Any well-formed objective name.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { existing: false }
+    }
+
+    /// The name of an objective on the scoreboard.
+    #[must_use]
+    pub const fn existing() -> Self {
+        Self { existing: true }
+    }
+
+    fn accepts(&self, name: &str, scoreboard: &Scoreboard) -> bool {
+        is_valid_name(name) && (!self.existing || scoreboard.objective(name).is_some())
+    }
+}
+
+impl Default for ObjectiveArgument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `name` fits the length limit and only uses unquoted string characters.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_LENGTH
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+'))
+}
+
+impl CommandArgument for ObjectiveArgument {
+    type Output = String;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let name = *arg.first()?;
+        if !self.accepts(name, &context.server.scoreboard.lock()) {
+            return None;
+        }
+        Some((&arg[1..], name.to_owned()))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Objective, Some(SuggestionType::AskServer))
+    }
+
+    fn suggest(&self, prefix: &str, suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        suggestion_ctx
+            .server
+            .scoreboard
+            .lock()
+            .objectives()
+            .into_iter()
+            .filter(|objective| objective.name.starts_with(prefix))
+            .map(|objective| SuggestionEntry::new(objective.name.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use text_components::TextComponent;
+
+    use super::ObjectiveArgument;
+    use crate::scoreboard::{ObjectiveCriteria, Scoreboard};
+
+    fn scoreboard() -> Scoreboard {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.add_objective(
+            "kills".to_owned(),
+            ObjectiveCriteria::Dummy,
+            TextComponent::plain("Kills"),
+        );
+        scoreboard
+    }
+
+    #[test]
+    fn existing_objectives_are_accepted() {
+        let scoreboard = scoreboard();
+
+        assert!(ObjectiveArgument::existing().accepts("kills", &scoreboard));
+        assert!(!ObjectiveArgument::existing().accepts("kills!", &scoreboard));
+    }
+
+    #[test]
+    fn unknown_objectives_need_the_plain_variant() {
+        let scoreboard = scoreboard();
+
+        assert!(!ObjectiveArgument::existing().accepts("deaths", &scoreboard));
+        assert!(ObjectiveArgument::new().accepts("deaths", &scoreboard));
+        assert!(!ObjectiveArgument::new().accepts("a_very_long_objective", &scoreboard));
+    }
+}
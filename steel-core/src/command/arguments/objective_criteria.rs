@@ -0,0 +1,38 @@
+//! A scoreboard objective criteria argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+
+use crate::command::arguments::{CommandArgument, SuggestionContext};
+use crate::command::context::CommandContext;
+use crate::scoreboard::ObjectiveCriteria;
+
+/// What updates an objective's scores, vanilla's `ObjectiveCriteriaArgument`.
+pub struct ObjectiveCriteriaArgument;
+
+impl CommandArgument for ObjectiveCriteriaArgument {
+    type Output = ObjectiveCriteria;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let criteria = ObjectiveCriteria::from_name(arg.first()?)?;
+        Some((&arg[1..], criteria))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (
+            ArgumentType::ObjectiveCriteria,
+            Some(SuggestionType::AskServer),
+        )
+    }
+
+    fn suggest(&self, prefix: &str, _suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        ObjectiveCriteria::ALL
+            .into_iter()
+            .map(ObjectiveCriteria::name)
+            .filter(|name| name.starts_with(prefix))
+            .map(SuggestionEntry::new)
+            .collect()
+    }
+}
@@ -0,0 +1,93 @@
+//! A particle argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+use steel_registry::{REGISTRY, RegistryExt, particle_type::ParticleTypeRef};
+
+use crate::command::{
+    arguments::{CommandArgument, SuggestionContext, resource_location::parse_identifier},
+    context::CommandContext,
+};
+
+/// A vanilla `ParticleArgument`.
+// TODO: parse the particle options once the extracted particle data says which types take them
+pub struct ParticleArgument;
+
+/// A particle type as given to a command.
+pub struct ParsedParticle {
+    /// The particle type.
+    pub particle: ParticleTypeRef,
+    /// The SNBT options written right after the id, as in `dust{scale:2}`. They are kept as
+    /// written since they can't be parsed yet.
+    pub options: Option<String>,
+}
+
+impl ParticleArgument {
+    fn resolve(input: &str) -> Option<ParsedParticle> {
+        let (id, options) = match input.find('{') {
+            Some(start) => (&input[..start], Some(input[start..].to_owned())),
+            None => (input, None),
+        };
+        let key = parse_identifier(id)?;
+        let particle = REGISTRY.particle_types.by_key(&key)?;
+        Some(ParsedParticle { particle, options })
+    }
+}
+
+impl CommandArgument for ParticleArgument {
+    type Output = ParsedParticle;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        Self::resolve(arg.first()?).map(|particle| (&arg[1..], particle))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Particle, Some(SuggestionType::AskServer))
+    }
+
+    fn suggest(&self, prefix: &str, _suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        let stripped_prefix = prefix.strip_prefix("minecraft:").unwrap_or(prefix);
+        REGISTRY
+            .particle_types
+            .iter()
+            .map(|(_, particle)| SuggestionEntry::new(particle.key.to_string()))
+            .filter(|suggestion| {
+                suggestion
+                    .text
+                    .strip_prefix("minecraft:")
+                    .unwrap_or(&suggestion.text)
+                    .starts_with(stripped_prefix)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+
+    use super::ParticleArgument;
+
+    #[test]
+    fn resolves_particles_by_key() {
+        init_test_registry();
+
+        let flame = ParticleArgument::resolve("flame").expect("flame should resolve");
+        assert_eq!(flame.particle.key.path, "flame");
+        assert!(flame.options.is_none());
+        assert!(ParticleArgument::resolve("minecraft:heart").is_some());
+        assert!(ParticleArgument::resolve("not_a_particle").is_none());
+    }
+
+    #[test]
+    fn keeps_the_options_after_the_id() {
+        init_test_registry();
+
+        let dust = ParticleArgument::resolve("dust{scale:2}").expect("dust should resolve");
+        assert_eq!(dust.particle.key.path, "dust");
+        assert_eq!(dust.options.as_deref(), Some("{scale:2}"));
+        assert!(ParticleArgument::resolve("not_a_particle{}").is_none());
+    }
+}
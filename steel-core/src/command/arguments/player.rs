@@ -1,17 +1,15 @@
 //! A player argument.
 use crate::command::arguments::CommandArgument;
 use crate::command::arguments::SuggestionContext;
+use crate::command::arguments::selector::resolve_players;
 use crate::command::context::CommandContext;
-use crate::entity::Entity;
 use crate::player::Player;
-use rand::seq::IteratorRandom;
 use std::sync::Arc;
 use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
 use steel_utils::translations::{
     ARGUMENT_ENTITY_SELECTOR_ALL_PLAYERS, ARGUMENT_ENTITY_SELECTOR_NEAREST_PLAYER,
     ARGUMENT_ENTITY_SELECTOR_RANDOM_PLAYER, ARGUMENT_ENTITY_SELECTOR_SELF,
 };
-use uuid::Uuid;
 
 /// A player argument.
 #[derive(Default)]
@@ -40,54 +38,8 @@ impl CommandArgument for PlayerArgument {
         arg: &'a [&'a str],
         context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        let players = context.server.get_players();
-        if arg.is_empty() {
-            return None;
-        }
-        if players.is_empty() {
-            return Some((&arg[1..], vec![]));
-        }
-        let entities = match arg[0] {
-            "@a" => players,
-            "@p" => {
-                let position = context.position;
-                let mut near_dist = (f64::MAX, players[0].clone());
-                for player in players {
-                    let dist = player.position().distance_squared(position);
-                    if dist < near_dist.0 {
-                        near_dist = (dist, player);
-                    }
-                }
-                vec![near_dist.1]
-            }
-            "@r" => {
-                vec![players.into_iter().choose(&mut rand::rng())?]
-            }
-            "@s" => {
-                if let Some(player) = &context.player {
-                    vec![player.clone()]
-                } else {
-                    vec![]
-                }
-            }
-            name => {
-                let uuid = if let Ok(uuid) = Uuid::parse_str(name) {
-                    uuid
-                } else {
-                    Uuid::nil()
-                };
-                let player = players.into_iter().find_map(|p| {
-                    if p.gameprofile.name == name || p.uuid() == uuid {
-                        Some(p)
-                    } else {
-                        None
-                    }
-                })?;
-                vec![player]
-            }
-        };
-        // TODO: Add entity argiments. (e.g. @e[limit=1])
-        Some((&arg[1..], entities))
+        let players = resolve_players(arg.first()?, context, self.one, true)?;
+        Some((&arg[1..], players))
     }
 
     fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
@@ -0,0 +1,72 @@
+//! An integer range argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+
+/// A vanilla `RangeArgument.Ints`, written as `3`, `3..`, `..7` or `3..7`. Bounds are inclusive,
+/// and `None` means unbounded.
+pub struct RangeArgument;
+
+impl CommandArgument for RangeArgument {
+    type Output = (Option<i32>, Option<i32>);
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let range = parse_range(arg.first()?)?;
+        Some((&arg[1..], range))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::IntRange, None)
+    }
+}
+
+fn parse_range(s: &str) -> Option<(Option<i32>, Option<i32>)> {
+    let Some((min, max)) = s.split_once("..") else {
+        let value = s.parse().ok()?;
+        return Some((Some(value), Some(value)));
+    };
+
+    let parse_bound = |bound: &str| {
+        if bound.is_empty() {
+            Some(None)
+        } else {
+            bound.parse().ok().map(Some)
+        }
+    };
+    let (min, max) = (parse_bound(min)?, parse_bound(max)?);
+
+    match (min, max) {
+        // `argument.range.empty`
+        (None, None) => None,
+        // `argument.range.swapped`
+        (Some(min), Some(max)) if min > max => None,
+        range => Some(range),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_range;
+
+    #[test]
+    fn parses_all_bound_forms() {
+        assert_eq!(parse_range("3"), Some((Some(3), Some(3))));
+        assert_eq!(parse_range("3.."), Some((Some(3), None)));
+        assert_eq!(parse_range("..7"), Some((None, Some(7))));
+        assert_eq!(parse_range("3..7"), Some((Some(3), Some(7))));
+        assert_eq!(parse_range("-5..-1"), Some((Some(-5), Some(-1))));
+    }
+
+    #[test]
+    fn rejects_empty_swapped_and_malformed_ranges() {
+        assert_eq!(parse_range(".."), None);
+        assert_eq!(parse_range("7..3"), None);
+        assert_eq!(parse_range("1.5..3"), None);
+        assert_eq!(parse_range("a..b"), None);
+    }
+}
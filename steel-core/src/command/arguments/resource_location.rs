@@ -0,0 +1,65 @@
+//! A resource location argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+use steel_utils::Identifier;
+
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+
+/// A `namespace:path` argument. The namespace defaults to `minecraft` when omitted.
+pub struct ResourceLocationArgument;
+
+impl CommandArgument for ResourceLocationArgument {
+    type Output = Identifier;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let identifier = parse_identifier(arg.first()?)?;
+        Some((&arg[1..], identifier))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::ResourceLocation, None)
+    }
+}
+
+/// Parses a resource location, defaulting to the vanilla namespace.
+pub(crate) fn parse_identifier(s: &str) -> Option<Identifier> {
+    let (namespace, path) = s
+        .split_once(':')
+        .unwrap_or((Identifier::VANILLA_NAMESPACE, s));
+    Identifier::validate(namespace, path)
+        .then(|| Identifier::new(namespace.to_owned(), path.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::Identifier;
+
+    use super::parse_identifier;
+
+    #[test]
+    fn defaults_to_vanilla_namespace() {
+        assert_eq!(
+            parse_identifier("stone"),
+            Some(Identifier::vanilla_static("stone"))
+        );
+        assert_eq!(
+            parse_identifier("minecraft:stone"),
+            Some(Identifier::vanilla_static("stone"))
+        );
+        assert_eq!(
+            parse_identifier("steel:worlds/main"),
+            Some(Identifier::new("steel", "worlds/main"))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_characters() {
+        assert_eq!(parse_identifier("Stone"), None);
+        assert_eq!(parse_identifier("my mod:stone"), None);
+        assert_eq!(parse_identifier("a/b:stone"), None);
+    }
+}
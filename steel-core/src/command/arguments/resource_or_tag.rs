@@ -0,0 +1,159 @@
+//! A resource-or-tag argument over a tagged registry.
+
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+use steel_registry::biome::BiomeRegistry;
+use steel_registry::poi::PoiTypeRegistry;
+use steel_registry::{REGISTRY, RegistryEntry, RegistryExt, TaggedRegistryExt};
+use steel_utils::Identifier;
+
+use crate::command::{
+    arguments::{CommandArgument, SuggestionContext, resource_location::parse_identifier},
+    context::CommandContext,
+};
+
+/// An argument that resolves to either one registry entry or a `#` tag of entries.
+pub struct ResourceOrTagArgument<R: 'static> {
+    registry: fn() -> &'static R,
+    identifier: &'static str,
+}
+
+impl ResourceOrTagArgument<BiomeRegistry> {
+    /// A biome or biome tag.
+    #[must_use]
+    pub fn biome() -> Self {
+        Self {
+            registry: || &REGISTRY.biomes,
+            identifier: "minecraft:worldgen/biome",
+        }
+    }
+}
+
+impl ResourceOrTagArgument<PoiTypeRegistry> {
+    /// A point of interest type or point of interest type tag.
+    #[must_use]
+    pub fn poi_type() -> Self {
+        Self {
+            registry: || &REGISTRY.poi_types,
+            identifier: "minecraft:point_of_interest_type",
+        }
+    }
+}
+
+/// Resource-or-tag argument value: either one entry or a tag of entries.
+pub enum ResourceOrTag<T: 'static> {
+    /// A single entry.
+    Resource(&'static T),
+    /// A tag and its resolved entries.
+    Tag {
+        /// Tag key without the leading `#`.
+        key: Identifier,
+        /// Entries in the tag.
+        entries: Vec<&'static T>,
+    },
+}
+
+impl<T: RegistryEntry> ResourceOrTag<T> {
+    /// Whether `entry` is the resource or one of the tag's entries.
+    #[must_use]
+    pub fn matches(&self, entry: &T) -> bool {
+        match self {
+            Self::Resource(resource) => *resource == entry,
+            Self::Tag { entries, .. } => entries.iter().any(|candidate| *candidate == entry),
+        }
+    }
+
+    /// Printable command target, naming the entry found for a tag.
+    #[must_use]
+    pub fn printable_name(&self, found: &Identifier) -> String {
+        match self {
+            Self::Resource(resource) => resource.key().to_string(),
+            Self::Tag { key, .. } => format!("#{key} ({found})"),
+        }
+    }
+
+    /// Printable command target without resolved found entry.
+    #[must_use]
+    pub fn query_name(&self) -> String {
+        match self {
+            Self::Resource(resource) => resource.key().to_string(),
+            Self::Tag { key, .. } => format!("#{key}"),
+        }
+    }
+}
+
+impl<R> CommandArgument for ResourceOrTagArgument<R>
+where
+    R: TaggedRegistryExt + Send + Sync + 'static,
+{
+    type Output = ResourceOrTag<R::Entry>;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let s = arg.first()?;
+        let registry = (self.registry)();
+        if let Some(tag) = s.strip_prefix('#') {
+            let key = parse_identifier(tag)?;
+            let entries = registry.get_tag(&key)?;
+            if entries.is_empty() {
+                return None;
+            }
+            return Some((&arg[1..], ResourceOrTag::Tag { key, entries }));
+        }
+
+        let key = parse_identifier(s)?;
+        registry
+            .by_key(&key)
+            .map(|entry| (&arg[1..], ResourceOrTag::Resource(entry)))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (
+            ArgumentType::ResourceOrTag {
+                identifier: self.identifier,
+            },
+            Some(SuggestionType::AskServer),
+        )
+    }
+
+    fn suggest(&self, prefix: &str, _suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        let registry = (self.registry)();
+        let tags = registry
+            .tag_keys()
+            .map(|key| SuggestionEntry::new(format!("#{key}")));
+        if prefix.starts_with('#') {
+            let stripped_prefix = prefix
+                .strip_prefix("#minecraft:")
+                .or_else(|| prefix.strip_prefix('#'))
+                .unwrap_or(prefix);
+            return tags
+                .filter(|suggestion| {
+                    suggestion
+                        .text
+                        .strip_prefix("#minecraft:")
+                        .or_else(|| suggestion.text.strip_prefix('#'))
+                        .unwrap_or(&suggestion.text)
+                        .starts_with(stripped_prefix)
+                })
+                .collect();
+        }
+
+        let stripped_prefix = prefix.strip_prefix("minecraft:").unwrap_or(prefix);
+        (0..registry.len())
+            .filter_map(|id| registry.by_id(id))
+            .map(|entry| SuggestionEntry::new(entry.key().to_string()))
+            .chain(tags)
+            .filter(|suggestion| {
+                let text = suggestion
+                    .text
+                    .strip_prefix('#')
+                    .unwrap_or(&suggestion.text);
+                text.strip_prefix("minecraft:")
+                    .unwrap_or(text)
+                    .starts_with(stripped_prefix)
+            })
+            .collect()
+    }
+}
@@ -15,11 +15,9 @@ impl CommandArgument for RotationArgument {
         arg: &'a [&'a str],
         context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        let (origin_yaw, origin_pitch) = context.rotation.unwrap_or((0.0, 0.0));
-        let yaw = parse_rotation_coordinate(arg.first()?, origin_yaw)?;
-        let pitch = parse_rotation_coordinate(arg.get(1)?, origin_pitch)?;
+        let rotation = parse_rotation(arg, context.rotation.unwrap_or((0.0, 0.0)))?;
 
-        Some((&arg[2..], normalize_rotation((yaw, pitch))))
+        Some((&arg[2..], rotation))
     }
 
     fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
@@ -27,6 +25,14 @@ impl CommandArgument for RotationArgument {
     }
 }
 
+/// Resolves the `<yaw> <pitch>` tokens against the source's `(yaw, pitch)`.
+fn parse_rotation(arg: &[&str], (origin_yaw, origin_pitch): (f32, f32)) -> Option<(f32, f32)> {
+    let yaw = parse_rotation_coordinate(arg.first()?, origin_yaw)?;
+    let pitch = parse_rotation_coordinate(arg.get(1)?, origin_pitch)?;
+
+    Some(normalize_rotation((yaw, pitch)))
+}
+
 fn parse_rotation_coordinate(value: &str, origin: f32) -> Option<f32> {
     if value.starts_with('^') {
         return None;
@@ -43,22 +49,19 @@ fn parse_rotation_coordinate(value: &str, origin: f32) -> Option<f32> {
     }
 }
 
-fn normalize_rotation((mut yaw, mut pitch): (f32, f32)) -> (f32, f32) {
+/// Wraps the yaw into `[-180, 180)` and clamps the pitch to `[-90, 90]`.
+fn normalize_rotation((mut yaw, pitch): (f32, f32)) -> (f32, f32) {
     yaw = yaw.rem_euclid(360.0);
     if yaw >= 180.0 {
         yaw -= 360.0;
     }
-    pitch = pitch.rem_euclid(360.0);
-    if pitch >= 180.0 {
-        pitch -= 360.0;
-    }
 
-    (yaw, pitch)
+    (yaw, pitch.clamp(-90.0, 90.0))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{normalize_rotation, parse_rotation_coordinate};
+    use super::{normalize_rotation, parse_rotation, parse_rotation_coordinate};
 
     #[test]
     fn relative_rotation_coordinates_resolve_from_origin() {
@@ -74,8 +77,30 @@ mod tests {
     }
 
     #[test]
-    fn rotation_parser_keeps_existing_wrapping_behavior() {
-        assert_eq!(normalize_rotation((181.0, -181.0)), (-179.0, 179.0));
+    fn rotation_wraps_yaw_and_clamps_pitch() {
+        assert_eq!(normalize_rotation((181.0, -181.0)), (-179.0, -90.0));
         assert_eq!(normalize_rotation((90.0, 45.0)), (90.0, 45.0));
     }
+
+    #[test]
+    fn relative_rotation_keeps_the_source_rotation() {
+        assert_eq!(
+            parse_rotation(&["~", "~"], (30.0, -20.0)),
+            Some((30.0, -20.0))
+        );
+        assert_eq!(
+            parse_rotation(&["~", "~80"], (30.0, 20.0)),
+            Some((30.0, 90.0))
+        );
+    }
+
+    #[test]
+    fn absolute_rotation_ignores_the_source_rotation() {
+        assert_eq!(
+            parse_rotation(&["90", "0"], (30.0, -20.0)),
+            Some((90.0, 0.0))
+        );
+        assert!(parse_rotation(&["90", "up"], (30.0, -20.0)).is_none());
+        assert!(parse_rotation(&["90"], (30.0, -20.0)).is_none());
+    }
 }
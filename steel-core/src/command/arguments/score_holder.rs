@@ -0,0 +1,137 @@
+//! A scoreboard score holder argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+
+use crate::command::arguments::CommandArgument;
+use crate::command::arguments::selector::Selector;
+use crate::command::context::CommandContext;
+
+/// The names of score holders, vanilla's `ScoreHolderArgument`.
+pub struct ScoreHolderArgument {
+    multiple: bool,
+}
+
+impl ScoreHolderArgument {
+    const ALLOWS_MULTIPLE: u8 = 1;
+
+    /// A single score holder.
+    #[must_use]
+    pub const fn one() -> Self {
+        Self { multiple: false }
+    }
+
+    /// Any number of score holders.
+    #[must_use]
+    pub const fn multiple() -> Self {
+        Self { multiple: true }
+    }
+}
+
+/// A score holder token before it is resolved to names.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScoreHolders {
+    /// A player or fake player name.
+    Name(String),
+    /// `*`: every holder with a score.
+    All,
+    /// An entity selector.
+    Selector(Selector),
+}
+
+impl ScoreHolders {
+    /// Parses a token. Selectors that can match several entities need `multiple`.
+    #[must_use]
+    pub fn parse(token: &str, multiple: bool) -> Option<Self> {
+        if token.starts_with('@') {
+            let selector = Selector::parse(token)?;
+            if !multiple && !selector.is_single() {
+                return None;
+            }
+            return Some(Self::Selector(selector));
+        }
+        if token == "*" {
+            return Some(Self::All);
+        }
+        Some(Self::Name(token.to_owned()))
+    }
+
+    /// The names of the holders the token stands for when the command runs.
+    #[must_use]
+    pub fn resolve(&self, context: &CommandContext) -> Vec<String> {
+        match self {
+            Self::Name(name) => vec![name.clone()],
+            Self::All => context
+                .server
+                .scoreboard
+                .lock()
+                .holders()
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+            // TODO: select non-player entities, held by their UUID, once the world can list them
+            Self::Selector(selector) => selector
+                .select_players(context)
+                .iter()
+                .map(|player| player.gameprofile.name.clone())
+                .collect(),
+        }
+    }
+}
+
+impl CommandArgument for ScoreHolderArgument {
+    type Output = Vec<String>;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let holders = ScoreHolders::parse(arg.first()?, self.multiple)?.resolve(context);
+        if holders.is_empty() {
+            return None;
+        }
+        Some((&arg[1..], holders))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        let flags = if self.multiple {
+            Self::ALLOWS_MULTIPLE
+        } else {
+            0
+        };
+        (ArgumentType::ScoreHolder { flags }, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScoreHolders;
+    use crate::command::arguments::selector::SelectorBase;
+
+    #[test]
+    fn parses_literal_names() {
+        assert_eq!(
+            ScoreHolders::parse("Steve", false),
+            Some(ScoreHolders::Name("Steve".to_owned()))
+        );
+        assert_eq!(
+            ScoreHolders::parse("#fake", true),
+            Some(ScoreHolders::Name("#fake".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parses_the_wildcard() {
+        assert_eq!(ScoreHolders::parse("*", true), Some(ScoreHolders::All));
+    }
+
+    #[test]
+    fn parses_selectors_allowed_by_the_argument() {
+        let Some(ScoreHolders::Selector(selector)) = ScoreHolders::parse("@a", true) else {
+            panic!("@a is a valid selector");
+        };
+        assert_eq!(selector.base, SelectorBase::AllPlayers);
+
+        assert!(ScoreHolders::parse("@a", false).is_none());
+        assert!(ScoreHolders::parse("@p", false).is_some());
+    }
+}
@@ -0,0 +1,26 @@
+//! A scoreboard display slot argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+use crate::scoreboard::DisplaySlot;
+
+/// Where an objective is shown, vanilla's `ScoreboardSlotArgument`.
+pub struct ScoreboardSlotArgument;
+
+impl CommandArgument for ScoreboardSlotArgument {
+    type Output = DisplaySlot;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let slot = DisplaySlot::from_name(arg.first()?)?;
+        Some((&arg[1..], slot))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::ScoreboardSlot, None)
+    }
+}
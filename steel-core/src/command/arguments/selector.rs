@@ -0,0 +1,577 @@
+//! Entity selectors (`@a`, `@e`, `@n`, `@p`, `@r`, `@s`) and their bracketed options.
+//! Mirrors `net.minecraft.commands.arguments.selector.EntitySelectorParser`.
+use std::sync::Arc;
+
+use rand::seq::SliceRandom;
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+use steel_utils::translations::{
+    ARGUMENT_ENTITY_SELECTOR_ALL_ENTITIES, ARGUMENT_ENTITY_SELECTOR_ALL_PLAYERS,
+    ARGUMENT_ENTITY_SELECTOR_NEAREST_ENTITY, ARGUMENT_ENTITY_SELECTOR_NEAREST_PLAYER,
+    ARGUMENT_ENTITY_SELECTOR_RANDOM_PLAYER, ARGUMENT_ENTITY_SELECTOR_SELF,
+};
+use uuid::Uuid;
+
+use crate::command::arguments::{CommandArgument, SuggestionContext};
+use crate::command::context::CommandContext;
+use crate::entity::Entity;
+use crate::player::Player;
+
+/// The `@` variable a selector starts with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorBase {
+    /// `@a`: all players.
+    AllPlayers,
+    /// `@e`: all entities.
+    AllEntities,
+    /// `@n`: the nearest entity.
+    NearestEntity,
+    /// `@p`: the nearest player.
+    NearestPlayer,
+    /// `@r`: a random player.
+    RandomPlayer,
+    /// `@s`: the entity executing the command.
+    Executor,
+}
+
+/// The order selected entities are returned in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorSort {
+    /// Closest to the command position first.
+    Nearest,
+    /// Furthest from the command position first.
+    Furthest,
+    /// Shuffled.
+    Random,
+    /// Whatever order the world stores them in.
+    Arbitrary,
+}
+
+/// An inclusive distance range, as written in `distance=1..5`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistanceRange {
+    /// The minimum distance, if bounded.
+    pub min: Option<f64>,
+    /// The maximum distance, if bounded.
+    pub max: Option<f64>,
+}
+
+impl DistanceRange {
+    fn parse(s: &str) -> Option<Self> {
+        let (min, max) = match s.split_once("..") {
+            Some((min, max)) => (parse_bound(min)?, parse_bound(max)?),
+            None => {
+                let value = s.parse::<f64>().ok()?;
+                (Some(value), Some(value))
+            }
+        };
+        if min.is_none() && max.is_none() {
+            return None;
+        }
+        // Distances can't be negative, see `argument.entity.options.distance.negative`
+        if min.is_some_and(|v| v < 0.0) || max.is_some_and(|v| v < 0.0) {
+            return None;
+        }
+        if let (Some(min), Some(max)) = (min, max)
+            && min > max
+        {
+            return None;
+        }
+        Some(Self { min, max })
+    }
+
+    fn contains_squared(&self, distance_squared: f64) -> bool {
+        self.min.is_none_or(|min| distance_squared >= min * min)
+            && self.max.is_none_or(|max| distance_squared <= max * max)
+    }
+}
+
+fn parse_bound(s: &str) -> Option<Option<f64>> {
+    if s.is_empty() {
+        Some(None)
+    } else {
+        s.parse::<f64>().ok().map(Some)
+    }
+}
+
+/// A parsed entity selector such as `@e[distance=..5,limit=3,sort=nearest]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    /// The selector variable.
+    pub base: SelectorBase,
+    /// The `distance` option.
+    pub distance: Option<DistanceRange>,
+    /// The `limit` option, or the implicit limit of `@n`, `@p`, `@r` and `@s`.
+    pub limit: Option<usize>,
+    /// The `sort` option, or the implicit sort of the base.
+    pub sort: SelectorSort,
+    /// The `name` option. The flag is `true` for `name=!Foo`.
+    pub names: Vec<(String, bool)>,
+}
+
+impl Selector {
+    /// Parses a selector token. Returns `None` for unknown variables, unknown or repeated
+    /// options and options that the variable doesn't allow.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.strip_prefix('@')?;
+        let mut chars = s.chars();
+        let base = match chars.next()? {
+            'a' => SelectorBase::AllPlayers,
+            'e' => SelectorBase::AllEntities,
+            'n' => SelectorBase::NearestEntity,
+            'p' => SelectorBase::NearestPlayer,
+            'r' => SelectorBase::RandomPlayer,
+            's' => SelectorBase::Executor,
+            _ => return None,
+        };
+
+        let (limit, sort) = match base {
+            SelectorBase::AllPlayers | SelectorBase::AllEntities => (None, SelectorSort::Arbitrary),
+            SelectorBase::NearestEntity | SelectorBase::NearestPlayer => {
+                (Some(1), SelectorSort::Nearest)
+            }
+            SelectorBase::RandomPlayer => (Some(1), SelectorSort::Random),
+            SelectorBase::Executor => (Some(1), SelectorSort::Arbitrary),
+        };
+        let mut selector = Self {
+            base,
+            distance: None,
+            limit,
+            sort,
+            names: Vec::new(),
+        };
+
+        let rest = chars.as_str();
+        if rest.is_empty() {
+            return Some(selector);
+        }
+        let options = rest.strip_prefix('[')?.strip_suffix(']')?;
+        if !options.is_empty() {
+            for option in split_options(options)? {
+                let (key, value) = option.split_once('=')?;
+                selector.apply_option(key.trim(), value.trim())?;
+            }
+        }
+        Some(selector)
+    }
+
+    fn apply_option(&mut self, key: &str, value: &str) -> Option<()> {
+        // `limit` and `sort` may only be set on variables without an implicit one
+        let unlimited = matches!(
+            self.base,
+            SelectorBase::AllPlayers | SelectorBase::AllEntities
+        );
+        match key {
+            "distance" if self.distance.is_none() => {
+                self.distance = Some(DistanceRange::parse(value)?);
+            }
+            "limit" if unlimited && self.limit.is_none() => {
+                let limit = value.parse::<usize>().ok()?;
+                if limit == 0 {
+                    return None;
+                }
+                self.limit = Some(limit);
+            }
+            "sort" if unlimited && self.sort == SelectorSort::Arbitrary => {
+                self.sort = match value {
+                    "nearest" => SelectorSort::Nearest,
+                    "furthest" => SelectorSort::Furthest,
+                    "random" => SelectorSort::Random,
+                    "arbitrary" => SelectorSort::Arbitrary,
+                    _ => return None,
+                };
+            }
+            "name" => {
+                let (name, negated) = match value.strip_prefix('!') {
+                    Some(name) => (name, true),
+                    None => (value, false),
+                };
+                // Only one positive name filter is allowed, but any number of negated ones
+                if !negated && self.names.iter().any(|(_, negated)| !negated) {
+                    return None;
+                }
+                self.names.push((unquote(name)?, negated));
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// Whether this selector can match at most one entity.
+    #[must_use]
+    pub const fn is_single(&self) -> bool {
+        matches!(self.limit, Some(1))
+    }
+
+    /// Whether this selector can only match players.
+    #[must_use]
+    pub const fn players_only(&self) -> bool {
+        !matches!(
+            self.base,
+            SelectorBase::AllEntities | SelectorBase::NearestEntity
+        )
+    }
+
+    /// Whether this selector only looks at the dimension the command runs in. Distances are
+    /// meaningless across dimensions, so any selector that measures or sorts by them is limited.
+    #[must_use]
+    pub const fn world_limited(&self) -> bool {
+        self.distance.is_some()
+            || matches!(self.sort, SelectorSort::Nearest | SelectorSort::Furthest)
+    }
+
+    fn matches(&self, name: &str, distance_squared: f64) -> bool {
+        self.distance
+            .is_none_or(|range| range.contains_squared(distance_squared))
+            && self
+                .names
+                .iter()
+                .all(|(expected, negated)| (expected == name) != *negated)
+    }
+
+    /// Resolves the selector against the online players.
+    #[must_use]
+    pub fn select_players(&self, context: &CommandContext) -> Vec<Arc<Player>> {
+        let origin = context.position;
+        let candidates = if self.base == SelectorBase::Executor {
+            context.player.iter().cloned().collect()
+        } else if self.world_limited() {
+            let mut players = Vec::new();
+            context
+                .world
+                .players
+                .iter_players(|_, player: &Arc<Player>| {
+                    players.push(player.clone());
+                    true
+                });
+            players
+        } else {
+            context.server.get_players()
+        };
+
+        let mut selected: Vec<(f64, Arc<Player>)> = candidates
+            .into_iter()
+            .map(|player| (player.position().distance_squared(origin), player))
+            .filter(|(distance, player)| self.matches(&player.gameprofile.name, *distance))
+            .collect();
+
+        self.sort_and_limit(&mut selected);
+        selected.into_iter().map(|(_, player)| player).collect()
+    }
+
+    fn sort_and_limit<T>(&self, selected: &mut Vec<(f64, T)>) {
+        match self.sort {
+            SelectorSort::Nearest => selected.sort_by(|a, b| a.0.total_cmp(&b.0)),
+            SelectorSort::Furthest => selected.sort_by(|a, b| b.0.total_cmp(&a.0)),
+            SelectorSort::Random => selected.shuffle(&mut rand::rng()),
+            SelectorSort::Arbitrary => {}
+        }
+        if let Some(limit) = self.limit {
+            selected.truncate(limit);
+        }
+    }
+}
+
+/// Splits the inside of a selector's brackets on the commas between options, leaving commas in
+/// quoted values such as `name="a,b"` alone. Returns `None` for an unterminated quote.
+fn split_options(options: &str) -> Option<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in options.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == ',' => {
+                parts.push(&options[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if quote.is_some() {
+        return None;
+    }
+    parts.push(&options[start..]);
+    Some(parts)
+}
+
+/// Strips the quotes and escapes from a quoted option value, or returns an unquoted one as is.
+fn unquote(value: &str) -> Option<String> {
+    let Some(quote) = value.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+        return Some(value.to_string());
+    };
+    let inner = value.strip_prefix(quote)?.strip_suffix(quote)?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            out.push(chars.next()?);
+        } else {
+            out.push(c);
+        }
+    }
+    Some(out)
+}
+
+/// An entity argument that keeps the parsed [`Selector`], for executors that resolve it
+/// themselves. Only `@` selectors are accepted, not player names or UUIDs.
+pub struct EntitySelectorArgument {
+    /// If only accepts selectors matching at most one entity
+    one: bool,
+    /// If only accepts selectors matching players
+    players_only: bool,
+}
+
+impl EntitySelectorArgument {
+    /// Creates a selector for multiple entities
+    #[must_use]
+    pub const fn multiple() -> Self {
+        Self {
+            one: false,
+            players_only: false,
+        }
+    }
+
+    /// Creates a selector for one entity
+    #[must_use]
+    pub const fn one() -> Self {
+        Self {
+            one: true,
+            players_only: false,
+        }
+    }
+
+    /// Only accepts selectors matching players
+    #[must_use]
+    pub const fn players(self) -> Self {
+        Self {
+            players_only: true,
+            ..self
+        }
+    }
+
+    fn accepts(&self, selector: &Selector) -> bool {
+        (!self.one || selector.is_single()) && (!self.players_only || selector.players_only())
+    }
+}
+
+impl CommandArgument for EntitySelectorArgument {
+    type Output = Selector;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let selector = Selector::parse(arg.first()?).filter(|selector| self.accepts(selector))?;
+        Some((&arg[1..], selector))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (
+            ArgumentType::Entity {
+                flags: u8::from(self.one) | if self.players_only { 2 } else { 0 },
+            },
+            Some(SuggestionType::AskServer),
+        )
+    }
+
+    fn suggest(&self, prefix: &str, _suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        let mut suggestions = vec![
+            SuggestionEntry::with_tooltip("@a", &ARGUMENT_ENTITY_SELECTOR_ALL_PLAYERS),
+            SuggestionEntry::with_tooltip("@p", &ARGUMENT_ENTITY_SELECTOR_NEAREST_PLAYER),
+            SuggestionEntry::with_tooltip("@r", &ARGUMENT_ENTITY_SELECTOR_RANDOM_PLAYER),
+            SuggestionEntry::with_tooltip("@s", &ARGUMENT_ENTITY_SELECTOR_SELF),
+        ];
+        if !self.players_only {
+            suggestions.extend([
+                SuggestionEntry::with_tooltip("@e", &ARGUMENT_ENTITY_SELECTOR_ALL_ENTITIES),
+                SuggestionEntry::with_tooltip("@n", &ARGUMENT_ENTITY_SELECTOR_NEAREST_ENTITY),
+            ]);
+        }
+        suggestions.retain(|s| s.text.starts_with(prefix));
+        suggestions
+    }
+}
+
+/// Resolves a selector, player name or UUID token to online players.
+///
+/// Returns `None` if the token is invalid, names nobody online, or can match more targets than
+/// `single` or `players_only` allow.
+pub(crate) fn resolve_players(
+    token: &str,
+    context: &CommandContext,
+    single: bool,
+    players_only: bool,
+) -> Option<Vec<Arc<Player>>> {
+    if token.starts_with('@') {
+        let selector = Selector::parse(token)?;
+        if (players_only && !selector.players_only()) || (single && !selector.is_single()) {
+            return None;
+        }
+        return Some(selector.select_players(context));
+    }
+
+    let uuid = Uuid::parse_str(token).ok();
+    let player = context
+        .server
+        .get_players()
+        .into_iter()
+        .find(|p| p.gameprofile.name == token || Some(p.uuid()) == uuid)?;
+    Some(vec![player])
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+    use steel_protocol::packets::game::ArgumentType;
+
+    use super::{
+        DistanceRange, EntitySelectorArgument, Selector, SelectorBase, SelectorSort,
+        resolve_players,
+    };
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::arguments::CommandArgument;
+    use crate::command::context::CommandContext;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, test_server};
+
+    /// Runs a selector over named positions instead of online players.
+    fn select_from(selector: &Selector, origin: DVec3, entities: &[(&str, DVec3)]) -> Vec<String> {
+        let mut selected: Vec<(f64, String)> = entities
+            .iter()
+            .map(|(name, position)| (position.distance_squared(origin), (*name).to_string()))
+            .filter(|(distance, name)| selector.matches(name, *distance))
+            .collect();
+        selector.sort_and_limit(&mut selected);
+        selected.into_iter().map(|(_, name)| name).collect()
+    }
+
+    #[test]
+    fn parses_bare_variables_with_implicit_options() {
+        let nearest = Selector::parse("@p").expect("valid selector");
+        assert_eq!(nearest.base, SelectorBase::NearestPlayer);
+        assert_eq!(nearest.limit, Some(1));
+        assert_eq!(nearest.sort, SelectorSort::Nearest);
+
+        let all = Selector::parse("@e").expect("valid selector");
+        assert_eq!(all.limit, None);
+        assert!(!all.players_only());
+
+        assert!(Selector::parse("@x").is_none());
+        assert!(Selector::parse("Steve").is_none());
+    }
+
+    #[test]
+    fn parses_bracketed_options() {
+        let selector =
+            Selector::parse("@e[distance=..5,limit=3,sort=nearest,name=Foo]").expect("valid");
+
+        assert_eq!(
+            selector.distance,
+            Some(DistanceRange {
+                min: None,
+                max: Some(5.0)
+            })
+        );
+        assert_eq!(selector.limit, Some(3));
+        assert_eq!(selector.sort, SelectorSort::Nearest);
+        assert_eq!(selector.names, vec![("Foo".to_string(), false)]);
+    }
+
+    #[test]
+    fn rejects_unknown_repeated_and_disallowed_options() {
+        assert!(Selector::parse("@e[color=red]").is_none());
+        assert!(Selector::parse("@e[limit=1,limit=2]").is_none());
+        assert!(Selector::parse("@e[limit=0]").is_none());
+        assert!(Selector::parse("@p[limit=2]").is_none());
+        assert!(Selector::parse("@s[sort=random]").is_none());
+        assert!(Selector::parse("@e[distance=-1]").is_none());
+        assert!(Selector::parse("@e[distance=5..1]").is_none());
+        assert!(Selector::parse("@e[name=A,name=B]").is_none());
+        assert!(Selector::parse("@e[name=!A,name=!B]").is_some());
+    }
+
+    #[test]
+    fn quoted_names_may_contain_commas() {
+        let selector = Selector::parse(r#"@a[name="a,b",limit=1]"#).expect("valid");
+        assert_eq!(selector.names, vec![("a,b".to_string(), false)]);
+        assert_eq!(selector.limit, Some(1));
+
+        let selector = Selector::parse(r#"@a[name=!'it\'s']"#).expect("valid");
+        assert_eq!(selector.names, vec![("it's".to_string(), true)]);
+
+        assert!(Selector::parse(r#"@a[name="a,b]"#).is_none());
+    }
+
+    #[test]
+    fn distance_based_selectors_are_world_limited() {
+        let limited = |s: &str| Selector::parse(s).expect("valid").world_limited();
+
+        assert!(limited("@p"));
+        assert!(limited("@a[distance=..5]"));
+        assert!(limited("@a[sort=furthest]"));
+        assert!(!limited("@a"));
+        assert!(!limited("@r"));
+    }
+
+    #[test]
+    fn selection_filters_sorts_and_limits() {
+        let entities = [
+            ("far", DVec3::new(10.0, 0.0, 0.0)),
+            ("near", DVec3::new(1.0, 0.0, 0.0)),
+            ("mid", DVec3::new(4.0, 0.0, 0.0)),
+        ];
+
+        let selector = Selector::parse("@e[distance=..5,sort=furthest]").expect("valid");
+        assert_eq!(
+            select_from(&selector, DVec3::ZERO, &entities),
+            ["mid", "near"]
+        );
+
+        let selector = Selector::parse("@e[sort=nearest,limit=2,name=!near]").expect("valid");
+        assert_eq!(
+            select_from(&selector, DVec3::ZERO, &entities),
+            ["mid", "far"]
+        );
+    }
+
+    #[test]
+    fn selector_argument_keeps_the_parsed_selector() {
+        let server = test_server();
+        let mut context = CommandContext::new(CommandSender::capture(LEVEL_GAMEMASTERS), server);
+
+        let (rest, selector) = EntitySelectorArgument::multiple()
+            .parse(&["@e[limit=2]", "rest"], &mut context)
+            .expect("valid selector");
+        assert_eq!(rest, ["rest"]);
+        assert_eq!(selector.base, SelectorBase::AllEntities);
+        assert_eq!(selector.limit, Some(2));
+
+        let one_player = EntitySelectorArgument::one().players();
+        assert!(one_player.parse(&["@a"], &mut context).is_none());
+        assert!(one_player.parse(&["@n"], &mut context).is_none());
+        assert!(one_player.parse(&["@p"], &mut context).is_some());
+        assert!(one_player.parse(&["Steve"], &mut context).is_none());
+        assert!(matches!(
+            one_player.usage().0,
+            ArgumentType::Entity { flags: 3 }
+        ));
+    }
+
+    #[test]
+    fn tokens_that_are_neither_names_nor_uuids_match_nobody() {
+        let server = test_server();
+        let steve = add_player(&server, "Steve");
+        let context = CommandContext::new(CommandSender::capture(LEVEL_GAMEMASTERS), server);
+
+        let uuid = steve.player.uuid().to_string();
+        assert!(resolve_players(&uuid, &context, true, true).is_some());
+        assert!(resolve_players("Steve", &context, true, true).is_some());
+        assert!(resolve_players("Alex", &context, true, true).is_none());
+    }
+}
@@ -0,0 +1,35 @@
+//! A sound event argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+use steel_registry::{REGISTRY, RegistryExt, sound_event::SoundEventRef};
+
+use crate::command::{
+    arguments::{CommandArgument, resource_location::parse_identifier},
+    context::CommandContext,
+};
+
+/// A sound event id, suggested by the client from its available sounds.
+// TODO: accept unregistered ids once direct sound events can be sent
+pub struct SoundEventArgument;
+
+impl CommandArgument for SoundEventArgument {
+    type Output = SoundEventRef;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let key = parse_identifier(arg.first()?)?;
+        REGISTRY
+            .sound_events
+            .by_key(&key)
+            .map(|sound| (&arg[1..], sound))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (
+            ArgumentType::ResourceLocation,
+            Some(SuggestionType::AvailableSounds),
+        )
+    }
+}
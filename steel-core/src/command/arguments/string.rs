@@ -0,0 +1,162 @@
+//! A string argument.
+use steel_protocol::packets::game::{ArgumentStringTypeBehavior, ArgumentType, SuggestionType};
+
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+
+/// A vanilla `StringArgumentType`.
+pub enum StringArgument {
+    /// A single unquoted word.
+    Word,
+    /// A quoted phrase, or a single unquoted word.
+    Quoted,
+    /// The rest of the command.
+    Greedy,
+}
+
+impl StringArgument {
+    /// Takes the string off the front of `arg`, returning the remaining tokens.
+    fn read<'a>(&self, arg: &'a [&'a str]) -> Option<(&'a [&'a str], String)> {
+        match self {
+            Self::Word => read_unquoted(arg),
+            Self::Quoted => match arg.first()?.chars().next()? {
+                quote @ ('"' | '\'') => read_quoted(arg, quote),
+                _ => read_unquoted(arg),
+            },
+            Self::Greedy => {
+                if arg.is_empty() {
+                    return None;
+                }
+                Some((&[], arg.join(" ")))
+            }
+        }
+    }
+}
+
+impl CommandArgument for StringArgument {
+    type Output = String;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        self.read(arg)
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        let behavior = match self {
+            Self::Word => ArgumentStringTypeBehavior::SingleWord,
+            Self::Quoted => ArgumentStringTypeBehavior::QuotablePhrase,
+            Self::Greedy => ArgumentStringTypeBehavior::GreedyPhrase,
+        };
+        (ArgumentType::String { behavior }, None)
+    }
+}
+
+/// Brigadier only allows these characters in unquoted strings.
+const fn is_allowed_in_unquoted_string(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+fn read_unquoted<'a>(arg: &'a [&'a str]) -> Option<(&'a [&'a str], String)> {
+    let word = arg.first()?;
+    if !word.chars().all(is_allowed_in_unquoted_string) {
+        return None;
+    }
+    Some((&arg[1..], (*word).to_owned()))
+}
+
+/// Reads a string opened by `quote`, which may span several tokens. `\` escapes the quote and
+/// itself, like in Brigadier.
+fn read_quoted<'a>(arg: &'a [&'a str], quote: char) -> Option<(&'a [&'a str], String)> {
+    let mut result = String::new();
+    let mut escaped = false;
+
+    for (i, token) in arg.iter().enumerate() {
+        let mut chars = token.chars();
+        if i == 0 {
+            chars.next();
+        } else {
+            result.push(' ');
+        }
+
+        while let Some(c) = chars.next() {
+            if escaped {
+                if c != quote && c != '\\' {
+                    return None;
+                }
+                result.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == quote {
+                // The closing quote has to end the token
+                return chars.as_str().is_empty().then(|| (&arg[i + 1..], result));
+            } else {
+                result.push(c);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringArgument;
+
+    #[test]
+    fn word_leaves_remaining_tokens() {
+        let args = ["hello", "world", "5"];
+        let (rest, word) = StringArgument::Word.read(&args).expect("valid word");
+
+        assert_eq!(word, "hello");
+        assert_eq!(rest, ["world", "5"]);
+        assert!(StringArgument::Word.read(&["\"hi\""]).is_none());
+    }
+
+    #[test]
+    fn quoted_reads_across_tokens() {
+        let args = ["\"hello", "big", "\\\"world\\\"\"", "5"];
+        let (rest, phrase) = StringArgument::Quoted.read(&args).expect("valid phrase");
+
+        assert_eq!(phrase, "hello big \"world\"");
+        assert_eq!(rest, ["5"]);
+
+        let (rest, word) = StringArgument::Quoted
+            .read(&["plain", "5"])
+            .expect("valid word");
+        assert_eq!(word, "plain");
+        assert_eq!(rest, ["5"]);
+
+        assert!(StringArgument::Quoted.read(&["'unterminated"]).is_none());
+        assert!(StringArgument::Quoted.read(&["\"a\"b"]).is_none());
+    }
+
+    #[test]
+    fn quoted_handles_escapes_before_trailing_args() {
+        let args = ["\"C:\\\\temp", "dir\"", "1", "2"];
+        let (rest, phrase) = StringArgument::Quoted.read(&args).expect("valid phrase");
+
+        assert_eq!(phrase, "C:\\temp dir");
+        assert_eq!(rest, ["1", "2"]);
+
+        assert!(StringArgument::Quoted.read(&["\"bad\\n\""]).is_none());
+        assert!(
+            StringArgument::Quoted
+                .read(&["\"escaped\\\"", "end"])
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn greedy_consumes_everything() {
+        let (rest, text) = StringArgument::Greedy
+            .read(&["a", "b", "c"])
+            .expect("valid text");
+
+        assert_eq!(text, "a b c");
+        assert!(rest.is_empty());
+    }
+}
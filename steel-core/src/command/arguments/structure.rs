@@ -6,7 +6,7 @@ use steel_registry::{REGISTRY, RegistryExt, structure::StructureRef};
 use steel_utils::Identifier;
 
 use crate::command::{
-    arguments::{CommandArgument, SuggestionContext},
+    arguments::{CommandArgument, SuggestionContext, resource_location::parse_identifier},
     context::CommandContext,
 };
 
@@ -139,11 +139,3 @@ impl CommandArgument for StructureArgument {
         suggestions
     }
 }
-
-fn parse_identifier(s: &str) -> Option<Identifier> {
-    let (namespace, path) = s
-        .split_once(':')
-        .unwrap_or((Identifier::VANILLA_NAMESPACE, s));
-    Identifier::validate(namespace, path)
-        .then(|| Identifier::new(namespace.to_owned(), path.to_owned()))
-}
@@ -0,0 +1,39 @@
+//! A team name argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionType};
+
+use crate::command::arguments::{CommandArgument, SuggestionContext};
+use crate::command::context::CommandContext;
+
+/// The name of a team, vanilla's `TeamArgument`.
+///
+/// Executors look the team up, failing with `team.notFound`.
+pub struct TeamArgument;
+
+impl CommandArgument for TeamArgument {
+    type Output = String;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let name = arg.first()?;
+        Some((&arg[1..], (*name).to_owned()))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Team, Some(SuggestionType::AskServer))
+    }
+
+    fn suggest(&self, prefix: &str, suggestion_ctx: &SuggestionContext) -> Vec<SuggestionEntry> {
+        suggestion_ctx
+            .server
+            .scoreboard
+            .lock()
+            .teams()
+            .into_iter()
+            .filter(|team| team.name.starts_with(prefix))
+            .map(|team| SuggestionEntry::new(team.name.clone()))
+            .collect()
+    }
+}
@@ -1,8 +1,10 @@
 //! A text argument.
 use crate::command::arguments::CommandArgument;
+use crate::command::arguments::selector::resolve_players;
 use crate::command::context::CommandContext;
 use steel_protocol::packets::game::{ArgumentType, SuggestionType};
-use text_components::TextComponent;
+use text_components::content::{Content, Resolvable};
+use text_components::{Modifier, TextComponent};
 
 /// A text argument.
 pub struct TextComponentArgument;
@@ -15,16 +17,138 @@ impl CommandArgument for TextComponentArgument {
         arg: &'a [&'a str],
         _context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        match TextComponent::from_snbt(&arg.join(" ")) {
-            Ok(component) => Some((&[], component)),
-            Err(e) => {
-                log::warn!("{e}");
-                None
-            }
-        }
+        let component = parse_component(&arg.join(" "))?;
+        Some((&[], component))
     }
 
     fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
         (ArgumentType::Component, None)
     }
 }
+
+/// Parses SNBT into a component, so malformed input fails while parsing the argument.
+fn parse_component(s: &str) -> Option<TextComponent> {
+    match TextComponent::from_snbt(s) {
+        Ok(component) => Some(component),
+        Err(e) => {
+            log::warn!("{e}");
+            None
+        }
+    }
+}
+
+/// Replaces the `selector` components in `component` with the names they match, vanilla's
+/// `ComponentUtils.updateForEntity`. Selectors that are invalid or match nobody become empty.
+#[must_use]
+pub fn resolve_selectors(component: &TextComponent, context: &CommandContext) -> TextComponent {
+    let mut children = Vec::with_capacity(component.children.len() + 1);
+    let content = match &component.content {
+        Content::Resolvable(Resolvable::Entity {
+            selector,
+            separator,
+        }) => {
+            // TODO: Match non-player entities once selectors support them
+            let names = resolve_players(selector, context, false, false)
+                .unwrap_or_default()
+                .iter()
+                .map(|player| TextComponent::plain(player.gameprofile.name.clone()))
+                .collect();
+            children.push(format_list(names, separator));
+            TextComponent::new().content
+        }
+        Content::Translate(message) => {
+            let mut message = message.clone();
+            if let Some(args) = &mut message.args {
+                for arg in args.iter_mut() {
+                    *arg = resolve_selectors(arg, context);
+                }
+            }
+            Content::Translate(message)
+        }
+        content => content.clone(),
+    };
+    children.extend(
+        component
+            .children
+            .iter()
+            .map(|child| resolve_selectors(child, context)),
+    );
+
+    TextComponent {
+        content,
+        children,
+        ..component.clone()
+    }
+}
+
+/// Joins `items` with `separator`, vanilla's `ComponentUtils.formatList`.
+pub(crate) fn format_list(items: Vec<TextComponent>, separator: &TextComponent) -> TextComponent {
+    let mut children = Vec::with_capacity(items.len() * 2);
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            children.push(separator.clone());
+        }
+        children.push(item);
+    }
+    TextComponent::new().add_children(children)
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::text::{DisplayResolutor, to_plain_string};
+    use text_components::{Modifier, TextComponent};
+
+    use super::{format_list, parse_component, resolve_selectors};
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::context::CommandContext;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, test_server};
+
+    #[test]
+    fn parses_valid_snbt() {
+        let component = parse_component("{text:\"hi\",bold:true}").expect("valid component");
+        assert_eq!(
+            component.to_nbt_tag(),
+            TextComponent::plain("hi").bold(true).to_nbt_tag()
+        );
+        assert!(parse_component("\"plain\"").is_some());
+    }
+
+    #[test]
+    fn rejects_invalid_snbt() {
+        assert!(parse_component("{text:").is_none());
+        assert!(parse_component("{text:\"hi\"").is_none());
+    }
+
+    #[test]
+    fn format_list_separates_names() {
+        let names = vec![TextComponent::plain("Alex"), TextComponent::plain("Steve")];
+        let list = format_list(names, &TextComponent::plain(", "));
+
+        assert_eq!(to_plain_string(&list, &DisplayResolutor), "Alex, Steve");
+        assert_eq!(
+            to_plain_string(
+                &format_list(Vec::new(), &TextComponent::plain(", ")),
+                &DisplayResolutor
+            ),
+            ""
+        );
+    }
+
+    #[test]
+    fn all_players_selector_expands_to_online_names() {
+        let server = test_server();
+        add_player(&server, "Alex");
+        add_player(&server, "Steve");
+        let context = CommandContext::new(CommandSender::capture(LEVEL_GAMEMASTERS), server);
+
+        let component = parse_component("{selector:\"@a\"}").expect("valid component");
+        let names = to_plain_string(&resolve_selectors(&component, &context), &DisplayResolutor);
+        // `@a` doesn't sort, so the players may come in either order
+        assert!(names == "Alex, Steve" || names == "Steve, Alex", "{names}");
+
+        let component = parse_component("{selector:\"@x\"}").expect("valid component");
+        let empty = to_plain_string(&resolve_selectors(&component, &context), &DisplayResolutor);
+        assert_eq!(empty, "");
+    }
+}
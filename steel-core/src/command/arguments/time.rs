@@ -4,27 +4,31 @@ use steel_protocol::packets::game::{ArgumentType, SuggestionEntry, SuggestionTyp
 use crate::command::arguments::CommandArgument;
 use crate::command::context::CommandContext;
 
-/// A time argument.
-pub struct TimeArgument;
+/// A duration in ticks, optionally suffixed with `t` (ticks), `s` (seconds) or `d` (days).
+pub struct TimeArgument {
+    min: i32,
+}
 
-impl CommandArgument for TimeArgument {
-    type Output = i32;
+impl TimeArgument {
+    /// Creates a time argument that rejects negative durations.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { min: 0 }
+    }
 
-    fn parse<'a>(
-        &self,
-        arg: &'a [&'a str],
-        _context: &mut CommandContext,
-    ) -> Option<(&'a [&'a str], Self::Output)> {
-        let s = arg.first()?;
+    /// Creates a time argument that rejects durations shorter than `min` ticks.
+    #[must_use]
+    pub const fn with_min(min: i32) -> Self {
+        Self { min }
+    }
 
+    /// Parses a single token into ticks.
+    fn parse_value(&self, s: &str) -> Option<i32> {
         let (number, unit) = s
             .find(|c: char| c.is_alphabetic())
-            .map_or((*s, "t"), |pos| (&s[..pos], &s[pos..]));
+            .map_or((s, "t"), |pos| (&s[..pos], &s[pos..]));
 
         let number = number.parse::<f32>().ok()?;
-        if number < 0.0 {
-            return None;
-        }
 
         let ticks = match unit {
             "d" => number * 24000.0,
@@ -33,11 +37,31 @@ impl CommandArgument for TimeArgument {
             _ => return None,
         };
 
-        Some((&arg[1..], ticks.round() as i32))
+        let ticks = ticks.round() as i32;
+        (ticks >= self.min).then_some(ticks)
+    }
+}
+
+impl Default for TimeArgument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandArgument for TimeArgument {
+    type Output = i32;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let ticks = self.parse_value(arg.first()?)?;
+        Some((&arg[1..], ticks))
     }
 
     fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
-        (ArgumentType::Time { min: 0 }, None)
+        (ArgumentType::Time { min: self.min }, None)
     }
 
     /// ONLY FOR THE CONSOLE\
@@ -60,3 +84,26 @@ impl CommandArgument for TimeArgument {
         vec![]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TimeArgument;
+
+    #[test]
+    fn parses_units_into_ticks() {
+        let time = TimeArgument::new();
+        assert_eq!(time.parse_value("100"), Some(100));
+        assert_eq!(time.parse_value("100t"), Some(100));
+        assert_eq!(time.parse_value("10s"), Some(200));
+        assert_eq!(time.parse_value("2d"), Some(48000));
+        assert_eq!(time.parse_value("0.5d"), Some(12000));
+        assert_eq!(time.parse_value("3m"), None);
+    }
+
+    #[test]
+    fn rejects_durations_below_min() {
+        assert_eq!(TimeArgument::new().parse_value("-1"), None);
+        assert_eq!(TimeArgument::with_min(1).parse_value("0"), None);
+        assert_eq!(TimeArgument::with_min(1).parse_value("1"), Some(1));
+    }
+}
@@ -0,0 +1,59 @@
+//! A UUID argument.
+use steel_protocol::packets::game::{ArgumentType, SuggestionType};
+use uuid::Uuid;
+
+use crate::command::arguments::CommandArgument;
+use crate::command::context::CommandContext;
+
+/// A UUID in its hyphenated (`f81d4fae-7dec-11d0-a765-00a0c91e6bf6`) or simple 32 digit form.
+pub struct UuidArgument;
+
+impl CommandArgument for UuidArgument {
+    type Output = Uuid;
+
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let uuid = parse_uuid(arg.first()?)?;
+        Some((&arg[1..], uuid))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Uuid, None)
+    }
+}
+
+fn parse_uuid(s: &str) -> Option<Uuid> {
+    // `Uuid::parse_str` also takes the braced and urn forms, which commands shouldn't
+    if !matches!(s.len(), 32 | 36) {
+        return None;
+    }
+    Uuid::try_parse(s).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::parse_uuid;
+
+    const UUID: Uuid = Uuid::from_u128(0xf81d_4fae_7dec_11d0_a765_00a0_c91e_6bf6);
+
+    #[test]
+    fn parses_hyphenated_and_simple_forms() {
+        assert_eq!(
+            parse_uuid("f81d4fae-7dec-11d0-a765-00a0c91e6bf6"),
+            Some(UUID)
+        );
+        assert_eq!(parse_uuid("F81D4FAE7DEC11D0A76500A0C91E6BF6"), Some(UUID));
+    }
+
+    #[test]
+    fn rejects_bad_lengths_and_characters() {
+        assert_eq!(parse_uuid("f81d4fae-7dec-11d0-a765"), None);
+        assert_eq!(parse_uuid("g81d4fae-7dec-11d0-a765-00a0c91e6bf6"), None);
+        assert_eq!(parse_uuid("{f81d4fae-7dec-11d0-a765-00a0c91e6bf6}"), None);
+    }
+}
@@ -3,32 +3,218 @@ use glam::DVec3;
 use steel_protocol::packets::game::{ArgumentType, SuggestionType};
 
 use crate::command::arguments::{CommandArgument, Helper};
-use crate::command::context::CommandContext;
+use crate::command::context::{CommandContext, EntityAnchor};
+use crate::entity::Entity;
 
 /// A vector3 argument.
 pub struct Vector3Argument;
 
 impl CommandArgument for Vector3Argument {
-    type Output = DVec3;
+    type Output = Coordinates;
 
     fn parse<'a>(
         &self,
         arg: &'a [&'a str],
-        context: &mut CommandContext,
+        _context: &mut CommandContext,
     ) -> Option<(&'a [&'a str], Self::Output)> {
-        if arg.first()?.starts_with('^') {
-            let pos = Helper::parse_local_coordinates(arg, context)?;
-            return Some((&arg[3..], pos));
-        }
+        let coordinates = parse_coordinates::<true>(arg)?;
+        Some((&arg[3..], coordinates))
+    }
+
+    fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
+        (ArgumentType::Vec3, None)
+    }
+}
+
+/// A vector3 argument that keeps integer x and z as given, vanilla's `Vec3Argument.vec3(false)`.
+pub struct ExactVector3Argument;
 
-        let x = Helper::parse_relative_coordinate::<false>(arg.first()?, Some(context.position.x))?;
-        let y = Helper::parse_relative_coordinate::<true>(arg.get(1)?, Some(context.position.y))?;
-        let z = Helper::parse_relative_coordinate::<false>(arg.get(2)?, Some(context.position.z))?;
+impl CommandArgument for ExactVector3Argument {
+    type Output = Coordinates;
 
-        Some((&arg[3..], DVec3::new(x, y, z)))
+    fn parse<'a>(
+        &self,
+        arg: &'a [&'a str],
+        _context: &mut CommandContext,
+    ) -> Option<(&'a [&'a str], Self::Output)> {
+        let coordinates = parse_coordinates::<false>(arg)?;
+        Some((&arg[3..], coordinates))
     }
 
     fn usage(&self) -> (ArgumentType, Option<SuggestionType>) {
         (ArgumentType::Vec3, None)
     }
 }
+
+/// Parsed coordinates that still know what they are relative to, vanilla's `Coordinates`.
+///
+/// They are only turned into a position once the origin is known, see [`Self::position`] and
+/// [`Self::position_for`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Coordinates {
+    /// `x y z`, where every axis may be relative to the origin with `~`.
+    World([WorldCoordinate; 3]),
+    /// `^left ^up ^forwards`, relative to the origin and the way it is facing.
+    Local {
+        /// Blocks to the left of the origin.
+        left: f64,
+        /// Blocks above the origin.
+        up: f64,
+        /// Blocks in front of the origin.
+        forwards: f64,
+    },
+}
+
+/// One axis of [`Coordinates::World`], vanilla's `WorldCoordinate`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WorldCoordinate {
+    /// The coordinate, or the offset from the origin if `relative`.
+    pub value: f64,
+    /// Whether the coordinate was given with `~`.
+    pub relative: bool,
+}
+
+impl WorldCoordinate {
+    fn parse<const CENTER_CORRECT: bool>(s: &str) -> Option<Self> {
+        if let Some(offset) = s.strip_prefix('~') {
+            let value = if offset.is_empty() {
+                0.0
+            } else {
+                offset.parse().ok()?
+            };
+            return Some(Self {
+                value,
+                relative: true,
+            });
+        }
+
+        let mut value = s.parse().ok()?;
+        // set position to block center if no decimal place is given
+        if CENTER_CORRECT && !s.contains('.') {
+            value += 0.5;
+        }
+        Some(Self {
+            value,
+            relative: false,
+        })
+    }
+
+    fn resolve(self, origin: f64) -> f64 {
+        if self.relative {
+            origin + self.value
+        } else {
+            self.value
+        }
+    }
+}
+
+impl Coordinates {
+    /// The position relative to the command source, vanilla's `Coordinates.getPosition`.
+    #[must_use]
+    pub fn position(&self, context: &CommandContext) -> DVec3 {
+        match *self {
+            Self::World(axes) => Self::world_position(axes, context.position),
+            Self::Local { left, up, forwards } => {
+                Helper::local_coordinates_to_position(left, up, forwards, context)
+            }
+        }
+    }
+
+    /// The position relative to `entity`, using its rotation for local coordinates and `anchor`
+    /// for the height they start from.
+    #[must_use]
+    pub fn position_for<E: Entity + ?Sized>(&self, entity: &E, anchor: &EntityAnchor) -> DVec3 {
+        let position = entity.position();
+        match *self {
+            Self::World(axes) => Self::world_position(axes, position),
+            Self::Local { left, up, forwards } => {
+                let origin = match anchor {
+                    EntityAnchor::Feet => position,
+                    EntityAnchor::Eyes => DVec3::new(position.x, entity.get_eye_y(), position.z),
+                };
+                Helper::local_coordinates_to_anchor_position(
+                    origin,
+                    entity.rotation(),
+                    left,
+                    up,
+                    forwards,
+                )
+            }
+        }
+    }
+
+    fn world_position([x, y, z]: [WorldCoordinate; 3], origin: DVec3) -> DVec3 {
+        DVec3::new(
+            x.resolve(origin.x),
+            y.resolve(origin.y),
+            z.resolve(origin.z),
+        )
+    }
+}
+
+/// Parses `x y z` or `^left ^up ^forwards`. With `CENTER_CORRECT`, integer x and z are moved to
+/// the center of their block.
+fn parse_coordinates<const CENTER_CORRECT: bool>(arg: &[&str]) -> Option<Coordinates> {
+    if arg.first()?.starts_with('^') {
+        let (left, up, forwards) = Helper::parse_local_coordinate_triplet(arg)?;
+        return Some(Coordinates::Local { left, up, forwards });
+    }
+
+    Some(Coordinates::World([
+        WorldCoordinate::parse::<CENTER_CORRECT>(arg.first()?)?,
+        WorldCoordinate::parse::<false>(arg.get(1)?)?,
+        WorldCoordinate::parse::<CENTER_CORRECT>(arg.get(2)?)?,
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+
+    use super::{Coordinates, WorldCoordinate, parse_coordinates};
+
+    #[test]
+    fn relative_axes_are_kept_until_resolved() {
+        let coordinates = parse_coordinates::<true>(&["~", "~10", "5"]).expect("coordinates");
+
+        assert_eq!(
+            coordinates,
+            Coordinates::World([
+                WorldCoordinate {
+                    value: 0.0,
+                    relative: true
+                },
+                WorldCoordinate {
+                    value: 10.0,
+                    relative: true
+                },
+                WorldCoordinate {
+                    value: 5.5,
+                    relative: false
+                },
+            ])
+        );
+        let Coordinates::World(axes) = coordinates else {
+            unreachable!();
+        };
+        assert_eq!(
+            Coordinates::world_position(axes, DVec3::new(1.0, 64.0, 1.0)),
+            DVec3::new(1.0, 74.0, 5.5)
+        );
+    }
+
+    #[test]
+    fn only_x_and_z_are_center_corrected() {
+        assert_eq!(
+            parse_coordinates::<true>(&["1", "2", "3"]),
+            parse_coordinates::<false>(&["1.5", "2", "3.5"])
+        );
+    }
+
+    #[test]
+    fn local_and_world_axes_cannot_be_mixed() {
+        assert!(parse_coordinates::<true>(&["^", "^", "~"]).is_none());
+        assert!(parse_coordinates::<true>(&["~", "^", "~"]).is_none());
+        assert!(parse_coordinates::<true>(&["~", "~"]).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+//! Handler for the "ban" command.
+//! Mirrors `net.minecraft.server.commands.BanPlayerCommands`.
+use std::io;
+use std::sync::Arc;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_ADMINS;
+use crate::command::arguments::message::MessageArgument;
+use crate::command::arguments::player::PlayerArgument;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::player::Player;
+use crate::server::ban_list::{BanDetails, UserBanEntry};
+
+type TargetsArgs = ((), Vec<Arc<Player>>);
+
+/// Handler for the "ban" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    // TODO: accept offline players by name like vanilla's GameProfileArgument
+    CommandHandlerBuilder::new(
+        &["ban"],
+        "Bans players from the server.",
+        "minecraft:command.ban",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(
+        argument("targets", PlayerArgument::multiple())
+            .executes(|((), targets): TargetsArgs, context: &mut CommandContext| {
+                ban_players(context, &targets, None)
+            })
+            .then(argument("reason", MessageArgument).executes(
+                |(((), targets), reason): (TargetsArgs, String), context: &mut CommandContext| {
+                    ban_players(context, &targets, Some(reason))
+                },
+            )),
+    )
+}
+
+fn ban_players(
+    context: &mut CommandContext,
+    targets: &[Arc<Player>],
+    reason: Option<String>,
+) -> Result<(), CommandError> {
+    let mut count = 0;
+    for target in targets {
+        let entry = UserBanEntry {
+            uuid: target.gameprofile.id,
+            name: target.gameprofile.name.clone(),
+            details: BanDetails::new(context.sender.to_string(), reason.clone()),
+        };
+        let reason = TextComponent::from(entry.details.reason.clone());
+        if !context
+            .server
+            .banned_players
+            .add(entry)
+            .map_err(|e| save_failed(&e))?
+        {
+            continue;
+        }
+        count += 1;
+        context.send_success(
+            &translations::COMMANDS_BAN_SUCCESS
+                .message([
+                    TextComponent::plain(target.gameprofile.name.clone()),
+                    reason,
+                ])
+                .into(),
+            true,
+        );
+        target.disconnect(translations::MULTIPLAYER_DISCONNECT_BANNED.msg());
+    }
+
+    if count == 0 {
+        return Err(failed(translations::COMMANDS_BAN_FAILED.msg()));
+    }
+    context.result = count;
+    Ok(())
+}
+
+/// Logs a failed write of a ban list and turns it into a command error.
+pub(super) fn save_failed(e: &io::Error) -> CommandError {
+    log::error!("Failed to save the ban list: {e}");
+    failed(TextComponent::const_plain("Failed to save the ban list"))
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
@@ -0,0 +1,119 @@
+//! Handler for the "ban-ip" command.
+//! Mirrors `net.minecraft.server.commands.BanIpCommands`.
+use std::net::IpAddr;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_ADMINS;
+use crate::command::arguments::message::MessageArgument;
+use crate::command::arguments::string::StringArgument;
+use crate::command::arguments::text_component::format_list;
+use crate::command::commands::ban::save_failed;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::server::ban_list::{BanDetails, IpBanEntry};
+
+type TargetArgs = ((), String);
+
+/// Handler for the "ban-ip" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["ban-ip"],
+        "Bans an address, or the address of an online player, from the server.",
+        "minecraft:command.ban-ip",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(
+        argument("target", StringArgument::Word)
+            .executes(|((), target): TargetArgs, context: &mut CommandContext| {
+                ban_ip_or_player(context, &target, None)
+            })
+            .then(argument("reason", MessageArgument).executes(
+                |(((), target), reason): (TargetArgs, String), context: &mut CommandContext| {
+                    ban_ip_or_player(context, &target, Some(reason))
+                },
+            )),
+    )
+}
+
+fn ban_ip_or_player(
+    context: &mut CommandContext,
+    target: &str,
+    reason: Option<String>,
+) -> Result<(), CommandError> {
+    if let Ok(ip) = target.parse::<IpAddr>() {
+        return ban_ip(context, ip.to_canonical(), reason);
+    }
+    let ip = context
+        .server
+        .get_players()
+        .into_iter()
+        .find(|player| player.gameprofile.name.eq_ignore_ascii_case(target))
+        .and_then(|player| player.ip_address());
+    match ip {
+        Some(ip) => ban_ip(context, ip, reason),
+        None => Err(failed(translations::COMMANDS_BANIP_INVALID.msg())),
+    }
+}
+
+fn ban_ip(
+    context: &mut CommandContext,
+    ip: IpAddr,
+    reason: Option<String>,
+) -> Result<(), CommandError> {
+    let entry = IpBanEntry {
+        ip: ip.to_string(),
+        details: BanDetails::new(context.sender.to_string(), reason),
+    };
+    let reason = TextComponent::from(entry.details.reason.clone());
+    if !context
+        .server
+        .banned_ips
+        .add(entry)
+        .map_err(|e| save_failed(&e))?
+    {
+        return Err(failed(translations::COMMANDS_BANIP_FAILED.msg()));
+    }
+
+    let players: Vec<_> = context
+        .server
+        .get_players()
+        .into_iter()
+        .filter(|player| player.ip_address() == Some(ip))
+        .collect();
+    context.send_success(
+        &translations::COMMANDS_BANIP_SUCCESS
+            .message([TextComponent::from(ip.to_string()), reason])
+            .into(),
+        true,
+    );
+    if !players.is_empty() {
+        context.send_success(
+            &translations::COMMANDS_BANIP_INFO
+                .message([
+                    TextComponent::from(players.len().to_string()),
+                    format_list(
+                        players
+                            .iter()
+                            .map(|player| TextComponent::plain(player.gameprofile.name.clone()))
+                            .collect(),
+                        &TextComponent::plain(", "),
+                    ),
+                ])
+                .into(),
+            true,
+        );
+    }
+    for player in &players {
+        player.disconnect(translations::MULTIPLAYER_DISCONNECT_IP_BANNED.msg());
+    }
+    context.result = i32::try_from(players.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
@@ -0,0 +1,516 @@
+//! Handler for the "bossbar" command.
+//! Mirrors `net.minecraft.server.commands.BossBarCommands`.
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+use steel_protocol::packets::game::{BossBarColor, BossBarOverlay};
+use steel_utils::{Identifier, translations};
+use text_components::TextComponent;
+
+use crate::boss_bar::{BossBars, CustomBossBar};
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        bool::BoolArgument,
+        enumeration::EnumArgument,
+        integer::IntegerArgument,
+        player::PlayerArgument,
+        resource_location::ResourceLocationArgument,
+        text_component::{TextComponentArgument, format_list, resolve_selectors},
+    },
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::player::Player;
+
+/// The keywords of vanilla `BossBarColor.getName`.
+const COLORS: &[(&str, BossBarColor)] = &[
+    ("blue", BossBarColor::Blue),
+    ("green", BossBarColor::Green),
+    ("pink", BossBarColor::Pink),
+    ("purple", BossBarColor::Purple),
+    ("red", BossBarColor::Red),
+    ("white", BossBarColor::White),
+    ("yellow", BossBarColor::Yellow),
+];
+
+/// The keywords of vanilla `BossBarOverlay.getName`.
+const STYLES: &[(&str, BossBarOverlay)] = &[
+    ("progress", BossBarOverlay::Progress),
+    ("notched_6", BossBarOverlay::Notched6),
+    ("notched_10", BossBarOverlay::Notched10),
+    ("notched_12", BossBarOverlay::Notched12),
+    ("notched_20", BossBarOverlay::Notched20),
+];
+
+type IdArgs = ((), Identifier);
+
+/// Handler for the "bossbar" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["bossbar"],
+        "Creates and manages boss bars.",
+        "minecraft:command.bossbar",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        literal("add").then(argument("id", ResourceLocationArgument).then(
+            argument("name", TextComponentArgument).executes(
+                |(((), id), name): (IdArgs, TextComponent), context: &mut CommandContext| {
+                    add_bar(context, id, &name)
+                },
+            ),
+        )),
+    )
+    .then(
+        literal("remove").then(
+            argument("id", ResourceLocationArgument).executes(
+                |((), id): IdArgs, context: &mut CommandContext| remove_bar(context, &id),
+            ),
+        ),
+    )
+    .then(literal("list").executes(|(), context: &mut CommandContext| list_bars(context)))
+    .then(
+        literal("set").then(
+            argument("id", ResourceLocationArgument)
+                .then(
+                    literal("name").then(argument("name", TextComponentArgument).executes(
+                        |(((), id), name): (IdArgs, TextComponent),
+                         context: &mut CommandContext| {
+                            let name = resolve_selectors(&name, context);
+                            modify_bar(context, &id, |bar| set_name(bar, name))
+                        },
+                    )),
+                )
+                .then(
+                    literal("color").then(argument("color", EnumArgument::new(COLORS)).executes(
+                        |(((), id), color): (IdArgs, BossBarColor),
+                         context: &mut CommandContext| {
+                            modify_bar(context, &id, |bar| set_color(bar, color))
+                        },
+                    )),
+                )
+                .then(
+                    literal("style").then(argument("style", EnumArgument::new(STYLES)).executes(
+                        |(((), id), style): (IdArgs, BossBarOverlay),
+                         context: &mut CommandContext| {
+                            modify_bar(context, &id, |bar| set_style(bar, style))
+                        },
+                    )),
+                )
+                .then(literal("value").then(
+                    argument("value", IntegerArgument::bounded(Some(0), None)).executes(
+                        |(((), id), value): (IdArgs, i32), context: &mut CommandContext| {
+                            modify_bar(context, &id, |bar| set_value(bar, value))
+                        },
+                    ),
+                ))
+                .then(literal("max").then(
+                    argument("max", IntegerArgument::bounded(Some(1), None)).executes(
+                        |(((), id), max): (IdArgs, i32), context: &mut CommandContext| {
+                            modify_bar(context, &id, |bar| set_max(bar, max))
+                        },
+                    ),
+                ))
+                .then(
+                    literal("visible").then(argument("visible", BoolArgument).executes(
+                        |(((), id), visible): (IdArgs, bool), context: &mut CommandContext| {
+                            modify_bar(context, &id, |bar| set_visible(bar, visible))
+                        },
+                    )),
+                )
+                .then(
+                    literal("players")
+                        .executes(|((), id): IdArgs, context: &mut CommandContext| {
+                            modify_bar(context, &id, |bar| set_players(bar, &[]))
+                        })
+                        .then(argument("targets", PlayerArgument::multiple()).executes(
+                            |(((), id), targets): (IdArgs, Vec<Arc<Player>>),
+                             context: &mut CommandContext| {
+                                modify_bar(context, &id, |bar| set_players(bar, &targets))
+                            },
+                        )),
+                ),
+        ),
+    )
+    .then(
+        literal("get").then(
+            argument("id", ResourceLocationArgument)
+                .then(literal("value").executes(
+                    |((), id): IdArgs, context: &mut CommandContext| get_value(context, &id),
+                ))
+                .then(
+                    literal("max").executes(|((), id): IdArgs, context: &mut CommandContext| {
+                        get_max(context, &id)
+                    }),
+                )
+                .then(literal("visible").executes(
+                    |((), id): IdArgs, context: &mut CommandContext| get_visible(context, &id),
+                ))
+                .then(literal("players").executes(
+                    |((), id): IdArgs, context: &mut CommandContext| get_players(context, &id),
+                )),
+        ),
+    )
+}
+
+/// The bar with `id`, vanilla's `BossBarCommands.getBossBar`.
+fn bar(bars: &BossBars, id: &Identifier) -> Result<CustomBossBar, CommandError> {
+    bars.get(id).cloned().ok_or_else(|| unknown(id))
+}
+
+fn unknown(id: &Identifier) -> CommandError {
+    failed(translations::COMMANDS_BOSSBAR_UNKNOWN.message([TextComponent::from(id.to_string())]))
+}
+
+/// Creates the bar, returning its display name and how many bars there are.
+fn create_bar(
+    bars: &mut BossBars,
+    id: Identifier,
+    name: TextComponent,
+) -> Result<(TextComponent, i32), CommandError> {
+    let display_name = bars
+        .create(id.clone(), name)
+        .map(CustomBossBar::display_name)
+        .ok_or_else(|| {
+            failed(
+                translations::COMMANDS_BOSSBAR_CREATE_FAILED
+                    .message([TextComponent::from(id.to_string())]),
+            )
+        })?;
+    Ok((display_name, count(bars.len())))
+}
+
+fn add_bar(
+    context: &mut CommandContext,
+    id: Identifier,
+    name: &TextComponent,
+) -> Result<(), CommandError> {
+    let name = resolve_selectors(name, context);
+    let (display_name, bars) = create_bar(&mut context.server.boss_bars.lock(), id, name)?;
+    context.send_success(
+        &translations::COMMANDS_BOSSBAR_CREATE_SUCCESS
+            .message([display_name])
+            .into(),
+        true,
+    );
+    context.result = bars;
+    Ok(())
+}
+
+fn remove_bar(context: &mut CommandContext, id: &Identifier) -> Result<(), CommandError> {
+    let (removed, bars) = {
+        let mut bars = context.server.boss_bars.lock();
+        let removed = bar(&bars, id)?;
+        bars.remove(id);
+        (removed, bars.len())
+    };
+    context
+        .server
+        .send_boss_bar_packets(removed.remove_packets());
+    context.send_success(
+        &translations::COMMANDS_BOSSBAR_REMOVE_SUCCESS
+            .message([removed.display_name()])
+            .into(),
+        true,
+    );
+    context.result = count(bars);
+    Ok(())
+}
+
+fn list_bars(context: &mut CommandContext) -> Result<(), CommandError> {
+    let names: Vec<_> = context
+        .server
+        .boss_bars
+        .lock()
+        .sorted()
+        .into_iter()
+        .map(CustomBossBar::display_name)
+        .collect();
+    if names.is_empty() {
+        context.send_success(
+            &translations::COMMANDS_BOSSBAR_LIST_BARS_NONE.msg().into(),
+            false,
+        );
+    } else {
+        context.send_success(
+            &translations::COMMANDS_BOSSBAR_LIST_BARS_SOME
+                .message([
+                    TextComponent::from(names.len().to_string()),
+                    format_list(names.clone(), &TextComponent::plain(", ")),
+                ])
+                .into(),
+            false,
+        );
+    }
+    context.result = count(names.len());
+    Ok(())
+}
+
+/// Changes the bar, `change` returning the success message and result or an unchanged error.
+fn modify_bar(
+    context: &mut CommandContext,
+    id: &Identifier,
+    change: impl FnOnce(&mut CustomBossBar) -> Result<(TextComponent, i32), CommandError>,
+) -> Result<(), CommandError> {
+    let (message, result, packets) = {
+        let mut bars = context.server.boss_bars.lock();
+        let bar = bars.get_mut(id).ok_or_else(|| unknown(id))?;
+        let old = bar.clone();
+        let (message, result) = change(bar)?;
+        (message, result, bar.update_packets(&old))
+    };
+    context.server.send_boss_bar_packets(packets);
+    context.send_success(&message, true);
+    context.result = result;
+    Ok(())
+}
+
+fn set_name(
+    bar: &mut CustomBossBar,
+    name: TextComponent,
+) -> Result<(TextComponent, i32), CommandError> {
+    if bar.name.to_nbt_tag() == name.to_nbt_tag() {
+        return Err(failed(
+            translations::COMMANDS_BOSSBAR_SET_NAME_UNCHANGED.msg(),
+        ));
+    }
+    bar.name = name;
+    Ok((
+        translations::COMMANDS_BOSSBAR_SET_NAME_SUCCESS
+            .message([bar.display_name()])
+            .into(),
+        0,
+    ))
+}
+
+fn set_color(
+    bar: &mut CustomBossBar,
+    color: BossBarColor,
+) -> Result<(TextComponent, i32), CommandError> {
+    if bar.color == color {
+        return Err(failed(
+            translations::COMMANDS_BOSSBAR_SET_COLOR_UNCHANGED.msg(),
+        ));
+    }
+    bar.color = color;
+    Ok((
+        translations::COMMANDS_BOSSBAR_SET_COLOR_SUCCESS
+            .message([bar.display_name()])
+            .into(),
+        0,
+    ))
+}
+
+fn set_style(
+    bar: &mut CustomBossBar,
+    style: BossBarOverlay,
+) -> Result<(TextComponent, i32), CommandError> {
+    if bar.overlay == style {
+        return Err(failed(
+            translations::COMMANDS_BOSSBAR_SET_STYLE_UNCHANGED.msg(),
+        ));
+    }
+    bar.overlay = style;
+    Ok((
+        translations::COMMANDS_BOSSBAR_SET_STYLE_SUCCESS
+            .message([bar.display_name()])
+            .into(),
+        0,
+    ))
+}
+
+fn set_value(bar: &mut CustomBossBar, value: i32) -> Result<(TextComponent, i32), CommandError> {
+    if bar.value == value {
+        return Err(failed(
+            translations::COMMANDS_BOSSBAR_SET_VALUE_UNCHANGED.msg(),
+        ));
+    }
+    bar.value = value;
+    Ok((
+        translations::COMMANDS_BOSSBAR_SET_VALUE_SUCCESS
+            .message([bar.display_name(), TextComponent::from(value.to_string())])
+            .into(),
+        value,
+    ))
+}
+
+fn set_max(bar: &mut CustomBossBar, max: i32) -> Result<(TextComponent, i32), CommandError> {
+    if bar.max == max {
+        return Err(failed(
+            translations::COMMANDS_BOSSBAR_SET_MAX_UNCHANGED.msg(),
+        ));
+    }
+    bar.max = max;
+    Ok((
+        translations::COMMANDS_BOSSBAR_SET_MAX_SUCCESS
+            .message([bar.display_name(), TextComponent::from(max.to_string())])
+            .into(),
+        max,
+    ))
+}
+
+fn set_visible(
+    bar: &mut CustomBossBar,
+    visible: bool,
+) -> Result<(TextComponent, i32), CommandError> {
+    if bar.visible == visible {
+        let unchanged = if visible {
+            &translations::COMMANDS_BOSSBAR_SET_VISIBILITY_UNCHANGED_VISIBLE
+        } else {
+            &translations::COMMANDS_BOSSBAR_SET_VISIBILITY_UNCHANGED_HIDDEN
+        };
+        return Err(failed(unchanged.msg()));
+    }
+    bar.visible = visible;
+    let success = if visible {
+        &translations::COMMANDS_BOSSBAR_SET_VISIBLE_SUCCESS_VISIBLE
+    } else {
+        &translations::COMMANDS_BOSSBAR_SET_VISIBLE_SUCCESS_HIDDEN
+    };
+    Ok((success.message([bar.display_name()]).into(), 0))
+}
+
+fn set_players(
+    bar: &mut CustomBossBar,
+    targets: &[Arc<Player>],
+) -> Result<(TextComponent, i32), CommandError> {
+    let players: FxHashSet<_> = targets.iter().map(|target| target.gameprofile.id).collect();
+    if bar.players == players {
+        return Err(failed(
+            translations::COMMANDS_BOSSBAR_SET_PLAYERS_UNCHANGED.msg(),
+        ));
+    }
+    bar.players = players;
+    let message = if targets.is_empty() {
+        translations::COMMANDS_BOSSBAR_SET_PLAYERS_SUCCESS_NONE.message([bar.display_name()])
+    } else {
+        translations::COMMANDS_BOSSBAR_SET_PLAYERS_SUCCESS_SOME.message([
+            bar.display_name(),
+            TextComponent::from(targets.len().to_string()),
+            player_names(targets),
+        ])
+    };
+    Ok((message.into(), count(targets.len())))
+}
+
+fn get_value(context: &mut CommandContext, id: &Identifier) -> Result<(), CommandError> {
+    let bar = bar(&context.server.boss_bars.lock(), id)?;
+    context.send_success(
+        &translations::COMMANDS_BOSSBAR_GET_VALUE
+            .message([
+                bar.display_name(),
+                TextComponent::from(bar.value.to_string()),
+            ])
+            .into(),
+        false,
+    );
+    context.result = bar.value;
+    Ok(())
+}
+
+fn get_max(context: &mut CommandContext, id: &Identifier) -> Result<(), CommandError> {
+    let bar = bar(&context.server.boss_bars.lock(), id)?;
+    context.send_success(
+        &translations::COMMANDS_BOSSBAR_GET_MAX
+            .message([bar.display_name(), TextComponent::from(bar.max.to_string())])
+            .into(),
+        false,
+    );
+    context.result = bar.max;
+    Ok(())
+}
+
+fn get_visible(context: &mut CommandContext, id: &Identifier) -> Result<(), CommandError> {
+    let bar = bar(&context.server.boss_bars.lock(), id)?;
+    let message = if bar.visible {
+        &translations::COMMANDS_BOSSBAR_GET_VISIBLE_VISIBLE
+    } else {
+        &translations::COMMANDS_BOSSBAR_GET_VISIBLE_HIDDEN
+    };
+    context.send_success(&message.message([bar.display_name()]).into(), false);
+    context.result = i32::from(bar.visible);
+    Ok(())
+}
+
+fn get_players(context: &mut CommandContext, id: &Identifier) -> Result<(), CommandError> {
+    let bar = bar(&context.server.boss_bars.lock(), id)?;
+    let online: Vec<_> = context
+        .server
+        .get_players()
+        .into_iter()
+        .filter(|player| bar.players.contains(&player.gameprofile.id))
+        .collect();
+    let message = if online.is_empty() {
+        translations::COMMANDS_BOSSBAR_GET_PLAYERS_NONE.message([bar.display_name()])
+    } else {
+        translations::COMMANDS_BOSSBAR_GET_PLAYERS_SOME.message([
+            bar.display_name(),
+            TextComponent::from(online.len().to_string()),
+            player_names(&online),
+        ])
+    };
+    context.send_success(&message.into(), false);
+    context.result = count(online.len());
+    Ok(())
+}
+
+/// The names of `players`, separated by commas.
+fn player_names(players: &[Arc<Player>]) -> TextComponent {
+    format_list(
+        players
+            .iter()
+            .map(|player| TextComponent::from(player.gameprofile.name.clone()))
+            .collect(),
+        &TextComponent::plain(", "),
+    )
+}
+
+fn count(len: usize) -> i32 {
+    i32::try_from(len).unwrap_or(i32::MAX)
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_protocol::packets::game::BossEventAction;
+    use steel_utils::Identifier;
+    use text_components::TextComponent;
+    use uuid::Uuid;
+
+    use super::{create_bar, set_value};
+    use crate::boss_bar::BossBars;
+
+    #[test]
+    fn created_bars_take_new_values() {
+        let mut bars = BossBars::default();
+        let id = Identifier::new("steel", "test");
+
+        let (_, count) = create_bar(&mut bars, id.clone(), TextComponent::plain("Test"))
+            .expect("the id is free");
+        assert_eq!(count, 1);
+        assert!(create_bar(&mut bars, id.clone(), TextComponent::plain("Test")).is_err());
+
+        let bar = bars.get_mut(&id).expect("the bar was created");
+        bar.players.insert(Uuid::nil());
+        let old = bar.clone();
+
+        let (_, result) = set_value(bar, 25).expect("the value changed");
+        assert_eq!(result, 25);
+        assert!(set_value(bar, 25).is_err());
+        assert!((bar.progress() - 0.25).abs() < f32::EPSILON);
+
+        let packets = bar.update_packets(&old);
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].0, Uuid::nil());
+        assert!(matches!(
+            packets[0].1.action,
+            BossEventAction::UpdateProgress(_)
+        ));
+    }
+}
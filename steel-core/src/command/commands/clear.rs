@@ -1,4 +1,5 @@
 //! Handler for the "clear" command.
+//! Mirrors `net.minecraft.server.commands.ClearInventoryCommands`.
 use std::sync::Arc;
 
 use steel_registry::{item_stack::ItemStack, items::ItemRef};
@@ -7,11 +8,11 @@ use text_components::TextComponent;
 
 use crate::{
     command::{
+        LEVEL_GAMEMASTERS,
         arguments::{integer::IntegerArgument, item::ItemStackArgument, player::PlayerArgument},
         commands::{CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument},
         context::CommandContext,
         error::CommandError,
-        sender::CommandSender,
     },
     inventory::container::Container,
     player::Player,
@@ -25,6 +26,7 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Clears the Player's inventory.",
         "minecraft:command.clear",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .executes(ClearNoArgumentExecutor)
     .then(
         argument("targets", PlayerArgument::multiple())
@@ -44,22 +46,14 @@ struct ClearNoArgumentExecutor;
 
 impl CommandExecutor<()> for ClearNoArgumentExecutor {
     fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
-        let player = context
-            .sender
-            .get_player()
-            .ok_or(CommandError::InvalidRequirement)?;
+        let Some(player) = context.sender.get_player().cloned() else {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::PERMISSIONS_REQUIRES_PLAYER.msg().into(),
+            )));
+        };
 
         let count = { player.inventory.lock().clear_content() };
-
-        clear_messages(
-            &context.sender,
-            count,
-            1,
-            Some(player.gameprofile.name.clone()),
-            false,
-        );
-
-        Ok(())
+        clear_messages(context, count, &[player], false)
     }
 }
 
@@ -78,15 +72,7 @@ impl CommandExecutor<((), Vec<Arc<Player>>)> for ClearMultipleArgumentExecutor {
             .map(|player| player.inventory.lock().clear_content())
             .sum();
 
-        clear_messages(
-            &context.sender,
-            count,
-            targets.len(),
-            targets.first().map(|it| it.gameprofile.name.clone()),
-            false,
-        );
-
-        Ok(())
+        clear_messages(context, count, &targets, false)
     }
 }
 
@@ -107,15 +93,7 @@ impl CommandExecutor<(((), Vec<Arc<Player>>), ItemRef)> for ClearWithItemExecuto
             .map(|it| it.inventory.lock().clear_content_matching(&mut filter))
             .sum();
 
-        clear_messages(
-            &context.sender,
-            count,
-            targets.len(),
-            targets.first().map(|it| it.gameprofile.name.clone()),
-            false,
-        );
-
-        Ok(())
+        clear_messages(context, count, &targets, false)
     }
 }
 
@@ -131,108 +109,106 @@ impl CommandExecutor<((((), Vec<Arc<Player>>), ItemRef), i32)> for ClearWithMaxA
 
         let count: i32 = targets
             .iter()
-            .map(|it| {
-                let mut current_amount = max_amount;
-                let mut inventory = it.inventory.lock();
-                let mut removed = 0;
-                for i in 0..inventory.get_container_size() {
-                    if max_amount > 0 && current_amount == 0 {
-                        break;
-                    }
-                    let current_item = inventory.get_item_mut(i);
-                    if current_item.is_empty() || !current_item.is(item) {
-                        continue;
-                    }
-                    if max_amount == 0 {
-                        removed += current_item.count();
-                    } else {
-                        let amount_to_remove = current_amount.min(current_item.count());
-                        current_amount -= amount_to_remove;
-                        removed += amount_to_remove;
-                        current_item.shrink(amount_to_remove);
-                    }
-                }
-                if max_amount > 0 && removed > 0 {
-                    inventory.set_changed();
-                }
-                removed
-            })
+            .map(|it| clear_up_to(&mut *it.inventory.lock(), item, max_amount))
             .sum();
 
-        clear_messages(
-            &context.sender,
-            count,
-            targets.len(),
-            targets.first().map(|it| it.gameprofile.name.clone()),
-            max_amount == 0,
-        );
+        clear_messages(context, count, &targets, max_amount == 0)
+    }
+}
 
-        Ok(())
+/// Removes up to `max_amount` of `item` from `container`, returning how many were removed.
+/// A `max_amount` of `0` only counts the matching items.
+fn clear_up_to(container: &mut impl Container, item: ItemRef, max_amount: i32) -> i32 {
+    let mut current_amount = max_amount;
+    let mut removed = 0;
+    for i in 0..container.get_container_size() {
+        if max_amount > 0 && current_amount == 0 {
+            break;
+        }
+        let current_item = container.get_item_mut(i);
+        if current_item.is_empty() || !current_item.is(item) {
+            continue;
+        }
+        if max_amount == 0 {
+            removed += current_item.count();
+        } else {
+            let amount_to_remove = current_amount.min(current_item.count());
+            current_amount -= amount_to_remove;
+            removed += amount_to_remove;
+            current_item.shrink(amount_to_remove);
+        }
     }
+    if max_amount > 0 && removed > 0 {
+        container.set_changed();
+    }
+    removed
 }
 
 fn clear_messages(
-    sender: &CommandSender,
+    context: &mut CommandContext,
     count: i32,
-    player_amount: usize,
-    target_name: Option<String>,
+    targets: &[Arc<Player>],
     count_only: bool,
-) {
-    if count == 0
-        && player_amount == 1
-        && let Some(name) = target_name
-    {
-        sender.send_message(
-            &translations::CLEAR_FAILED_SINGLE
-                .message([TextComponent::from(name)])
-                .into(),
-        );
-    } else if count == 0 {
-        sender.send_message(
-            &translations::CLEAR_FAILED_MULTIPLE
-                .message([TextComponent::from(format!("{player_amount}"))])
-                .into(),
-        );
-    } else if count_only
-        && player_amount == 1
-        && let Some(name) = target_name
-    {
-        sender.send_message(
-            &translations::COMMANDS_CLEAR_TEST_SINGLE
-                .message([
-                    TextComponent::from(format!("{count}")),
-                    TextComponent::from(name),
-                ])
-                .into(),
-        );
-    } else if count_only {
-        sender.send_message(
-            &translations::COMMANDS_CLEAR_TEST_MULTIPLE
-                .message([
-                    TextComponent::from(format!("{count}")),
-                    TextComponent::from(format!("{player_amount}")),
-                ])
-                .into(),
-        );
-    } else if player_amount == 1
-        && let Some(name) = target_name
-    {
-        sender.send_message(
-            &translations::COMMANDS_CLEAR_SUCCESS_SINGLE
-                .message([
-                    TextComponent::from(format!("{count}")),
-                    TextComponent::from(name),
-                ])
-                .into(),
-        );
-    } else {
-        sender.send_message(
-            &translations::COMMANDS_CLEAR_SUCCESS_MULTIPLE
-                .message([
-                    TextComponent::from(format!("{count}")),
-                    TextComponent::from(format!("{player_amount}")),
-                ])
-                .into(),
-        );
+) -> Result<(), CommandError> {
+    // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
+    let single = match targets {
+        [target] => Some(TextComponent::from(target.gameprofile.name.clone())),
+        _ => None,
+    };
+    let player_amount = TextComponent::from(format!("{}", targets.len()));
+
+    if count == 0 {
+        return Err(CommandError::CommandFailed(Box::new(
+            match single {
+                Some(name) => translations::CLEAR_FAILED_SINGLE.message([name]),
+                None => translations::CLEAR_FAILED_MULTIPLE.message([player_amount]),
+            }
+            .into(),
+        )));
+    }
+
+    let count_text = TextComponent::from(format!("{count}"));
+    let message = match (count_only, single) {
+        (true, Some(name)) => translations::COMMANDS_CLEAR_TEST_SINGLE.message([count_text, name]),
+        (true, None) => {
+            translations::COMMANDS_CLEAR_TEST_MULTIPLE.message([count_text, player_amount])
+        }
+        (false, Some(name)) => {
+            translations::COMMANDS_CLEAR_SUCCESS_SINGLE.message([count_text, name])
+        }
+        (false, None) => {
+            translations::COMMANDS_CLEAR_SUCCESS_MULTIPLE.message([count_text, player_amount])
+        }
+    };
+    context.send_success(&message.into(), true);
+    context.result = count;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::item_stack::ItemStack;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_items;
+
+    use super::clear_up_to;
+    use crate::inventory::container::Container;
+    use crate::inventory::crafting::CraftingContainer;
+
+    #[test]
+    fn max_count_leaves_the_rest() {
+        init_test_registry();
+        let diamond = &vanilla_items::ITEMS.diamond;
+        let mut container = CraftingContainer::new(2, 1);
+        container.set_item(0, ItemStack::with_count(diamond, 10));
+        container.set_item(1, ItemStack::with_count(&vanilla_items::ITEMS.stick, 5));
+
+        assert_eq!(clear_up_to(&mut container, diamond, 4), 4);
+        assert_eq!(container.get_item(0).count(), 6);
+        assert_eq!(container.get_item(1).count(), 5);
+
+        // A max count of 0 only counts the matching items.
+        assert_eq!(clear_up_to(&mut container, diamond, 0), 6);
+        assert_eq!(container.get_item(0).count(), 6);
     }
 }
@@ -0,0 +1,732 @@
+//! Handler for the "data" command.
+//! Mirrors `net.minecraft.server.commands.data.DataCommands`.
+use std::io::Cursor;
+use std::sync::Arc;
+
+use glam::DVec3;
+use simdnbt::borrow::read_compound as read_borrowed_compound;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_utils::snbt;
+use steel_utils::text::tag_to_snbt;
+use steel_utils::uuid_ext::UuidExt;
+use steel_utils::{BlockPos, Identifier, translations};
+use text_components::TextComponent;
+
+use crate::block_entity::SharedBlockEntity;
+use crate::command::LEVEL_GAMEMASTERS;
+use crate::command::arguments::CommandArgument;
+use crate::command::arguments::block_pos::BlockPosArgument;
+use crate::command::arguments::double::DoubleArgument;
+use crate::command::arguments::entity::EntityArgument;
+use crate::command::arguments::integer::IntegerArgument;
+use crate::command::arguments::nbt_compound::NbtCompoundArgument;
+use crate::command::arguments::nbt_path::{NbtPath, NbtPathArgument, with_elements};
+use crate::command::arguments::nbt_tag::NbtTagArgument;
+use crate::command::arguments::resource_location::ResourceLocationArgument;
+use crate::command::commands::setblock::{load_block_entity, loaded_pos};
+use crate::command::commands::summon::entity_display_name;
+use crate::command::commands::{
+    CommandHandlerBuilder, CommandHandlerDyn, CommandParserExecutor, argument, literal,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::entity::LivingEntity;
+
+type PathArgs<T> = (((), <T as DataTarget>::Arg), NbtPath);
+type InsertArgs<T> = ((PathArgs<T>, i32), NbtTag);
+
+/// Handler for the "data" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["data"],
+        "Gets, merges, modifies and removes block entity, entity and storage NBT data.",
+        "minecraft:command.data",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        literal("merge")
+            .then(merge_branch::<BlockTarget>())
+            .then(merge_branch::<EntityTarget>())
+            .then(merge_branch::<StorageTarget>()),
+    )
+    .then(
+        literal("get")
+            .then(get_branch::<BlockTarget>())
+            .then(get_branch::<EntityTarget>())
+            .then(get_branch::<StorageTarget>()),
+    )
+    .then(
+        literal("remove")
+            .then(remove_branch::<BlockTarget>())
+            .then(remove_branch::<EntityTarget>())
+            .then(remove_branch::<StorageTarget>()),
+    )
+    .then(
+        literal("modify")
+            .then(modify_branch::<BlockTarget>())
+            .then(modify_branch::<EntityTarget>())
+            .then(modify_branch::<StorageTarget>()),
+    )
+}
+
+/// A kind of `/data` target, vanilla's `DataCommands.DataProvider`.
+trait DataTarget: 'static {
+    type Arg: 'static;
+
+    /// The literal that picks this kind.
+    const LITERAL: &'static str;
+    /// The name of the argument after the literal.
+    const ARGUMENT: &'static str;
+
+    fn argument() -> impl CommandArgument<Output = Self::Arg> + 'static;
+
+    fn access(arg: Self::Arg, context: &CommandContext) -> Result<DataAccessor, CommandError>;
+}
+
+struct BlockTarget;
+
+impl DataTarget for BlockTarget {
+    type Arg = BlockPos;
+
+    const LITERAL: &'static str = "block";
+    const ARGUMENT: &'static str = "targetPos";
+
+    fn argument() -> impl CommandArgument<Output = BlockPos> + 'static {
+        BlockPosArgument
+    }
+
+    fn access(pos: BlockPos, context: &CommandContext) -> Result<DataAccessor, CommandError> {
+        loaded_pos(&context.world, pos)?;
+        let block_entity = context
+            .world
+            .get_block_entity(pos)
+            .ok_or_else(|| failed(translations::COMMANDS_DATA_BLOCK_INVALID.msg()))?;
+        Ok(DataAccessor::Block { pos, block_entity })
+    }
+}
+
+struct EntityTarget;
+
+impl DataTarget for EntityTarget {
+    type Arg = Vec<Arc<dyn LivingEntity + Send + Sync>>;
+
+    const LITERAL: &'static str = "entity";
+    const ARGUMENT: &'static str = "target";
+
+    fn argument() -> impl CommandArgument<Output = Self::Arg> + 'static {
+        EntityArgument::one()
+    }
+
+    fn access(
+        mut entities: Self::Arg,
+        _context: &CommandContext,
+    ) -> Result<DataAccessor, CommandError> {
+        entities
+            .pop()
+            .map(DataAccessor::Entity)
+            .ok_or_else(|| failed(translations::ARGUMENT_ENTITY_NOTFOUND_ENTITY.msg()))
+    }
+}
+
+struct StorageTarget;
+
+impl DataTarget for StorageTarget {
+    type Arg = Identifier;
+
+    const LITERAL: &'static str = "storage";
+    const ARGUMENT: &'static str = "target";
+
+    fn argument() -> impl CommandArgument<Output = Identifier> + 'static {
+        ResourceLocationArgument
+    }
+
+    fn access(id: Identifier, _context: &CommandContext) -> Result<DataAccessor, CommandError> {
+        Ok(DataAccessor::Storage(id))
+    }
+}
+
+fn get_branch<T: DataTarget>() -> impl CommandParserExecutor<()> {
+    literal(T::LITERAL).then(
+        argument(T::ARGUMENT, T::argument())
+            .executes(|((), target): ((), T::Arg), context: &mut CommandContext| {
+                let accessor = T::access(target, context)?;
+                query_data(context, &accessor)
+            })
+            .then(
+                argument("path", NbtPathArgument)
+                    .executes(
+                        |(((), target), path): PathArgs<T>, context: &mut CommandContext| {
+                            let accessor = T::access(target, context)?;
+                            get_data(context, &accessor, &path)
+                        },
+                    )
+                    .then(argument("scale", DoubleArgument::new()).executes(
+                        |((((), target), path), scale): (PathArgs<T>, f64),
+                         context: &mut CommandContext| {
+                            let accessor = T::access(target, context)?;
+                            get_numeric(context, &accessor, &path, scale)
+                        },
+                    )),
+            ),
+    )
+}
+
+fn merge_branch<T: DataTarget>() -> impl CommandParserExecutor<()> {
+    literal(T::LITERAL).then(argument(T::ARGUMENT, T::argument()).then(
+        argument("nbt", NbtCompoundArgument).executes(
+            |(((), target), nbt): (((), T::Arg), NbtCompound), context: &mut CommandContext| {
+                let accessor = T::access(target, context)?;
+                merge_data(context, &accessor, &nbt)
+            },
+        ),
+    ))
+}
+
+fn remove_branch<T: DataTarget>() -> impl CommandParserExecutor<()> {
+    literal(T::LITERAL).then(argument(T::ARGUMENT, T::argument()).then(
+        argument("path", NbtPathArgument).executes(
+            |(((), target), path): PathArgs<T>, context: &mut CommandContext| {
+                let accessor = T::access(target, context)?;
+                modify_data(context, &accessor, |data| Ok(path.remove(data)))
+            },
+        ),
+    ))
+}
+
+fn modify_branch<T: DataTarget>() -> impl CommandParserExecutor<()> {
+    literal(T::LITERAL).then(
+        argument(T::ARGUMENT, T::argument()).then(
+            argument("targetPath", NbtPathArgument)
+                .then(literal("append").then(value_source::<T>(Operation::Insert(-1))))
+                .then(
+                    literal("insert").then(argument("index", IntegerArgument::new()).then(
+                        literal("value").then(argument("value", NbtTagArgument).executes(
+                            |(((((), target), path), index), value): InsertArgs<T>,
+                             context: &mut CommandContext| {
+                                let accessor = T::access(target, context)?;
+                                modify_data(context, &accessor, |data| {
+                                    Operation::Insert(index).apply(data, &path, &[value])
+                                })
+                            },
+                        )),
+                    )),
+                )
+                .then(literal("merge").then(value_source::<T>(Operation::Merge)))
+                .then(literal("prepend").then(value_source::<T>(Operation::Insert(0))))
+                .then(literal("set").then(value_source::<T>(Operation::Set))),
+        ),
+    )
+}
+
+/// `value <value>` after a modify operation.
+// TODO: add the `from <source> [sourcePath]` and `string <source> [sourcePath]` sources
+fn value_source<T: DataTarget>(operation: Operation) -> impl CommandParserExecutor<PathArgs<T>> {
+    literal("value").then(argument("value", NbtTagArgument).executes(
+        move |((((), target), path), value): (PathArgs<T>, NbtTag),
+              context: &mut CommandContext| {
+            let accessor = T::access(target, context)?;
+            modify_data(context, &accessor, |data| {
+                operation.apply(data, &path, std::slice::from_ref(&value))
+            })
+        },
+    ))
+}
+
+/// A way `/data modify` writes its source values to the target path.
+#[derive(Clone, Copy)]
+enum Operation {
+    /// Replaces the tags the path leads to with the last source.
+    Set,
+    /// Merges every source into the compounds the path leads to.
+    Merge,
+    /// Inserts the sources into the lists the path leads to, `-1` appending.
+    Insert(i32),
+}
+
+impl Operation {
+    /// Applies the operation to `data`, returning how many tags changed.
+    fn apply(
+        self,
+        data: &mut NbtCompound,
+        path: &NbtPath,
+        sources: &[NbtTag],
+    ) -> Result<usize, CommandError> {
+        match self {
+            Self::Set => Ok(sources.last().map_or(0, |source| path.set(data, source))),
+            Self::Merge => {
+                let mut combined = NbtCompound::new();
+                for source in sources {
+                    let NbtTag::Compound(source) = source else {
+                        return Err(expected_object(source));
+                    };
+                    merge_compound(&mut combined, source);
+                }
+                path.get_or_create(
+                    data,
+                    || NbtTag::Compound(NbtCompound::new()),
+                    |target| {
+                        let NbtTag::Compound(target) = target else {
+                            return Err(expected_object(target));
+                        };
+                        let original = target.clone();
+                        merge_compound(target, &combined);
+                        Ok(usize::from(*target != original))
+                    },
+                )
+            }
+            Self::Insert(index) => path.get_or_create(
+                data,
+                || NbtTag::List(NbtList::Empty),
+                |target| insert_into_list(target, index, sources),
+            ),
+        }
+    }
+}
+
+/// Vanilla `DataCommands.insertIntoList`.
+fn insert_into_list(
+    target: &mut NbtTag,
+    index: i32,
+    sources: &[NbtTag],
+) -> Result<usize, CommandError> {
+    if !matches!(
+        target,
+        NbtTag::List(_) | NbtTag::ByteArray(_) | NbtTag::IntArray(_) | NbtTag::LongArray(_)
+    ) {
+        return Err(failed(
+            translations::COMMANDS_DATA_MODIFY_EXPECTED_LIST
+                .message([TextComponent::from(tag_to_snbt(target))]),
+        ));
+    }
+    with_elements(target, |elements| {
+        let len = i64::try_from(elements.len()).unwrap_or(i64::MAX);
+        let at = if index < 0 {
+            len + i64::from(index) + 1
+        } else {
+            i64::from(index)
+        };
+        let at = usize::try_from(at)
+            .ok()
+            .filter(|&at| at <= elements.len())
+            .ok_or_else(|| {
+                failed(
+                    translations::COMMANDS_DATA_MODIFY_INVALID_INDEX
+                        .message([TextComponent::from(index.to_string())]),
+                )
+            })?;
+        for (offset, source) in sources.iter().enumerate() {
+            elements.insert(at + offset, source.clone());
+        }
+        Ok(sources.len())
+    })
+}
+
+/// Vanilla `CompoundTag.merge`: nested compounds are merged, everything else is replaced.
+///
+/// Existing keys keep their place, so merging a compound's own values leaves it equal to before.
+fn merge_compound(target: &mut NbtCompound, source: &NbtCompound) {
+    for (key, value) in source.iter() {
+        let key = key.to_str();
+        match (value, target.get_mut(&key)) {
+            (NbtTag::Compound(source), Some(NbtTag::Compound(target))) => {
+                merge_compound(target, source);
+            }
+            (_, Some(existing)) => *existing = value.clone(),
+            (_, None) => target.insert(&*key, value.clone()),
+        }
+    }
+}
+
+/// What a `/data` target resolved to, vanilla's `DataAccessor`.
+enum DataAccessor {
+    Block {
+        pos: BlockPos,
+        block_entity: SharedBlockEntity,
+    },
+    Entity(Arc<dyn LivingEntity + Send + Sync>),
+    Storage(Identifier),
+}
+
+impl DataAccessor {
+    fn data(&self, context: &CommandContext) -> NbtCompound {
+        match self {
+            Self::Block { pos, block_entity } => {
+                let block_entity = block_entity.lock();
+                let mut nbt = NbtCompound::new();
+                nbt.insert(
+                    "id",
+                    NbtTag::String(block_entity.get_type().key.to_string().into()),
+                );
+                nbt.insert("x", pos.x());
+                nbt.insert("y", pos.y());
+                nbt.insert("z", pos.z());
+                block_entity.save_additional(&mut nbt);
+                nbt
+            }
+            Self::Entity(entity) => entity_data(entity.as_ref()),
+            Self::Storage(id) => context
+                .server
+                .command_storage
+                .lock()
+                .get(id)
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn set_data(&self, context: &CommandContext, mut nbt: NbtCompound) -> Result<(), CommandError> {
+        match self {
+            Self::Block { pos, .. } => {
+                for key in ["id", "x", "y", "z"] {
+                    let _ = nbt.remove(key);
+                }
+                load_block_entity(&context.world, *pos, &nbt);
+            }
+            Self::Entity(entity) => {
+                if entity.as_player().is_some() {
+                    return Err(failed(translations::COMMANDS_DATA_ENTITY_INVALID.msg()));
+                }
+                load_base_entity_data(entity.as_ref(), &nbt)?;
+                let mut bytes = Vec::new();
+                nbt.write(&mut bytes);
+                if let Ok(borrowed) = read_borrowed_compound(&mut Cursor::new(&bytes)) {
+                    entity.load_additional((&borrowed).into());
+                    entity.sync_base_entity_data();
+                }
+            }
+            Self::Storage(id) => {
+                context
+                    .server
+                    .command_storage
+                    .lock()
+                    .insert(id.clone(), nbt);
+            }
+        }
+        Ok(())
+    }
+
+    fn query_message(&self, tag: &NbtTag) -> TextComponent {
+        // TODO: vanilla highlights the SNBT with `NbtUtils.toPrettyComponent`
+        let value = TextComponent::from(tag_to_snbt(tag));
+        match self {
+            Self::Block { pos, .. } => {
+                let [x, y, z] = block_coordinates(*pos);
+                translations::COMMANDS_DATA_BLOCK_QUERY.message([x, y, z, value])
+            }
+            Self::Entity(entity) => translations::COMMANDS_DATA_ENTITY_QUERY
+                .message([entity_display_name(entity.as_ref()), value]),
+            Self::Storage(id) => translations::COMMANDS_DATA_STORAGE_QUERY
+                .message([TextComponent::from(id.to_string()), value]),
+        }
+        .into()
+    }
+
+    fn get_message(&self, path: &NbtPath, scale: f64, value: i32) -> TextComponent {
+        let path = TextComponent::from(path.to_string());
+        let scale = TextComponent::from(format!("{scale:.2}"));
+        let value = TextComponent::from(value.to_string());
+        match self {
+            Self::Block { pos, .. } => {
+                let [x, y, z] = block_coordinates(*pos);
+                translations::COMMANDS_DATA_BLOCK_GET.message([path, x, y, z, scale, value])
+            }
+            Self::Entity(entity) => translations::COMMANDS_DATA_ENTITY_GET.message([
+                path,
+                entity_display_name(entity.as_ref()),
+                scale,
+                value,
+            ]),
+            Self::Storage(id) => translations::COMMANDS_DATA_STORAGE_GET.message([
+                path,
+                TextComponent::from(id.to_string()),
+                scale,
+                value,
+            ]),
+        }
+        .into()
+    }
+
+    fn modified_message(&self) -> TextComponent {
+        match self {
+            Self::Block { pos, .. } => {
+                translations::COMMANDS_DATA_BLOCK_MODIFIED.message(block_coordinates(*pos))
+            }
+            Self::Entity(entity) => translations::COMMANDS_DATA_ENTITY_MODIFIED
+                .message([entity_display_name(entity.as_ref())]),
+            Self::Storage(id) => translations::COMMANDS_DATA_STORAGE_MODIFIED
+                .message([TextComponent::from(id.to_string())]),
+        }
+        .into()
+    }
+}
+
+fn block_coordinates(pos: BlockPos) -> [TextComponent; 3] {
+    [pos.x(), pos.y(), pos.z()].map(|c| TextComponent::from(c.to_string()))
+}
+
+/// The saved fields of an entity, as vanilla's `NbtPredicate.getEntityTagToCompare` sees them.
+// TODO: the rest of vanilla's `Entity.saveWithoutId` (Air, Fire, CustomName, Passengers, ...)
+fn entity_data(entity: &(dyn LivingEntity + Send + Sync)) -> NbtCompound {
+    let mut nbt = NbtCompound::new();
+    let pos = entity.position();
+    let velocity = entity.velocity();
+    let (yaw, pitch) = entity.rotation();
+    nbt.insert(
+        "Pos",
+        NbtTag::List(NbtList::Double(vec![pos.x, pos.y, pos.z])),
+    );
+    nbt.insert(
+        "Motion",
+        NbtTag::List(NbtList::Double(vec![velocity.x, velocity.y, velocity.z])),
+    );
+    nbt.insert("Rotation", NbtTag::List(NbtList::Float(vec![yaw, pitch])));
+    nbt.insert("OnGround", i8::from(entity.on_ground()));
+    nbt.insert(
+        "UUID",
+        NbtTag::IntArray(entity.uuid().to_int_array().to_vec()),
+    );
+    let tags = entity.base().tags();
+    if !tags.is_empty() {
+        nbt.insert(
+            "Tags",
+            NbtTag::List(NbtList::String(tags.into_iter().map(Into::into).collect())),
+        );
+    }
+    entity.save_additional(&mut nbt);
+    nbt
+}
+
+/// Applies the base fields [`entity_data`] writes, like vanilla `Entity.load` does before the
+/// entity's own fields. The UUID is kept, as in vanilla `EntityDataAccessor.setData`.
+fn load_base_entity_data(
+    entity: &(dyn LivingEntity + Send + Sync),
+    nbt: &NbtCompound,
+) -> Result<(), CommandError> {
+    if let Some(NbtTag::List(NbtList::Double(pos))) = nbt.get("Pos")
+        && let [x, y, z] = pos[..]
+    {
+        entity
+            .try_set_position(DVec3::new(x, y, z))
+            .map_err(|error| {
+                CommandError::CommandFailed(Box::new(TextComponent::plain(format!(
+                    "Failed to move the entity: {error}"
+                ))))
+            })?;
+    }
+    if let Some(NbtTag::List(NbtList::Double(motion))) = nbt.get("Motion")
+        && let [x, y, z] = motion[..]
+    {
+        entity.set_velocity(DVec3::new(x, y, z));
+    }
+    if let Some(NbtTag::List(NbtList::Float(rotation))) = nbt.get("Rotation")
+        && let [yaw, pitch] = rotation[..]
+    {
+        entity.set_rotation((yaw, pitch));
+        entity.set_y_head_rot(yaw);
+    }
+    if let Some(NbtTag::Byte(on_ground)) = nbt.get("OnGround") {
+        entity.set_on_ground(*on_ground != 0);
+    }
+
+    let base = entity.base();
+    for tag in base.tags() {
+        base.remove_tag(&tag);
+    }
+    if let Some(NbtTag::List(NbtList::String(tags))) = nbt.get("Tags") {
+        for tag in tags {
+            base.add_tag(tag.to_str().into_owned());
+        }
+    }
+    Ok(())
+}
+
+/// `data get <target>`, showing all of the target's data.
+fn query_data(context: &mut CommandContext, accessor: &DataAccessor) -> Result<(), CommandError> {
+    let data = NbtTag::Compound(accessor.data(context));
+    context.send_success(&accessor.query_message(&data), false);
+    context.result = 1;
+    Ok(())
+}
+
+/// `data get <target> <path>`, showing the single tag the path leads to.
+fn get_data(
+    context: &mut CommandContext,
+    accessor: &DataAccessor,
+    path: &NbtPath,
+) -> Result<(), CommandError> {
+    let tag = single_tag(path, &accessor.data(context))?;
+    context.send_success(&accessor.query_message(&tag), false);
+    context.result = tag_result(&tag);
+    Ok(())
+}
+
+/// `data get <target> <path> <scale>`, reading a number multiplied by `scale`.
+fn get_numeric(
+    context: &mut CommandContext,
+    accessor: &DataAccessor,
+    path: &NbtPath,
+    scale: f64,
+) -> Result<(), CommandError> {
+    let tag = single_tag(path, &accessor.data(context))?;
+    let value = numeric_value(&tag).ok_or_else(|| {
+        failed(
+            translations::COMMANDS_DATA_GET_INVALID
+                .message([TextComponent::from(path.to_string())]),
+        )
+    })?;
+    let result = (value * scale).floor() as i32;
+    context.send_success(&accessor.get_message(path, scale, result), false);
+    context.result = result;
+    Ok(())
+}
+
+/// `data merge <target> <nbt>`.
+fn merge_data(
+    context: &mut CommandContext,
+    accessor: &DataAccessor,
+    nbt: &NbtCompound,
+) -> Result<(), CommandError> {
+    let original = accessor.data(context);
+    let mut merged = original.clone();
+    merge_compound(&mut merged, nbt);
+    if merged == original {
+        return Err(failed(translations::COMMANDS_DATA_MERGE_FAILED.msg()));
+    }
+    accessor.set_data(context, merged)?;
+    context.send_success(&accessor.modified_message(), true);
+    context.result = 1;
+    Ok(())
+}
+
+/// Runs `change` on the target's data and writes it back, failing if nothing changed.
+fn modify_data(
+    context: &mut CommandContext,
+    accessor: &DataAccessor,
+    change: impl FnOnce(&mut NbtCompound) -> Result<usize, CommandError>,
+) -> Result<(), CommandError> {
+    let mut data = accessor.data(context);
+    let changed = change(&mut data)?;
+    if changed == 0 {
+        return Err(failed(translations::COMMANDS_DATA_MERGE_FAILED.msg()));
+    }
+    accessor.set_data(context, data)?;
+    context.send_success(&accessor.modified_message(), true);
+    context.result = i32::try_from(changed).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+/// The one tag `path` leads to in `data`.
+fn single_tag(path: &NbtPath, data: &NbtCompound) -> Result<NbtTag, CommandError> {
+    let mut tags = path.get(data);
+    match tags.len() {
+        0 => Err(failed(
+            translations::ARGUMENTS_NBTPATH_NOTHING_FOUND
+                .message([TextComponent::from(path.to_string())]),
+        )),
+        1 => Ok(tags.swap_remove(0)),
+        _ => Err(failed(translations::COMMANDS_DATA_GET_MULTIPLE.msg())),
+    }
+}
+
+fn numeric_value(tag: &NbtTag) -> Option<f64> {
+    Some(match *tag {
+        NbtTag::Byte(value) => f64::from(value),
+        NbtTag::Short(value) => f64::from(value),
+        NbtTag::Int(value) => f64::from(value),
+        NbtTag::Long(value) => value as f64,
+        NbtTag::Float(value) => f64::from(value),
+        NbtTag::Double(value) => value,
+        _ => return None,
+    })
+}
+
+/// Vanilla `DataCommands.getSingleTag`'s result: numbers rounded down, the size of collections
+/// and compounds, the length of strings.
+fn tag_result(tag: &NbtTag) -> i32 {
+    let len = match tag {
+        NbtTag::Compound(compound) => compound.len(),
+        NbtTag::List(list) => snbt::tags_from_list(list.clone()).len(),
+        NbtTag::ByteArray(bytes) => bytes.len(),
+        NbtTag::IntArray(ints) => ints.len(),
+        NbtTag::LongArray(longs) => longs.len(),
+        NbtTag::String(string) => string.to_str().chars().count(),
+        number => return numeric_value(number).map_or(0, |value| value.floor() as i32),
+    };
+    i32::try_from(len).unwrap_or(i32::MAX)
+}
+
+fn expected_object(tag: &NbtTag) -> CommandError {
+    failed(
+        translations::COMMANDS_DATA_MODIFY_EXPECTED_OBJECT
+            .message([TextComponent::from(tag_to_snbt(tag))]),
+    )
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+    use simdnbt::owned::NbtTag;
+    use steel_utils::snbt::parse_compound;
+
+    use super::{load_base_entity_data, merge_compound, single_tag, tag_result};
+    use crate::command::arguments::nbt_path::NbtPath;
+    use crate::entity::Entity;
+    use crate::test_support::server::{add_player, test_server};
+
+    #[test]
+    fn reads_a_value_by_path() {
+        let data =
+            parse_compound("{Items:[{id:\"minecraft:stone\",count:12b}],CustomName:\"Loot\"}")
+                .expect("valid snbt");
+
+        let count = NbtPath::parse("Items[0].count").expect("valid path");
+        let tag = single_tag(&count, &data).expect("the path leads to one tag");
+        assert_eq!(tag, NbtTag::Byte(12));
+        assert_eq!(tag_result(&tag), 12);
+
+        let name = NbtPath::parse("CustomName").expect("valid path");
+        let tag = single_tag(&name, &data).expect("the path leads to one tag");
+        assert_eq!(tag_result(&tag), 4);
+
+        let missing = NbtPath::parse("Items[1].count").expect("valid path");
+        assert!(single_tag(&missing, &data).is_err());
+    }
+
+    #[test]
+    fn merging_keeps_the_order_of_existing_keys() {
+        let original = parse_compound("{a:1b,nested:{x:1,y:2},b:\"text\"}").expect("valid snbt");
+        let mut merged = original.clone();
+        merge_compound(&mut merged, &original);
+        assert_eq!(merged, original);
+
+        let changes = parse_compound("{nested:{y:3,z:4},a:2b,c:5}").expect("valid snbt");
+        merge_compound(&mut merged, &changes);
+        let expected =
+            parse_compound("{a:2b,nested:{x:1,y:3,z:4},b:\"text\",c:5}").expect("valid snbt");
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn base_entity_fields_are_applied() {
+        let server = test_server();
+        let steve = add_player(&server, "Steve");
+        steve.player.base().add_tag("old".to_owned());
+        let nbt = parse_compound(concat!(
+            "{Pos:[1.5d,70.0d,-2.5d],Motion:[0.0d,0.5d,0.0d],",
+            "Rotation:[90.0f,15.0f],Tags:[\"new\"]}"
+        ))
+        .expect("valid snbt");
+
+        load_base_entity_data(steve.player.as_ref(), &nbt).expect("the fields should load");
+
+        assert_eq!(steve.player.position(), DVec3::new(1.5, 70.0, -2.5));
+        assert_eq!(steve.player.velocity(), DVec3::new(0.0, 0.5, 0.0));
+        assert_eq!(steve.player.rotation(), (90.0, 15.0));
+        assert_eq!(steve.player.base().tags(), ["new"]);
+    }
+}
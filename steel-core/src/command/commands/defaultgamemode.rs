@@ -0,0 +1,38 @@
+//! Handler for the "defaultgamemode" command.
+//! Mirrors `net.minecraft.server.commands.DefaultGameModeCommands`.
+use crate::command::LEVEL_GAMEMASTERS;
+use crate::command::arguments::gamemode::GameModeArgument;
+use crate::command::commands::gamemode::get_gamemode_translation;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument};
+use crate::command::context::CommandContext;
+use steel_utils::translations;
+use steel_utils::types::GameType;
+use text_components::TextComponent;
+
+/// Handler for the "defaultgamemode" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["defaultgamemode"],
+        "Sets the game mode new players join with.",
+        "minecraft:command.defaultgamemode",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(argument("gamemode", GameModeArgument).executes(
+        |((), gamemode): ((), GameType), context: &mut CommandContext| {
+            let domain = context.world.domain().to_owned();
+            for world in context.server.worlds.worlds_in_domain(&domain) {
+                world.set_default_gamemode(gamemode);
+            }
+
+            // TODO: enforce the game mode on online players once force-gamemode is supported
+            context.send_success(
+                &translations::COMMANDS_DEFAULTGAMEMODE_SUCCESS
+                    .message([TextComponent::from(get_gamemode_translation(gamemode))])
+                    .into(),
+                true,
+            );
+            Ok(())
+        },
+    ))
+}
@@ -0,0 +1,73 @@
+//! Handler for the "deop" command.
+//! Mirrors `net.minecraft.server.commands.DeOpCommand`.
+use std::sync::Arc;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::arguments::player::PlayerArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::command::{LEVEL_ADMINS, LEVEL_ALL};
+use crate::player::Player;
+
+/// Handler for the "deop" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["deop"],
+        "Revokes operator status from players.",
+        "minecraft:command.deop",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(argument("targets", PlayerArgument::multiple()).executes(DeopExecutor))
+}
+
+struct DeopExecutor;
+
+impl CommandExecutor<((), Vec<Arc<Player>>)> for DeopExecutor {
+    fn execute(
+        &self,
+        args: ((), Vec<Arc<Player>>),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((), targets) = args;
+
+        let mut count = 0;
+        for target in targets {
+            let removed = context
+                .server
+                .op_list
+                .remove(target.gameprofile.id)
+                .map_err(|e| {
+                    log::error!("Failed to save ops.json: {e}");
+                    CommandError::CommandFailed(Box::new(TextComponent::const_plain(
+                        "Failed to save the operator list",
+                    )))
+                })?;
+            if !removed {
+                continue;
+            }
+
+            target.set_permission_level(LEVEL_ALL);
+            count += 1;
+            context.send_success(
+                &translations::COMMANDS_DEOP_SUCCESS
+                    .message([TextComponent::plain(target.gameprofile.name.clone())])
+                    .into(),
+                true,
+            );
+        }
+
+        if count == 0 {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_DEOP_FAILED.msg().into(),
+            )));
+        }
+        context.result = count;
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 //! Handler for the "difficulty" command
 
+use crate::command::LEVEL_GAMEMASTERS;
+use crate::command::arguments::integer::IntegerArgument;
 use crate::command::commands::{
-    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, literal,
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal,
 };
 use crate::command::context::CommandContext;
 use crate::command::error::CommandError;
@@ -19,11 +21,14 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Gets or sets the world difficulty.",
         "minecraft:command.difficulty",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .executes(QueryExecutor)
     .then(literal("peaceful").executes(SetExecutor(Difficulty::Peaceful)))
     .then(literal("easy").executes(SetExecutor(Difficulty::Easy)))
     .then(literal("normal").executes(SetExecutor(Difficulty::Normal)))
     .then(literal("hard").executes(SetExecutor(Difficulty::Hard)))
+    // Not in vanilla, accepts the ids used by `server.properties`.
+    .then(argument("id", IntegerArgument::bounded(Some(0), Some(3))).executes(SetByIdExecutor))
 }
 
 /// Returns the string key for a [`Difficulty`] variant
@@ -54,12 +59,13 @@ impl CommandExecutor<()> for QueryExecutor {
         let difficulty = context.world.level_data.read().data().difficulty;
         let display_name = difficulty_display_name(difficulty);
 
-        context.sender.send_message(
+        context.send_success(
             &translations::COMMANDS_DIFFICULTY_QUERY
                 .message([TextComponent::from(display_name)])
                 .into(),
+            false,
         );
-
+        context.result = i32::from(u8::from(difficulty));
         Ok(())
     }
 }
@@ -69,38 +75,79 @@ struct SetExecutor(Difficulty);
 
 impl CommandExecutor<()> for SetExecutor {
     fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
-        let difficulty = self.0;
-
-        let domain = context.world.domain().to_owned();
-        let worlds = context.server.worlds.worlds_in_domain(&domain);
-
-        if worlds
-            .iter()
-            .all(|world| world.level_data.read().data().difficulty == difficulty)
-        {
-            return Err(CommandError::CommandFailed(Box::new(
-                translations::COMMANDS_DIFFICULTY_FAILURE
-                    .message([TextComponent::plain(difficulty_key(difficulty))])
-                    .into(),
-            )));
-        }
+        set_difficulty(context, self.0)
+    }
+}
 
-        for world in worlds {
-            let mut level_data = world.level_data.write();
-            level_data.data_mut().difficulty = difficulty;
-            let locked = level_data.data().difficulty_locked;
-            drop(level_data);
+/// Sets the world difficulty from its numeric id
+struct SetByIdExecutor;
 
-            world.broadcast_to_all(CChangeDifficulty { difficulty, locked });
-        }
+impl CommandExecutor<((), i32)> for SetByIdExecutor {
+    fn execute(&self, args: ((), i32), context: &mut CommandContext) -> Result<(), CommandError> {
+        let ((), id) = args;
+        set_difficulty(context, difficulty_from_id(id))
+    }
+}
 
-        let display_name = difficulty_display_name(difficulty);
-        context.sender.send_message(
-            &translations::COMMANDS_DIFFICULTY_SUCCESS
-                .message([TextComponent::from(display_name)])
+/// Maps an id in `0..=3` to its difficulty, anything else is [`Difficulty::Normal`]
+fn difficulty_from_id(id: i32) -> Difficulty {
+    u8::try_from(id).map_or(Difficulty::Normal, Difficulty::from)
+}
+
+fn set_difficulty(
+    context: &mut CommandContext,
+    difficulty: Difficulty,
+) -> Result<(), CommandError> {
+    let domain = context.world.domain().to_owned();
+    let worlds = context.server.worlds.worlds_in_domain(&domain);
+
+    if worlds
+        .iter()
+        .all(|world| world.level_data.read().data().difficulty == difficulty)
+    {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_DIFFICULTY_FAILURE
+                .message([TextComponent::plain(difficulty_key(difficulty))])
                 .into(),
-        );
+        )));
+    }
 
-        Ok(())
+    for world in worlds {
+        let mut level_data = world.level_data.write();
+        level_data.data_mut().difficulty = difficulty;
+        let locked = level_data.data().difficulty_locked;
+        drop(level_data);
+
+        world.broadcast_to_all(CChangeDifficulty { difficulty, locked });
+    }
+
+    let display_name = difficulty_display_name(difficulty);
+    context.send_success(
+        &translations::COMMANDS_DIFFICULTY_SUCCESS
+            .message([TextComponent::from(display_name)])
+            .into(),
+        true,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::types::Difficulty;
+
+    use super::{difficulty_from_id, difficulty_key};
+
+    #[test]
+    fn names_and_ids_match_vanilla() {
+        let spellings = [
+            (Difficulty::Peaceful, "peaceful", 0),
+            (Difficulty::Easy, "easy", 1),
+            (Difficulty::Normal, "normal", 2),
+            (Difficulty::Hard, "hard", 3),
+        ];
+        for (difficulty, name, id) in spellings {
+            assert_eq!(difficulty_key(difficulty), name);
+            assert_eq!(difficulty_from_id(id), difficulty);
+        }
     }
 }
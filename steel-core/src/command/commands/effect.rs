@@ -0,0 +1,475 @@
+//! Handler for the "effect" command.
+//! Mirrors `net.minecraft.server.commands.EffectCommands`.
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use steel_registry::mob_effect::MobEffectRef;
+use steel_utils::translations;
+use text_components::TextComponent;
+use text_components::translation::TranslatedMessage;
+
+use crate::command::LEVEL_GAMEMASTERS;
+use crate::command::arguments::bool::BoolArgument;
+use crate::command::arguments::entity::EntityArgument;
+use crate::command::arguments::integer::IntegerArgument;
+use crate::command::arguments::mob_effect::MobEffectArgument;
+use crate::command::commands::summon::entity_display_name;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::entity::{LivingEntity, MobEffectInstance};
+
+type Targets = Vec<Arc<dyn LivingEntity + Send + Sync>>;
+type EffectArgs = (((), Targets), MobEffectRef);
+
+/// Handler for the "effect" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["effect"],
+        "Adds or removes status effects.",
+        "minecraft:command.effect",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        literal("clear").executes(ClearSelfExecutor).then(
+            argument("targets", EntityArgument::multiple())
+                .executes(ClearExecutor)
+                .then(argument("effect", MobEffectArgument).executes(ClearExecutor)),
+        ),
+    )
+    .then(
+        literal("give").then(
+            argument("targets", EntityArgument::multiple()).then(
+                argument("effect", MobEffectArgument)
+                    .executes(GiveExecutor)
+                    .then(
+                        argument(
+                            "seconds",
+                            IntegerArgument::bounded(Some(1), Some(1_000_000)),
+                        )
+                        .executes(GiveExecutor)
+                        .then(
+                            argument("amplifier", IntegerArgument::bounded(Some(0), Some(255)))
+                                .executes(GiveExecutor)
+                                .then(
+                                    argument("hideParticles", BoolArgument).executes(GiveExecutor),
+                                ),
+                        ),
+                    )
+                    .then(
+                        literal("infinite").executes(GiveInfiniteExecutor).then(
+                            argument("amplifier", IntegerArgument::bounded(Some(0), Some(255)))
+                                .executes(GiveInfiniteExecutor)
+                                .then(
+                                    argument("hideParticles", BoolArgument)
+                                        .executes(GiveInfiniteExecutor),
+                                ),
+                        ),
+                    ),
+            ),
+        ),
+    )
+}
+
+/// How long a given effect lasts.
+#[derive(Clone, Copy)]
+enum EffectDuration {
+    /// 30 seconds, or a single tick for instantaneous effects.
+    Default,
+    Seconds(i32),
+    Infinite,
+}
+
+impl EffectDuration {
+    /// Returns the duration in ticks. Instantaneous effects take the seconds as ticks, like
+    /// vanilla.
+    fn ticks(self, effect: MobEffectRef) -> i32 {
+        match self {
+            Self::Default if effect.is_instantaneous() => 1,
+            Self::Default => 600,
+            Self::Seconds(seconds) if effect.is_instantaneous() => seconds,
+            Self::Seconds(seconds) => seconds * 20,
+            Self::Infinite => -1,
+        }
+    }
+}
+
+struct GiveExecutor;
+
+impl CommandExecutor<EffectArgs> for GiveExecutor {
+    fn execute(&self, args: EffectArgs, context: &mut CommandContext) -> Result<(), CommandError> {
+        let (((), targets), effect) = args;
+        give(context, &targets, effect, EffectDuration::Default, 0, false)
+    }
+}
+
+impl CommandExecutor<(EffectArgs, i32)> for GiveExecutor {
+    fn execute(
+        &self,
+        args: (EffectArgs, i32),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((((), targets), effect), seconds) = args;
+        let duration = EffectDuration::Seconds(seconds);
+        give(context, &targets, effect, duration, 0, false)
+    }
+}
+
+impl CommandExecutor<((EffectArgs, i32), i32)> for GiveExecutor {
+    fn execute(
+        &self,
+        args: ((EffectArgs, i32), i32),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let (((((), targets), effect), seconds), amplifier) = args;
+        let duration = EffectDuration::Seconds(seconds);
+        give(context, &targets, effect, duration, amplifier, false)
+    }
+}
+
+impl CommandExecutor<(((EffectArgs, i32), i32), bool)> for GiveExecutor {
+    fn execute(
+        &self,
+        args: (((EffectArgs, i32), i32), bool),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((((((), targets), effect), seconds), amplifier), hide_particles) = args;
+        let duration = EffectDuration::Seconds(seconds);
+        give(
+            context,
+            &targets,
+            effect,
+            duration,
+            amplifier,
+            hide_particles,
+        )
+    }
+}
+
+struct GiveInfiniteExecutor;
+
+impl CommandExecutor<EffectArgs> for GiveInfiniteExecutor {
+    fn execute(&self, args: EffectArgs, context: &mut CommandContext) -> Result<(), CommandError> {
+        let (((), targets), effect) = args;
+        give(
+            context,
+            &targets,
+            effect,
+            EffectDuration::Infinite,
+            0,
+            false,
+        )
+    }
+}
+
+impl CommandExecutor<(EffectArgs, i32)> for GiveInfiniteExecutor {
+    fn execute(
+        &self,
+        args: (EffectArgs, i32),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((((), targets), effect), amplifier) = args;
+        give(
+            context,
+            &targets,
+            effect,
+            EffectDuration::Infinite,
+            amplifier,
+            false,
+        )
+    }
+}
+
+impl CommandExecutor<((EffectArgs, i32), bool)> for GiveInfiniteExecutor {
+    fn execute(
+        &self,
+        args: ((EffectArgs, i32), bool),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let (((((), targets), effect), amplifier), hide_particles) = args;
+        let duration = EffectDuration::Infinite;
+        give(
+            context,
+            &targets,
+            effect,
+            duration,
+            amplifier,
+            hide_particles,
+        )
+    }
+}
+
+struct ClearSelfExecutor;
+
+impl CommandExecutor<()> for ClearSelfExecutor {
+    fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
+        let Some(player) = context.sender.get_player().cloned() else {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::PERMISSIONS_REQUIRES_ENTITY.msg().into(),
+            )));
+        };
+        clear(
+            context,
+            &[player as Arc<dyn LivingEntity + Send + Sync>],
+            None,
+        )
+    }
+}
+
+struct ClearExecutor;
+
+impl CommandExecutor<((), Targets)> for ClearExecutor {
+    fn execute(
+        &self,
+        args: ((), Targets),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((), targets) = args;
+        clear(context, &targets, None)
+    }
+}
+
+impl CommandExecutor<EffectArgs> for ClearExecutor {
+    fn execute(&self, args: EffectArgs, context: &mut CommandContext) -> Result<(), CommandError> {
+        let (((), targets), effect) = args;
+        clear(context, &targets, Some(effect))
+    }
+}
+
+fn give(
+    context: &mut CommandContext,
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
+    effect: MobEffectRef,
+    duration: EffectDuration,
+    amplifier: i32,
+    hide_particles: bool,
+) -> Result<(), CommandError> {
+    let ticks = duration.ticks(effect);
+    let instance =
+        MobEffectInstance::with_duration(effect, ticks, amplifier).with_visible(!hide_particles);
+    let affected = give_effect(targets, &instance);
+
+    let seconds = TextComponent::plain((ticks / 20).to_string());
+    let message = match affected.as_slice() {
+        [] => {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_EFFECT_GIVE_FAILED.msg().into(),
+            )));
+        }
+        [target] => translations::COMMANDS_EFFECT_GIVE_SUCCESS_SINGLE.message([
+            effect_display_name(effect),
+            entity_display_name(&***target),
+            seconds,
+        ]),
+        _ => translations::COMMANDS_EFFECT_GIVE_SUCCESS_MULTIPLE.message([
+            effect_display_name(effect),
+            TextComponent::plain(affected.len().to_string()),
+            seconds,
+        ]),
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(affected.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+/// Adds `instance` to every target, returning the targets that accepted it.
+fn give_effect<'a>(
+    targets: &'a [Arc<dyn LivingEntity + Send + Sync>],
+    instance: &MobEffectInstance,
+) -> Vec<&'a Arc<dyn LivingEntity + Send + Sync>> {
+    targets
+        .iter()
+        .filter(|target| target.add_mob_effect(instance.clone()))
+        .collect()
+}
+
+fn clear(
+    context: &mut CommandContext,
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
+    effect: Option<MobEffectRef>,
+) -> Result<(), CommandError> {
+    let affected = clear_effects(targets, effect);
+
+    let message = match (effect, affected.as_slice()) {
+        (None, []) => {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_EFFECT_CLEAR_EVERYTHING_FAILED
+                    .msg()
+                    .into(),
+            )));
+        }
+        (Some(_), []) => {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_EFFECT_CLEAR_SPECIFIC_FAILED
+                    .msg()
+                    .into(),
+            )));
+        }
+        (None, [target]) => translations::COMMANDS_EFFECT_CLEAR_EVERYTHING_SUCCESS_SINGLE
+            .message([entity_display_name(&***target)]),
+        (None, _) => translations::COMMANDS_EFFECT_CLEAR_EVERYTHING_SUCCESS_MULTIPLE
+            .message([TextComponent::plain(affected.len().to_string())]),
+        (Some(effect), [target]) => translations::COMMANDS_EFFECT_CLEAR_SPECIFIC_SUCCESS_SINGLE
+            .message([effect_display_name(effect), entity_display_name(&***target)]),
+        (Some(effect), _) => {
+            translations::COMMANDS_EFFECT_CLEAR_SPECIFIC_SUCCESS_MULTIPLE.message([
+                effect_display_name(effect),
+                TextComponent::plain(affected.len().to_string()),
+            ])
+        }
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(affected.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+/// Removes `effect`, or every effect when `None`, returning the targets that lost any.
+fn clear_effects(
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
+    effect: Option<MobEffectRef>,
+) -> Vec<&Arc<dyn LivingEntity + Send + Sync>> {
+    targets
+        .iter()
+        .filter(|target| match effect {
+            Some(effect) => target.remove_mob_effect(effect),
+            None => target.remove_all_mob_effects(),
+        })
+        .collect()
+}
+
+fn effect_display_name(effect: MobEffectRef) -> TextComponent {
+    TextComponent::translated(TranslatedMessage {
+        key: Cow::Owned(format!(
+            "effect.{}.{}",
+            effect.key.namespace, effect.key.path
+        )),
+        fallback: None,
+        args: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Weak};
+
+    use glam::DVec3;
+    use steel_registry::entity_type::EntityTypeRef;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{vanilla_entities, vanilla_mob_effects};
+    use steel_utils::locks::SyncMutex;
+
+    use super::{EffectDuration, clear_effects, give_effect};
+    use crate::entity::{Entity, EntityBase, LivingEntity, LivingEntityBase, MobEffectInstance};
+
+    struct TestLivingEntity {
+        base: EntityBase,
+        living_base: LivingEntityBase,
+        health: SyncMutex<f32>,
+        entity_type: EntityTypeRef,
+    }
+
+    impl TestLivingEntity {
+        fn new(id: i32, entity_type: EntityTypeRef) -> Arc<dyn LivingEntity + Send + Sync> {
+            Arc::new(Self {
+                base: EntityBase::new(id, DVec3::ZERO, entity_type.dimensions, Weak::new()),
+                living_base: LivingEntityBase::new(entity_type),
+                health: SyncMutex::new(20.0),
+                entity_type,
+            })
+        }
+    }
+
+    impl Entity for TestLivingEntity {
+        fn base(&self) -> &EntityBase {
+            &self.base
+        }
+
+        fn entity_type(&self) -> EntityTypeRef {
+            self.entity_type
+        }
+
+        fn as_living_entity(&self) -> Option<&dyn LivingEntity> {
+            Some(self)
+        }
+    }
+
+    impl LivingEntity for TestLivingEntity {
+        fn living_base(&self) -> &LivingEntityBase {
+            &self.living_base
+        }
+
+        fn get_health(&self) -> f32 {
+            *self.health.lock()
+        }
+
+        fn set_health(&self, health: f32) {
+            *self.health.lock() = health;
+        }
+    }
+
+    #[test]
+    fn give_applies_the_effect_to_every_target() {
+        init_test_registry();
+        let targets = [
+            TestLivingEntity::new(1, &vanilla_entities::ZOMBIE),
+            TestLivingEntity::new(2, &vanilla_entities::PIG),
+        ];
+        let speed = vanilla_mob_effects::SPEED;
+        let ticks = EffectDuration::Seconds(10).ticks(speed);
+        let instance = MobEffectInstance::with_duration(speed, ticks, 1);
+
+        assert_eq!(give_effect(&targets, &instance).len(), 2);
+        for target in &targets {
+            let effect = target.mob_effect(speed).expect("speed should be applied");
+            assert_eq!(effect.duration(), 200);
+            assert_eq!(effect.amplifier(), 1);
+        }
+    }
+
+    #[test]
+    fn instantaneous_effects_count_seconds_as_ticks() {
+        init_test_registry();
+
+        assert_eq!(
+            EffectDuration::Default.ticks(vanilla_mob_effects::INSTANT_HEALTH),
+            1
+        );
+        assert_eq!(
+            EffectDuration::Seconds(3).ticks(vanilla_mob_effects::INSTANT_HEALTH),
+            3
+        );
+        assert_eq!(
+            EffectDuration::Default.ticks(vanilla_mob_effects::SPEED),
+            600
+        );
+        assert_eq!(
+            EffectDuration::Infinite.ticks(vanilla_mob_effects::SPEED),
+            -1
+        );
+    }
+
+    #[test]
+    fn clear_removes_specific_or_all_effects() {
+        init_test_registry();
+        let targets = [TestLivingEntity::new(1, &vanilla_entities::ZOMBIE)];
+        let speed = vanilla_mob_effects::SPEED;
+        let slowness = vanilla_mob_effects::SLOWNESS;
+        give_effect(&targets, &MobEffectInstance::with_duration(speed, 600, 0));
+        give_effect(
+            &targets,
+            &MobEffectInstance::with_duration(slowness, 600, 0),
+        );
+
+        assert_eq!(clear_effects(&targets, Some(speed)).len(), 1);
+        assert!(targets[0].mob_effect(speed).is_none());
+        assert!(targets[0].mob_effect(slowness).is_some());
+        assert!(clear_effects(&targets, Some(speed)).is_empty());
+
+        assert_eq!(clear_effects(&targets, None).len(), 1);
+        assert!(targets[0].active_mob_effects().is_empty());
+        assert!(clear_effects(&targets, None).is_empty());
+    }
+}
@@ -1,51 +1,56 @@
 //! Handler for the "enchant" command.
-//!
-//! Vanilla targets any `LivingEntity`, but steel currently only supports players.
-// TODO: Support all LivingEntity targets when the entity system supports it
+//! Mirrors `net.minecraft.server.commands.EnchantCommand`.
 use std::borrow::Cow;
 use std::sync::Arc;
 
 use steel_registry::enchantment::{Enchantment, EnchantmentRef};
+use steel_registry::item_stack::ItemStack;
 use steel_utils::translations;
 use text_components::translation::TranslatedMessage;
 use text_components::{Modifier, TextComponent};
 
 use crate::{
     command::{
+        LEVEL_GAMEMASTERS,
         arguments::{
-            enchantment::EnchantmentArgument, integer::IntegerArgument, player::PlayerArgument,
+            enchantment::EnchantmentArgument, entity::EntityArgument, integer::IntegerArgument,
+        },
+        commands::{
+            CommandHandlerBuilder, CommandHandlerDyn, argument, summon::entity_display_name,
         },
-        commands::{CommandHandlerBuilder, CommandHandlerDyn, argument},
         context::CommandContext,
         error::CommandError,
     },
-    player::Player,
+    entity::LivingEntity,
+    inventory::equipment::EquipmentSlot,
 };
 
+type EnchantArgs = (
+    ((), Vec<Arc<dyn LivingEntity + Send + Sync>>),
+    EnchantmentRef,
+);
+type EnchantWithLevelArgs = (EnchantArgs, i32);
+
 /// Handler for the `/enchant` command.
 #[must_use]
 pub fn command_handler() -> impl CommandHandlerDyn {
     CommandHandlerBuilder::new(
         &["enchant"],
-        "Enchants a player's selected item.",
+        "Enchants the item held by the targets.",
         "minecraft:command.enchant",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .then(
-        argument("targets", PlayerArgument::multiple()).then(
+        argument("targets", EntityArgument::multiple()).then(
             argument("enchantment", EnchantmentArgument)
                 .executes(
-                    |(((), targets), enchantment): (((), Vec<Arc<Player>>), EnchantmentRef),
-                     ctx: &mut CommandContext| {
+                    |(((), targets), enchantment): EnchantArgs, ctx: &mut CommandContext| {
                         enchant(&targets, enchantment, 1, ctx)
                     },
                 )
                 .then(
                     argument("level", IntegerArgument::bounded(Some(0), None)).executes(
-                        #[expect(clippy::type_complexity, reason = "command framework pattern")]
-                        |((((), targets), enchantment), level): (
-                            (((), Vec<Arc<Player>>), EnchantmentRef),
-                            i32,
-                        ),
+                        |((((), targets), enchantment), level): EnchantWithLevelArgs,
                          ctx: &mut CommandContext| {
                             enchant(&targets, enchantment, level, ctx)
                         },
@@ -55,8 +60,16 @@ pub fn command_handler() -> impl CommandHandlerDyn {
     )
 }
 
+/// Why an item could not be enchanted.
+#[derive(Debug, PartialEq, Eq)]
+enum EnchantFailure {
+    Itemless,
+    /// Holds the name of the rejected item.
+    Incompatible(String),
+}
+
 fn enchant(
-    targets: &[Arc<Player>],
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
     enchantment: EnchantmentRef,
     level: i32,
     ctx: &mut CommandContext,
@@ -72,41 +85,31 @@ fn enchant(
         )));
     }
 
-    let mut success = 0u32;
-    let enchantment_key = enchantment.key.clone();
-
+    let mut success = 0;
     for target in targets {
-        let mut inv = target.inventory.lock();
-        let item = inv.get_selected_item();
-
-        if item.is_empty() {
-            if targets.len() == 1 {
+        let mut result = Err(EnchantFailure::Itemless);
+        target.with_equipment_slot_mut(EquipmentSlot::MainHand, &mut |item| {
+            result = enchant_item(item, enchantment, level);
+        });
+
+        match result {
+            Ok(()) => success += 1,
+            Err(_) if targets.len() > 1 => {}
+            Err(EnchantFailure::Itemless) => {
                 return Err(CommandError::CommandFailed(Box::new(
                     translations::COMMANDS_ENCHANT_FAILED_ITEMLESS
-                        .message([TextComponent::from(target.gameprofile.name.clone())])
+                        .message([entity_display_name(&**target)])
                         .into(),
                 )));
             }
-            continue;
-        }
-
-        if !enchantment.can_enchant(item.item)
-            || !Enchantment::is_compatible_with_existing(enchantment, item)
-        {
-            if targets.len() == 1 {
-                let item_name = item.item.key.to_string();
+            Err(EnchantFailure::Incompatible(item_name)) => {
                 return Err(CommandError::CommandFailed(Box::new(
                     translations::COMMANDS_ENCHANT_FAILED_INCOMPATIBLE
                         .message([TextComponent::from(item_name)])
                         .into(),
                 )));
             }
-            continue;
         }
-
-        let item = inv.get_selected_item_mut();
-        item.upgrade_enchantment(enchantment_key.clone(), level.max(0) as u32);
-        success += 1;
     }
 
     if success == 0 {
@@ -116,27 +119,37 @@ fn enchant(
     }
 
     let enchantment_name = enchantment_display_name(enchantment, level);
-
-    if targets.len() == 1 {
-        ctx.sender.send_message(
-            &translations::COMMANDS_ENCHANT_SUCCESS_SINGLE
-                .message([
-                    enchantment_name,
-                    TextComponent::from(targets[0].gameprofile.name.clone()),
-                ])
-                .into(),
-        );
+    let message = if let [target] = targets {
+        translations::COMMANDS_ENCHANT_SUCCESS_SINGLE
+            .message([enchantment_name, entity_display_name(&**target)])
     } else {
-        ctx.sender.send_message(
-            &translations::COMMANDS_ENCHANT_SUCCESS_MULTIPLE
-                .message([
-                    enchantment_name,
-                    TextComponent::from(targets.len().to_string()),
-                ])
-                .into(),
-        );
+        translations::COMMANDS_ENCHANT_SUCCESS_MULTIPLE.message([
+            enchantment_name,
+            TextComponent::from(targets.len().to_string()),
+        ])
+    };
+    ctx.send_success(&message.into(), true);
+    ctx.result = success;
+    Ok(())
+}
+
+/// Upgrades `enchantment` on `item` to `level`, if the item supports it and its existing
+/// enchantments are compatible.
+fn enchant_item(
+    item: &mut ItemStack,
+    enchantment: EnchantmentRef,
+    level: i32,
+) -> Result<(), EnchantFailure> {
+    if item.is_empty() {
+        return Err(EnchantFailure::Itemless);
+    }
+    if !enchantment.can_enchant(item.item)
+        || !Enchantment::is_compatible_with_existing(enchantment, item)
+    {
+        return Err(EnchantFailure::Incompatible(item.item.key.to_string()));
     }
 
+    item.upgrade_enchantment(enchantment.key.clone(), level.max(0) as u32);
     Ok(())
 }
 
@@ -166,3 +179,42 @@ fn enchantment_display_name(enchantment: EnchantmentRef, level: i32) -> TextComp
 
     component
 }
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::item_stack::ItemStack;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{vanilla_enchantments, vanilla_items};
+
+    use super::{EnchantFailure, enchant_item};
+
+    #[test]
+    fn enchanting_upgrades_a_supported_item() {
+        init_test_registry();
+        let sharpness = &vanilla_enchantments::SHARPNESS;
+        let mut sword = ItemStack::new(&vanilla_items::ITEMS.diamond_sword);
+
+        assert_eq!(enchant_item(&mut sword, sharpness, 3), Ok(()));
+        assert_eq!(sword.get_enchantment_level(&sharpness.key), 3);
+
+        // Like vanilla, a lower level never downgrades the existing one.
+        assert_eq!(enchant_item(&mut sword, sharpness, 1), Ok(()));
+        assert_eq!(sword.get_enchantment_level(&sharpness.key), 3);
+    }
+
+    #[test]
+    fn enchanting_rejects_unsupported_or_missing_items() {
+        init_test_registry();
+        let sharpness = &vanilla_enchantments::SHARPNESS;
+        let mut dirt = ItemStack::new(&vanilla_items::ITEMS.dirt);
+
+        assert!(matches!(
+            enchant_item(&mut dirt, sharpness, 1),
+            Err(EnchantFailure::Incompatible(_))
+        ));
+        assert_eq!(
+            enchant_item(&mut ItemStack::empty(), sharpness, 1),
+            Err(EnchantFailure::Itemless)
+        );
+    }
+}
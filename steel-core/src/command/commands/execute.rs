@@ -73,3 +73,28 @@ impl CommandExecutor<()> for RunExecutor {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::translations;
+    use text_components::TextComponent;
+
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, run_command, test_server};
+
+    #[test]
+    fn execute_run_dispatches_the_rest_from_the_root() {
+        let server = test_server();
+        let steve = add_player(&server, "Steve");
+        let sender = CommandSender::capture(LEVEL_GAMEMASTERS);
+
+        run_command(&server, sender.clone(), "execute run say hi");
+
+        let announcement: TextComponent = translations::CHAT_TYPE_ANNOUNCEMENT
+            .message([TextComponent::from("Server"), TextComponent::from("hi")])
+            .into();
+        assert!(steve.received_message(&announcement));
+        assert!(sender.captured().is_empty());
+    }
+}
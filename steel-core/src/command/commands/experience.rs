@@ -0,0 +1,288 @@
+//! Handler for the "experience" command.
+//! Mirrors `net.minecraft.server.commands.ExperienceCommand`.
+use std::sync::Arc;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+use text_components::translation::Translation;
+
+use crate::{
+    command::{
+        LEVEL_GAMEMASTERS,
+        arguments::{integer::IntegerArgument, player::PlayerArgument},
+        commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+        context::CommandContext,
+        error::CommandError,
+    },
+    player::{Player, experience::Experience},
+};
+
+type TargetsArgs = ((), Vec<Arc<Player>>);
+type AmountArgs = (((), Vec<Arc<Player>>), i32);
+
+/// Handler for the "experience" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["experience", "xp"],
+        "Gives, queries and sets a player's experience level and points.",
+        "minecraft:command.experience",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        literal("add").then(
+            argument("targets", PlayerArgument::multiple()).then(
+                argument("amount", IntegerArgument::new())
+                    .executes(
+                        |(((), targets), amount): AmountArgs, ctx: &mut CommandContext| {
+                            add_experience(ctx, &targets, amount, ExperienceType::Points);
+                            Ok(())
+                        },
+                    )
+                    .then(literal("points").executes(
+                        |(((), targets), amount): AmountArgs, ctx: &mut CommandContext| {
+                            add_experience(ctx, &targets, amount, ExperienceType::Points);
+                            Ok(())
+                        },
+                    ))
+                    .then(literal("levels").executes(
+                        |(((), targets), amount): AmountArgs, ctx: &mut CommandContext| {
+                            add_experience(ctx, &targets, amount, ExperienceType::Levels);
+                            Ok(())
+                        },
+                    )),
+            ),
+        ),
+    )
+    .then(
+        literal("set").then(
+            argument("targets", PlayerArgument::multiple()).then(
+                argument("amount", IntegerArgument::bounded(Some(0), None))
+                    .executes(
+                        |(((), targets), amount): AmountArgs, ctx: &mut CommandContext| {
+                            set_experience(ctx, &targets, amount, ExperienceType::Points)
+                        },
+                    )
+                    .then(literal("points").executes(
+                        |(((), targets), amount): AmountArgs, ctx: &mut CommandContext| {
+                            set_experience(ctx, &targets, amount, ExperienceType::Points)
+                        },
+                    ))
+                    .then(literal("levels").executes(
+                        |(((), targets), amount): AmountArgs, ctx: &mut CommandContext| {
+                            set_experience(ctx, &targets, amount, ExperienceType::Levels)
+                        },
+                    )),
+            ),
+        ),
+    )
+    .then(
+        literal("query").then(
+            argument("target", PlayerArgument::one())
+                .then(literal("points").executes(
+                    |((), targets): TargetsArgs, ctx: &mut CommandContext| {
+                        query_experience(ctx, &targets, ExperienceType::Points)
+                    },
+                ))
+                .then(literal("levels").executes(
+                    |((), targets): TargetsArgs, ctx: &mut CommandContext| {
+                        query_experience(ctx, &targets, ExperienceType::Levels)
+                    },
+                )),
+        ),
+    )
+    .then(
+        literal("clear")
+            .executes(|(): (), ctx: &mut CommandContext| {
+                if let Some(player) = ctx.sender.get_player() {
+                    player.experience.lock().set_total_points(0);
+                }
+                Ok(())
+            })
+            .then(argument("targets", PlayerArgument::multiple()).executes(
+                |((), targets): TargetsArgs, _ctx: &mut CommandContext| {
+                    for target in targets {
+                        target.experience.lock().set_total_points(0);
+                    }
+                    Ok(())
+                },
+            )),
+    )
+}
+
+/// Vanilla `ExperienceCommand.Type`.
+#[derive(Clone, Copy)]
+enum ExperienceType {
+    Points,
+    Levels,
+}
+
+impl ExperienceType {
+    fn add(self, experience: &mut Experience, amount: i32) {
+        match self {
+            Self::Points => experience.add_points(amount),
+            Self::Levels => experience.add_levels(amount),
+        }
+    }
+
+    /// Returns false when `amount` points don't fit in the current level.
+    fn set(self, experience: &mut Experience, amount: i32) -> bool {
+        match self {
+            Self::Points => experience.set_points(amount).is_ok(),
+            Self::Levels => {
+                experience.set_levels(amount);
+                true
+            }
+        }
+    }
+
+    fn query(self, experience: Experience) -> i32 {
+        match self {
+            Self::Points => experience.points(),
+            Self::Levels => experience.level(),
+        }
+    }
+}
+
+fn add_experience(
+    ctx: &mut CommandContext,
+    targets: &[Arc<Player>],
+    amount: i32,
+    xp_type: ExperienceType,
+) {
+    for target in targets {
+        xp_type.add(&mut target.experience.lock(), amount);
+    }
+
+    let translation = match (xp_type, targets) {
+        (ExperienceType::Points, [_]) => {
+            &translations::COMMANDS_EXPERIENCE_ADD_POINTS_SUCCESS_SINGLE
+        }
+        (ExperienceType::Levels, [_]) => {
+            &translations::COMMANDS_EXPERIENCE_ADD_LEVELS_SUCCESS_SINGLE
+        }
+        (ExperienceType::Points, _) => {
+            &translations::COMMANDS_EXPERIENCE_ADD_POINTS_SUCCESS_MULTIPLE
+        }
+        (ExperienceType::Levels, _) => {
+            &translations::COMMANDS_EXPERIENCE_ADD_LEVELS_SUCCESS_MULTIPLE
+        }
+    };
+    ctx.send_success(&targets_message(translation, amount, targets), true);
+    ctx.result = i32::try_from(targets.len()).unwrap_or(i32::MAX);
+}
+
+fn set_experience(
+    ctx: &mut CommandContext,
+    targets: &[Arc<Player>],
+    amount: i32,
+    xp_type: ExperienceType,
+) -> Result<(), CommandError> {
+    let success = targets
+        .iter()
+        .filter(|target| xp_type.set(&mut target.experience.lock(), amount))
+        .count();
+    if success == 0 {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_EXPERIENCE_SET_POINTS_INVALID
+                .msg()
+                .into(),
+        )));
+    }
+
+    let translation = match (xp_type, targets) {
+        (ExperienceType::Points, [_]) => {
+            &translations::COMMANDS_EXPERIENCE_SET_POINTS_SUCCESS_SINGLE
+        }
+        (ExperienceType::Levels, [_]) => {
+            &translations::COMMANDS_EXPERIENCE_SET_LEVELS_SUCCESS_SINGLE
+        }
+        (ExperienceType::Points, _) => {
+            &translations::COMMANDS_EXPERIENCE_SET_POINTS_SUCCESS_MULTIPLE
+        }
+        (ExperienceType::Levels, _) => {
+            &translations::COMMANDS_EXPERIENCE_SET_LEVELS_SUCCESS_MULTIPLE
+        }
+    };
+    ctx.send_success(&targets_message(translation, amount, targets), true);
+    ctx.result = i32::try_from(success).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn query_experience(
+    ctx: &mut CommandContext,
+    targets: &[Arc<Player>],
+    xp_type: ExperienceType,
+) -> Result<(), CommandError> {
+    let Some(target) = targets.first() else {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_ENTITY_NOTFOUND_PLAYER.msg().into(),
+        )));
+    };
+
+    let result = xp_type.query(*target.experience.lock());
+    let translation = match xp_type {
+        ExperienceType::Points => &translations::COMMANDS_EXPERIENCE_QUERY_POINTS,
+        ExperienceType::Levels => &translations::COMMANDS_EXPERIENCE_QUERY_LEVELS,
+    };
+    ctx.send_success(
+        &translation
+            .message([
+                // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
+                TextComponent::from(target.gameprofile.name.clone()),
+                TextComponent::from(result.to_string()),
+            ])
+            .into(),
+        false,
+    );
+    ctx.result = result;
+    Ok(())
+}
+
+/// Fills `translation` with the amount and either the single target's name or the target count.
+fn targets_message(
+    translation: &Translation<2>,
+    amount: i32,
+    targets: &[Arc<Player>],
+) -> TextComponent {
+    let targets = match targets {
+        [target] => TextComponent::from(target.gameprofile.name.clone()),
+        _ => TextComponent::from(targets.len().to_string()),
+    };
+    translation
+        .message([TextComponent::from(amount.to_string()), targets])
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExperienceType;
+    use crate::player::experience::Experience;
+
+    #[test]
+    fn adding_levels_keeps_the_progress() {
+        let mut experience = Experience::new(Experience::total_points_at_level(5) + 3);
+
+        ExperienceType::Levels.add(&mut experience, 2);
+
+        assert_eq!(ExperienceType::Levels.query(experience), 7);
+        assert!(ExperienceType::Points.query(experience) > 0);
+    }
+
+    #[test]
+    fn querying_points_counts_from_the_current_level() {
+        let experience = Experience::new(Experience::total_points_at_level(3) + 4);
+
+        assert_eq!(ExperienceType::Points.query(experience), 4);
+        assert_eq!(ExperienceType::Levels.query(experience), 3);
+    }
+
+    #[test]
+    fn setting_points_past_the_level_fails() {
+        let mut experience = Experience::new(0);
+
+        assert!(!ExperienceType::Points.set(&mut experience, Experience::points_for_level(0)));
+        assert!(ExperienceType::Points.set(&mut experience, 3));
+        assert_eq!(ExperienceType::Points.query(experience), 3);
+    }
+}
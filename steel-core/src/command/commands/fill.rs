@@ -0,0 +1,278 @@
+//! Handler for the "fill" command.
+//! Mirrors `net.minecraft.server.commands.FillCommand`.
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::game_rules::GameRuleValue;
+use steel_registry::vanilla_blocks;
+use steel_registry::vanilla_game_rules::MAX_BLOCK_MODIFICATIONS;
+use steel_utils::{BlockPos, BlockStateId, ChunkPos, translations};
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        block_pos::BlockPosArgument,
+        block_state::{BlockInput, BlockStateArgument},
+    },
+    commands::{
+        CommandHandlerBuilder, CommandHandlerDyn, argument, literal,
+        setblock::{loaded_pos, place_block},
+    },
+    context::CommandContext,
+    error::CommandError,
+};
+
+type FillArgs = ((((), BlockPos), BlockPos), BlockInput);
+
+/// Handler for the "fill" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["fill"],
+        "Fills a region with a block.",
+        "minecraft:command.fill",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        argument("from", BlockPosArgument).then(
+            argument("to", BlockPosArgument).then(
+                argument("block", BlockStateArgument)
+                    .executes(|args: FillArgs, context: &mut CommandContext| {
+                        fill(context, args, Mode::Replace)
+                    })
+                    .then(literal("destroy").executes(
+                        |args: FillArgs, context: &mut CommandContext| {
+                            fill(context, args, Mode::Destroy)
+                        },
+                    ))
+                    .then(literal("hollow").executes(
+                        |args: FillArgs, context: &mut CommandContext| {
+                            fill(context, args, Mode::Hollow)
+                        },
+                    ))
+                    .then(literal("keep").executes(
+                        |args: FillArgs, context: &mut CommandContext| {
+                            fill(context, args, Mode::Keep)
+                        },
+                    ))
+                    .then(literal("outline").executes(
+                        |args: FillArgs, context: &mut CommandContext| {
+                            fill(context, args, Mode::Outline)
+                        },
+                    ))
+                    // TODO: `replace <filter>` once a block predicate argument exists
+                    .then(literal("replace").executes(
+                        |args: FillArgs, context: &mut CommandContext| {
+                            fill(context, args, Mode::Replace)
+                        },
+                    ))
+                    .then(literal("strict").executes(
+                        |args: FillArgs, context: &mut CommandContext| {
+                            fill(context, args, Mode::Strict)
+                        },
+                    )),
+            ),
+        ),
+    )
+}
+
+/// Vanilla `FillCommand.Mode`, with `keep` and `strict` folded in.
+#[derive(Clone, Copy)]
+enum Mode {
+    Replace,
+    Keep,
+    Outline,
+    Hollow,
+    Destroy,
+    Strict,
+}
+
+/// What a mode places at one position of the region.
+#[derive(Debug, PartialEq)]
+enum Placement {
+    /// The block from the command.
+    Block,
+    /// Air, for the inside of a hollow fill.
+    HollowCore,
+    /// Nothing, the position is left as it is.
+    Skip,
+}
+
+impl Mode {
+    fn placement(self, region: &Region, pos: BlockPos, current: BlockStateId) -> Placement {
+        match self {
+            Self::Keep if !current.is_air() => Placement::Skip,
+            Self::Outline if !region.is_edge(pos) => Placement::Skip,
+            Self::Hollow if !region.is_edge(pos) => Placement::HollowCore,
+            _ => Placement::Block,
+        }
+    }
+}
+
+/// The inclusive box between two corners, vanilla `BoundingBox.fromCorners`.
+struct Region {
+    min: BlockPos,
+    max: BlockPos,
+}
+
+impl Region {
+    fn from_corners(a: BlockPos, b: BlockPos) -> Self {
+        Self {
+            min: BlockPos::new(a.x().min(b.x()), a.y().min(b.y()), a.z().min(b.z())),
+            max: BlockPos::new(a.x().max(b.x()), a.y().max(b.y()), a.z().max(b.z())),
+        }
+    }
+
+    fn volume(&self) -> i64 {
+        let span = |min: i32, max: i32| i64::from(max) - i64::from(min) + 1;
+        span(self.min.x(), self.max.x())
+            * span(self.min.y(), self.max.y())
+            * span(self.min.z(), self.max.z())
+    }
+
+    fn is_edge(&self, pos: BlockPos) -> bool {
+        pos.x() == self.min.x()
+            || pos.x() == self.max.x()
+            || pos.y() == self.min.y()
+            || pos.y() == self.max.y()
+            || pos.z() == self.min.z()
+            || pos.z() == self.max.z()
+    }
+
+    /// Every position in the box, x fastest like vanilla `BlockPos.betweenClosed`.
+    fn positions(&self) -> impl Iterator<Item = BlockPos> + '_ {
+        (self.min.z()..=self.max.z()).flat_map(move |z| {
+            (self.min.y()..=self.max.y()).flat_map(move |y| {
+                (self.min.x()..=self.max.x()).map(move |x| BlockPos::new(x, y, z))
+            })
+        })
+    }
+
+    fn chunks(&self) -> impl Iterator<Item = ChunkPos> + '_ {
+        let min = ChunkPos::from_block_pos(self.min);
+        let max = ChunkPos::from_block_pos(self.max);
+        (min.0.x..=max.0.x).flat_map(move |x| (min.0.y..=max.0.y).map(move |z| ChunkPos::new(x, z)))
+    }
+}
+
+fn fill(
+    context: &mut CommandContext,
+    ((((), from), to), block): FillArgs,
+    mode: Mode,
+) -> Result<(), CommandError> {
+    let world = context.world.clone();
+    loaded_pos(&world, from)?;
+    loaded_pos(&world, to)?;
+
+    let region = Region::from_corners(from, to);
+    let limit = match world.get_game_rule(&MAX_BLOCK_MODIFICATIONS) {
+        GameRuleValue::Int(limit) => i64::from(limit),
+        GameRuleValue::Bool(_) => i64::from(i32::MAX),
+    };
+    let volume = region.volume();
+    if volume > limit {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_FILL_TOOBIG
+                .message([
+                    TextComponent::from(limit.to_string()),
+                    TextComponent::from(volume.to_string()),
+                ])
+                .into(),
+        )));
+    }
+    if !region.chunks().all(|chunk| world.has_full_chunk(chunk)) {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_POS_UNLOADED.msg().into(),
+        )));
+    }
+
+    let strict = matches!(mode, Mode::Strict);
+    let hollow_core = BlockInput {
+        state: vanilla_blocks::AIR.default_state(),
+        nbt: None,
+    };
+    let mut updated = Vec::new();
+    let mut count = 0;
+    for pos in region.positions() {
+        let affected = matches!(mode, Mode::Destroy) && world.destroy_block(pos, true);
+        let input = match mode.placement(&region, pos, world.get_block_state(pos)) {
+            Placement::Block => &block,
+            Placement::HollowCore => &hollow_core,
+            Placement::Skip => {
+                count += i32::from(affected);
+                continue;
+            }
+        };
+        if place_block(&world, pos, input, strict) {
+            updated.push((pos, input.state));
+            count += 1;
+        } else {
+            count += i32::from(affected);
+        }
+    }
+
+    // Neighbors are only told once the whole region is filled.
+    if !strict {
+        for (pos, state) in updated {
+            world.update_neighbors_at(pos, state.get_block());
+        }
+    }
+
+    if count == 0 {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_FILL_FAILED.msg().into(),
+        )));
+    }
+    context.send_success(
+        &translations::COMMANDS_FILL_SUCCESS
+            .message([TextComponent::from(count.to_string())])
+            .into(),
+        true,
+    );
+    context.result = count;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{REGISTRY, vanilla_blocks};
+    use steel_utils::BlockPos;
+
+    use super::{Mode, Placement, Region};
+
+    fn count(mode: Mode, region: &Region, placement: &Placement) -> usize {
+        let air = REGISTRY.blocks.get_default_state_id(&vanilla_blocks::AIR);
+        region
+            .positions()
+            .filter(|&pos| mode.placement(region, pos, air) == *placement)
+            .count()
+    }
+
+    #[test]
+    fn filling_a_small_cuboid_counts_each_placed_block() {
+        init_test_registry();
+        let region = Region::from_corners(BlockPos::new(2, 66, 2), BlockPos::new(0, 64, 0));
+
+        assert_eq!(region.volume(), 27);
+        assert_eq!(count(Mode::Replace, &region, &Placement::Block), 27);
+        assert_eq!(count(Mode::Outline, &region, &Placement::Block), 26);
+        assert_eq!(count(Mode::Hollow, &region, &Placement::Block), 26);
+        assert_eq!(count(Mode::Hollow, &region, &Placement::HollowCore), 1);
+    }
+
+    #[test]
+    fn keep_skips_non_air_blocks() {
+        init_test_registry();
+        let region = Region::from_corners(BlockPos::new(0, 0, 0), BlockPos::new(1, 1, 1));
+        let stone = REGISTRY.blocks.get_default_state_id(&vanilla_blocks::STONE);
+
+        assert_eq!(
+            Mode::Keep.placement(&region, BlockPos::new(0, 0, 0), stone),
+            Placement::Skip
+        );
+        assert_eq!(
+            Mode::Replace.placement(&region, BlockPos::new(0, 0, 0), stone),
+            Placement::Block
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Handler for the "forceload" command.
+//! Mirrors `net.minecraft.server.commands.ForceLoadCommand`.
+use glam::IVec2;
+use steel_utils::{ChunkPos, translations};
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::column_pos::ColumnPosArgument,
+    clickable_coordinates,
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+
+/// The most chunks one `add` or `remove` may cover.
+const MAX_CHUNKS: i64 = 256;
+/// Block columns past this distance from the origin are outside the world.
+const MAX_COLUMN: i32 = 30_000_000;
+
+type FromArgs = ((), IVec2);
+type FromToArgs = (FromArgs, IVec2);
+
+/// Handler for the "forceload" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["forceload"],
+        "Forces chunks to stay loaded.",
+        "minecraft:command.forceload",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        literal("add").then(
+            argument("from", ColumnPosArgument)
+                .executes(|((), from): FromArgs, context: &mut CommandContext| {
+                    change_force_load(context, from, from, true)
+                })
+                .then(argument("to", ColumnPosArgument).executes(
+                    |(((), from), to): FromToArgs, context: &mut CommandContext| {
+                        change_force_load(context, from, to, true)
+                    },
+                )),
+        ),
+    )
+    .then(
+        literal("remove")
+            .then(
+                argument("from", ColumnPosArgument)
+                    .executes(|((), from): FromArgs, context: &mut CommandContext| {
+                        change_force_load(context, from, from, false)
+                    })
+                    .then(argument("to", ColumnPosArgument).executes(
+                        |(((), from), to): FromToArgs, context: &mut CommandContext| {
+                            change_force_load(context, from, to, false)
+                        },
+                    )),
+            )
+            .then(literal("all").executes(|(), context: &mut CommandContext| {
+                for chunk in context.world.forced_chunks() {
+                    context.world.set_chunk_forced(chunk, false);
+                }
+                context.send_success(
+                    &translations::COMMANDS_FORCELOAD_REMOVED_ALL
+                        .message([dimension_name(context)])
+                        .into(),
+                    true,
+                );
+                Ok(())
+            })),
+    )
+    .then(
+        literal("query")
+            .executes(|(), context: &mut CommandContext| list_force_load(context))
+            .then(argument("pos", ColumnPosArgument).executes(
+                |((), pos): FromArgs, context: &mut CommandContext| query_force_load(context, pos),
+            )),
+    )
+}
+
+/// The chunks covering a rectangle of block columns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ChunkRegion {
+    min: ChunkPos,
+    max: ChunkPos,
+}
+
+impl ChunkRegion {
+    /// The region between two corner columns, or an error when it leaves the world.
+    fn between(from: IVec2, to: IVec2) -> Result<Self, CommandError> {
+        let min = from.min(to);
+        let max = from.max(to);
+        if min.x < -MAX_COLUMN || min.y < -MAX_COLUMN || max.x >= MAX_COLUMN || max.y >= MAX_COLUMN
+        {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::ARGUMENT_POS_OUTOFWORLD.msg().into(),
+            )));
+        }
+        Ok(Self {
+            min: ChunkPos::new(min.x >> 4, min.y >> 4),
+            max: ChunkPos::new(max.x >> 4, max.y >> 4),
+        })
+    }
+
+    fn count(self) -> i64 {
+        let width = i64::from(self.max.0.x - self.min.0.x) + 1;
+        let depth = i64::from(self.max.0.y - self.min.0.y) + 1;
+        width * depth
+    }
+
+    fn chunks(self) -> impl Iterator<Item = ChunkPos> {
+        (self.min.0.x..=self.max.0.x)
+            .flat_map(move |x| (self.min.0.y..=self.max.0.y).map(move |z| ChunkPos::new(x, z)))
+    }
+}
+
+fn change_force_load(
+    context: &mut CommandContext,
+    from: IVec2,
+    to: IVec2,
+    add: bool,
+) -> Result<(), CommandError> {
+    let region = ChunkRegion::between(from, to)?;
+    let count = region.count();
+    if count > MAX_CHUNKS {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_FORCELOAD_TOOBIG
+                .message([
+                    TextComponent::from(MAX_CHUNKS.to_string()),
+                    TextComponent::from(count.to_string()),
+                ])
+                .into(),
+        )));
+    }
+
+    let mut first_changed = None;
+    let mut changed = 0;
+    for chunk in region.chunks() {
+        if context.world.set_chunk_forced(chunk, add) {
+            changed += 1;
+            first_changed = first_changed.or(Some(chunk));
+        }
+    }
+
+    let dimension = dimension_name(context);
+    let message = match (first_changed, changed) {
+        (None, _) => {
+            let failure = if add {
+                &translations::COMMANDS_FORCELOAD_ADDED_FAILURE
+            } else {
+                &translations::COMMANDS_FORCELOAD_REMOVED_FAILURE
+            };
+            return Err(CommandError::CommandFailed(Box::new(failure.msg().into())));
+        }
+        (Some(chunk), 1) => {
+            let translation = if add {
+                &translations::COMMANDS_FORCELOAD_ADDED_SINGLE
+            } else {
+                &translations::COMMANDS_FORCELOAD_REMOVED_SINGLE
+            };
+            translation.message([chunk_name(chunk), dimension])
+        }
+        (Some(_), _) => {
+            let translation = if add {
+                &translations::COMMANDS_FORCELOAD_ADDED_MULTIPLE
+            } else {
+                &translations::COMMANDS_FORCELOAD_REMOVED_MULTIPLE
+            };
+            translation.message([
+                TextComponent::from(changed.to_string()),
+                dimension,
+                chunk_name(region.min),
+                chunk_name(region.max),
+            ])
+        }
+    };
+    context.send_success(&message.into(), true);
+    context.result = changed;
+    Ok(())
+}
+
+fn query_force_load(context: &mut CommandContext, pos: IVec2) -> Result<(), CommandError> {
+    let chunk = ChunkPos::new(pos.x >> 4, pos.y >> 4);
+    let corner = clickable_coordinates(
+        &(chunk.0.x << 4).to_string(),
+        "~",
+        &(chunk.0.y << 4).to_string(),
+    );
+    let args = [corner, dimension_name(context)];
+    if !context.world.is_chunk_forced(chunk) {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_FORCELOAD_QUERY_FAILURE
+                .message(args)
+                .into(),
+        )));
+    }
+    context.send_success(
+        &translations::COMMANDS_FORCELOAD_QUERY_SUCCESS
+            .message(args)
+            .into(),
+        false,
+    );
+    Ok(())
+}
+
+fn list_force_load(context: &mut CommandContext) -> Result<(), CommandError> {
+    let mut chunks = context.world.forced_chunks();
+    chunks.sort_by_key(|chunk| (chunk.0.x, chunk.0.y));
+    let dimension = dimension_name(context);
+    let list = chunks
+        .iter()
+        .map(|&chunk| format!("[{}, {}]", chunk.0.x, chunk.0.y))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let message = match chunks.len() {
+        0 => {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_FORCELOAD_ADDED_NONE
+                    .message([dimension])
+                    .into(),
+            )));
+        }
+        1 => translations::COMMANDS_FORCELOAD_LIST_SINGLE
+            .message([dimension, TextComponent::from(list)]),
+        count => translations::COMMANDS_FORCELOAD_LIST_MULTIPLE.message([
+            TextComponent::from(count.to_string()),
+            dimension,
+            TextComponent::from(list),
+        ]),
+    };
+    context.send_success(&message.into(), false);
+    context.result = i32::try_from(chunks.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn chunk_name(chunk: ChunkPos) -> TextComponent {
+    TextComponent::from(format!("[{}, {}]", chunk.0.x, chunk.0.y))
+}
+
+fn dimension_name(context: &CommandContext) -> TextComponent {
+    TextComponent::from(context.world.key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+    use steel_registry::test_support::init_test_registry;
+    use steel_utils::ChunkPos;
+
+    use super::{ChunkRegion, MAX_CHUNKS};
+    use crate::level_data::LevelData;
+
+    #[test]
+    fn adding_a_region_forces_each_chunk_once() {
+        init_test_registry();
+        let mut data = LevelData::new_with_seed(1);
+        let region =
+            ChunkRegion::between(IVec2::new(31, 31), IVec2::new(0, 0)).expect("inside the world");
+
+        assert_eq!(region.count(), 4);
+        let changed = region
+            .chunks()
+            .filter(|&chunk| data.set_chunk_forced(chunk, true))
+            .count();
+        assert_eq!(changed, 4);
+
+        assert!(data.is_chunk_forced(ChunkPos::new(1, 0)));
+        assert!(!data.is_chunk_forced(ChunkPos::new(2, 0)));
+        assert!(!data.set_chunk_forced(ChunkPos::new(1, 1), true));
+    }
+
+    #[test]
+    fn regions_past_the_limit_are_counted_in_full() {
+        let region = ChunkRegion::between(IVec2::new(0, 0), IVec2::new(16 * 16, 15))
+            .expect("inside the world");
+
+        assert_eq!(region.count(), 17);
+        let too_big = ChunkRegion::between(IVec2::new(0, 0), IVec2::new(16 * 16, 16 * 16))
+            .expect("inside the world");
+        assert!(too_big.count() > MAX_CHUNKS);
+    }
+}
@@ -1,4 +1,5 @@
 //! Handler for the "gamemode" command.
+use crate::command::LEVEL_GAMEMASTERS;
 use crate::command::arguments::gamemode::GameModeArgument;
 use crate::command::arguments::player::PlayerArgument;
 use crate::command::commands::{
@@ -6,9 +7,14 @@ use crate::command::commands::{
 };
 use crate::command::context::CommandContext;
 use crate::command::error::CommandError;
+use crate::command::sender::CommandSender;
 use crate::entity::Entity;
 use crate::player::Player;
+use crate::server::Server;
+use std::slice;
 use std::sync::Arc;
+use steel_registry::game_rules::GameRuleValue;
+use steel_registry::vanilla_game_rules::SEND_COMMAND_FEEDBACK;
 use steel_utils::translations;
 use steel_utils::types::GameType;
 use text_components::TextComponent;
@@ -22,6 +28,7 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Sets the game mode.",
         "minecraft:command.gamemode",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .then(
         argument("gamemode", GameModeArgument)
             .executes(GameModeCommandExecutor)
@@ -42,15 +49,13 @@ impl CommandExecutor<((), GameType)> for GameModeCommandExecutor {
     ) -> Result<(), CommandError> {
         let ((), gamemode) = args;
 
-        // Get the player executing the command
         let player = context
             .sender
             .get_player()
-            .ok_or(CommandError::InvalidRequirement)?;
-
-        // Set the player's game mode
-        player.set_game_mode(gamemode);
+            .ok_or(CommandError::InvalidRequirement)?
+            .clone();
 
+        set_mode(context, &[player], gamemode);
         Ok(())
     }
 }
@@ -65,31 +70,61 @@ impl CommandExecutor<(((), GameType), Vec<Arc<Player>>)> for GameModeTargetComma
     ) -> Result<(), CommandError> {
         let (((), gamemode), targets) = args;
 
-        let mode_translation = get_gamemode_translation(gamemode);
-
-        for target in targets {
-            if target.set_game_mode(gamemode) {
-                // Send feedback to sender if sender is not the target
-                let sender_is_target = if let Some(sender_player) = context.sender.get_player() {
-                    sender_player.id() == target.id()
-                } else {
-                    false
-                };
-
-                if !sender_is_target {
-                    context.sender.send_message(
-                        &translations::COMMANDS_GAMEMODE_SUCCESS_OTHER
-                            .message([
-                                TextComponent::plain(target.gameprofile.name.clone()),
-                                TextComponent::from(mode_translation),
-                            ])
-                            .into(),
-                    );
-                }
+        set_mode(context, &targets, gamemode);
+        Ok(())
+    }
+}
+
+/// Vanilla's `GameModeCommand.setGameMode`, used by the game mode switcher: changes the player's
+/// game mode and reports it as if they ran `/gamemode`.
+pub fn set_game_mode(player: &Arc<Player>, server: Arc<Server>, gamemode: GameType) {
+    let mut context = CommandContext::new(CommandSender::Player(player.clone()), server);
+    set_mode(&mut context, slice::from_ref(player), gamemode);
+}
+
+/// Vanilla's `GameModeCommand.setMode`: changes every target's game mode and reports it.
+fn set_mode(context: &mut CommandContext, targets: &[Arc<Player>], gamemode: GameType) {
+    let mut count = 0;
+    for target in targets {
+        if !target.set_game_mode(gamemode) {
+            continue;
+        }
+        count += 1;
+
+        let is_sender = context
+            .sender
+            .get_player()
+            .is_some_and(|sender| sender.id() == target.id());
+        if is_sender {
+            context.send_success(&success_message(gamemode, None), true);
+        } else {
+            if context.world.get_game_rule(&SEND_COMMAND_FEEDBACK) == GameRuleValue::Bool(true) {
+                target.send_message(
+                    &translations::GAME_MODE_CHANGED
+                        .message([TextComponent::from(get_gamemode_translation(gamemode))])
+                        .into(),
+                );
             }
+            // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
+            context.send_success(
+                &success_message(gamemode, Some(target.gameprofile.name.clone())),
+                true,
+            );
         }
+    }
+    context.result = count;
+}
 
-        Ok(())
+/// The feedback for a game mode change, for `target` or the sender themselves if `None`.
+fn success_message(gamemode: GameType, target: Option<String>) -> TextComponent {
+    let mode = TextComponent::from(get_gamemode_translation(gamemode));
+    match target {
+        Some(name) => translations::COMMANDS_GAMEMODE_SUCCESS_OTHER
+            .message([TextComponent::plain(name), mode])
+            .into(),
+        None => translations::COMMANDS_GAMEMODE_SUCCESS_SELF
+            .message([mode])
+            .into(),
     }
 }
 
@@ -103,3 +138,47 @@ pub fn get_gamemode_translation(gamemode: GameType) -> &'static Translation<0> {
         GameType::Spectator => &translations::GAME_MODE_SPECTATOR,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use text_components::content::Content;
+
+    use super::success_message;
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, run_command, test_server};
+    use steel_utils::types::GameType;
+
+    fn key(component: &text_components::TextComponent) -> &str {
+        match &component.content {
+            Content::Translate(message) => &message.key,
+            _ => panic!("expected a translated component"),
+        }
+    }
+
+    #[test]
+    fn feedback_depends_on_whether_the_sender_is_the_target() {
+        let own = success_message(GameType::Creative, None);
+        assert_eq!(key(&own), "commands.gamemode.success.self");
+
+        let other = success_message(GameType::Creative, Some("Alex".to_owned()));
+        assert_eq!(key(&other), "commands.gamemode.success.other");
+    }
+
+    #[test]
+    fn target_defaults_to_the_sender_when_omitted() {
+        let server = test_server();
+        let steve = add_player(&server, "Steve");
+        let alex = add_player(&server, "Alex");
+        steve.player.set_permission_level(LEVEL_GAMEMASTERS);
+        let sender = CommandSender::Player(steve.player.clone());
+
+        run_command(&server, sender.clone(), "gamemode creative");
+        assert_eq!(steve.player.game_mode(), GameType::Creative);
+        assert_eq!(alex.player.game_mode(), GameType::Survival);
+
+        run_command(&server, sender, "gamemode adventure Alex");
+        assert_eq!(alex.player.game_mode(), GameType::Adventure);
+        assert_eq!(steve.player.game_mode(), GameType::Creative);
+    }
+}
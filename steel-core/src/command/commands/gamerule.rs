@@ -1,4 +1,5 @@
 //! Handler for the "gamerule" command.
+use crate::command::LEVEL_GAMEMASTERS;
 use crate::command::arguments::bool::BoolArgument;
 use crate::command::arguments::integer::IntegerArgument;
 use crate::command::commands::{
@@ -19,7 +20,8 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         &["gamerule"],
         "Gets or sets a game rule value.",
         "minecraft:command.gamerule",
-    );
+    )
+    .requires_level(LEVEL_GAMEMASTERS);
 
     for (_, rule) in REGISTRY.game_rules.iter() {
         let Cow::Borrowed(rule_name) = &rule.key.path else {
@@ -55,19 +57,18 @@ struct QueryExecutor(GameRuleRef);
 
 impl CommandExecutor<()> for QueryExecutor {
     fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
-        let world = &context.world;
-        let rule_name = self.0.key.path.to_string();
-        let value = world.get_game_rule(self.0);
+        let value = context.world.get_game_rule(self.0);
 
-        context.sender.send_message(
+        context.send_success(
             &translations::COMMANDS_GAMERULE_QUERY
                 .message([
-                    TextComponent::from(rule_name),
+                    TextComponent::from(self.0.key.path.to_string()),
                     TextComponent::from(value.to_string()),
                 ])
                 .into(),
+            false,
         );
-
+        context.result = command_result(value);
         Ok(())
     }
 }
@@ -77,20 +78,7 @@ struct SetBoolExecutor(GameRuleRef);
 impl CommandExecutor<((), bool)> for SetBoolExecutor {
     fn execute(&self, args: ((), bool), context: &mut CommandContext) -> Result<(), CommandError> {
         let ((), value) = args;
-        let world = &context.world;
-        let rule_name = self.0.key.path.to_string();
-
-        world.set_game_rule(self.0, GameRuleValue::Bool(value));
-
-        context.sender.send_message(
-            &translations::COMMANDS_GAMERULE_SET
-                .message([
-                    TextComponent::from(rule_name),
-                    TextComponent::from(value.to_string()),
-                ])
-                .into(),
-        );
-
+        set_rule(context, self.0, GameRuleValue::Bool(value));
         Ok(())
     }
 }
@@ -100,20 +88,47 @@ struct SetIntExecutor(GameRuleRef);
 impl CommandExecutor<((), i32)> for SetIntExecutor {
     fn execute(&self, args: ((), i32), context: &mut CommandContext) -> Result<(), CommandError> {
         let ((), value) = args;
-        let world = &context.world;
-        let rule_name = self.0.key.path.to_string();
+        set_rule(context, self.0, GameRuleValue::Int(value));
+        Ok(())
+    }
+}
 
-        world.set_game_rule(self.0, GameRuleValue::Int(value));
+fn set_rule(context: &mut CommandContext, rule: GameRuleRef, value: GameRuleValue) {
+    context.world.set_game_rule(rule, value);
+
+    context.send_success(
+        &translations::COMMANDS_GAMERULE_SET
+            .message([
+                TextComponent::from(rule.key.path.to_string()),
+                TextComponent::from(value.to_string()),
+            ])
+            .into(),
+        true,
+    );
+    context.result = command_result(value);
+}
 
-        context.sender.send_message(
-            &translations::COMMANDS_GAMERULE_SET
-                .message([
-                    TextComponent::from(rule_name),
-                    TextComponent::from(value.to_string()),
-                ])
-                .into(),
-        );
+/// Vanilla's `GameRule.getCommandResult`: `1` or `0` for booleans, the value for integers.
+const fn command_result(value: GameRuleValue) -> i32 {
+    match value {
+        GameRuleValue::Bool(value) => value as i32,
+        GameRuleValue::Int(value) => value,
+    }
+}
 
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use steel_registry::game_rules::{GameRuleType, GameRuleValue};
+    use steel_registry::vanilla_game_rules::ADVANCE_TIME;
+
+    use super::command_result;
+
+    #[test]
+    fn setting_advance_time_to_false_reports_zero() {
+        // `doDaylightCycle` was renamed to `advance_time`.
+        assert_eq!(ADVANCE_TIME.value_type, GameRuleType::Bool);
+        assert_eq!(ADVANCE_TIME.default_value, GameRuleValue::Bool(true));
+        assert_eq!(command_result(GameRuleValue::Bool(false)), 0);
+        assert_eq!(command_result(GameRuleValue::Int(3)), 3);
     }
 }
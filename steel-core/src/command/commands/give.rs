@@ -1,4 +1,5 @@
-//! /// Handler for the "give" command.
+//! Handler for the "give" command.
+//! Mirrors `net.minecraft.server.commands.GiveCommand`.
 use std::sync::Arc;
 
 use steel_registry::{data_components::vanilla_components, item_stack::ItemStack, items::ItemRef};
@@ -7,15 +8,17 @@ use text_components::{Modifier, TextComponent, interactivity::HoverEvent};
 
 use crate::{
     command::{
+        LEVEL_GAMEMASTERS,
         arguments::{integer::IntegerArgument, item::ItemStackArgument, player::PlayerArgument},
         commands::{CommandHandlerBuilder, CommandHandlerDyn, argument},
         context::CommandContext,
-        sender::CommandSender,
+        error::CommandError,
     },
     inventory::container::Container,
     player::Player,
 };
 
+type GiveArgs = (((), Vec<Arc<Player>>), ItemRef);
 type GiveWithCountArgs = ((((), Vec<Arc<Player>>), ItemRef), i32);
 
 /// Handler for the "give" command.
@@ -26,24 +29,20 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Give players the specified item with a specific amount.",
         "minecraft:command.give",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .then(
         argument("targets", PlayerArgument::multiple()).then(
             argument("item", ItemStackArgument) // FIXME: should be item predicate instead to also handle tags and components
                 .executes(
-                    |(((), targets), item): (((), Vec<Arc<Player>>), ItemRef),
-                     ctx: &mut CommandContext| {
-                        give(&targets, item, 1, &ctx.sender);
-
-                        Ok(())
+                    |(((), targets), item): GiveArgs, ctx: &mut CommandContext| {
+                        give(ctx, &targets, item, 1)
                     },
                 )
                 .then(
                     argument("count", IntegerArgument::bounded(Some(1), None)).executes(
-                        |((((), targets), item), input_count): GiveWithCountArgs,
+                        |((((), targets), item), count): GiveWithCountArgs,
                          ctx: &mut CommandContext| {
-                            give(&targets, item, input_count, &ctx.sender);
-
-                            Ok(())
+                            give(ctx, &targets, item, count)
                         },
                     ),
                 ),
@@ -51,76 +50,107 @@ pub fn command_handler() -> impl CommandHandlerDyn {
     )
 }
 
-fn give(targets: &Vec<Arc<Player>>, item: ItemRef, count: i32, sender: &CommandSender) {
+fn give(
+    context: &mut CommandContext,
+    targets: &[Arc<Player>],
+    item: ItemRef,
+    count: i32,
+) -> Result<(), CommandError> {
     let max_stack_size = item
         .components
         .get(vanilla_components::MAX_STACK_SIZE)
         .unwrap_or(1);
+    let max_count = max_stack_size * 100;
 
-    if count > max_stack_size * 100 {
-        sender.send_message(
-            &translations::COMMANDS_GIVE_FAILED_TOOMANYITEMS
+    if count > max_count {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_GIVE_FAILED_TOOMANYITEMS
                 .message([
-                    TextComponent::from(format!("{}", max_stack_size * 100)),
-                    TextComponent::from(format!("[{}]", item.key.path)).hover_event(
-                        // FIXME: display name
-                        HoverEvent::show_item(item.key.path.clone(), None, None::<&str>),
-                    ),
+                    TextComponent::from(format!("{max_count}")),
+                    item_display_name(item),
                 ])
                 .into(),
-        );
-        return;
+        )));
     }
 
-    let stack = ItemStack::new(item);
+    let Some(first_target) = targets.first() else {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_ENTITY_NOTFOUND_PLAYER.msg().into(),
+        )));
+    };
 
     for target in targets {
-        let mut remaining = count;
-
-        while remaining > 0 {
-            let stack_size = max_stack_size.min(remaining);
-            remaining -= stack_size;
-            let mut copy = stack.copy_with_count(stack_size);
-            let added = target.inventory.lock().add(&mut copy);
-
-            if !added || !copy.is_empty() {
-                target.drop_item(copy, false, false);
+        for mut stack in split_into_stacks(item, count, max_stack_size) {
+            let added = target.inventory.lock().add(&mut stack);
+            if !added || !stack.is_empty() {
+                target.drop_item(stack, false, false);
             }
         }
     }
 
-    if targets.len() == 1 {
-        sender.send_message(
-            &translations::COMMANDS_GIVE_SUCCESS_SINGLE
-                .message([
-                    TextComponent::from(format!("{count}")),
-                    TextComponent::from(format!("[{}]", item.key.path)).hover_event(
-                        // FIXME: display name
-                        HoverEvent::show_item(item.key.path.clone(), None, None::<&str>),
-                    ),
-                    TextComponent::from(
-                        targets
-                            .first()
-                            .expect("targets cannot be empty.")
-                            .gameprofile
-                            .name
-                            .clone(),
-                    ),
-                ])
-                .into(),
-        );
+    let message = if targets.len() == 1 {
+        translations::COMMANDS_GIVE_SUCCESS_SINGLE.message([
+            TextComponent::from(format!("{count}")),
+            item_display_name(item),
+            // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
+            TextComponent::from(first_target.gameprofile.name.clone()),
+        ])
     } else {
-        sender.send_message(
-            &translations::COMMANDS_GIVE_SUCCESS_MULTIPLE
-                .message([
-                    TextComponent::from(format!("{count}")),
-                    TextComponent::from(format!("[{}]", item.key.path)).hover_event(
-                        // FIXME: display name
-                        HoverEvent::show_item(item.key.path.clone(), None, None::<&str>),
-                    ),
-                    TextComponent::from(targets.len().to_string()),
-                ])
-                .into(),
-        );
+        translations::COMMANDS_GIVE_SUCCESS_MULTIPLE.message([
+            TextComponent::from(format!("{count}")),
+            item_display_name(item),
+            TextComponent::from(targets.len().to_string()),
+        ])
+    };
+    context.send_success(&message.into(), true);
+    context.result = count.saturating_mul(i32::try_from(targets.len()).unwrap_or(i32::MAX));
+    Ok(())
+}
+
+/// Splits `count` items into stacks of at most `max_stack_size`.
+fn split_into_stacks(item: ItemRef, count: i32, max_stack_size: i32) -> Vec<ItemStack> {
+    let mut stacks = Vec::new();
+    let mut remaining = count;
+    while remaining > 0 {
+        let stack_size = max_stack_size.min(remaining);
+        remaining -= stack_size;
+        stacks.push(ItemStack::with_count(item, stack_size));
+    }
+    stacks
+}
+
+/// The bracketed item name shown in feedback, vanilla's `ItemStack.getDisplayName()`.
+fn item_display_name(item: ItemRef) -> TextComponent {
+    let name = item
+        .components
+        .get(vanilla_components::ITEM_NAME)
+        .unwrap_or_else(|| TextComponent::plain(item.key.path.to_string()));
+    translations::CHAT_SQUARE_BRACKETS
+        .message([name])
+        .component()
+        .hover_event(HoverEvent::show_item(
+            item.key.path.clone(),
+            None,
+            None::<&str>,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_items;
+
+    use super::split_into_stacks;
+
+    #[test]
+    fn counts_above_the_stack_size_are_split() {
+        init_test_registry();
+        let diamond = &vanilla_items::ITEMS.diamond;
+
+        let stacks = split_into_stacks(diamond, 130, 64);
+
+        let counts: Vec<i32> = stacks.iter().map(|stack| stack.count()).collect();
+        assert_eq!(counts, [64, 64, 2]);
+        assert!(stacks.iter().all(|stack| stack.item().key == diamond.key));
     }
 }
@@ -0,0 +1,306 @@
+//! Handler for the "help" command.
+//! Mirrors `net.minecraft.server.commands.HelpCommand`, listing commands a page at a time.
+use std::iter;
+use std::sync::Arc;
+
+use steel_protocol::packets::game::CommandNode;
+use steel_utils::translations;
+use text_components::format::Color;
+use text_components::interactivity::ClickEvent;
+use text_components::{Modifier, TextComponent};
+
+use crate::command::arguments::integer::IntegerArgument;
+use crate::command::arguments::string::StringArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+
+/// How many commands are listed per page.
+const PAGE_SIZE: usize = 8;
+
+type Handlers = [Arc<dyn CommandHandlerDyn + Send + Sync>];
+
+/// Handler for the "help" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["help"],
+        "Lists commands, or shows how to use one.",
+        "minecraft:command.help",
+    )
+    .executes(HelpPageExecutor)
+    .then(argument("page", IntegerArgument::bounded(Some(1), None)).executes(HelpPageExecutor))
+    .then(argument("command", StringArgument::Greedy).executes(HelpUsageExecutor))
+}
+
+struct HelpPageExecutor;
+
+impl CommandExecutor<()> for HelpPageExecutor {
+    fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
+        show_page(context, 1)
+    }
+}
+
+impl CommandExecutor<((), i32)> for HelpPageExecutor {
+    fn execute(&self, args: ((), i32), context: &mut CommandContext) -> Result<(), CommandError> {
+        let ((), page) = args;
+        show_page(context, usize::try_from(page).unwrap_or(1))
+    }
+}
+
+fn show_page(context: &mut CommandContext, page: usize) -> Result<(), CommandError> {
+    let handlers = context
+        .server
+        .command_dispatcher
+        .read()
+        .usable_handlers(context.sender.permission_level());
+    let Some(lines) = help_page(&handlers, page) else {
+        return Err(CommandError::CommandFailed(Box::new(TextComponent::plain(
+            format!(
+                "There is no help page {page}, the last one is {}",
+                page_count(&handlers)
+            ),
+        ))));
+    };
+
+    for line in &lines {
+        context.send_success(line, false);
+    }
+    context.result = i32::try_from(lines.len() - 1).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn page_count(handlers: &Handlers) -> usize {
+    handlers.len().div_ceil(PAGE_SIZE).max(1)
+}
+
+/// Builds a header followed by the commands on `page`, or `None` if there is no such page.
+fn help_page(handlers: &Handlers, page: usize) -> Option<Vec<TextComponent>> {
+    let pages = page_count(handlers);
+    if page == 0 || page > pages {
+        return None;
+    }
+
+    let header =
+        TextComponent::plain(format!("--- Help page {page} of {pages} ---")).color(Color::Yellow);
+    let entries = handlers
+        .iter()
+        .skip((page - 1) * PAGE_SIZE)
+        .take(PAGE_SIZE)
+        .map(|handler| {
+            let name = handler.names()[0];
+            TextComponent::plain(format!("/{name}"))
+                .color(Color::Gold)
+                .click_event(ClickEvent::suggest_command(format!("/{name} ")))
+                .add_children(vec![
+                    TextComponent::plain(format!(": {}", handler.description()))
+                        .color(Color::White),
+                ])
+        });
+    Some(iter::once(header).chain(entries).collect())
+}
+
+struct HelpUsageExecutor;
+
+impl CommandExecutor<((), String)> for HelpUsageExecutor {
+    fn execute(
+        &self,
+        args: ((), String),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((), input) = args;
+        let handlers = context
+            .server
+            .command_dispatcher
+            .read()
+            .usable_handlers(context.sender.permission_level());
+        let case_insensitive = context.server.config.case_insensitive_commands;
+
+        let Some(usages) = command_usages(&handlers, &input, case_insensitive) else {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_HELP_FAILED.msg().into(),
+            )));
+        };
+
+        for usage in &usages {
+            context.send_success(&TextComponent::plain(format!("/{input} {usage}")), false);
+        }
+        context.result = i32::try_from(usages.len()).unwrap_or(i32::MAX);
+        Ok(())
+    }
+}
+
+/// Finds the node `input` leads to and returns the usage of each of its children, like vanilla
+/// does. Words only match literals, except where an argument is the only way to continue.
+fn command_usages(handlers: &Handlers, input: &str, case_insensitive: bool) -> Option<Vec<String>> {
+    let mut words = input.split_whitespace();
+    let name = words.next()?;
+    let handler = handlers.iter().find(|handler| {
+        handler.names().iter().any(|candidate| {
+            *candidate == name || (case_insensitive && candidate.eq_ignore_ascii_case(name))
+        })
+    })?;
+
+    let mut nodes = vec![CommandNode::new_root()];
+    let mut root_children = Vec::new();
+    handler.usage(&mut nodes, &mut root_children);
+
+    // The primary name comes first, aliases only redirect to it.
+    let mut index = usize::try_from(*root_children.first()?).ok()?;
+    for word in words {
+        let (children, _, _) = node_parts(&nodes[index]);
+        let literal = children.iter().find(|&&child| {
+            matches!(&nodes[child as usize], CommandNode::Literal { name, .. }
+                if name == word || (case_insensitive && name.eq_ignore_ascii_case(word)))
+        });
+        index = match (literal, children) {
+            (Some(&child), _) | (None, &[child]) => child as usize,
+            _ => return None,
+        };
+        if let (_, Some(target), _) = node_parts(&nodes[index]) {
+            index = target as usize;
+        }
+    }
+
+    let (children, _, executable) = node_parts(&nodes[index]);
+    Some(
+        children
+            .iter()
+            .map(|&child| smart_usage(&nodes, child as usize, executable, false))
+            .collect(),
+    )
+}
+
+/// Returns the children, redirect target and whether the node is executable.
+fn node_parts(node: &CommandNode) -> (&[i32], Option<i32>, bool) {
+    match node {
+        CommandNode::Root { children } => (children.as_slice(), None, false),
+        CommandNode::Literal {
+            children,
+            redirects_to,
+            is_executable,
+            ..
+        }
+        | CommandNode::Argument {
+            children,
+            redirects_to,
+            is_executable,
+            ..
+        } => (children.as_slice(), *redirects_to, *is_executable),
+    }
+}
+
+fn usage_text(node: &CommandNode) -> String {
+    match node {
+        CommandNode::Root { .. } => String::new(),
+        CommandNode::Literal { name, .. } => name.to_string(),
+        CommandNode::Argument { name, .. } => format!("<{name}>"),
+    }
+}
+
+/// Brigadier's `CommandDispatcher.getSmartUsage`, which shortens a node's usage to a line.
+fn smart_usage(nodes: &[CommandNode], index: usize, optional: bool, deep: bool) -> String {
+    let node = &nodes[index];
+    let own = if optional {
+        format!("[{}]", usage_text(node))
+    } else {
+        usage_text(node)
+    };
+    if deep {
+        return own;
+    }
+
+    let (children, redirect, executable) = node_parts(node);
+    if let Some(target) = redirect {
+        return if target == 0 {
+            format!("{own} ...")
+        } else {
+            format!("{own} -> {}", usage_text(&nodes[target as usize]))
+        };
+    }
+
+    match children {
+        [] => own,
+        [child] => format!(
+            "{own} {}",
+            smart_usage(nodes, *child as usize, executable, executable)
+        ),
+        _ => {
+            let mut usages: Vec<String> = Vec::new();
+            for &child in children {
+                let usage = smart_usage(nodes, child as usize, executable, true);
+                if !usages.contains(&usage) {
+                    usages.push(usage);
+                }
+            }
+            if let [usage] = usages.as_slice() {
+                return if executable {
+                    format!("{own} [{usage}]")
+                } else {
+                    format!("{own} {usage}")
+                };
+            }
+
+            let alternatives = children
+                .iter()
+                .map(|&child| usage_text(&nodes[child as usize]))
+                .collect::<Vec<_>>()
+                .join("|");
+            if executable {
+                format!("{own} [{alternatives}]")
+            } else {
+                format!("{own} ({alternatives})")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+    use steel_utils::text::{DisplayResolutor, to_plain_string};
+
+    use super::{command_usages, help_page, page_count};
+    use crate::command::commands::{self, CommandHandlerDyn};
+    use crate::command::{CommandDispatcher, LEVEL_ALL, LEVEL_OWNERS};
+
+    #[test]
+    fn help_lists_tellraw_with_its_description() {
+        init_test_registry();
+        let handlers = CommandDispatcher::new().usable_handlers(LEVEL_OWNERS);
+        let lines: Vec<String> = (1..=page_count(&handlers))
+            .flat_map(|page| help_page(&handlers, page).expect("page is in range"))
+            .map(|line| to_plain_string(&line, &DisplayResolutor))
+            .collect();
+
+        let description = commands::tellraw::command_handler().description();
+        assert!(lines.contains(&format!("/tellraw: {description}")));
+        assert!(help_page(&handlers, page_count(&handlers) + 1).is_none());
+    }
+
+    #[test]
+    fn help_hides_commands_above_the_sender_level() {
+        init_test_registry();
+        let handlers = CommandDispatcher::new().usable_handlers(LEVEL_ALL);
+
+        assert!(handlers.iter().all(|handler| handler.names()[0] != "stop"));
+        assert!(command_usages(&handlers, "stop", false).is_none());
+    }
+
+    #[test]
+    fn usage_follows_vanilla_smart_usage() {
+        init_test_registry();
+        let handlers = CommandDispatcher::new().usable_handlers(LEVEL_OWNERS);
+
+        assert_eq!(
+            command_usages(&handlers, "gamemode", false).expect("gamemode is registered"),
+            ["<gamemode> [<targets>]"]
+        );
+        assert_eq!(
+            command_usages(&handlers, "time set", false).expect("time is registered"),
+            ["day", "midnight", "night", "noon", "<time>"]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Handler for the "kick" command.
+//! Mirrors `net.minecraft.server.commands.KickCommand`.
+use std::sync::Arc;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_ADMINS;
+use crate::command::arguments::message::MessageArgument;
+use crate::command::arguments::player::PlayerArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::player::Player;
+
+/// Handler for the "kick" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["kick"],
+        "Kicks players from the server.",
+        "minecraft:command.kick",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(
+        argument("targets", PlayerArgument::multiple())
+            .executes(KickExecutor)
+            .then(argument("reason", MessageArgument).executes(KickWithReasonExecutor)),
+    )
+}
+
+struct KickExecutor;
+
+impl CommandExecutor<((), Vec<Arc<Player>>)> for KickExecutor {
+    fn execute(
+        &self,
+        args: ((), Vec<Arc<Player>>),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((), targets) = args;
+        kick_players(
+            context,
+            &targets,
+            &translations::MULTIPLAYER_DISCONNECT_KICKED.msg().into(),
+        )
+    }
+}
+
+struct KickWithReasonExecutor;
+
+impl CommandExecutor<(((), Vec<Arc<Player>>), String)> for KickWithReasonExecutor {
+    fn execute(
+        &self,
+        args: (((), Vec<Arc<Player>>), String),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let (((), targets), reason) = args;
+        kick_players(context, &targets, &TextComponent::plain(reason))
+    }
+}
+
+fn kick_players(
+    context: &mut CommandContext,
+    targets: &[Arc<Player>],
+    reason: &TextComponent,
+) -> Result<(), CommandError> {
+    if targets.is_empty() {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_ENTITY_NOTFOUND_PLAYER.msg().into(),
+        )));
+    }
+
+    for target in targets {
+        target.disconnect(reason.clone());
+        // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
+        context.send_success(
+            &translations::COMMANDS_KICK_SUCCESS
+                .message([
+                    TextComponent::plain(target.gameprofile.name.clone()),
+                    reason.clone(),
+                ])
+                .into(),
+            true,
+        );
+    }
+
+    context.result = i32::try_from(targets.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
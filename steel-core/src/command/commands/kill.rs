@@ -5,15 +5,16 @@ use std::sync::Arc;
 
 use text_components::TextComponent;
 
+use crate::command::LEVEL_GAMEMASTERS;
 use crate::command::arguments::entity::EntityArgument;
+use crate::command::commands::summon::entity_display_name;
 use crate::command::commands::{
     CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
 };
 use crate::command::context::CommandContext;
 use crate::command::error::CommandError;
+use crate::entity::LivingEntity;
 use crate::entity::damage::DamageSource;
-use crate::entity::{Entity, LivingEntity};
-use crate::player::Player;
 use steel_registry::vanilla_damage_types;
 use steel_utils::translations;
 
@@ -21,37 +22,22 @@ use steel_utils::translations;
 #[must_use]
 pub fn command_handler() -> impl CommandHandlerDyn {
     CommandHandlerBuilder::new(&["kill"], "Kills entities.", "minecraft:command.kill")
+        .requires_level(LEVEL_GAMEMASTERS)
         .executes(KillSelfExecutor)
         .then(argument("targets", EntityArgument::multiple()).executes(KillTargetsExecutor))
 }
 
-/// `LivingEntity.kill()` — hurt with `genericKill` at `Float.MAX_VALUE`.
-fn kill_player(player: &Player) {
-    player.hurt(
-        &DamageSource::environment(&vanilla_damage_types::GENERIC_KILL),
-        f32::MAX,
-    );
-}
-
 struct KillSelfExecutor;
 
 impl CommandExecutor<()> for KillSelfExecutor {
     fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
-        let player = context
-            .sender
-            .get_player()
-            .ok_or(CommandError::InvalidRequirement)?;
-
-        kill_player(player);
-
-        // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
-        context.sender.send_message(
-            &translations::COMMANDS_KILL_SUCCESS_SINGLE
-                .message([TextComponent::plain(player.gameprofile.name.clone())])
-                .into(),
-        );
+        let Some(player) = context.sender.get_player().cloned() else {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::PERMISSIONS_REQUIRES_ENTITY.msg().into(),
+            )));
+        };
 
-        Ok(())
+        kill(context, &[player])
     }
 }
 
@@ -64,48 +50,80 @@ impl CommandExecutor<((), Vec<Arc<dyn LivingEntity + Send + Sync>>)> for KillTar
         context: &mut CommandContext,
     ) -> Result<(), CommandError> {
         let ((), targets) = args;
+        kill(context, &targets)
+    }
+}
 
-        if targets.is_empty() {
-            return Err(CommandError::CommandFailed(Box::new(
-                TextComponent::const_plain("No entity was found"),
-            )));
-        }
+/// `LivingEntity.kill()`, hurting each target with `genericKill` at `Float.MAX_VALUE`.
+fn kill<E: LivingEntity + ?Sized>(
+    context: &mut CommandContext,
+    targets: &[Arc<E>],
+) -> Result<(), CommandError> {
+    if targets.is_empty() {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_ENTITY_NOTFOUND_ENTITY.msg().into(),
+        )));
+    }
 
-        let players = context.server.get_players();
-
-        let mut last_name = String::new();
-        let mut victim_count = 0;
-        for target in &targets {
-            let target_uuid = target.uuid();
-            if let Some(player) = players.iter().find(|p| p.uuid() == target_uuid) {
-                kill_player(player);
-                victim_count += 1;
-                last_name.clone_from(&player.gameprofile.name);
-            }
-            // TODO: non-player entities via Entity::kill() (remove with RemovalReason::KILLED)
-        }
+    let source = DamageSource::environment(&vanilla_damage_types::GENERIC_KILL);
+    let names: Vec<TextComponent> = targets
+        .iter()
+        .map(|target| {
+            target.hurt(&source, f32::MAX);
+            entity_display_name(&**target)
+        })
+        .collect();
+
+    context.send_success(&kill_feedback(names), true);
+    context.result = i32::try_from(targets.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
 
-        if victim_count == 0 {
-            return Err(CommandError::CommandFailed(Box::new(
-                TextComponent::const_plain("No entity was found"),
-            )));
-        }
+/// Names the victim when there is only one, counts them otherwise.
+fn kill_feedback(mut names: Vec<TextComponent>) -> TextComponent {
+    if names.len() == 1
+        && let Some(name) = names.pop()
+    {
+        return translations::COMMANDS_KILL_SUCCESS_SINGLE
+            .message([name])
+            .into();
+    }
+    translations::COMMANDS_KILL_SUCCESS_MULTIPLE
+        .message([TextComponent::plain(names.len().to_string())])
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::text::{DisplayResolutor, to_plain_string};
+    use text_components::TextComponent;
+    use text_components::content::Content;
 
-        // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
-        if victim_count == 1 {
-            context.sender.send_message(
-                &translations::COMMANDS_KILL_SUCCESS_SINGLE
-                    .message([TextComponent::plain(last_name)])
-                    .into(),
-            );
-        } else {
-            context.sender.send_message(
-                &translations::COMMANDS_KILL_SUCCESS_MULTIPLE
-                    .message([TextComponent::plain(victim_count.to_string())])
-                    .into(),
-            );
+    use super::kill_feedback;
+
+    fn key(component: &TextComponent) -> &str {
+        match &component.content {
+            Content::Translate(message) => &message.key,
+            _ => panic!("expected a translated component"),
         }
+    }
+
+    #[test]
+    fn feedback_counts_several_victims() {
+        let feedback = kill_feedback(vec![
+            TextComponent::plain("Steve"),
+            TextComponent::plain("Alex"),
+        ]);
+
+        assert_eq!(key(&feedback), "commands.kill.success.multiple");
+        assert!(to_plain_string(&feedback, &DisplayResolutor).contains('2'));
+    }
+
+    #[test]
+    fn feedback_names_a_single_victim() {
+        let feedback = kill_feedback(vec![TextComponent::plain("Steve")]);
 
-        Ok(())
+        assert_eq!(key(&feedback), "commands.kill.success.single");
+        assert!(to_plain_string(&feedback, &DisplayResolutor).contains("Steve"));
     }
 }
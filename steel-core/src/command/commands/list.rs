@@ -52,7 +52,7 @@ fn list_players(context: &mut CommandContext, show_uuids: bool) {
         .collect::<Vec<String>>()
         .join(", ");
 
-    context.sender.send_message(
+    context.send_success(
         &COMMANDS_LIST_PLAYERS
             .message([
                 player_number.to_string(),
@@ -60,5 +60,33 @@ fn list_players(context: &mut CommandContext, show_uuids: bool) {
                 formatted_player_list,
             ])
             .into(),
+        false,
     );
+    // Vanilla returns the number of online players
+    context.result = i32::try_from(player_number).unwrap_or(i32::MAX);
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::text::{DisplayResolutor, to_plain_string};
+
+    use crate::command::LEVEL_ALL;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, run_command, test_server};
+
+    #[test]
+    fn lists_every_online_player() {
+        let server = test_server();
+        add_player(&server, "Alex");
+        add_player(&server, "Steve");
+        let sender = CommandSender::capture(LEVEL_ALL);
+
+        run_command(&server, sender.clone(), "list");
+
+        let captured = sender.captured();
+        assert_eq!(captured.len(), 1);
+        let message = to_plain_string(&captured[0], &DisplayResolutor);
+        assert!(message.contains("Alex"), "{message}");
+        assert!(message.contains("Steve"), "{message}");
+    }
 }
@@ -3,35 +3,45 @@
 use std::sync::Arc;
 use std::time::Instant;
 
+use glam::IVec3;
+use steel_registry::biome::{Biome, BiomeRef};
+use steel_registry::poi::PointOfInterestType;
+use steel_registry::{REGISTRY, RegistryExt};
 use steel_utils::{BlockPos, ChunkPos, Identifier, translations};
-use text_components::format::Color;
-use text_components::interactivity::{ClickEvent, HoverEvent};
-use text_components::{Modifier, TextComponent};
+use text_components::TextComponent;
 
 use crate::chunk::chunk_access::ChunkStatus;
 use crate::chunk::chunk_request::{
     ChunkRequest, ChunkRequestHandle, ChunkRequestState, ChunkTicketKind,
 };
+use crate::command::arguments::resource_or_tag::{ResourceOrTag, ResourceOrTagArgument};
 use crate::command::arguments::structure::{StructureArgument, StructureArgumentValue};
 use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal};
 use crate::command::context::CommandContext;
 use crate::command::error::CommandError;
 use crate::command::sender::CommandSender;
+use crate::command::{LEVEL_GAMEMASTERS, clickable_coordinates, clickable_position};
+use crate::poi::OccupationStatus;
 use crate::server::jobs::{JobPoll, ServerJob, ServerJobContext};
 use crate::world::World;
 use crate::worldgen::generator::ChunkGenerator;
 use crate::worldgen::structure::{StructureLocateCandidate, StructureLocatePlan, squared_distance};
 
 const MAX_STRUCTURE_LOCATE_RADIUS: i32 = 100;
+const BIOME_SEARCH_RADIUS: i32 = 6400;
+const BIOME_HORIZONTAL_STEP: i32 = 32;
+const BIOME_VERTICAL_STEP: i32 = 64;
+const POI_SEARCH_RADIUS: i32 = 256;
 
 /// Handler for the "locate" command.
 #[must_use]
 pub fn command_handler() -> impl CommandHandlerDyn {
     CommandHandlerBuilder::new(
         &["locate"],
-        "Locates structures.",
+        "Locates structures, biomes and points of interest.",
         "minecraft:command.locate",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .then(
         literal("structure").then(argument("structure", StructureArgument).executes(
             |((), structure): ((), StructureArgumentValue),
@@ -39,6 +49,218 @@ pub fn command_handler() -> impl CommandHandlerDyn {
              -> Result<(), CommandError> { locate_structure(structure, context) },
         )),
     )
+    .then(
+        literal("biome").then(argument("biome", ResourceOrTagArgument::biome()).executes(
+            |((), biomes): ((), ResourceOrTag<Biome>), context: &mut CommandContext| {
+                locate_biome(&biomes, context)
+            },
+        )),
+    )
+    .then(
+        literal("poi").then(argument("poi", ResourceOrTagArgument::poi_type()).executes(
+            |((), poi_types): ((), ResourceOrTag<PointOfInterestType>),
+             context: &mut CommandContext| { locate_poi(&poi_types, context) },
+        )),
+    )
+}
+
+/// A search for the nearest match of a biome or point of interest query.
+trait Locator {
+    /// The nearest match around `origin` and the key of the entry found there.
+    fn locate(&mut self, origin: BlockPos) -> Option<(BlockPos, Identifier)>;
+}
+
+/// Vanilla `BiomeSource.findClosestBiome3d` over the world's generator.
+struct BiomeLocator<'a> {
+    sample: Box<dyn FnMut(IVec3) -> BiomeRef + 'a>,
+    biomes: &'a ResourceOrTag<Biome>,
+    min_y: i32,
+    max_y: i32,
+}
+
+impl Locator for BiomeLocator<'_> {
+    fn locate(&mut self, origin: BlockPos) -> Option<(BlockPos, Identifier)> {
+        let step_radius = BIOME_SEARCH_RADIUS.div_euclid(BIOME_HORIZONTAL_STEP);
+        let y_coords = out_from_origin(origin.0.y, self.min_y, self.max_y, BIOME_VERTICAL_STEP);
+        for (dx, dz) in spiral_around(step_radius) {
+            let x = origin.0.x + dx * BIOME_HORIZONTAL_STEP;
+            let z = origin.0.z + dz * BIOME_HORIZONTAL_STEP;
+            for &y in &y_coords {
+                let biome = (self.sample)(IVec3::new(x >> 2, y >> 2, z >> 2));
+                if self.biomes.matches(biome) {
+                    return Some((BlockPos::new(x, y, z), biome.key.clone()));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The nearest point of interest recorded in the world's loaded chunks.
+struct PoiLocator<'a> {
+    world: &'a World,
+    poi_types: &'a ResourceOrTag<PointOfInterestType>,
+}
+
+impl Locator for PoiLocator<'_> {
+    fn locate(&mut self, origin: BlockPos) -> Option<(BlockPos, Identifier)> {
+        let matches = |id: usize| {
+            REGISTRY
+                .poi_types
+                .by_id(id)
+                .is_some_and(|poi_type| self.poi_types.matches(poi_type))
+        };
+        let (pos, id) = self.world.poi_storage.lock().get_nearest(
+            &matches,
+            origin,
+            POI_SEARCH_RADIUS,
+            OccupationStatus::Any,
+        )?;
+        Some((pos, REGISTRY.poi_types.by_id(id)?.key.clone()))
+    }
+}
+
+/// A match reported by `/locate`.
+#[derive(Debug, PartialEq, Eq)]
+struct Located {
+    pos: BlockPos,
+    found: Identifier,
+    distance: i32,
+}
+
+/// Runs `locator` from `origin`, measuring in 3D when the reported y is absolute.
+fn locate_nearest(
+    locator: &mut impl Locator,
+    origin: BlockPos,
+    absolute_y: bool,
+) -> Option<Located> {
+    let (pos, found) = locator.locate(origin)?;
+    let distance = if absolute_y {
+        let delta = (pos.0 - origin.0).as_dvec3();
+        delta.length().floor() as i32
+    } else {
+        horizontal_distance(origin, pos)
+    };
+    Some(Located {
+        pos,
+        found,
+        distance,
+    })
+}
+
+fn locate_biome(
+    biomes: &ResourceOrTag<Biome>,
+    context: &mut CommandContext,
+) -> Result<(), CommandError> {
+    let started_at = Instant::now();
+    let origin = BlockPos::from(context.position);
+    let world = context.world.clone();
+    let located = world
+        .chunk_map
+        .world_gen_context
+        .generator
+        .biome_sampler()
+        .and_then(|sample| {
+            let mut locator = BiomeLocator {
+                sample,
+                biomes,
+                min_y: world.get_min_y() + 1,
+                max_y: world.get_max_y() + 1,
+            };
+            locate_nearest(&mut locator, origin, true)
+        });
+    let Some(located) = located else {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_LOCATE_BIOME_NOT_FOUND
+                .message([TextComponent::from(biomes.query_name())])
+                .into(),
+        )));
+    };
+
+    let biome_name = biomes.printable_name(&located.found);
+    context.send_success(
+        &translations::COMMANDS_LOCATE_BIOME_SUCCESS
+            .message([
+                TextComponent::from(biome_name.clone()),
+                locate_coordinates_component(located.pos, true),
+                TextComponent::from(located.distance.to_string()),
+            ])
+            .into(),
+        false,
+    );
+    context.result = located.distance;
+    tracing::info!(
+        "Locating biome {} took {} ms",
+        biome_name,
+        started_at.elapsed().as_millis()
+    );
+    Ok(())
+}
+
+fn locate_poi(
+    poi_types: &ResourceOrTag<PointOfInterestType>,
+    context: &mut CommandContext,
+) -> Result<(), CommandError> {
+    let origin = BlockPos::from(context.position);
+    let mut locator = PoiLocator {
+        world: &context.world,
+        poi_types,
+    };
+    let Some(located) = locate_nearest(&mut locator, origin, false) else {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_LOCATE_POI_NOT_FOUND
+                .message([TextComponent::from(poi_types.query_name())])
+                .into(),
+        )));
+    };
+
+    context.send_success(
+        &translations::COMMANDS_LOCATE_POI_SUCCESS
+            .message([
+                TextComponent::from(poi_types.printable_name(&located.found)),
+                locate_coordinates_component(located.pos, false),
+                TextComponent::from(located.distance.to_string()),
+            ])
+            .into(),
+        false,
+    );
+    context.result = located.distance;
+    Ok(())
+}
+
+/// Column offsets in vanilla `BlockPos.spiralAround` order, starting east then south.
+fn spiral_around(radius: i32) -> impl Iterator<Item = (i32, i32)> {
+    const DIRECTIONS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+    let steps = (0..=4 * radius).flat_map(|leg| {
+        std::iter::repeat_n(DIRECTIONS[(leg % 4) as usize], (leg / 2 + 1) as usize)
+    });
+    std::iter::once((0, 0)).chain(steps.scan((0, 0), |pos, (dx, dz)| {
+        *pos = (pos.0 + dx, pos.1 + dz);
+        Some(*pos)
+    }))
+}
+
+/// Vanilla `Mth.outFromOrigin`: `origin`, then alternating above and below it by `step`.
+fn out_from_origin(origin: i32, lower: i32, upper: i32, step: i32) -> Vec<i32> {
+    let origin = origin.clamp(lower, upper);
+    let mut coords = Vec::new();
+    let mut cursor = origin;
+    loop {
+        let distance = (origin - cursor).abs();
+        if origin - distance < lower && origin + distance > upper {
+            return coords;
+        }
+        coords.push(cursor);
+
+        let previous_was_negative = cursor <= origin;
+        let next_positive = origin + distance + step;
+        let next_negative = origin - distance - if previous_was_negative { step } else { 0 };
+        cursor = if (!previous_was_negative || next_positive > upper) && next_negative >= lower {
+            next_negative
+        } else {
+            next_positive
+        };
+    }
 }
 
 fn locate_structure(
@@ -352,42 +574,30 @@ fn locate_success_component(structure_name: String, pos: BlockPos, distance: i32
     translations::COMMANDS_LOCATE_STRUCTURE_SUCCESS
         .message([
             TextComponent::from(structure_name),
-            locate_coordinates_component(pos),
+            locate_coordinates_component(pos, false),
             TextComponent::from(distance.to_string()),
         ])
         .component()
 }
 
-fn locate_coordinates_component(pos: BlockPos) -> TextComponent {
-    let displayed_y = "~";
-    TextComponent::plain("[")
-        .add_child(
-            translations::CHAT_COORDINATES
-                .message([
-                    TextComponent::from(pos.0.x.to_string()),
-                    TextComponent::from(displayed_y),
-                    TextComponent::from(pos.0.z.to_string()),
-                ])
-                .component(),
-        )
-        .add_child(TextComponent::plain("]"))
-        .color(Color::Green)
-        .hover_event(HoverEvent::show_text(
-            &translations::CHAT_COORDINATES_TOOLTIP,
-        ))
-        .click_event(ClickEvent::suggest_command(format!(
-            "/tp @s {} {} {}",
-            pos.0.x, displayed_y, pos.0.z
-        )))
+fn locate_coordinates_component(pos: BlockPos, absolute_y: bool) -> TextComponent {
+    if absolute_y {
+        clickable_position(pos.0.x, pos.0.y, pos.0.z)
+    } else {
+        clickable_coordinates(&pos.0.x.to_string(), "~", &pos.0.z.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use text_components::format::Color;
+    use text_components::interactivity::{ClickEvent, HoverEvent};
+
     use super::*;
 
     #[test]
     fn locate_coordinates_component_matches_vanilla_interactivity() {
-        let component = locate_coordinates_component(BlockPos::new(12, 0, -34));
+        let component = locate_coordinates_component(BlockPos::new(12, 0, -34), false);
 
         assert_eq!(component.format.color, Some(Color::Green));
         assert!(matches!(
@@ -400,4 +610,54 @@ mod tests {
             Some(HoverEvent::ShowText { .. })
         ));
     }
+
+    struct FixedLocator(BlockPos);
+
+    impl Locator for FixedLocator {
+        fn locate(&mut self, _origin: BlockPos) -> Option<(BlockPos, Identifier)> {
+            Some((self.0, Identifier::vanilla_static("plains")))
+        }
+    }
+
+    #[test]
+    fn nearest_match_reports_its_position_and_distance() {
+        let origin = BlockPos::new(0, 70, 0);
+        let mut locator = FixedLocator(BlockPos::new(100, 64, 0));
+
+        let biome = locate_nearest(&mut locator, origin, true).expect("locator always matches");
+        assert_eq!(biome.pos, BlockPos::new(100, 64, 0));
+        assert_eq!(biome.found, Identifier::vanilla_static("plains"));
+        assert_eq!(biome.distance, 100);
+
+        let mut locator = FixedLocator(BlockPos::new(30, 0, 40));
+        let poi = locate_nearest(&mut locator, origin, false).expect("locator always matches");
+        assert_eq!(poi.distance, 50);
+
+        let component = locate_coordinates_component(biome.pos, true);
+        assert!(matches!(
+            component.interactions.click,
+            Some(ClickEvent::SuggestCommand { ref command })
+                if command.as_ref() == "/tp @s 100 64 0"
+        ));
+    }
+
+    #[test]
+    fn biome_search_spirals_out_from_the_origin() {
+        let columns: Vec<_> = spiral_around(1).take(9).collect();
+        assert_eq!(
+            columns,
+            [
+                (0, 0),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+                (0, -1),
+                (1, -1)
+            ]
+        );
+        assert_eq!(out_from_origin(0, -128, 128, 64), [0, 64, -64, 128, -128]);
+    }
 }
@@ -0,0 +1,41 @@
+//! Handler for the "me" command.
+use crate::command::arguments::message::MessageArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use steel_utils::translations;
+use text_components::TextComponent;
+
+/// Handler for the "me" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["me"],
+        "Displays a message about yourself.",
+        "minecraft:command.me",
+    )
+    .then(argument("action", MessageArgument).executes(MeCommandExecutor))
+}
+
+struct MeCommandExecutor;
+
+impl CommandExecutor<((), String)> for MeCommandExecutor {
+    fn execute(
+        &self,
+        args: ((), String),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let message: TextComponent = translations::CHAT_TYPE_EMOTE
+            .message([
+                TextComponent::from(context.sender.to_string()),
+                TextComponent::from(args.1),
+            ])
+            .into();
+
+        log::info!("{:p}", message);
+        context.server.broadcast_system_message(&message);
+        Ok(())
+    }
+}
@@ -1,39 +1,73 @@
 //! This module contains the command building structs.
+pub mod ban;
+pub mod ban_ip;
+pub mod bossbar;
 pub mod clear;
+pub mod data;
+pub mod defaultgamemode;
+pub mod deop;
 pub mod difficulty;
 pub mod domain;
+pub mod effect;
 pub mod enchant;
 pub mod execute;
+pub mod experience;
+pub mod fill;
 pub mod fly;
+pub mod forceload;
 pub mod gamemode;
 pub mod gamerule;
 pub mod give;
+pub mod help;
+pub mod kick;
 pub mod kill;
 pub mod list;
 pub mod locate;
+pub mod me;
+pub mod msg;
+pub mod op;
+pub mod pardon;
+pub mod pardon_ip;
+pub mod particle;
+pub mod playsound;
+pub mod reload;
+pub mod reply;
+pub mod save;
+pub mod say;
+pub mod scoreboard;
 pub mod seed;
+pub mod setblock;
+pub mod setidletimeout;
 pub mod setworldspawn;
+pub mod spawnpoint;
+pub mod spectate;
 pub mod steel;
 pub mod stop;
+pub mod stopsound;
 pub mod summon;
+pub mod tag;
+pub mod team;
+pub mod teleport;
 pub mod tellraw;
 pub mod tick;
 pub mod time;
-pub mod tp;
+pub mod title;
+pub mod trigger;
 pub mod weather;
-pub mod xp;
+pub mod whitelist;
+pub mod worldborder;
 
 use std::marker::PhantomData;
-use std::ops::Not;
 use std::sync::Arc;
 
 use steel_protocol::packets::game::{
     CommandNode, CommandNodeInfo, SuggestionEntry, SuggestionType,
 };
 
+use crate::command::LEVEL_ALL;
 use crate::command::arguments::{CommandArgument, SuggestionContext};
 use crate::command::context::CommandContext;
-use crate::command::error::CommandError;
+use crate::command::error::{CommandError, ParseFailure};
 use crate::command::sender::CommandSender;
 use crate::server::Server;
 
@@ -48,9 +82,16 @@ pub struct SuggestionResult {
     pub length: i32,
 }
 
+/// The outcome of walking the command tree: `Err` if the arguments didn't match any branch,
+/// otherwise the result of the executor that ran.
+pub type ParseResult = Result<Result<(), CommandError>, ParseFailure>;
+
 /// A trait that defines the behavior of a type safe command executor.
 pub trait CommandExecutor<S> {
     /// Executes the command with the given type safe arguments.
+    ///
+    /// `context.result` and `context.success_count` start at `1` and may be changed, failing
+    /// resets both to `0`.
     fn execute(&self, parsed: S, context: &mut CommandContext) -> Result<(), CommandError>;
 }
 
@@ -68,6 +109,7 @@ pub struct CommandHandlerBuilder {
     names: &'static [&'static str],
     description: &'static str,
     permission: &'static str,
+    permission_level: u8,
 }
 
 /// The struct that holds command handler data and executor.
@@ -75,6 +117,7 @@ pub struct CommandHandler<E> {
     names: &'static [&'static str],
     description: &'static str,
     permission: &'static str,
+    permission_level: u8,
     executor: E,
 }
 
@@ -89,13 +132,16 @@ pub trait CommandHandlerDyn {
     /// Returns the permission of the command.
     fn permission(&self) -> &'static str;
 
+    /// Returns the permission level a sender needs to run the command.
+    fn permission_level(&self) -> u8;
+
     /// Handles the execution of a command sent by a player.
     fn execute(
         &self,
         command_args: &[&str],
         context: &mut CommandContext,
         server: &Arc<Server>,
-    ) -> Result<(), CommandError>;
+    ) -> ParseResult;
 
     /// Generates the usage information for the command.
     fn usage(&self, buffer: &mut Vec<CommandNode>, root_children: &mut Vec<i32>);
@@ -125,9 +171,17 @@ impl CommandHandlerBuilder {
             names,
             description,
             permission,
+            permission_level: LEVEL_ALL,
         }
     }
 
+    /// Requires senders to have at least the given permission level, see [`LEVEL_ALL`] and friends.
+    #[must_use]
+    pub const fn requires_level(mut self, level: u8) -> Self {
+        self.permission_level = level;
+        self
+    }
+
     /// Chains a command executor to this command handler.
     #[must_use]
     pub const fn then<E>(self, executor: E) -> CommandHandler<E>
@@ -138,6 +192,7 @@ impl CommandHandlerBuilder {
             names: self.names,
             description: self.description,
             permission: self.permission,
+            permission_level: self.permission_level,
             executor,
         }
     }
@@ -151,6 +206,7 @@ impl CommandHandlerBuilder {
             names: self.names,
             description: self.description,
             permission: self.permission,
+            permission_level: self.permission_level,
             executor: CommandParserLeafExecutor {
                 executor,
                 _source: PhantomData,
@@ -170,6 +226,7 @@ impl<E1> CommandHandler<E1> {
             names: self.names,
             description: self.description,
             permission: self.permission,
+            permission_level: self.permission_level,
             executor: CommandParserSplitExecutor {
                 first_executor: self.executor,
                 second_executor: executor,
@@ -187,6 +244,7 @@ impl<E1> CommandHandler<E1> {
             names: self.names,
             description: self.description,
             permission: self.permission,
+            permission_level: self.permission_level,
             executor: CommandParserLeafExecutor {
                 executor,
                 _source: PhantomData,
@@ -214,22 +272,20 @@ where
         self.permission
     }
 
+    /// Returns the permission level a sender needs to run the command.
+    fn permission_level(&self) -> u8 {
+        self.permission_level
+    }
+
     /// Executes the command with the given unparsed arguments.
     fn execute(
         &self,
         command_args: &[&str],
         context: &mut CommandContext,
         server: &Arc<Server>,
-    ) -> Result<(), CommandError> {
-        match self
-            .executor
+    ) -> ParseResult {
+        self.executor
             .execute(command_args, (), context, server, self)
-        {
-            Some(result) => result,
-            None => Err(CommandError::CommandFailed(Box::new(
-                "Invalid Syntax.".into(),
-            ))),
-        }
     }
 
     fn usage(&self, buffer: &mut Vec<CommandNode>, root_children: &mut Vec<i32>) {
@@ -275,7 +331,7 @@ pub trait CommandParserExecutor<S> {
         context: &mut CommandContext,
         server: &Arc<Server>,
         handler: &dyn CommandHandlerDyn,
-    ) -> Option<Result<(), CommandError>>;
+    ) -> ParseResult;
 
     /// Generates usage information for the command.
     fn usage(&self, buffer: &mut Vec<CommandNode>, node_index: i32) -> CommandNodeInfo;
@@ -311,9 +367,19 @@ where
         context: &mut CommandContext,
         _server: &Arc<Server>,
         _: &dyn CommandHandlerDyn,
-    ) -> Option<Result<(), CommandError>> {
-        args.is_empty()
-            .then(|| self.executor.execute(parsed, context))
+    ) -> ParseResult {
+        if !args.is_empty() {
+            return Err(ParseFailure::at(args));
+        }
+
+        context.result = 1;
+        context.success_count = 1;
+        let result = self.executor.execute(parsed, context);
+        if result.is_err() {
+            context.result = 0;
+            context.success_count = 0;
+        }
+        Ok(result)
     }
 
     fn usage(&self, _buffer: &mut Vec<CommandNode>, _: i32) -> CommandNodeInfo {
@@ -353,16 +419,17 @@ where
         context: &mut CommandContext,
         server: &Arc<Server>,
         handler: &dyn CommandHandlerDyn,
-    ) -> Option<Result<(), CommandError>> {
-        let result = self
+    ) -> ParseResult {
+        match self
             .first_executor
-            .execute(args, parsed.clone(), context, server, handler);
-        if result.is_some() {
-            return result;
+            .execute(args, parsed.clone(), context, server, handler)
+        {
+            Err(first) => self
+                .second_executor
+                .execute(args, parsed, context, server, handler)
+                .map_err(|second| first.furthest(second)),
+            result => result,
         }
-
-        self.second_executor
-            .execute(args, parsed, context, server, handler)
     }
 
     fn usage(&self, buffer: &mut Vec<CommandNode>, node_index: i32) -> CommandNodeInfo {
@@ -443,20 +510,21 @@ where
         context: &mut CommandContext,
         server: &Arc<Server>,
         handler: &dyn CommandHandlerDyn,
-    ) -> Option<Result<(), CommandError>> {
+    ) -> ParseResult {
         if let Err(err) = self.executor.execute(parsed, context) {
-            return Some(Err(err));
+            return Ok(Err(err));
         }
 
-        args.is_empty().not().then(|| match self.to {
+        let Some((first, rest)) = args.split_first() else {
+            return Err(ParseFailure::at(args));
+        };
+        match self.to {
             CommandRedirectTarget::Current => handler.execute(args, context, server),
-            CommandRedirectTarget::All => {
-                server
-                    .command_dispatcher
-                    .read()
-                    .execute(args[0], &args[1..], context, server)
-            }
-        })
+            CommandRedirectTarget::All => server
+                .command_dispatcher
+                .read()
+                .execute(first, rest, context, server),
+        }
     }
 
     fn usage(&self, _buffer: &mut Vec<CommandNode>, node_index: i32) -> CommandNodeInfo {
@@ -589,12 +657,16 @@ where
         context: &mut CommandContext,
         server: &Arc<Server>,
         handler: &dyn CommandHandlerDyn,
-    ) -> Option<Result<(), CommandError>> {
-        if *args.first()? == self.expected {
+    ) -> ParseResult {
+        let case_insensitive = server.config.case_insensitive_commands;
+        if args
+            .first()
+            .is_some_and(|first| literal_matches(self.expected, first, case_insensitive))
+        {
             self.executor
                 .execute(&args[1..], parsed, context, server, handler)
         } else {
-            None
+            Err(ParseFailure::at(args))
         }
     }
 
@@ -636,6 +708,15 @@ where
     }
 }
 
+/// Whether `input` matches the literal `expected`, ignoring ASCII case if `case_insensitive`.
+fn literal_matches(expected: &str, input: &str, case_insensitive: bool) -> bool {
+    if case_insensitive {
+        expected.eq_ignore_ascii_case(input)
+    } else {
+        expected == input
+    }
+}
+
 /// A builder struct for creating typed command argument executors.
 /// Arguments are parsed values (e.g., integers, coordinates, entities) defined by `CommandArgument` implementations.
 pub struct CommandParserArgumentBuilder<S, A> {
@@ -701,10 +782,13 @@ where
         context: &mut CommandContext,
         server: &Arc<Server>,
         handler: &dyn CommandHandlerDyn,
-    ) -> Option<Result<(), CommandError>> {
-        let (args, arg) = self.argument.parse(args, context)?;
+    ) -> ParseResult {
+        // Arguments report where they started consuming, not how far they got
+        let Some((rest, arg)) = self.argument.parse(args, context) else {
+            return Err(ParseFailure::at(args));
+        };
         self.executor
-            .execute(args, (parsed, arg), context, server, handler)
+            .execute(rest, (parsed, arg), context, server, handler)
     }
 
     fn usage(&self, buffer: &mut Vec<CommandNode>, node_index: i32) -> CommandNodeInfo {
@@ -861,7 +945,7 @@ impl<S> CommandParserExecutor<S> for BoxedExecutor<S> {
         context: &mut CommandContext,
         server: &Arc<Server>,
         handler: &dyn CommandHandlerDyn,
-    ) -> Option<Result<(), CommandError>> {
+    ) -> ParseResult {
         (**self).execute(args, parsed, context, server, handler)
     }
 
@@ -885,6 +969,7 @@ pub struct DynCommandHandler {
     names: &'static [&'static str],
     description: &'static str,
     permission: &'static str,
+    permission_level: u8,
     executors: Vec<BoxedExecutor<()>>,
 }
 
@@ -900,10 +985,18 @@ impl DynCommandHandler {
             names,
             description,
             permission,
+            permission_level: LEVEL_ALL,
             executors: Vec::new(),
         }
     }
 
+    /// Requires senders to have at least the given permission level.
+    #[must_use]
+    pub const fn requires_level(mut self, level: u8) -> Self {
+        self.permission_level = level;
+        self
+    }
+
     /// Adds an executor branch to this command handler.
     #[must_use]
     pub fn then<E>(mut self, executor: E) -> Self
@@ -928,20 +1021,24 @@ impl CommandHandlerDyn for DynCommandHandler {
         self.permission
     }
 
+    fn permission_level(&self) -> u8 {
+        self.permission_level
+    }
+
     fn execute(
         &self,
         command_args: &[&str],
         context: &mut CommandContext,
         server: &Arc<Server>,
-    ) -> Result<(), CommandError> {
+    ) -> ParseResult {
+        let mut failure = ParseFailure::at(command_args);
         for executor in &self.executors {
-            if let Some(result) = executor.execute(command_args, (), context, server, self) {
-                return result;
+            match executor.execute(command_args, (), context, server, self) {
+                Err(err) => failure = failure.furthest(err),
+                result => return result,
             }
         }
-        Err(CommandError::CommandFailed(Box::new(
-            "Invalid Syntax.".into(),
-        )))
+        Err(failure)
     }
 
     fn usage(&self, buffer: &mut Vec<CommandNode>, root_children: &mut Vec<i32>) {
@@ -993,3 +1090,16 @@ impl CommandHandlerDyn for DynCommandHandler {
         combined
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::literal_matches;
+
+    #[test]
+    fn literals_ignore_case_only_when_enabled() {
+        assert!(literal_matches("set", "set", false));
+        assert!(!literal_matches("set", "SET", false));
+        assert!(literal_matches("set", "SET", true));
+        assert!(!literal_matches("set", "sett", true));
+    }
+}
@@ -0,0 +1,92 @@
+//! Handler for the "msg" command.
+use crate::command::arguments::message::MessageArgument;
+use crate::command::arguments::player::PlayerArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::command::sender::CommandSender;
+use crate::player::Player;
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+use steel_utils::translations;
+use text_components::format::Color;
+use text_components::{Modifier, TextComponent};
+use uuid::Uuid;
+
+/// Handler for the "msg" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["msg", "tell", "w"],
+        "Sends a private message to players.",
+        "minecraft:command.msg",
+    )
+    .then(
+        argument("targets", PlayerArgument::multiple())
+            .then(argument("message", MessageArgument).executes(MsgCommandExecutor)),
+    )
+}
+
+struct MsgCommandExecutor;
+
+impl CommandExecutor<(((), Vec<Arc<Player>>), String)> for MsgCommandExecutor {
+    fn execute(
+        &self,
+        args: (((), Vec<Arc<Player>>), String),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let (((), targets), message) = args;
+        if targets.is_empty() {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::ARGUMENT_ENTITY_NOTFOUND_PLAYER.msg().into(),
+            )));
+        }
+
+        send_private_message(context, &targets, message);
+        Ok(())
+    }
+}
+
+/// Whispers `message` to each target and records who `/r` replies to.
+pub(super) fn send_private_message(
+    context: &CommandContext,
+    targets: &[Arc<Player>],
+    message: String,
+) {
+    let sender_name = TextComponent::from(context.sender.to_string());
+    let message = TextComponent::from(message);
+
+    for target in targets {
+        let target_name = TextComponent::plain(target.gameprofile.name.clone());
+
+        context.sender.send_message(
+            &translations::COMMANDS_MESSAGE_DISPLAY_OUTGOING
+                .message([target_name, message.clone()])
+                .component()
+                .color(Color::Gray)
+                .italic(true),
+        );
+        target.send_message(
+            &translations::COMMANDS_MESSAGE_DISPLAY_INCOMING
+                .message([sender_name.clone(), message.clone()])
+                .component()
+                .color(Color::Gray)
+                .italic(true),
+        );
+        if let CommandSender::Player(player) = &context.sender {
+            record_whisper(
+                &mut context.server.whisper_partners.lock(),
+                player.gameprofile.id,
+                target.gameprofile.id,
+            );
+        }
+    }
+}
+
+/// Remembers that `sender` whispered `target`, so each one's `/r` answers the other.
+pub(super) fn record_whisper(partners: &mut FxHashMap<Uuid, Uuid>, sender: Uuid, target: Uuid) {
+    partners.insert(sender, target);
+    partners.insert(target, sender);
+}
@@ -0,0 +1,77 @@
+//! Handler for the "op" command.
+//! Mirrors `net.minecraft.server.commands.OpCommand`.
+use std::sync::Arc;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::arguments::player::PlayerArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::command::{LEVEL_ADMINS, LEVEL_OWNERS};
+use crate::player::Player;
+use crate::server::op_list::OpEntry;
+
+/// Handler for the "op" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["op"],
+        "Grants operator status to players.",
+        "minecraft:command.op",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(argument("targets", PlayerArgument::multiple()).executes(OpExecutor))
+}
+
+struct OpExecutor;
+
+impl CommandExecutor<((), Vec<Arc<Player>>)> for OpExecutor {
+    fn execute(
+        &self,
+        args: ((), Vec<Arc<Player>>),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((), targets) = args;
+
+        let mut count = 0;
+        for target in targets {
+            let entry = OpEntry {
+                uuid: target.gameprofile.id,
+                name: target.gameprofile.name.clone(),
+                // TODO: Read vanilla's `op-permission-level` from the config
+                level: LEVEL_OWNERS,
+                bypasses_player_limit: false,
+            };
+            let added = context.server.op_list.add(entry).map_err(|e| {
+                log::error!("Failed to save ops.json: {e}");
+                CommandError::CommandFailed(Box::new(TextComponent::const_plain(
+                    "Failed to save the operator list",
+                )))
+            })?;
+            if !added {
+                continue;
+            }
+
+            target.set_permission_level(LEVEL_OWNERS);
+            count += 1;
+            context.send_success(
+                &translations::COMMANDS_OP_SUCCESS
+                    .message([TextComponent::plain(target.gameprofile.name.clone())])
+                    .into(),
+                true,
+            );
+        }
+
+        if count == 0 {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_OP_FAILED.msg().into(),
+            )));
+        }
+        context.result = count;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! Handler for the "pardon" command.
+//! Mirrors `net.minecraft.server.commands.PardonCommand`.
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_ADMINS;
+use crate::command::arguments::string::StringArgument;
+use crate::command::commands::ban::save_failed;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+
+/// Handler for the "pardon" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    // Banned players can't be online, so they are found by the name they were banned under.
+    CommandHandlerBuilder::new(
+        &["pardon"],
+        "Removes players from the ban list.",
+        "minecraft:command.pardon",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(argument("targets", StringArgument::Word).executes(
+        |((), target): ((), String), context: &mut CommandContext| pardon(context, &target),
+    ))
+}
+
+fn pardon(context: &mut CommandContext, target: &str) -> Result<(), CommandError> {
+    let bans = &context.server.banned_players;
+    let Some(entry) = bans
+        .entries()
+        .into_iter()
+        .find(|entry| entry.name.eq_ignore_ascii_case(target))
+    else {
+        return Err(failed(translations::COMMANDS_PARDON_FAILED.msg()));
+    };
+    if !bans.remove(&entry.uuid).map_err(|e| save_failed(&e))? {
+        return Err(failed(translations::COMMANDS_PARDON_FAILED.msg()));
+    }
+    context.send_success(
+        &translations::COMMANDS_PARDON_SUCCESS
+            .message([TextComponent::plain(entry.name)])
+            .into(),
+        true,
+    );
+    context.result = 1;
+    Ok(())
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
@@ -0,0 +1,54 @@
+//! Handler for the "pardon-ip" command.
+//! Mirrors `net.minecraft.server.commands.PardonIpCommand`.
+use std::net::IpAddr;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_ADMINS;
+use crate::command::arguments::string::StringArgument;
+use crate::command::commands::ban::save_failed;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+
+/// Handler for the "pardon-ip" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["pardon-ip"],
+        "Removes an address from the ban list.",
+        "minecraft:command.pardon-ip",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(argument("target", StringArgument::Word).executes(
+        |((), target): ((), String), context: &mut CommandContext| pardon_ip(context, &target),
+    ))
+}
+
+fn pardon_ip(context: &mut CommandContext, target: &str) -> Result<(), CommandError> {
+    let Ok(ip) = target.parse::<IpAddr>() else {
+        return Err(failed(translations::COMMANDS_PARDONIP_INVALID.msg()));
+    };
+    let ip = ip.to_canonical().to_string();
+    if !context
+        .server
+        .banned_ips
+        .remove(&ip)
+        .map_err(|e| save_failed(&e))?
+    {
+        return Err(failed(translations::COMMANDS_PARDONIP_FAILED.msg()));
+    }
+    context.send_success(
+        &translations::COMMANDS_PARDONIP_SUCCESS
+            .message([TextComponent::from(ip)])
+            .into(),
+        true,
+    );
+    context.result = 1;
+    Ok(())
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
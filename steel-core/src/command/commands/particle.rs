@@ -0,0 +1,302 @@
+//! Handler for the "particle" command.
+//! Mirrors `net.minecraft.server.commands.ParticleCommand`.
+use std::sync::Arc;
+
+use glam::{DVec3, Vec3};
+use steel_protocol::packets::game::CLevelParticles;
+use steel_registry::{RegistryEntry, particle_type::ParticleTypeRef};
+use steel_utils::{BlockPos, translations};
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        float::FloatArgument,
+        integer::IntegerArgument,
+        particle::{ParsedParticle, ParticleArgument},
+        player::PlayerArgument,
+        vector3::{Coordinates, ExactVector3Argument, Vector3Argument},
+    },
+    commands::{CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::entity::Entity;
+use crate::player::Player;
+
+type NameArgs = ((), ParsedParticle);
+type PosArgs = (NameArgs, Coordinates);
+type CountArgs = (((PosArgs, Coordinates), f32), i32);
+type ViewersArgs = (CountArgs, Vec<Arc<Player>>);
+
+/// Handler for the "particle" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["particle"],
+        "Creates particles.",
+        "minecraft:command.particle",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        argument("name", ParticleArgument)
+            .executes(ParticleExecutor { force: false })
+            .then(
+                argument("pos", Vector3Argument)
+                    .executes(ParticleExecutor { force: false })
+                    .then(
+                        argument("delta", ExactVector3Argument).then(
+                            argument("speed", FloatArgument::bounded(Some(0.0), None)).then(
+                                argument("count", IntegerArgument::bounded(Some(0), None))
+                                    .executes(ParticleExecutor { force: false })
+                                    .then(
+                                        literal("force")
+                                            .executes(ParticleExecutor { force: true })
+                                            .then(
+                                                argument("viewers", PlayerArgument::multiple())
+                                                    .executes(ParticleExecutor { force: true }),
+                                            ),
+                                    )
+                                    .then(
+                                        literal("normal")
+                                            .executes(ParticleExecutor { force: false })
+                                            .then(
+                                                argument("viewers", PlayerArgument::multiple())
+                                                    .executes(ParticleExecutor { force: false }),
+                                            ),
+                                    ),
+                            ),
+                        ),
+                    ),
+            ),
+    )
+}
+
+/// Runs the command, `force` being set by the `force` literal.
+struct ParticleExecutor {
+    force: bool,
+}
+
+impl CommandExecutor<NameArgs> for ParticleExecutor {
+    fn execute(
+        &self,
+        ((), particle): NameArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let viewers = world_players(context);
+        send_particles(context, particle, Emission::at(context.position), &viewers)
+    }
+}
+
+impl CommandExecutor<PosArgs> for ParticleExecutor {
+    fn execute(
+        &self,
+        (((), particle), pos): PosArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let viewers = world_players(context);
+        send_particles(
+            context,
+            particle,
+            Emission::at(pos.position(context)),
+            &viewers,
+        )
+    }
+}
+
+impl CommandExecutor<CountArgs> for ParticleExecutor {
+    fn execute(&self, args: CountArgs, context: &mut CommandContext) -> Result<(), CommandError> {
+        let ((((((), particle), pos), delta), speed), count) = args;
+        let emission = Emission {
+            delta: delta.position(context).as_vec3(),
+            speed,
+            count,
+            force: self.force,
+            ..Emission::at(pos.position(context))
+        };
+        let viewers = world_players(context);
+        send_particles(context, particle, emission, &viewers)
+    }
+}
+
+impl CommandExecutor<ViewersArgs> for ParticleExecutor {
+    fn execute(&self, args: ViewersArgs, context: &mut CommandContext) -> Result<(), CommandError> {
+        let (((((((), particle), pos), delta), speed), count), viewers) = args;
+        let emission = Emission {
+            delta: delta.position(context).as_vec3(),
+            speed,
+            count,
+            force: self.force,
+            ..Emission::at(pos.position(context))
+        };
+        send_particles(context, particle, emission, &viewers)
+    }
+}
+
+/// The optional arguments of the command.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Emission {
+    pos: DVec3,
+    delta: Vec3,
+    speed: f32,
+    count: i32,
+    force: bool,
+}
+
+impl Emission {
+    /// Vanilla's defaults for everything after the position.
+    const fn at(pos: DVec3) -> Self {
+        Self {
+            pos,
+            delta: Vec3::ZERO,
+            speed: 0.0,
+            count: 0,
+            force: false,
+        }
+    }
+
+    /// How far away viewers still receive the particles, as in vanilla `ServerLevel.sendParticles`.
+    const fn view_distance(&self) -> f64 {
+        if self.force { 512.0 } else { 32.0 }
+    }
+
+    /// Whether a viewer standing in `viewer` is close enough to see the particles.
+    fn reaches(&self, viewer: BlockPos) -> bool {
+        let center = DVec3::new(
+            f64::from(viewer.x()) + 0.5,
+            f64::from(viewer.y()) + 0.5,
+            f64::from(viewer.z()) + 0.5,
+        );
+        center.distance_squared(self.pos) < self.view_distance() * self.view_distance()
+    }
+
+    fn packet(&self, particle: ParticleTypeRef) -> CLevelParticles {
+        CLevelParticles {
+            override_limiter: self.force,
+            always_show: false,
+            pos: self.pos,
+            delta: self.delta,
+            max_speed: self.speed,
+            count: self.count,
+            particle_id: particle.id() as i32,
+        }
+    }
+}
+
+/// Every player in the command's world, the default viewers.
+fn world_players(context: &CommandContext) -> Vec<Arc<Player>> {
+    let mut players = Vec::new();
+    context.world.players.iter_players(|_, player| {
+        players.push(player.clone());
+        true
+    });
+    players
+}
+
+fn send_particles(
+    context: &mut CommandContext,
+    particle: ParsedParticle,
+    emission: Emission,
+    viewers: &[Arc<Player>],
+) -> Result<(), CommandError> {
+    if let Some(options) = particle.options {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::PARTICLE_INVALID_OPTIONS
+                .message([TextComponent::from(options)])
+                .into(),
+        )));
+    }
+    let particle = particle.particle;
+
+    let packet = emission.packet(particle);
+    let mut count = 0;
+    for viewer in viewers {
+        if !viewer
+            .level()
+            .is_some_and(|world| Arc::ptr_eq(&world, &context.world))
+            || !emission.reaches(BlockPos::from(viewer.position()))
+        {
+            continue;
+        }
+        viewer.send_packet(packet.clone());
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_PARTICLE_FAILED.msg().into(),
+        )));
+    }
+
+    context.send_success(
+        &translations::COMMANDS_PARTICLE_SUCCESS
+            .message([TextComponent::from(particle.key.to_string())])
+            .into(),
+        true,
+    );
+    context.result = count;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{DVec3, Vec3};
+    use steel_registry::{REGISTRY, RegistryExt, test_support::init_test_registry};
+    use steel_utils::{BlockPos, Identifier, translations};
+    use text_components::{Modifier, TextComponent, format::Color};
+
+    use super::Emission;
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, run_command, test_server};
+
+    #[test]
+    fn omitted_arguments_use_vanilla_defaults() {
+        let emission = Emission::at(DVec3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(emission.count, 0);
+        assert!(emission.speed.abs() < f32::EPSILON);
+        assert_eq!(emission.delta, Vec3::ZERO);
+        assert!(!emission.force);
+    }
+
+    #[test]
+    fn force_reaches_further_and_overrides_the_limiter() {
+        init_test_registry();
+        let flame = REGISTRY
+            .particle_types
+            .by_key(&Identifier::vanilla_static("flame"))
+            .expect("flame should be registered");
+        let normal = Emission::at(DVec3::ZERO);
+        let forced = Emission {
+            force: true,
+            ..normal
+        };
+        let far_viewer = BlockPos::new(100, 0, 0);
+
+        assert!(forced.view_distance() > normal.view_distance());
+        assert!(!normal.reaches(far_viewer));
+        assert!(forced.reaches(far_viewer));
+        assert!(!normal.packet(flame).override_limiter);
+        assert!(forced.packet(flame).override_limiter);
+    }
+
+    #[test]
+    fn particle_options_are_rejected() {
+        let server = test_server();
+        add_player(&server, "Steve");
+        let sender = CommandSender::capture(LEVEL_GAMEMASTERS);
+
+        run_command(&server, sender.clone(), "particle dust{scale:2}");
+
+        let failure: TextComponent = translations::PARTICLE_INVALID_OPTIONS
+            .message([TextComponent::from("{scale:2}".to_owned())])
+            .into();
+        let captured: Vec<_> = sender
+            .captured()
+            .iter()
+            .map(TextComponent::to_nbt_tag)
+            .collect();
+        assert_eq!(captured, [failure.color(Color::Red).to_nbt_tag()]);
+    }
+}
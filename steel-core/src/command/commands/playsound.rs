@@ -0,0 +1,343 @@
+//! Handler for the "playsound" command.
+//! Mirrors `net.minecraft.server.commands.PlaySoundCommand`.
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_protocol::packets::game::{CSound, SoundSource};
+use steel_registry::sound_event::SoundEventRef;
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        enumeration::EnumArgument,
+        float::FloatArgument,
+        player::PlayerArgument,
+        sound::SoundEventArgument,
+        vector3::{Coordinates, Vector3Argument},
+    },
+    commands::{
+        CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+        summon::entity_display_name,
+    },
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::entity::Entity;
+use crate::player::Player;
+
+/// The keywords of vanilla `SoundSource.getName`.
+const SOUND_SOURCES: &[(&str, SoundSource)] = &[
+    ("master", SoundSource::Master),
+    ("music", SoundSource::Music),
+    ("record", SoundSource::Records),
+    ("weather", SoundSource::Weather),
+    ("block", SoundSource::Blocks),
+    ("hostile", SoundSource::Hostile),
+    ("neutral", SoundSource::Neutral),
+    ("player", SoundSource::Players),
+    ("ambient", SoundSource::Ambient),
+    ("voice", SoundSource::Voice),
+    ("ui", SoundSource::Ui),
+];
+
+type SoundArgs = ((), SoundEventRef);
+type SourceArgs = (SoundArgs, SoundSource);
+type TargetsArgs = (SourceArgs, Vec<Arc<Player>>);
+type PosArgs = (TargetsArgs, Coordinates);
+type VolumeArgs = (PosArgs, f32);
+type PitchArgs = (VolumeArgs, f32);
+type MinVolumeArgs = (PitchArgs, f32);
+
+/// Handler for the "playsound" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["playsound"],
+        "Plays a sound to players.",
+        "minecraft:command.playsound",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        argument("sound", SoundEventArgument)
+            .executes(PlaySoundExecutor)
+            .then(
+                argument("source", EnumArgument::new(SOUND_SOURCES))
+                    .executes(PlaySoundExecutor)
+                    .then(
+                        argument("targets", PlayerArgument::multiple())
+                            .executes(PlaySoundExecutor)
+                            .then(
+                                argument("pos", Vector3Argument)
+                                    .executes(PlaySoundExecutor)
+                                    .then(
+                                        argument("volume", FloatArgument::bounded(Some(0.0), None))
+                                            .executes(PlaySoundExecutor)
+                                            .then(
+                                                argument(
+                                                    "pitch",
+                                                    FloatArgument::bounded(Some(0.0), Some(2.0)),
+                                                )
+                                                .executes(PlaySoundExecutor)
+                                                .then(
+                                                    argument(
+                                                        "minVolume",
+                                                        FloatArgument::bounded(
+                                                            Some(0.0),
+                                                            Some(1.0),
+                                                        ),
+                                                    )
+                                                    .executes(PlaySoundExecutor),
+                                                ),
+                                            ),
+                                    ),
+                            ),
+                    ),
+            ),
+    )
+}
+
+struct PlaySoundExecutor;
+
+impl CommandExecutor<SoundArgs> for PlaySoundExecutor {
+    fn execute(
+        &self,
+        ((), sound): SoundArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let targets = [source_player(context)?];
+        play_sound(context, sound, &targets, PlaySound::at(context.position))
+    }
+}
+
+impl CommandExecutor<SourceArgs> for PlaySoundExecutor {
+    fn execute(
+        &self,
+        (((), sound), source): SourceArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let targets = [source_player(context)?];
+        let options = PlaySound {
+            source,
+            ..PlaySound::at(context.position)
+        };
+        play_sound(context, sound, &targets, options)
+    }
+}
+
+impl CommandExecutor<TargetsArgs> for PlaySoundExecutor {
+    fn execute(
+        &self,
+        ((((), sound), source), targets): TargetsArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let options = PlaySound {
+            source,
+            ..PlaySound::at(context.position)
+        };
+        play_sound(context, sound, &targets, options)
+    }
+}
+
+impl CommandExecutor<PosArgs> for PlaySoundExecutor {
+    fn execute(
+        &self,
+        (((((), sound), source), targets), pos): PosArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let options = PlaySound {
+            source,
+            ..PlaySound::at(pos.position(context))
+        };
+        play_sound(context, sound, &targets, options)
+    }
+}
+
+impl CommandExecutor<VolumeArgs> for PlaySoundExecutor {
+    fn execute(
+        &self,
+        ((((((), sound), source), targets), pos), volume): VolumeArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let options = PlaySound {
+            source,
+            volume,
+            ..PlaySound::at(pos.position(context))
+        };
+        play_sound(context, sound, &targets, options)
+    }
+}
+
+impl CommandExecutor<PitchArgs> for PlaySoundExecutor {
+    fn execute(
+        &self,
+        (((((((), sound), source), targets), pos), volume), pitch): PitchArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let options = PlaySound {
+            source,
+            volume,
+            pitch,
+            ..PlaySound::at(pos.position(context))
+        };
+        play_sound(context, sound, &targets, options)
+    }
+}
+
+impl CommandExecutor<MinVolumeArgs> for PlaySoundExecutor {
+    fn execute(
+        &self,
+        args: MinVolumeArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let (((((((((), sound), source), targets), pos), volume), pitch), min_volume)) = args;
+        let options = PlaySound {
+            source,
+            pos: pos.position(context),
+            volume,
+            pitch,
+            min_volume,
+        };
+        play_sound(context, sound, &targets, options)
+    }
+}
+
+/// The optional arguments of the command.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PlaySound {
+    source: SoundSource,
+    pos: DVec3,
+    volume: f32,
+    pitch: f32,
+    min_volume: f32,
+}
+
+impl PlaySound {
+    /// Vanilla's defaults for everything after the position.
+    const fn at(pos: DVec3) -> Self {
+        Self {
+            source: SoundSource::Master,
+            pos,
+            volume: 1.0,
+            pitch: 1.0,
+            min_volume: 0.0,
+        }
+    }
+
+    /// Where and how loud a listener at `listener` hears the sound, or `None` when it is too far.
+    ///
+    /// Listeners out of range hear it at `min_volume` from two blocks away in its direction.
+    fn heard_from(&self, listener: DVec3) -> Option<(DVec3, f32)> {
+        let range = if self.volume > 1.0 {
+            f64::from(self.volume) * 16.0
+        } else {
+            16.0
+        };
+        let delta = self.pos - listener;
+        let distance_sq = delta.length_squared();
+        if distance_sq <= range * range {
+            return Some((self.pos, self.volume));
+        }
+        if self.min_volume <= 0.0 {
+            return None;
+        }
+        let distance = distance_sq.sqrt();
+        Some((listener + delta / distance * 2.0, self.min_volume))
+    }
+}
+
+fn source_player(context: &CommandContext) -> Result<Arc<Player>, CommandError> {
+    context.sender.get_player().cloned().ok_or_else(|| {
+        CommandError::CommandFailed(Box::new(
+            translations::PERMISSIONS_REQUIRES_PLAYER.msg().into(),
+        ))
+    })
+}
+
+fn play_sound(
+    context: &mut CommandContext,
+    sound: SoundEventRef,
+    targets: &[Arc<Player>],
+    options: PlaySound,
+) -> Result<(), CommandError> {
+    let seed = rand::random::<i64>();
+    let mut count = 0;
+    for target in targets {
+        if !target
+            .level()
+            .is_some_and(|world| Arc::ptr_eq(&world, &context.world))
+        {
+            continue;
+        }
+        let Some((pos, volume)) = options.heard_from(target.position()) else {
+            continue;
+        };
+        target.send_packet(CSound::new(
+            sound,
+            options.source,
+            pos,
+            volume,
+            options.pitch,
+            seed,
+        ));
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_PLAYSOUND_FAILED.msg().into(),
+        )));
+    }
+
+    let sound_name = TextComponent::from(sound.key.to_string());
+    let message = if let [target] = targets {
+        translations::COMMANDS_PLAYSOUND_SUCCESS_SINGLE
+            .message([sound_name, entity_display_name(target.as_ref())])
+    } else {
+        translations::COMMANDS_PLAYSOUND_SUCCESS_MULTIPLE
+            .message([sound_name, TextComponent::from(targets.len().to_string())])
+    };
+    context.send_success(&message.into(), true);
+    context.result = count;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+    use steel_protocol::packets::game::SoundSource;
+
+    use super::PlaySound;
+
+    #[test]
+    fn omitted_volume_and_pitch_use_vanilla_defaults() {
+        let options = PlaySound::at(DVec3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(options.source, SoundSource::Master);
+        assert!((options.volume - 1.0).abs() < f32::EPSILON);
+        assert!((options.pitch - 1.0).abs() < f32::EPSILON);
+        assert!(options.min_volume.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn distant_listeners_need_a_minimum_volume() {
+        let options = PlaySound::at(DVec3::ZERO);
+        let listener = DVec3::new(20.0, 0.0, 0.0);
+
+        assert_eq!(
+            options.heard_from(DVec3::new(10.0, 0.0, 0.0)),
+            Some((DVec3::ZERO, 1.0))
+        );
+        assert_eq!(options.heard_from(listener), None);
+
+        let quiet = PlaySound {
+            min_volume: 0.5,
+            ..options
+        };
+        assert_eq!(
+            quiet.heard_from(listener),
+            Some((DVec3::new(18.0, 0.0, 0.0), 0.5))
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Handler for the "reload" command.
+//! Mirrors `net.minecraft.server.commands.ReloadCommand`.
+use steel_utils::translations;
+
+use crate::command::LEVEL_GAMEMASTERS;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn};
+use crate::command::context::CommandContext;
+use crate::command::sender::CommandSender;
+use crate::server::jobs::{JobPoll, ServerJob, ServerJobContext};
+
+/// Handler for the "reload" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["reload"],
+        "Reloads the server's commands.",
+        "minecraft:command.reload",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .executes(|(), context: &mut CommandContext| {
+        // Commands run under the dispatcher's read lock, which the reload has to replace
+        context.server.jobs.spawn(ReloadJob {
+            sender: context.sender.clone(),
+        });
+        Ok(())
+    })
+}
+
+/// Reloads on the next server job tick, then tells the sender.
+struct ReloadJob {
+    sender: CommandSender,
+}
+
+impl ServerJob for ReloadJob {
+    fn poll(&mut self, context: &mut ServerJobContext) -> JobPoll {
+        let Some(server) = context.server() else {
+            return JobPoll::Finished;
+        };
+        server.reload_resources();
+
+        CommandContext::new(self.sender.clone(), server)
+            .send_success(&translations::COMMANDS_RELOAD_SUCCESS.msg().into(), true);
+        JobPoll::Finished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use steel_utils::translations;
+    use text_components::TextComponent;
+
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::commands::CommandHandlerBuilder;
+    use crate::command::context::CommandContext;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{run_command, test_server};
+
+    #[test]
+    fn registered_commands_still_run_after_a_reload() {
+        let server = test_server();
+        let ran = Arc::new(AtomicBool::new(false));
+        let flag = ran.clone();
+        server.command_dispatcher.read().register(
+            CommandHandlerBuilder::new(&["greet"], "Says hello.", "steel:command.greet").executes(
+                move |(), _context: &mut CommandContext| {
+                    flag.store(true, Ordering::Relaxed);
+                    Ok(())
+                },
+            ),
+        );
+        let sender = CommandSender::capture(LEVEL_GAMEMASTERS);
+
+        run_command(&server, sender.clone(), "reload");
+        assert!(
+            sender.captured().is_empty(),
+            "the reload waits for the job tick"
+        );
+        server.jobs.tick(Arc::downgrade(&server), 0, true);
+
+        let success: TextComponent = translations::COMMANDS_RELOAD_SUCCESS.msg().into();
+        let captured: Vec<_> = sender
+            .captured()
+            .iter()
+            .map(TextComponent::to_nbt_tag)
+            .collect();
+        assert_eq!(captured, [success.to_nbt_tag()]);
+
+        run_command(&server, sender.clone(), "greet");
+        assert!(ran.load(Ordering::Relaxed));
+        run_command(&server, sender.clone(), "list");
+        assert_eq!(sender.captured().len(), 2);
+    }
+}
@@ -0,0 +1,87 @@
+//! Handler for the "r" command, a `/msg` to whoever the sender last whispered with.
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use steel_utils::translations;
+use text_components::TextComponent;
+use uuid::Uuid;
+
+use crate::command::arguments::message::MessageArgument;
+use crate::command::commands::msg::send_private_message;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::command::sender::CommandSender;
+use crate::player::Player;
+
+/// Handler for the "r" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["r", "reply"],
+        "Replies to the last player you whispered with.",
+        "minecraft:command.reply",
+    )
+    .then(argument("message", MessageArgument).executes(
+        |((), message): ((), String), context: &mut CommandContext| reply(context, message),
+    ))
+}
+
+fn reply(context: &CommandContext, message: String) -> Result<(), CommandError> {
+    let CommandSender::Player(player) = &context.sender else {
+        return Err(failed(translations::PERMISSIONS_REQUIRES_PLAYER.msg()));
+    };
+    let Some(partner) = reply_target(
+        &context.server.whisper_partners.lock(),
+        player.gameprofile.id,
+    ) else {
+        return Err(failed(TextComponent::const_plain(
+            "You have no one to reply to",
+        )));
+    };
+    let Some(target) = context
+        .server
+        .get_players()
+        .into_iter()
+        .find(|online| online.gameprofile.id == partner)
+    else {
+        return Err(failed(translations::ARGUMENT_ENTITY_NOTFOUND_PLAYER.msg()));
+    };
+
+    let targets: [Arc<Player>; 1] = [target];
+    send_private_message(context, &targets, message);
+    Ok(())
+}
+
+/// The player `id` last whispered with, if any.
+fn reply_target(partners: &FxHashMap<Uuid, Uuid>, id: Uuid) -> Option<Uuid> {
+    partners.get(&id).copied()
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashMap;
+    use uuid::Uuid;
+
+    use super::reply_target;
+    use crate::command::commands::msg::record_whisper;
+
+    #[test]
+    fn replies_go_to_the_last_whisper_partner() {
+        let (a, b, c) = (Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3));
+        let mut partners = FxHashMap::default();
+        assert_eq!(reply_target(&partners, b), None);
+
+        record_whisper(&mut partners, a, b);
+        assert_eq!(reply_target(&partners, b), Some(a));
+        assert_eq!(reply_target(&partners, a), Some(b));
+
+        record_whisper(&mut partners, c, b);
+        assert_eq!(reply_target(&partners, b), Some(c));
+        assert_eq!(reply_target(&partners, a), Some(b));
+    }
+}
@@ -0,0 +1,108 @@
+//! Handlers for the "save-all", "save-off" and "save-on" commands.
+//! Mirrors `net.minecraft.server.commands.SaveAllCommand`, `SaveOffCommand` and `SaveOnCommand`.
+use steel_utils::translations;
+
+use crate::command::LEVEL_OWNERS;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, literal};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+
+/// Handler for the "save-all" command.
+#[must_use]
+pub fn save_all_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["save-all"],
+        "Saves the server to disk.",
+        "minecraft:command.save-all",
+    )
+    .requires_level(LEVEL_OWNERS)
+    .executes(|(), context: &mut CommandContext| save_all(context, false))
+    .then(literal("flush").executes(|(), context: &mut CommandContext| save_all(context, true)))
+}
+
+/// Handler for the "save-off" command.
+#[must_use]
+pub fn save_off_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["save-off"],
+        "Stops the worlds from being saved.",
+        "minecraft:command.save-off",
+    )
+    .requires_level(LEVEL_OWNERS)
+    .executes(|(), context: &mut CommandContext| {
+        set_no_save(context, true)?;
+        context.send_success(&translations::COMMANDS_SAVE_DISABLED.msg().into(), true);
+        Ok(())
+    })
+}
+
+/// Handler for the "save-on" command.
+#[must_use]
+pub fn save_on_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["save-on"],
+        "Lets the worlds be saved again.",
+        "minecraft:command.save-on",
+    )
+    .requires_level(LEVEL_OWNERS)
+    .executes(|(), context: &mut CommandContext| {
+        set_no_save(context, false)?;
+        context.send_success(&translations::COMMANDS_SAVE_ENABLED.msg().into(), true);
+        Ok(())
+    })
+}
+
+/// Saves in the background and reports back once the save is done.
+fn save_all(context: &mut CommandContext, flush: bool) -> Result<(), CommandError> {
+    context.send_success(&translations::COMMANDS_SAVE_SAVING.msg().into(), false);
+    let context = context.clone();
+    tokio::spawn(async move {
+        if context.server.save_everything(flush, true).await {
+            context.send_success(&translations::COMMANDS_SAVE_SUCCESS.msg().into(), true);
+        } else {
+            context.send_failure(&translations::COMMANDS_SAVE_FAILED.msg().into());
+        }
+    });
+    Ok(())
+}
+
+/// Turns saving off or back on for every world. Fails if no world changed.
+fn set_no_save(context: &CommandContext, no_save: bool) -> Result<(), CommandError> {
+    let mut changed = false;
+    for world in context.server.worlds.values() {
+        if world.no_save() != no_save {
+            world.set_no_save(no_save);
+            changed = true;
+        }
+    }
+    if changed {
+        return Ok(());
+    }
+    let already = if no_save {
+        &translations::COMMANDS_SAVE_ALREADY_OFF
+    } else {
+        &translations::COMMANDS_SAVE_ALREADY_ON
+    };
+    Err(CommandError::CommandFailed(Box::new(already.msg().into())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command::LEVEL_OWNERS;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{run_command, test_server};
+
+    #[test]
+    fn save_off_stops_every_world_from_saving() {
+        let server = test_server();
+        let sender = CommandSender::capture(LEVEL_OWNERS);
+
+        run_command(&server, sender.clone(), "save-off");
+        assert!(server.worlds.values().all(|world| world.no_save()));
+        run_command(&server, sender.clone(), "save-off");
+        assert_eq!(sender.captured().len(), 2, "the second save-off fails");
+
+        run_command(&server, sender.clone(), "save-on");
+        assert!(server.worlds.values().all(|world| !world.no_save()));
+    }
+}
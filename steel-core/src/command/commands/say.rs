@@ -0,0 +1,41 @@
+//! Handler for the "say" command.
+use crate::command::arguments::message::MessageArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use steel_utils::translations;
+use text_components::TextComponent;
+
+/// Handler for the "say" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["say"],
+        "Broadcasts a message to all players.",
+        "minecraft:command.say",
+    )
+    .then(argument("message", MessageArgument).executes(SayCommandExecutor))
+}
+
+struct SayCommandExecutor;
+
+impl CommandExecutor<((), String)> for SayCommandExecutor {
+    fn execute(
+        &self,
+        args: ((), String),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let message: TextComponent = translations::CHAT_TYPE_ANNOUNCEMENT
+            .message([
+                TextComponent::from(context.sender.to_string()),
+                TextComponent::from(args.1),
+            ])
+            .into();
+
+        log::info!("{:p}", message);
+        context.server.broadcast_system_message(&message);
+        Ok(())
+    }
+}
@@ -0,0 +1,715 @@
+//! Handler for the "scoreboard" command.
+//! Mirrors `net.minecraft.server.commands.ScoreboardCommand`.
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        integer::IntegerArgument,
+        objective::ObjectiveArgument,
+        objective_criteria::ObjectiveCriteriaArgument,
+        score_holder::ScoreHolderArgument,
+        scoreboard_slot::ScoreboardSlotArgument,
+        string::StringArgument,
+        text_component::{TextComponentArgument, format_list},
+    },
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::scoreboard::{DisplaySlot, Objective, ObjectiveCriteria, Scoreboard, ScoreboardPacket};
+
+type Holders = Vec<String>;
+type AddArgs = (((), String), ObjectiveCriteria);
+type HolderObjectiveArgs = (((), Holders), String);
+type ScoreArgs = (HolderObjectiveArgs, i32);
+
+/// Handler for the "scoreboard" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    // TODO: add objectives modify and players operation
+    CommandHandlerBuilder::new(
+        &["scoreboard"],
+        "Manages scoreboard objectives and scores.",
+        "minecraft:command.scoreboard",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        literal("objectives")
+            .then(
+                literal("list")
+                    .executes(|(), context: &mut CommandContext| list_objectives(context)),
+            )
+            .then(
+                literal("add").then(
+                    argument("objective", StringArgument::Word).then(
+                        argument("criteria", ObjectiveCriteriaArgument)
+                            .executes(
+                                |(((), name), criteria): AddArgs, context: &mut CommandContext| {
+                                    let display_name = TextComponent::plain(name.clone());
+                                    add_objective(context, name, criteria, display_name)
+                                },
+                            )
+                            .then(argument("displayName", TextComponentArgument).executes(
+                                |((((), name), criteria), display_name): (
+                                    AddArgs,
+                                    TextComponent,
+                                ),
+                                 context: &mut CommandContext| {
+                                    add_objective(context, name, criteria, display_name)
+                                },
+                            )),
+                    ),
+                ),
+            )
+            .then(literal("remove").then(
+                argument("objective", ObjectiveArgument::existing()).executes(
+                    |((), name): ((), String), context: &mut CommandContext| {
+                        remove_objective(context, &name)
+                    },
+                ),
+            ))
+            .then(
+                literal("setdisplay").then(
+                    argument("slot", ScoreboardSlotArgument)
+                        .executes(
+                            |((), slot): ((), DisplaySlot), context: &mut CommandContext| {
+                                clear_display_slot(context, slot)
+                            },
+                        )
+                        .then(
+                            argument("objective", ObjectiveArgument::existing()).executes(
+                                |(((), slot), name): (((), DisplaySlot), String),
+                                 context: &mut CommandContext| {
+                                    set_display_slot(context, slot, &name)
+                                },
+                            ),
+                        ),
+                ),
+            ),
+    )
+    .then(
+        literal("players")
+            .then(
+                literal("list")
+                    .executes(|(), context: &mut CommandContext| list_holders(context))
+                    .then(argument("target", ScoreHolderArgument::one()).executes(
+                        |((), holders): ((), Holders), context: &mut CommandContext| {
+                            list_holder_scores(context, &single(&holders)?)
+                        },
+                    )),
+            )
+            .then(
+                literal("set").then(argument("targets", ScoreHolderArgument::multiple()).then(
+                    argument("objective", ObjectiveArgument::existing()).then(
+                        argument("score", IntegerArgument::new()).executes(
+                            |((((), holders), name), score): ScoreArgs,
+                             context: &mut CommandContext| {
+                                set_scores(context, &holders, &name, score)
+                            },
+                        ),
+                    ),
+                )),
+            )
+            .then(
+                literal("get").then(argument("target", ScoreHolderArgument::one()).then(
+                    argument("objective", ObjectiveArgument::existing()).executes(
+                        |(((), holders), name): HolderObjectiveArgs,
+                         context: &mut CommandContext| {
+                            get_score(context, &single(&holders)?, &name)
+                        },
+                    ),
+                )),
+            )
+            .then(
+                literal("add").then(argument("targets", ScoreHolderArgument::multiple()).then(
+                    argument("objective", ObjectiveArgument::existing()).then(
+                        argument("score", IntegerArgument::bounded(Some(0), None)).executes(
+                            |((((), holders), name), amount): ScoreArgs,
+                             context: &mut CommandContext| {
+                                add_scores(context, &holders, &name, amount, true)
+                            },
+                        ),
+                    ),
+                )),
+            )
+            .then(literal("remove").then(
+                argument("targets", ScoreHolderArgument::multiple()).then(
+                    argument("objective", ObjectiveArgument::existing()).then(
+                        argument("score", IntegerArgument::bounded(Some(0), None)).executes(
+                            |((((), holders), name), amount): ScoreArgs,
+                             context: &mut CommandContext| {
+                                add_scores(context, &holders, &name, amount, false)
+                            },
+                        ),
+                    ),
+                ),
+            ))
+            .then(literal("enable").then(
+                argument("targets", ScoreHolderArgument::multiple()).then(
+                    argument("objective", ObjectiveArgument::existing()).executes(
+                        |(((), holders), name): HolderObjectiveArgs,
+                         context: &mut CommandContext| {
+                            enable_triggers(context, &holders, &name)
+                        },
+                    ),
+                ),
+            ))
+            .then(
+                literal("reset").then(
+                    argument("targets", ScoreHolderArgument::multiple())
+                        .executes(
+                            |((), holders): ((), Holders), context: &mut CommandContext| {
+                                reset_all_scores(context, &holders)
+                            },
+                        )
+                        .then(
+                            argument("objective", ObjectiveArgument::existing()).executes(
+                                |(((), holders), name): HolderObjectiveArgs,
+                                 context: &mut CommandContext| {
+                                    reset_score(context, &holders, &name)
+                                },
+                            ),
+                        ),
+                ),
+            ),
+    )
+}
+
+/// The objective named `name`, vanilla's `ObjectiveArgument.getObjective`.
+fn objective(scoreboard: &Scoreboard, name: &str) -> Result<Objective, CommandError> {
+    scoreboard.objective(name).cloned().ok_or_else(|| {
+        failed(translations::ARGUMENTS_OBJECTIVE_NOT_FOUND.message([TextComponent::from(name)]))
+    })
+}
+
+/// The objective named `name` if commands may change its scores.
+fn writable_objective(scoreboard: &Scoreboard, name: &str) -> Result<Objective, CommandError> {
+    let objective = objective(scoreboard, name)?;
+    if objective.criteria.is_read_only() {
+        return Err(failed(
+            translations::ARGUMENTS_OBJECTIVE_READONLY.message([TextComponent::from(name)]),
+        ));
+    }
+    Ok(objective)
+}
+
+/// Creates the objective, failing if its name is taken.
+fn create_objective(
+    scoreboard: &mut Scoreboard,
+    name: String,
+    criteria: ObjectiveCriteria,
+    display_name: TextComponent,
+) -> Result<Objective, CommandError> {
+    if !scoreboard.add_objective(name.clone(), criteria, display_name) {
+        return Err(failed(
+            translations::COMMANDS_SCOREBOARD_OBJECTIVES_ADD_DUPLICATE.msg(),
+        ));
+    }
+    objective(scoreboard, &name)
+}
+
+/// The new scores of a score change and the packets announcing them.
+struct ScoreChange {
+    objective: Objective,
+    scores: Vec<i32>,
+    packets: Vec<ScoreboardPacket>,
+}
+
+/// Replaces each holder's score in `name` with `change` of its current score, 0 when unset.
+fn change_scores(
+    scoreboard: &mut Scoreboard,
+    holders: &[String],
+    name: &str,
+    change: impl Fn(i32) -> i32,
+) -> Result<ScoreChange, CommandError> {
+    let objective = writable_objective(scoreboard, name)?;
+    let mut packets = Vec::new();
+    let scores = holders
+        .iter()
+        .map(|holder| {
+            let score = change(scoreboard.score(holder, name).unwrap_or(0));
+            packets.extend(scoreboard.set_score(holder, name, score));
+            score
+        })
+        .collect();
+    Ok(ScoreChange {
+        objective,
+        scores,
+        packets,
+    })
+}
+
+fn add_objective(
+    context: &mut CommandContext,
+    name: String,
+    criteria: ObjectiveCriteria,
+    display_name: TextComponent,
+) -> Result<(), CommandError> {
+    let objective = create_objective(
+        &mut context.server.scoreboard.lock(),
+        name,
+        criteria,
+        display_name,
+    )?;
+    context.send_success(
+        &translations::COMMANDS_SCOREBOARD_OBJECTIVES_ADD_SUCCESS
+            .message([objective.formatted_display_name()])
+            .into(),
+        true,
+    );
+    Ok(())
+}
+
+fn remove_objective(context: &mut CommandContext, name: &str) -> Result<(), CommandError> {
+    let (objective, packets) = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        let objective = objective(&scoreboard, name)?;
+        (objective, scoreboard.remove_objective(name))
+    };
+    context.server.broadcast_scoreboard(&packets);
+    context.send_success(
+        &translations::COMMANDS_SCOREBOARD_OBJECTIVES_REMOVE_SUCCESS
+            .message([objective.formatted_display_name()])
+            .into(),
+        true,
+    );
+    Ok(())
+}
+
+fn list_objectives(context: &mut CommandContext) -> Result<(), CommandError> {
+    let names: Vec<_> = context
+        .server
+        .scoreboard
+        .lock()
+        .objectives()
+        .into_iter()
+        .map(Objective::formatted_display_name)
+        .collect();
+    if names.is_empty() {
+        context.send_success(
+            &translations::COMMANDS_SCOREBOARD_OBJECTIVES_LIST_EMPTY
+                .msg()
+                .into(),
+            false,
+        );
+    } else {
+        context.send_success(
+            &translations::COMMANDS_SCOREBOARD_OBJECTIVES_LIST_SUCCESS
+                .message([
+                    TextComponent::from(names.len().to_string()),
+                    format_list(names.clone(), &TextComponent::plain(", ")),
+                ])
+                .into(),
+            false,
+        );
+    }
+    context.result = i32::try_from(names.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn clear_display_slot(context: &mut CommandContext, slot: DisplaySlot) -> Result<(), CommandError> {
+    let packets = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        if scoreboard.display_objective(slot).is_none() {
+            return Err(failed(
+                translations::COMMANDS_SCOREBOARD_OBJECTIVES_DISPLAY_ALREADY_EMPTY.msg(),
+            ));
+        }
+        scoreboard.set_display_objective(slot, None)
+    };
+    context.server.broadcast_scoreboard(&packets);
+    context.send_success(
+        &translations::COMMANDS_SCOREBOARD_OBJECTIVES_DISPLAY_CLEARED
+            .message([TextComponent::from(slot.name())])
+            .into(),
+        true,
+    );
+    Ok(())
+}
+
+fn set_display_slot(
+    context: &mut CommandContext,
+    slot: DisplaySlot,
+    name: &str,
+) -> Result<(), CommandError> {
+    let (objective, packets) = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        let objective = objective(&scoreboard, name)?;
+        if scoreboard
+            .display_objective(slot)
+            .is_some_and(|shown| shown.name == name)
+        {
+            return Err(failed(
+                translations::COMMANDS_SCOREBOARD_OBJECTIVES_DISPLAY_ALREADY_SET.msg(),
+            ));
+        }
+        (
+            objective,
+            scoreboard.set_display_objective(slot, Some(name)),
+        )
+    };
+    context.server.broadcast_scoreboard(&packets);
+    context.send_success(
+        &translations::COMMANDS_SCOREBOARD_OBJECTIVES_DISPLAY_SET
+            .message([
+                TextComponent::from(slot.name()),
+                objective.formatted_display_name(),
+            ])
+            .into(),
+        true,
+    );
+    Ok(())
+}
+
+fn list_holders(context: &mut CommandContext) -> Result<(), CommandError> {
+    let holders: Vec<_> = context
+        .server
+        .scoreboard
+        .lock()
+        .holders()
+        .into_iter()
+        .map(TextComponent::from)
+        .collect();
+    if holders.is_empty() {
+        context.send_success(
+            &translations::COMMANDS_SCOREBOARD_PLAYERS_LIST_EMPTY
+                .msg()
+                .into(),
+            false,
+        );
+    } else {
+        context.send_success(
+            &translations::COMMANDS_SCOREBOARD_PLAYERS_LIST_SUCCESS
+                .message([
+                    TextComponent::from(holders.len().to_string()),
+                    format_list(holders.clone(), &TextComponent::plain(", ")),
+                ])
+                .into(),
+            false,
+        );
+    }
+    context.result = i32::try_from(holders.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn list_holder_scores(context: &mut CommandContext, holder: &str) -> Result<(), CommandError> {
+    let scores: Vec<_> = context
+        .server
+        .scoreboard
+        .lock()
+        .holder_scores(holder)
+        .into_iter()
+        .map(|(objective, score)| (objective.formatted_display_name(), score))
+        .collect();
+    if scores.is_empty() {
+        context.send_success(
+            &translations::COMMANDS_SCOREBOARD_PLAYERS_LIST_ENTITY_EMPTY
+                .message([TextComponent::from(holder)])
+                .into(),
+            false,
+        );
+    } else {
+        context.send_success(
+            &translations::COMMANDS_SCOREBOARD_PLAYERS_LIST_ENTITY_SUCCESS
+                .message([
+                    TextComponent::from(holder),
+                    TextComponent::from(scores.len().to_string()),
+                ])
+                .into(),
+            false,
+        );
+        for (objective, score) in &scores {
+            context.send_success(
+                &translations::COMMANDS_SCOREBOARD_PLAYERS_LIST_ENTITY_ENTRY
+                    .message([objective.clone(), TextComponent::from(score.to_string())])
+                    .into(),
+                false,
+            );
+        }
+    }
+    context.result = i32::try_from(scores.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn set_scores(
+    context: &mut CommandContext,
+    holders: &[String],
+    name: &str,
+    score: i32,
+) -> Result<(), CommandError> {
+    let change = change_scores(&mut context.server.scoreboard.lock(), holders, name, |_| {
+        score
+    })?;
+    context.server.broadcast_scoreboard(&change.packets);
+
+    let objective = change.objective.formatted_display_name();
+    let message = if let [holder] = holders {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_SET_SUCCESS_SINGLE.message([
+            objective,
+            TextComponent::from(holder.as_str()),
+            TextComponent::from(score.to_string()),
+        ])
+    } else {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_SET_SUCCESS_MULTIPLE.message([
+            objective,
+            TextComponent::from(holders.len().to_string()),
+            TextComponent::from(score.to_string()),
+        ])
+    };
+    context.send_success(&message.into(), true);
+    context.result = score.wrapping_mul(i32::try_from(holders.len()).unwrap_or(i32::MAX));
+    Ok(())
+}
+
+fn add_scores(
+    context: &mut CommandContext,
+    holders: &[String],
+    name: &str,
+    amount: i32,
+    add: bool,
+) -> Result<(), CommandError> {
+    let delta = if add { amount } else { amount.wrapping_neg() };
+    let change = change_scores(
+        &mut context.server.scoreboard.lock(),
+        holders,
+        name,
+        |score| score.wrapping_add(delta),
+    )?;
+    context.server.broadcast_scoreboard(&change.packets);
+
+    let objective = change.objective.formatted_display_name();
+    let amount = TextComponent::from(amount.to_string());
+    let message = match (holders, change.scores.as_slice()) {
+        ([holder], [total]) => {
+            let translation = if add {
+                &translations::COMMANDS_SCOREBOARD_PLAYERS_ADD_SUCCESS_SINGLE
+            } else {
+                &translations::COMMANDS_SCOREBOARD_PLAYERS_REMOVE_SUCCESS_SINGLE
+            };
+            translation.message([
+                amount,
+                objective,
+                TextComponent::from(holder.as_str()),
+                TextComponent::from(total.to_string()),
+            ])
+        }
+        _ => {
+            let translation = if add {
+                &translations::COMMANDS_SCOREBOARD_PLAYERS_ADD_SUCCESS_MULTIPLE
+            } else {
+                &translations::COMMANDS_SCOREBOARD_PLAYERS_REMOVE_SUCCESS_MULTIPLE
+            };
+            translation.message([
+                amount,
+                objective,
+                TextComponent::from(holders.len().to_string()),
+            ])
+        }
+    };
+    context.send_success(&message.into(), true);
+    context.result = change
+        .scores
+        .iter()
+        .fold(0, |total: i32, score| total.wrapping_add(*score));
+    Ok(())
+}
+
+fn get_score(context: &mut CommandContext, holder: &str, name: &str) -> Result<(), CommandError> {
+    let (objective, score) = {
+        let scoreboard = context.server.scoreboard.lock();
+        let objective = objective(&scoreboard, name)?;
+        (objective, scoreboard.score(holder, name))
+    };
+    let Some(score) = score else {
+        return Err(failed(
+            translations::COMMANDS_SCOREBOARD_PLAYERS_GET_NULL
+                .message([TextComponent::from(name), TextComponent::from(holder)]),
+        ));
+    };
+    context.send_success(
+        &translations::COMMANDS_SCOREBOARD_PLAYERS_GET_SUCCESS
+            .message([
+                TextComponent::from(holder),
+                TextComponent::from(score.to_string()),
+                objective.formatted_display_name(),
+            ])
+            .into(),
+        false,
+    );
+    context.result = score;
+    Ok(())
+}
+
+fn enable_triggers(
+    context: &mut CommandContext,
+    holders: &[String],
+    name: &str,
+) -> Result<(), CommandError> {
+    let (objective, enabled, packets) = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        let objective = objective(&scoreboard, name)?;
+        if objective.criteria != ObjectiveCriteria::Trigger {
+            return Err(failed(
+                translations::COMMANDS_SCOREBOARD_PLAYERS_ENABLE_INVALID.msg(),
+            ));
+        }
+        let mut enabled = 0;
+        let mut packets = Vec::new();
+        for holder in holders {
+            let (was_disabled, holder_packets) = scoreboard.enable_trigger(holder, name);
+            enabled += i32::from(was_disabled);
+            packets.extend(holder_packets);
+        }
+        (objective, enabled, packets)
+    };
+    context.server.broadcast_scoreboard(&packets);
+    if enabled == 0 {
+        return Err(failed(
+            translations::COMMANDS_SCOREBOARD_PLAYERS_ENABLE_FAILED.msg(),
+        ));
+    }
+
+    let objective = objective.formatted_display_name();
+    let message = if let [holder] = holders {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_ENABLE_SUCCESS_SINGLE
+            .message([objective, TextComponent::from(holder.as_str())])
+    } else {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_ENABLE_SUCCESS_MULTIPLE
+            .message([objective, TextComponent::from(holders.len().to_string())])
+    };
+    context.send_success(&message.into(), true);
+    context.result = enabled;
+    Ok(())
+}
+
+fn reset_all_scores(context: &mut CommandContext, holders: &[String]) -> Result<(), CommandError> {
+    let packets: Vec<_> = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        holders
+            .iter()
+            .flat_map(|holder| scoreboard.reset_scores(holder, None).1)
+            .collect()
+    };
+    context.server.broadcast_scoreboard(&packets);
+
+    let message = if let [holder] = holders {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_RESET_ALL_SINGLE
+            .message([TextComponent::from(holder.as_str())])
+    } else {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_RESET_ALL_MULTIPLE
+            .message([TextComponent::from(holders.len().to_string())])
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(holders.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn reset_score(
+    context: &mut CommandContext,
+    holders: &[String],
+    name: &str,
+) -> Result<(), CommandError> {
+    let (objective, packets) = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        let objective = objective(&scoreboard, name)?;
+        let packets: Vec<_> = holders
+            .iter()
+            .flat_map(|holder| scoreboard.reset_scores(holder, Some(name)).1)
+            .collect();
+        (objective, packets)
+    };
+    context.server.broadcast_scoreboard(&packets);
+
+    let objective = objective.formatted_display_name();
+    let message = if let [holder] = holders {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_RESET_SPECIFIC_SINGLE
+            .message([objective, TextComponent::from(holder.as_str())])
+    } else {
+        translations::COMMANDS_SCOREBOARD_PLAYERS_RESET_SPECIFIC_MULTIPLE
+            .message([objective, TextComponent::from(holders.len().to_string())])
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(holders.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn single(holders: &[String]) -> Result<String, CommandError> {
+    match holders {
+        [holder] => Ok(holder.clone()),
+        [] => Err(failed(translations::ARGUMENT_SCORE_HOLDER_EMPTY.msg())),
+        _ => Err(failed(translations::ARGUMENT_ENTITY_TOOMANY.msg())),
+    }
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use text_components::TextComponent;
+
+    use super::{change_scores, create_objective};
+    use crate::scoreboard::{ObjectiveCriteria, Scoreboard};
+
+    #[test]
+    fn objectives_are_created_once() {
+        let mut scoreboard = Scoreboard::default();
+
+        let objective = create_objective(
+            &mut scoreboard,
+            "kills".to_owned(),
+            ObjectiveCriteria::Dummy,
+            TextComponent::plain("Kills"),
+        )
+        .expect("the name is free");
+        assert_eq!(objective.name, "kills");
+        assert!(scoreboard.objective("kills").is_some());
+
+        assert!(
+            create_objective(
+                &mut scoreboard,
+                "kills".to_owned(),
+                ObjectiveCriteria::Dummy,
+                TextComponent::plain("Kills"),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn setting_and_adding_scores_updates_each_holder() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.add_objective(
+            "kills".to_owned(),
+            ObjectiveCriteria::Dummy,
+            TextComponent::plain("Kills"),
+        );
+        let holders = ["Steve".to_owned(), "Alex".to_owned()];
+
+        let change =
+            change_scores(&mut scoreboard, &holders, "kills", |_| 5).expect("kills is writable");
+        assert_eq!(change.scores, [5, 5]);
+
+        let change = change_scores(&mut scoreboard, &holders[..1], "kills", |score| score + 2)
+            .expect("kills is writable");
+        assert_eq!(change.scores, [7]);
+        assert_eq!(scoreboard.score("Steve", "kills"), Some(7));
+        assert_eq!(scoreboard.score("Alex", "kills"), Some(5));
+    }
+
+    #[test]
+    fn read_only_objectives_reject_changes() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.add_objective(
+            "hp".to_owned(),
+            ObjectiveCriteria::Health,
+            TextComponent::plain("Health"),
+        );
+
+        assert!(change_scores(&mut scoreboard, &["Steve".to_owned()], "hp", |_| 1).is_err());
+        assert!(change_scores(&mut scoreboard, &["Steve".to_owned()], "missing", |_| 1).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 //! Handler for the "seed" command.
+use crate::command::LEVEL_GAMEMASTERS;
 use crate::command::commands::{CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn};
 use crate::command::context::CommandContext;
 use crate::command::error::CommandError;
@@ -15,6 +16,8 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Displays the world seed.",
         "minecraft:command.seed",
     )
+    // Vanilla only lifts this outside dedicated servers, which Steel always is.
+    .requires_level(LEVEL_GAMEMASTERS)
     .executes(SeedCommandExecutor)
 }
 
@@ -22,15 +25,40 @@ struct SeedCommandExecutor;
 
 impl CommandExecutor<()> for SeedCommandExecutor {
     fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
-        let seed = context.world.seed().to_string();
-        context.sender.send_message(
-            &translations::COMMANDS_SEED_SUCCESS
-                .message([TextComponent::from(seed.clone())
-                    .color(Color::Green)
-                    .hover_event(HoverEvent::show_text(&translations::CHAT_COPY_CLICK))
-                    .click_event(ClickEvent::CopyToClipboard { value: seed.into() })])
-                .component(),
-        );
+        let seed = context.world.seed();
+        context.send_success(&seed_message(seed), false);
+        // Vanilla returns the seed truncated to an int.
+        context.result = seed as i32;
         Ok(())
     }
 }
+
+/// Builds "Seed: [<seed>]", with the seed copied on click like vanilla's `copyOnClickText`.
+fn seed_message(seed: i64) -> TextComponent {
+    let seed = seed.to_string();
+    translations::COMMANDS_SEED_SUCCESS
+        .message([translations::CHAT_SQUARE_BRACKETS
+            .message([TextComponent::from(seed.clone())
+                .color(Color::Green)
+                .hover_event(HoverEvent::show_text(&translations::CHAT_COPY_CLICK))
+                .click_event(ClickEvent::CopyToClipboard {
+                    value: seed.clone().into(),
+                })
+                .insertion(seed)])
+            .component()])
+        .component()
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::text::{DisplayResolutor, to_plain_string};
+
+    use super::seed_message;
+
+    #[test]
+    fn message_contains_the_seed() {
+        let message = to_plain_string(&seed_message(-4_172_144_997_902_289_642), &DisplayResolutor);
+
+        assert!(message.contains("-4172144997902289642"));
+    }
+}
@@ -0,0 +1,203 @@
+//! Handler for the "setblock" command.
+//! Mirrors `net.minecraft.server.commands.SetBlockCommand`.
+use std::io::Cursor;
+use std::sync::Arc;
+
+use simdnbt::borrow::read_compound as read_borrowed_compound;
+use simdnbt::owned::NbtCompound;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, BlockStateId, ChunkPos, translations};
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        block_pos::BlockPosArgument,
+        block_state::{BlockInput, BlockStateArgument},
+    },
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::world::World;
+
+type SetBlockArgs = (((), BlockPos), BlockInput);
+
+/// Handler for the "setblock" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["setblock"],
+        "Changes a block at a position.",
+        "minecraft:command.setblock",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        argument("pos", BlockPosArgument).then(
+            argument("block", BlockStateArgument)
+                .executes(
+                    |(((), pos), block): SetBlockArgs, context: &mut CommandContext| {
+                        set_block(context, pos, block, Mode::Replace)
+                    },
+                )
+                .then(literal("destroy").executes(
+                    |(((), pos), block): SetBlockArgs, context: &mut CommandContext| {
+                        set_block(context, pos, block, Mode::Destroy)
+                    },
+                ))
+                .then(literal("keep").executes(
+                    |(((), pos), block): SetBlockArgs, context: &mut CommandContext| {
+                        set_block(context, pos, block, Mode::Keep)
+                    },
+                ))
+                .then(literal("replace").executes(
+                    |(((), pos), block): SetBlockArgs, context: &mut CommandContext| {
+                        set_block(context, pos, block, Mode::Replace)
+                    },
+                ))
+                .then(literal("strict").executes(
+                    |(((), pos), block): SetBlockArgs, context: &mut CommandContext| {
+                        set_block(context, pos, block, Mode::Strict)
+                    },
+                )),
+        ),
+    )
+}
+
+/// How the existing block is handled.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// Replaces the block, updating its neighbors.
+    Replace,
+    /// Breaks the existing block first, dropping its items.
+    Destroy,
+    /// Only places into an empty block.
+    Keep,
+    /// Replaces the block without neighbor or shape updates.
+    Strict,
+}
+
+impl Mode {
+    /// Vanilla's `keep` filter, which only passes empty blocks.
+    fn allows(self, current: BlockStateId) -> bool {
+        !matches!(self, Self::Keep) || current.is_air()
+    }
+}
+
+fn set_block(
+    context: &mut CommandContext,
+    pos: BlockPos,
+    block: BlockInput,
+    mode: Mode,
+) -> Result<(), CommandError> {
+    loaded_pos(&context.world, pos)?;
+
+    let world = context.world.clone();
+    if !mode.allows(world.get_block_state(pos)) {
+        return Err(failed());
+    }
+    // Destroying the old block may already leave the requested air behind.
+    let place = if matches!(mode, Mode::Destroy) {
+        world.destroy_block(pos, true);
+        !block.state.is_air() || !world.get_block_state(pos).is_air()
+    } else {
+        true
+    };
+    let strict = matches!(mode, Mode::Strict);
+    if place && !place_block(&world, pos, &block, strict) {
+        return Err(failed());
+    }
+    if !strict {
+        world.update_neighbors_at(pos, block.state.get_block());
+    }
+
+    let [x, y, z] = [pos.x(), pos.y(), pos.z()].map(|c| TextComponent::from(c.to_string()));
+    context.send_success(
+        &translations::COMMANDS_SETBLOCK_SUCCESS
+            .message([x, y, z])
+            .into(),
+        true,
+    );
+    context.result = 1;
+    Ok(())
+}
+
+/// Vanilla `BlockInput.place`. Neighbor updates are left to the caller.
+///
+/// Returns false when the block was already there.
+pub(crate) fn place_block(
+    world: &Arc<World>,
+    pos: BlockPos,
+    block: &BlockInput,
+    strict: bool,
+) -> bool {
+    let flags = if strict {
+        UpdateFlags::UPDATE_CLIENTS
+            | UpdateFlags::UPDATE_KNOWN_SHAPE
+            | UpdateFlags::UPDATE_SUPPRESS_DROPS
+            | UpdateFlags::UPDATE_SKIP_BLOCK_ENTITY_SIDEEFFECTS
+            | UpdateFlags::UPDATE_SKIP_ON_PLACE
+    } else {
+        UpdateFlags::UPDATE_CLIENTS | UpdateFlags::UPDATE_SKIP_BLOCK_ENTITY_SIDEEFFECTS
+    };
+    if !world.set_block(pos, block.state, flags) {
+        return false;
+    }
+    if let Some(nbt) = &block.nbt {
+        load_block_entity(world, pos, nbt);
+    }
+    true
+}
+
+/// Loads `nbt` into the block entity at `pos`.
+pub(crate) fn load_block_entity(world: &World, pos: BlockPos, nbt: &NbtCompound) {
+    let Some(block_entity) = world.get_block_entity(pos) else {
+        return;
+    };
+    let mut bytes = Vec::new();
+    nbt.write(&mut bytes);
+    if let Ok(borrowed) = read_borrowed_compound(&mut Cursor::new(&bytes)) {
+        let mut block_entity = block_entity.lock();
+        block_entity.load_additional(&borrowed);
+        block_entity.set_changed();
+    }
+}
+
+/// Vanilla `BlockPosArgument.getLoadedBlockPos`.
+pub(crate) fn loaded_pos(world: &World, pos: BlockPos) -> Result<(), CommandError> {
+    let error = if !world.is_in_valid_bounds(pos) {
+        translations::ARGUMENT_POS_OUTOFWORLD.msg()
+    } else if !world.has_full_chunk(ChunkPos::from_block_pos(pos)) {
+        translations::ARGUMENT_POS_UNLOADED.msg()
+    } else {
+        return Ok(());
+    };
+    Err(CommandError::CommandFailed(Box::new(error.into())))
+}
+
+fn failed() -> CommandError {
+    CommandError::CommandFailed(Box::new(
+        translations::COMMANDS_SETBLOCK_FAILED.msg().into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{REGISTRY, vanilla_blocks};
+
+    use super::Mode;
+
+    #[test]
+    fn keep_skips_a_non_air_block() {
+        init_test_registry();
+        let stone = REGISTRY.blocks.get_default_state_id(&vanilla_blocks::STONE);
+        let air = REGISTRY.blocks.get_default_state_id(&vanilla_blocks::AIR);
+
+        assert!(!Mode::Keep.allows(stone));
+        assert!(Mode::Keep.allows(air));
+        assert!(Mode::Replace.allows(stone));
+        assert!(Mode::Destroy.allows(stone));
+    }
+}
@@ -0,0 +1,73 @@
+//! Handler for the "setidletimeout" command.
+//! Mirrors `net.minecraft.server.commands.SetPlayerIdleTimeoutCommand`.
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_ADMINS;
+use crate::command::arguments::integer::IntegerArgument;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument};
+use crate::command::context::CommandContext;
+
+/// The longest idle timeout the command accepts, one week in minutes.
+const MAX_IDLE_TIMEOUT: i32 = 10080;
+
+/// Handler for the "setidletimeout" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["setidletimeout"],
+        "Sets how many minutes players may stay idle before being kicked.",
+        "minecraft:command.setidletimeout",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(
+        argument(
+            "minutes",
+            IntegerArgument::bounded(Some(0), Some(MAX_IDLE_TIMEOUT)),
+        )
+        .executes(|((), minutes): ((), i32), context: &mut CommandContext| {
+            context
+                .server
+                .set_player_idle_timeout(minutes.unsigned_abs());
+            let message: TextComponent = if minutes > 0 {
+                translations::COMMANDS_SETIDLETIMEOUT_SUCCESS
+                    .message([TextComponent::from(minutes.to_string())])
+                    .into()
+            } else {
+                translations::COMMANDS_SETIDLETIMEOUT_SUCCESS_DISABLED
+                    .msg()
+                    .into()
+            };
+            context.send_success(&message, true);
+            context.result = minutes;
+            Ok(())
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command::LEVEL_ADMINS;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{run_command, test_server};
+
+    #[test]
+    fn updates_the_servers_idle_timeout() {
+        let server = test_server();
+        assert_eq!(server.player_idle_timeout(), 0);
+
+        run_command(
+            &server,
+            CommandSender::capture(LEVEL_ADMINS),
+            "setidletimeout 15",
+        );
+        assert_eq!(server.player_idle_timeout(), 15);
+
+        run_command(
+            &server,
+            CommandSender::capture(LEVEL_ADMINS),
+            "setidletimeout 0",
+        );
+        assert_eq!(server.player_idle_timeout(), 0);
+    }
+}
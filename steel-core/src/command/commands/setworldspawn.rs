@@ -1,11 +1,10 @@
 //! Handler for the `setworldspawn` command.
-
-use std::borrow::Cow;
+//! Mirrors `net.minecraft.server.commands.SetWorldSpawnCommand`.
 
 use text_components::TextComponent;
-use text_components::translation::TranslatedMessage;
 
 use crate::command::{
+    LEVEL_GAMEMASTERS,
     arguments::block_pos::BlockPosArgument,
     arguments::rotation::RotationArgument,
     commands::{CommandHandlerBuilder, CommandHandlerDyn, argument},
@@ -14,7 +13,7 @@ use crate::command::{
 };
 use crate::level_data::RespawnData;
 use crate::world::World;
-use steel_utils::BlockPos;
+use steel_utils::{BlockPos, translations};
 
 type PositionRotationArgs = (((), BlockPos), (f32, f32));
 
@@ -26,6 +25,7 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Sets the world spawn.",
         "minecraft:command.setworldspawn",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .executes(|(), context: &mut CommandContext| {
         set_spawn(context, BlockPos::from(context.position), (0.0, 0.0))
     })
@@ -46,10 +46,9 @@ fn set_spawn(
     rotation: (f32, f32),
 ) -> Result<(), CommandError> {
     if !World::is_in_spawnable_bounds(pos) {
-        return Err(CommandError::CommandFailed(Box::new(translated(
-            "argument.pos.outofbounds",
-            [],
-        ))));
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_POS_OUTOFBOUNDS.msg().into(),
+        )));
     }
 
     let respawn_data = RespawnData::of(context.world.key.clone(), pos, rotation.0, rotation.1);
@@ -58,30 +57,23 @@ fn set_spawn(
         .set_respawn_data(respawn_data.clone())
         .map_err(command_failed)?;
 
-    context.sender.send_message(&translated(
-        "commands.setworldspawn.success",
-        [
-            TextComponent::from(pos.x().to_string()),
-            TextComponent::from(pos.y().to_string()),
-            TextComponent::from(pos.z().to_string()),
-            TextComponent::from(respawn_data.yaw.to_string()),
-            TextComponent::from(respawn_data.pitch.to_string()),
-            TextComponent::from(context.world.key.to_string()),
-        ],
-    ));
-
+    context.send_success(
+        &translations::COMMANDS_SETWORLDSPAWN_SUCCESS
+            .message([
+                TextComponent::from(pos.x().to_string()),
+                TextComponent::from(pos.y().to_string()),
+                TextComponent::from(pos.z().to_string()),
+                TextComponent::from(respawn_data.yaw.to_string()),
+                TextComponent::from(respawn_data.pitch.to_string()),
+                TextComponent::from(context.world.key.to_string()),
+            ])
+            .into(),
+        true,
+    );
+    context.result = 1;
     Ok(())
 }
 
 fn command_failed(error: String) -> CommandError {
     CommandError::CommandFailed(Box::new(TextComponent::from(error)))
 }
-
-fn translated<const N: usize>(key: &'static str, args: [TextComponent; N]) -> TextComponent {
-    TranslatedMessage {
-        key: Cow::Borrowed(key),
-        fallback: None,
-        args: Some(Box::new(args)),
-    }
-    .component()
-}
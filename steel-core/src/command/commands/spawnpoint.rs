@@ -0,0 +1,119 @@
+//! Handler for the "spawnpoint" command.
+//! Mirrors `net.minecraft.server.commands.SetSpawnCommand`.
+use std::sync::Arc;
+
+use steel_utils::{BlockPos, translations};
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{block_pos::BlockPosArgument, player::PlayerArgument, rotation::RotationArgument},
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::level_data::RespawnData;
+use crate::player::{Player, RespawnConfig};
+use crate::world::World;
+
+type TargetsArgs = ((), Vec<Arc<Player>>);
+type PositionArgs = (TargetsArgs, BlockPos);
+type PositionRotationArgs = (PositionArgs, (f32, f32));
+
+/// Handler for the "spawnpoint" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["spawnpoint"],
+        "Sets the spawn point for a player.",
+        "minecraft:command.spawnpoint",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .executes(|(), context: &mut CommandContext| {
+        let Some(player) = context.sender.get_player().cloned() else {
+            return Err(CommandError::CommandFailed(Box::new(
+                translations::PERMISSIONS_REQUIRES_PLAYER.msg().into(),
+            )));
+        };
+        let pos = BlockPos::from(context.position);
+        set_spawn(context, &[player], pos, (0.0, 0.0))
+    })
+    .then(
+        argument("targets", PlayerArgument::multiple())
+            .executes(|((), targets): TargetsArgs, context: &mut CommandContext| {
+                let pos = BlockPos::from(context.position);
+                set_spawn(context, &targets, pos, (0.0, 0.0))
+            })
+            .then(
+                argument("pos", BlockPosArgument)
+                    .executes(
+                        |(((), targets), pos): PositionArgs, context: &mut CommandContext| {
+                            spawnable(pos)?;
+                            set_spawn(context, &targets, pos, (0.0, 0.0))
+                        },
+                    )
+                    .then(argument("rotation", RotationArgument).executes(
+                        |((((), targets), pos), rotation): PositionRotationArgs,
+                         context: &mut CommandContext| {
+                            spawnable(pos)?;
+                            set_spawn(context, &targets, pos, rotation)
+                        },
+                    )),
+            ),
+    )
+}
+
+/// Vanilla `BlockPosArgument.getSpawnablePos`.
+fn spawnable(pos: BlockPos) -> Result<(), CommandError> {
+    if World::is_in_spawnable_bounds(pos) {
+        Ok(())
+    } else {
+        Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_POS_OUTOFBOUNDS.msg().into(),
+        )))
+    }
+}
+
+fn set_spawn(
+    context: &mut CommandContext,
+    targets: &[Arc<Player>],
+    pos: BlockPos,
+    (yaw, pitch): (f32, f32),
+) -> Result<(), CommandError> {
+    let dimension = context.world.key.clone();
+    for target in targets {
+        target.set_respawn_position(Some(RespawnConfig {
+            respawn_data: RespawnData::of(dimension.clone(), pos, yaw, pitch),
+            forced: true,
+        }));
+    }
+
+    let [x, y, z] = [pos.x(), pos.y(), pos.z()].map(|c| TextComponent::from(c.to_string()));
+    let [yaw, pitch] = [yaw, pitch].map(|angle| TextComponent::from(angle.to_string()));
+    let dimension = TextComponent::from(dimension.to_string());
+    let message = if let [target] = targets {
+        translations::COMMANDS_SPAWNPOINT_SUCCESS_SINGLE.message([
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+            dimension,
+            // TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
+            TextComponent::from(target.gameprofile.name.clone()),
+        ])
+    } else {
+        translations::COMMANDS_SPAWNPOINT_SUCCESS_MULTIPLE.message([
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+            dimension,
+            TextComponent::from(targets.len().to_string()),
+        ])
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(targets.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
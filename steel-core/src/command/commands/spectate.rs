@@ -0,0 +1,150 @@
+//! Handler for the "spectate" command.
+//! Mirrors `net.minecraft.server.commands.SpectateCommand`.
+use std::sync::Arc;
+
+use steel_protocol::packets::game::CSetCamera;
+use steel_utils::translations;
+use steel_utils::types::GameType;
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{entity::EntityArgument, player::PlayerArgument},
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, summon::entity_display_name},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::entity::{Entity, LivingEntity};
+use crate::player::Player;
+
+type Targets = Vec<Arc<dyn LivingEntity + Send + Sync>>;
+
+/// Handler for the "spectate" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["spectate"],
+        "Makes a spectator view through another entity.",
+        "minecraft:command.spectate",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .executes(|(), context: &mut CommandContext| {
+        let player = source_player(context)?;
+        spectate(context, None, &player)
+    })
+    .then(
+        argument("target", EntityArgument::one())
+            .executes(
+                |((), target): ((), Targets), context: &mut CommandContext| {
+                    let player = source_player(context)?;
+                    spectate(context, Some(single(&target)?), &player)
+                },
+            )
+            .then(argument("player", PlayerArgument::one()).executes(
+                |(((), target), player): (((), Targets), Vec<Arc<Player>>),
+                 context: &mut CommandContext| {
+                    let Some(player) = player.first() else {
+                        return Err(CommandError::CommandFailed(Box::new(
+                            translations::ARGUMENT_ENTITY_NOTFOUND_PLAYER.msg().into(),
+                        )));
+                    };
+                    spectate(context, Some(single(&target)?), player)
+                },
+            )),
+    )
+}
+
+fn source_player(context: &CommandContext) -> Result<Arc<Player>, CommandError> {
+    context.sender.get_player().cloned().ok_or_else(|| {
+        CommandError::CommandFailed(Box::new(
+            translations::PERMISSIONS_REQUIRES_PLAYER.msg().into(),
+        ))
+    })
+}
+
+fn single(targets: &Targets) -> Result<&(dyn LivingEntity + Send + Sync), CommandError> {
+    targets.first().map(|target| &**target).ok_or_else(|| {
+        CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_ENTITY_NOTFOUND_ENTITY.msg().into(),
+        ))
+    })
+}
+
+/// The entity id the camera of `spectator` follows, the spectator itself when `target` is `None`.
+fn camera_id<E: Entity + ?Sized>(spectator: i32, target: Option<&E>) -> Result<i32, CommandError> {
+    match target {
+        None => Ok(spectator),
+        Some(target) if target.entity_type().client_tracking_range == 0 => {
+            Err(CommandError::CommandFailed(Box::new(
+                translations::COMMANDS_SPECTATE_CANNOT_SPECTATE.msg().into(),
+            )))
+        }
+        Some(target) => Ok(target.id()),
+    }
+}
+
+fn spectate(
+    context: &mut CommandContext,
+    target: Option<&(dyn LivingEntity + Send + Sync)>,
+    player: &Arc<Player>,
+) -> Result<(), CommandError> {
+    if target.is_some_and(|target| target.id() == player.id()) {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_SPECTATE_SELF.msg().into(),
+        )));
+    }
+    if player.game_mode() != GameType::Spectator {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_SPECTATE_NOT_SPECTATOR
+                .message([entity_display_name(player.as_ref())])
+                .into(),
+        )));
+    }
+
+    // TODO: store the camera entity so it follows the target and resets on leaving spectator
+    let camera_id = camera_id(player.id(), target)?;
+    player.send_packet(CSetCamera { camera_id });
+
+    let message: TextComponent = match target {
+        Some(target) => translations::COMMANDS_SPECTATE_SUCCESS_STARTED
+            .message([entity_display_name(target)])
+            .into(),
+        None => translations::COMMANDS_SPECTATE_SUCCESS_STOPPED.msg().into(),
+    };
+    context.send_success(&message, false);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use glam::DVec3;
+    use steel_registry::vanilla_entities;
+
+    use super::camera_id;
+    use crate::entity::{ENTITIES, Entity, init_test_entities, next_entity_id};
+    use crate::world::World;
+
+    #[test]
+    fn spectating_uses_the_target_as_camera() {
+        init_test_entities();
+        let pig = ENTITIES
+            .create(
+                &vanilla_entities::PIG,
+                next_entity_id(),
+                DVec3::ZERO,
+                Weak::<World>::new(),
+            )
+            .expect("pig should be creatable");
+
+        let camera = camera_id(7, Some(&*pig)).expect("pigs can be spectated");
+        assert_eq!(camera, pig.id());
+    }
+
+    #[test]
+    fn stopping_returns_the_camera_to_the_spectator() {
+        let camera = camera_id::<dyn Entity>(7, None).expect("stopping always works");
+        assert_eq!(camera, 7);
+    }
+}
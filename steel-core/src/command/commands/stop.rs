@@ -1,19 +1,38 @@
 //! Handler for the "stop" command.
+use crate::command::LEVEL_OWNERS;
 use crate::command::commands::{CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn};
 use crate::command::context::CommandContext;
 use crate::command::error::CommandError;
+use steel_utils::translations;
 
 /// Handler for the "stop" command.
 #[must_use]
 pub fn command_handler() -> impl CommandHandlerDyn {
     CommandHandlerBuilder::new(&["stop"], "Stops the server.", "minecraft:command.stop")
+        .requires_level(LEVEL_OWNERS)
         .executes(StopCommandExecutor)
 }
 
 struct StopCommandExecutor;
 impl CommandExecutor<()> for StopCommandExecutor {
     fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
-        context.server.cancel_token.cancel();
+        context.send_success(&translations::COMMANDS_STOP_STOPPING.msg().into(), true);
+        context.server.stop();
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{run_command, test_server};
+
+    #[test]
+    fn rcon_can_stop_the_server() {
+        let server = test_server();
+
+        run_command(&server, CommandSender::Rcon, "stop");
+
+        assert!(server.cancel_token.is_cancelled());
+    }
+}
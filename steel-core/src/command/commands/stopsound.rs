@@ -0,0 +1,164 @@
+//! Handler for the "stopsound" command.
+//! Mirrors `net.minecraft.server.commands.StopSoundCommand`.
+use std::sync::Arc;
+
+use steel_protocol::packets::game::{CStopSound, SoundSource};
+use steel_utils::{Identifier, translations};
+use text_components::TextComponent;
+use text_components::translation::TranslatedMessage;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        enumeration::EnumArgument, player::PlayerArgument,
+        resource_location::ResourceLocationArgument,
+    },
+    commands::{CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::player::Player;
+
+/// The keywords of vanilla `SoundSource.getName`, plus `*` for every source.
+const STOP_SOURCES: &[(&str, Option<SoundSource>)] = &[
+    ("*", None),
+    ("master", Some(SoundSource::Master)),
+    ("music", Some(SoundSource::Music)),
+    ("record", Some(SoundSource::Records)),
+    ("weather", Some(SoundSource::Weather)),
+    ("block", Some(SoundSource::Blocks)),
+    ("hostile", Some(SoundSource::Hostile)),
+    ("neutral", Some(SoundSource::Neutral)),
+    ("player", Some(SoundSource::Players)),
+    ("ambient", Some(SoundSource::Ambient)),
+    ("voice", Some(SoundSource::Voice)),
+    ("ui", Some(SoundSource::Ui)),
+];
+
+type TargetsArgs = ((), Vec<Arc<Player>>);
+type SourceArgs = (TargetsArgs, Option<SoundSource>);
+type SoundArgs = (SourceArgs, Identifier);
+
+/// Handler for the "stopsound" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["stopsound"],
+        "Stops sounds playing for players.",
+        "minecraft:command.stopsound",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        argument("targets", PlayerArgument::multiple())
+            .executes(StopSoundExecutor)
+            .then(
+                argument("source", EnumArgument::new(STOP_SOURCES))
+                    .executes(StopSoundExecutor)
+                    .then(argument("sound", ResourceLocationArgument).executes(StopSoundExecutor)),
+            ),
+    )
+}
+
+struct StopSoundExecutor;
+
+impl CommandExecutor<TargetsArgs> for StopSoundExecutor {
+    fn execute(
+        &self,
+        ((), targets): TargetsArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        stop_sound(context, &targets, None, None);
+        Ok(())
+    }
+}
+
+impl CommandExecutor<SourceArgs> for StopSoundExecutor {
+    fn execute(
+        &self,
+        (((), targets), source): SourceArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        stop_sound(context, &targets, source, None);
+        Ok(())
+    }
+}
+
+impl CommandExecutor<SoundArgs> for StopSoundExecutor {
+    fn execute(
+        &self,
+        ((((), targets), source), sound): SoundArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        stop_sound(context, &targets, source, Some(sound));
+        Ok(())
+    }
+}
+
+fn stop_sound(
+    context: &mut CommandContext,
+    targets: &[Arc<Player>],
+    source: Option<SoundSource>,
+    sound: Option<Identifier>,
+) {
+    let message = success_message(source, sound.as_ref());
+    let packet = CStopSound {
+        source,
+        name: sound,
+    };
+    for target in targets {
+        target.send_packet(packet.clone());
+    }
+
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(targets.len()).unwrap_or(i32::MAX);
+}
+
+fn success_message(source: Option<SoundSource>, sound: Option<&Identifier>) -> TranslatedMessage {
+    let source = source.map(|source| {
+        let name = STOP_SOURCES
+            .iter()
+            .find(|(_, value)| *value == Some(source))
+            .map_or("", |(name, _)| *name);
+        TextComponent::from(name)
+    });
+    let sound = sound.map(|sound| TextComponent::from(sound.to_string()));
+    match (source, sound) {
+        (Some(source), Some(sound)) => {
+            translations::COMMANDS_STOPSOUND_SUCCESS_SOURCE_SOUND.message([sound, source])
+        }
+        (Some(source), None) => {
+            translations::COMMANDS_STOPSOUND_SUCCESS_SOURCE_ANY.message([source])
+        }
+        (None, Some(sound)) => {
+            translations::COMMANDS_STOPSOUND_SUCCESS_SOURCELESS_SOUND.message([sound])
+        }
+        (None, None) => translations::COMMANDS_STOPSOUND_SUCCESS_SOURCELESS_ANY.msg(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_protocol::packets::game::SoundSource;
+    use steel_utils::Identifier;
+
+    use super::success_message;
+
+    #[test]
+    fn stopping_a_whole_source_differs_from_one_sound() {
+        let sound = Identifier::vanilla_static("block.note_block.harp");
+
+        let any = success_message(Some(SoundSource::Blocks), None);
+        let specific = success_message(Some(SoundSource::Blocks), Some(&sound));
+
+        assert_eq!(any.key, "commands.stopsound.success.source.any");
+        assert_eq!(specific.key, "commands.stopsound.success.source.sound");
+        assert_eq!(
+            success_message(None, Some(&sound)).key,
+            "commands.stopsound.success.sourceless.sound"
+        );
+        assert_eq!(
+            success_message(None, None).key,
+            "commands.stopsound.success.sourceless.any"
+        );
+    }
+}
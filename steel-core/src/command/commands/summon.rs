@@ -1,17 +1,23 @@
 //! Handler for the "summon" command.
+//! Mirrors `net.minecraft.server.commands.SummonCommand`.
 
 use std::borrow::Cow;
-use std::sync::Arc;
+use std::io::Cursor;
+use std::sync::{Arc, Weak};
 
 use glam::DVec3;
+use simdnbt::borrow::read_compound as read_borrowed_compound;
+use simdnbt::owned::NbtCompound;
 use steel_registry::entity_type::EntityTypeRef;
 use steel_utils::types::Difficulty;
 use steel_utils::{BlockPos, translations};
 use text_components::TextComponent;
 use text_components::translation::TranslatedMessage;
 
+use crate::command::LEVEL_GAMEMASTERS;
 use crate::command::arguments::entity_type::EntitySummonArgument;
-use crate::command::arguments::vector3::Vector3Argument;
+use crate::command::arguments::nbt_compound::NbtCompoundArgument;
+use crate::command::arguments::vector3::{Coordinates, Vector3Argument};
 use crate::command::commands::{
     CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
 };
@@ -30,10 +36,15 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Summons an entity.",
         "minecraft:command.summon",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .then(
         argument("entity", EntitySummonArgument)
             .executes(SummonAtSourceExecutor)
-            .then(argument("pos", Vector3Argument).executes(SummonAtPosExecutor)),
+            .then(
+                argument("pos", Vector3Argument)
+                    .executes(SummonAtPosExecutor)
+                    .then(argument("nbt", NbtCompoundArgument).executes(SummonWithNbtExecutor)),
+            ),
     )
 }
 
@@ -45,19 +56,36 @@ impl CommandExecutor<((), EntityTypeRef)> for SummonAtSourceExecutor {
         ((), entity_type): ((), EntityTypeRef),
         context: &mut CommandContext,
     ) -> Result<(), CommandError> {
-        summon_entity(context, entity_type, context.position)
+        summon_entity(context, entity_type, context.position, &NbtCompound::new())
     }
 }
 
 struct SummonAtPosExecutor;
 
-impl CommandExecutor<(((), EntityTypeRef), DVec3)> for SummonAtPosExecutor {
+impl CommandExecutor<(((), EntityTypeRef), Coordinates)> for SummonAtPosExecutor {
     fn execute(
         &self,
-        (((), entity_type), pos): (((), EntityTypeRef), DVec3),
+        (((), entity_type), pos): (((), EntityTypeRef), Coordinates),
         context: &mut CommandContext,
     ) -> Result<(), CommandError> {
-        summon_entity(context, entity_type, pos)
+        summon_entity(
+            context,
+            entity_type,
+            pos.position(context),
+            &NbtCompound::new(),
+        )
+    }
+}
+
+struct SummonWithNbtExecutor;
+
+impl CommandExecutor<((((), EntityTypeRef), Coordinates), NbtCompound)> for SummonWithNbtExecutor {
+    fn execute(
+        &self,
+        ((((), entity_type), pos), nbt): ((((), EntityTypeRef), Coordinates), NbtCompound),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        summon_entity(context, entity_type, pos.position(context), &nbt)
     }
 }
 
@@ -65,13 +93,16 @@ fn summon_entity(
     context: &mut CommandContext,
     entity_type: EntityTypeRef,
     pos: DVec3,
+    nbt: &NbtCompound,
 ) -> Result<(), CommandError> {
-    let entity = create_entity(context, entity_type, pos)?;
-    context.sender.send_message(
+    let entity = create_entity(context, entity_type, pos, nbt)?;
+    context.send_success(
         &translations::COMMANDS_SUMMON_SUCCESS
             .message([entity_display_name(entity.as_ref())])
             .into(),
+        true,
     );
+    context.result = 1;
     Ok(())
 }
 
@@ -79,6 +110,7 @@ fn create_entity(
     context: &CommandContext,
     entity_type: EntityTypeRef,
     pos: DVec3,
+    nbt: &NbtCompound,
 ) -> Result<SharedEntity, CommandError> {
     let block_pos = BlockPos::containing(pos.x, pos.y, pos.z);
     if !World::is_in_spawnable_bounds(block_pos) {
@@ -94,12 +126,13 @@ fn create_entity(
     }
 
     let world = Arc::clone(&context.world);
-    let Some(entity) = ENTITIES.create(entity_type, next_entity_id(), pos, Arc::downgrade(&world))
-    else {
+    let Some(entity) = new_entity(entity_type, pos, nbt, Arc::downgrade(&world)) else {
         return Err(command_failed(translations::COMMANDS_SUMMON_FAILED.msg()));
     };
 
-    if let Some(mob) = entity.as_mob() {
+    if nbt.is_empty()
+        && let Some(mob) = entity.as_mob()
+    {
         let _ = mob.finalize_spawn(&world, EntitySpawnReason::Command, None);
     }
 
@@ -112,11 +145,35 @@ fn create_entity(
     }
 }
 
+/// Creates the entity and applies the command's NBT on top of its defaults.
+// TODO: also apply base entity data (CustomName, Motion, Rotation, ...) and passengers
+fn new_entity(
+    entity_type: EntityTypeRef,
+    pos: DVec3,
+    nbt: &NbtCompound,
+    world: Weak<World>,
+) -> Option<SharedEntity> {
+    let entity = ENTITIES.create(entity_type, next_entity_id(), pos, world)?;
+    if !nbt.is_empty() {
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(&bytes)).ok()?;
+        entity.load_additional((&borrowed).into());
+        entity.sync_base_entity_data();
+    }
+    Some(entity)
+}
+
 fn command_failed(message: TranslatedMessage) -> CommandError {
     CommandError::CommandFailed(Box::new(message.into()))
 }
 
-fn entity_display_name(entity: &dyn Entity) -> TextComponent {
+/// A player's name, or an entity's custom name falling back to the name of its type.
+// TODO: use getDisplayName() (team formatting, hover event, UUID insertion)
+pub(crate) fn entity_display_name<E: Entity + ?Sized>(entity: &E) -> TextComponent {
+    if let Some(player) = entity.as_player() {
+        return TextComponent::plain(player.gameprofile.name.clone());
+    }
     entity
         .custom_name()
         .unwrap_or_else(|| entity_type_display_name(entity.entity_type()))
@@ -132,3 +189,43 @@ fn entity_type_display_name(entity_type: EntityTypeRef) -> TextComponent {
         args: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use glam::DVec3;
+    use simdnbt::owned::NbtCompound;
+    use steel_registry::vanilla_entities;
+
+    use super::new_entity;
+    use crate::command::arguments::Helper;
+    use crate::entity::{Entity, init_test_entities};
+    use crate::world::World;
+
+    #[test]
+    fn summons_at_relative_coordinates_with_nbt() {
+        init_test_entities();
+        let source = DVec3::new(10.5, 64.0, -3.5);
+        let pos = DVec3::new(
+            Helper::parse_relative_coordinate::<false>("~1", Some(source.x))
+                .expect("relative x should parse"),
+            Helper::parse_relative_coordinate::<true>("~", Some(source.y))
+                .expect("relative y should parse"),
+            Helper::parse_relative_coordinate::<false>("~-2", Some(source.z))
+                .expect("relative z should parse"),
+        );
+        let mut nbt = NbtCompound::new();
+        nbt.insert("PersistenceRequired", 1_i8);
+
+        let entity = new_entity(&vanilla_entities::PIG, pos, &nbt, Weak::<World>::new())
+            .expect("pig should be summonable");
+
+        assert_eq!(entity.position(), DVec3::new(11.5, 64.0, -5.5));
+        assert!(
+            entity
+                .as_mob()
+                .is_some_and(|mob| mob.is_persistence_required())
+        );
+    }
+}
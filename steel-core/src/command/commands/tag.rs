@@ -0,0 +1,226 @@
+//! Handler for the "tag" command.
+//! Mirrors `net.minecraft.server.commands.TagCommand`.
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_GAMEMASTERS;
+use crate::command::arguments::entity::EntityArgument;
+use crate::command::arguments::string::StringArgument;
+use crate::command::arguments::text_component::format_list;
+use crate::command::commands::summon::entity_display_name;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::entity::LivingEntity;
+
+type Targets = Vec<Arc<dyn LivingEntity + Send + Sync>>;
+type TagArgs = (((), Targets), String);
+
+/// Handler for the "tag" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["tag"],
+        "Controls entity scoreboard tags.",
+        "minecraft:command.tag",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        argument("targets", EntityArgument::multiple())
+            .then(
+                literal("add").then(argument("name", StringArgument::Word).executes(
+                    |(((), targets), tag): TagArgs, context: &mut CommandContext| {
+                        change_tag(context, &targets, &tag, true)
+                    },
+                )),
+            )
+            .then(
+                literal("remove").then(argument("name", StringArgument::Word).executes(
+                    |(((), targets), tag): TagArgs, context: &mut CommandContext| {
+                        change_tag(context, &targets, &tag, false)
+                    },
+                )),
+            )
+            .then(literal("list").executes(
+                |((), targets): ((), Targets), context: &mut CommandContext| {
+                    list_tags(context, &targets)
+                },
+            )),
+    )
+}
+
+/// Adds or removes `tag` on every target, returning how many changed.
+fn apply_tag(targets: &[Arc<dyn LivingEntity + Send + Sync>], tag: &str, add: bool) -> i32 {
+    let changed = targets
+        .iter()
+        .filter(|target| {
+            if add {
+                target.base().add_tag(tag.to_owned())
+            } else {
+                target.base().remove_tag(tag)
+            }
+        })
+        .count();
+    i32::try_from(changed).unwrap_or(i32::MAX)
+}
+
+/// The tags of every target, sorted and without duplicates.
+fn collect_tags(targets: &[Arc<dyn LivingEntity + Send + Sync>]) -> BTreeSet<String> {
+    targets
+        .iter()
+        .flat_map(|target| target.base().tags())
+        .collect()
+}
+
+fn change_tag(
+    context: &mut CommandContext,
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
+    tag: &str,
+    add: bool,
+) -> Result<(), CommandError> {
+    let changed = apply_tag(targets, tag, add);
+    if changed == 0 {
+        let failure = if add {
+            &translations::COMMANDS_TAG_ADD_FAILED
+        } else {
+            &translations::COMMANDS_TAG_REMOVE_FAILED
+        };
+        return Err(CommandError::CommandFailed(Box::new(failure.msg().into())));
+    }
+
+    let tag = TextComponent::from(tag);
+    let message = if let [target] = targets {
+        let translation = if add {
+            &translations::COMMANDS_TAG_ADD_SUCCESS_SINGLE
+        } else {
+            &translations::COMMANDS_TAG_REMOVE_SUCCESS_SINGLE
+        };
+        translation.message([tag, entity_display_name(target.as_ref())])
+    } else {
+        let translation = if add {
+            &translations::COMMANDS_TAG_ADD_SUCCESS_MULTIPLE
+        } else {
+            &translations::COMMANDS_TAG_REMOVE_SUCCESS_MULTIPLE
+        };
+        translation.message([tag, TextComponent::from(targets.len().to_string())])
+    };
+    context.send_success(&message.into(), true);
+    context.result = changed;
+    Ok(())
+}
+
+fn list_tags(
+    context: &mut CommandContext,
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
+) -> Result<(), CommandError> {
+    let tags = collect_tags(targets);
+    let count = TextComponent::from(tags.len().to_string());
+    let list = format_list(
+        tags.iter()
+            .map(|tag| TextComponent::from(tag.as_str()))
+            .collect(),
+        &TextComponent::plain(", "),
+    );
+    let message = match (targets, tags.is_empty()) {
+        ([target], true) => translations::COMMANDS_TAG_LIST_SINGLE_EMPTY
+            .message([entity_display_name(target.as_ref())]),
+        ([target], false) => translations::COMMANDS_TAG_LIST_SINGLE_SUCCESS.message([
+            entity_display_name(target.as_ref()),
+            count,
+            list,
+        ]),
+        (_, true) => translations::COMMANDS_TAG_LIST_MULTIPLE_EMPTY
+            .message([TextComponent::from(targets.len().to_string())]),
+        (_, false) => translations::COMMANDS_TAG_LIST_MULTIPLE_SUCCESS.message([
+            TextComponent::from(targets.len().to_string()),
+            count,
+            list,
+        ]),
+    };
+    context.send_success(&message.into(), false);
+    context.result = i32::try_from(tags.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Weak};
+
+    use glam::DVec3;
+    use steel_registry::entity_type::EntityTypeRef;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_entities;
+    use steel_utils::locks::SyncMutex;
+
+    use super::{apply_tag, collect_tags};
+    use crate::entity::{Entity, EntityBase, LivingEntity, LivingEntityBase};
+
+    struct TestLivingEntity {
+        base: EntityBase,
+        living_base: LivingEntityBase,
+        health: SyncMutex<f32>,
+        entity_type: EntityTypeRef,
+    }
+
+    impl TestLivingEntity {
+        fn new(id: i32, entity_type: EntityTypeRef) -> Arc<dyn LivingEntity + Send + Sync> {
+            Arc::new(Self {
+                base: EntityBase::new(id, DVec3::ZERO, entity_type.dimensions, Weak::new()),
+                living_base: LivingEntityBase::new(entity_type),
+                health: SyncMutex::new(20.0),
+                entity_type,
+            })
+        }
+    }
+
+    impl Entity for TestLivingEntity {
+        fn base(&self) -> &EntityBase {
+            &self.base
+        }
+
+        fn entity_type(&self) -> EntityTypeRef {
+            self.entity_type
+        }
+
+        fn as_living_entity(&self) -> Option<&dyn LivingEntity> {
+            Some(self)
+        }
+    }
+
+    impl LivingEntity for TestLivingEntity {
+        fn living_base(&self) -> &LivingEntityBase {
+            &self.living_base
+        }
+
+        fn get_health(&self) -> f32 {
+            *self.health.lock()
+        }
+
+        fn set_health(&self, health: f32) {
+            *self.health.lock() = health;
+        }
+    }
+
+    #[test]
+    fn adding_a_tag_tags_every_target_once() {
+        init_test_registry();
+        let targets = [
+            TestLivingEntity::new(1, &vanilla_entities::ZOMBIE),
+            TestLivingEntity::new(2, &vanilla_entities::PIG),
+        ];
+        targets[0].base().add_tag("boss".to_owned());
+
+        assert_eq!(apply_tag(&targets, "marked", true), 2);
+        assert_eq!(apply_tag(&targets, "marked", true), 0);
+        assert_eq!(
+            collect_tags(&targets).into_iter().collect::<Vec<_>>(),
+            ["boss", "marked"]
+        );
+
+        assert_eq!(apply_tag(&targets, "boss", false), 1);
+        assert_eq!(collect_tags(&targets[..1]).len(), 1);
+    }
+}
@@ -0,0 +1,596 @@
+//! Handler for the "team" command.
+//! Mirrors `net.minecraft.server.commands.TeamCommand`.
+use steel_protocol::packets::game::{CollisionRule, NameTagVisibility};
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        bool::BoolArgument,
+        color::ColorArgument,
+        score_holder::ScoreHolderArgument,
+        string::StringArgument,
+        team::TeamArgument,
+        text_component::{TextComponentArgument, format_list},
+    },
+    commands::{CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::scoreboard::{PlayerTeam, Scoreboard, ScoreboardPacket, TeamColor};
+
+type Holders = Vec<String>;
+type TeamArgs = ((), String);
+
+/// Handler for the "team" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(&["team"], "Manages teams.", "minecraft:command.team")
+        .requires_level(LEVEL_GAMEMASTERS)
+        .then(
+            literal("list")
+                .executes(|(), context: &mut CommandContext| list_teams(context))
+                .then(argument("team", TeamArgument).executes(
+                    |((), name): TeamArgs, context: &mut CommandContext| {
+                        list_members(context, &name)
+                    },
+                )),
+        )
+        .then(
+            literal("add").then(
+                argument("team", StringArgument::Word)
+                    .executes(|((), name): TeamArgs, context: &mut CommandContext| {
+                        let display_name = TextComponent::plain(name.clone());
+                        add_team(context, name, display_name)
+                    })
+                    .then(argument("displayName", TextComponentArgument).executes(
+                        |(((), name), display_name): (TeamArgs, TextComponent),
+                         context: &mut CommandContext| {
+                            add_team(context, name, display_name)
+                        },
+                    )),
+            ),
+        )
+        .then(
+            literal("remove").then(argument("team", TeamArgument).executes(
+                |((), name): TeamArgs, context: &mut CommandContext| remove_team(context, &name),
+            )),
+        )
+        .then(
+            literal("empty").then(argument("team", TeamArgument).executes(
+                |((), name): TeamArgs, context: &mut CommandContext| empty_team(context, &name),
+            )),
+        )
+        .then(
+            literal("join").then(
+                argument("team", TeamArgument)
+                    .executes(|((), name): TeamArgs, context: &mut CommandContext| {
+                        let Some(player) = context.player.as_ref() else {
+                            return Err(failed(translations::PERMISSIONS_REQUIRES_ENTITY.msg()));
+                        };
+                        let holders = vec![player.gameprofile.name.clone()];
+                        join_team(context, &name, &holders)
+                    })
+                    .then(
+                        argument("members", ScoreHolderArgument::multiple()).executes(
+                            |(((), name), holders): (TeamArgs, Holders),
+                             context: &mut CommandContext| {
+                                join_team(context, &name, &holders)
+                            },
+                        ),
+                    ),
+            ),
+        )
+        .then(literal("leave").then(
+            argument("members", ScoreHolderArgument::multiple()).executes(
+                |((), holders): ((), Holders), context: &mut CommandContext| {
+                    leave_team(context, &holders)
+                },
+            ),
+        ))
+        .then(
+            // TODO: add displayName, seeFriendlyInvisibles and deathMessageVisibility
+            literal("modify").then(
+                argument("team", TeamArgument)
+                    .then(
+                        literal("color").then(argument("value", ColorArgument).executes(
+                            |(((), name), color): (TeamArgs, TeamColor),
+                             context: &mut CommandContext| {
+                                set_color(context, &name, color)
+                            },
+                        )),
+                    )
+                    .then(
+                        literal("friendlyFire").then(argument("allowed", BoolArgument).executes(
+                            |(((), name), allowed): (TeamArgs, bool),
+                             context: &mut CommandContext| {
+                                set_friendly_fire(context, &name, allowed)
+                            },
+                        )),
+                    )
+                    .then(
+                        literal("nametagVisibility")
+                            .then(
+                                literal("never")
+                                    .executes(NameTagExecutor(NameTagVisibility::Never)),
+                            )
+                            .then(
+                                literal("hideForOtherTeams").executes(NameTagExecutor(
+                                    NameTagVisibility::HideForOtherTeams,
+                                )),
+                            )
+                            .then(
+                                literal("hideForOwnTeam")
+                                    .executes(NameTagExecutor(NameTagVisibility::HideForOwnTeam)),
+                            )
+                            .then(
+                                literal("always")
+                                    .executes(NameTagExecutor(NameTagVisibility::Always)),
+                            ),
+                    )
+                    .then(
+                        literal("collisionRule")
+                            .then(
+                                literal("never").executes(CollisionExecutor(CollisionRule::Never)),
+                            )
+                            .then(
+                                literal("pushOtherTeams")
+                                    .executes(CollisionExecutor(CollisionRule::PushOtherTeams)),
+                            )
+                            .then(
+                                literal("pushOwnTeam")
+                                    .executes(CollisionExecutor(CollisionRule::PushOwnTeam)),
+                            )
+                            .then(
+                                literal("always")
+                                    .executes(CollisionExecutor(CollisionRule::Always)),
+                            ),
+                    )
+                    .then(literal("prefix").then(
+                        argument("prefix", TextComponentArgument).executes(
+                            |(((), name), prefix): (TeamArgs, TextComponent),
+                             context: &mut CommandContext| {
+                                set_prefix(context, &name, prefix)
+                            },
+                        ),
+                    ))
+                    .then(literal("suffix").then(
+                        argument("suffix", TextComponentArgument).executes(
+                            |(((), name), suffix): (TeamArgs, TextComponent),
+                             context: &mut CommandContext| {
+                                set_suffix(context, &name, suffix)
+                            },
+                        ),
+                    )),
+            ),
+        )
+}
+
+struct NameTagExecutor(NameTagVisibility);
+
+impl CommandExecutor<TeamArgs> for NameTagExecutor {
+    fn execute(
+        &self,
+        ((), name): TeamArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let visibility = self.0;
+        modify_team(context, &name, |team| {
+            if team.nametag_visibility == visibility {
+                return Err(failed(
+                    translations::COMMANDS_TEAM_OPTION_NAMETAG_VISIBILITY_UNCHANGED.msg(),
+                ));
+            }
+            team.nametag_visibility = visibility;
+            Ok(
+                translations::COMMANDS_TEAM_OPTION_NAMETAG_VISIBILITY_SUCCESS
+                    .message([team.formatted_display_name(), visibility_name(visibility)])
+                    .into(),
+            )
+        })
+    }
+}
+
+struct CollisionExecutor(CollisionRule);
+
+impl CommandExecutor<TeamArgs> for CollisionExecutor {
+    fn execute(
+        &self,
+        ((), name): TeamArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let rule = self.0;
+        modify_team(context, &name, |team| {
+            if team.collision_rule == rule {
+                return Err(failed(
+                    translations::COMMANDS_TEAM_OPTION_COLLISION_RULE_UNCHANGED.msg(),
+                ));
+            }
+            team.collision_rule = rule;
+            Ok(translations::COMMANDS_TEAM_OPTION_COLLISION_RULE_SUCCESS
+                .message([team.formatted_display_name(), collision_rule_name(rule)])
+                .into())
+        })
+    }
+}
+
+/// The team named `name`, vanilla's `TeamArgument.getTeam`.
+fn team(scoreboard: &Scoreboard, name: &str) -> Result<PlayerTeam, CommandError> {
+    scoreboard
+        .team(name)
+        .cloned()
+        .ok_or_else(|| failed(translations::TEAM_NOT_FOUND.message([TextComponent::from(name)])))
+}
+
+/// Creates the team, failing if its name is taken.
+fn create_team(
+    scoreboard: &mut Scoreboard,
+    name: String,
+    display_name: TextComponent,
+) -> Result<(PlayerTeam, Vec<ScoreboardPacket>), CommandError> {
+    let packets = scoreboard
+        .add_team(name.clone(), display_name)
+        .ok_or_else(|| failed(translations::COMMANDS_TEAM_ADD_DUPLICATE.msg()))?;
+    Ok((team(scoreboard, &name)?, packets))
+}
+
+fn add_team(
+    context: &mut CommandContext,
+    name: String,
+    display_name: TextComponent,
+) -> Result<(), CommandError> {
+    let (team, packets) = create_team(&mut context.server.scoreboard.lock(), name, display_name)?;
+    context.server.broadcast_scoreboard(&packets);
+    context.send_success(
+        &translations::COMMANDS_TEAM_ADD_SUCCESS
+            .message([team.formatted_display_name()])
+            .into(),
+        true,
+    );
+    context.result = 1;
+    Ok(())
+}
+
+fn remove_team(context: &mut CommandContext, name: &str) -> Result<(), CommandError> {
+    let (team, packets) = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        let team = team(&scoreboard, name)?;
+        (team, scoreboard.remove_team(name))
+    };
+    context.server.broadcast_scoreboard(&packets);
+    context.send_success(
+        &translations::COMMANDS_TEAM_REMOVE_SUCCESS
+            .message([team.formatted_display_name()])
+            .into(),
+        true,
+    );
+    context.result = 1;
+    Ok(())
+}
+
+fn empty_team(context: &mut CommandContext, name: &str) -> Result<(), CommandError> {
+    let (team, packets) = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        let team = team(&scoreboard, name)?;
+        if team.players.is_empty() {
+            return Err(failed(translations::COMMANDS_TEAM_EMPTY_UNCHANGED.msg()));
+        }
+        let packets: Vec<_> = team
+            .sorted_players()
+            .iter()
+            .filter_map(|holder| scoreboard.remove_holder_from_team(holder))
+            .flatten()
+            .collect();
+        (team, packets)
+    };
+    context.server.broadcast_scoreboard(&packets);
+    context.send_success(
+        &translations::COMMANDS_TEAM_EMPTY_SUCCESS
+            .message([
+                TextComponent::from(team.players.len().to_string()),
+                team.formatted_display_name(),
+            ])
+            .into(),
+        true,
+    );
+    context.result = i32::try_from(team.players.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+/// Moves every holder into the team.
+fn join_holders(
+    scoreboard: &mut Scoreboard,
+    name: &str,
+    holders: &[String],
+) -> Result<(PlayerTeam, Vec<ScoreboardPacket>), CommandError> {
+    let team = team(scoreboard, name)?;
+    let packets = holders
+        .iter()
+        .flat_map(|holder| scoreboard.add_holder_to_team(holder, name))
+        .collect();
+    Ok((team, packets))
+}
+
+fn join_team(
+    context: &mut CommandContext,
+    name: &str,
+    holders: &[String],
+) -> Result<(), CommandError> {
+    let (team, packets) = join_holders(&mut context.server.scoreboard.lock(), name, holders)?;
+    context.server.broadcast_scoreboard(&packets);
+
+    let team = team.formatted_display_name();
+    let message = if let [holder] = holders {
+        translations::COMMANDS_TEAM_JOIN_SUCCESS_SINGLE
+            .message([TextComponent::from(holder.as_str()), team])
+    } else {
+        translations::COMMANDS_TEAM_JOIN_SUCCESS_MULTIPLE
+            .message([TextComponent::from(holders.len().to_string()), team])
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(holders.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn leave_team(context: &mut CommandContext, holders: &[String]) -> Result<(), CommandError> {
+    let packets: Vec<_> = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        holders
+            .iter()
+            .filter_map(|holder| scoreboard.remove_holder_from_team(holder))
+            .flatten()
+            .collect()
+    };
+    context.server.broadcast_scoreboard(&packets);
+
+    let message = if let [holder] = holders {
+        translations::COMMANDS_TEAM_LEAVE_SUCCESS_SINGLE
+            .message([TextComponent::from(holder.as_str())])
+    } else {
+        translations::COMMANDS_TEAM_LEAVE_SUCCESS_MULTIPLE
+            .message([TextComponent::from(holders.len().to_string())])
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(holders.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn list_teams(context: &mut CommandContext) -> Result<(), CommandError> {
+    let teams: Vec<_> = context
+        .server
+        .scoreboard
+        .lock()
+        .teams()
+        .into_iter()
+        .map(PlayerTeam::formatted_display_name)
+        .collect();
+    if teams.is_empty() {
+        context.send_success(
+            &translations::COMMANDS_TEAM_LIST_TEAMS_EMPTY.msg().into(),
+            false,
+        );
+    } else {
+        context.send_success(
+            &translations::COMMANDS_TEAM_LIST_TEAMS_SUCCESS
+                .message([
+                    TextComponent::from(teams.len().to_string()),
+                    format_list(teams.clone(), &TextComponent::plain(", ")),
+                ])
+                .into(),
+            false,
+        );
+    }
+    context.result = i32::try_from(teams.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn list_members(context: &mut CommandContext, name: &str) -> Result<(), CommandError> {
+    let team = team(&context.server.scoreboard.lock(), name)?;
+    let members: Vec<_> = team
+        .sorted_players()
+        .into_iter()
+        .map(TextComponent::from)
+        .collect();
+    if members.is_empty() {
+        context.send_success(
+            &translations::COMMANDS_TEAM_LIST_MEMBERS_EMPTY
+                .message([team.formatted_display_name()])
+                .into(),
+            false,
+        );
+    } else {
+        context.send_success(
+            &translations::COMMANDS_TEAM_LIST_MEMBERS_SUCCESS
+                .message([
+                    team.formatted_display_name(),
+                    TextComponent::from(members.len().to_string()),
+                    format_list(members.clone(), &TextComponent::plain(", ")),
+                ])
+                .into(),
+            false,
+        );
+    }
+    context.result = i32::try_from(members.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+/// Changes the options of a team, `change` returning the success message or an unchanged error.
+fn modify_team(
+    context: &mut CommandContext,
+    name: &str,
+    change: impl FnOnce(&mut PlayerTeam) -> Result<TextComponent, CommandError>,
+) -> Result<(), CommandError> {
+    let (message, packets) = {
+        let mut scoreboard = context.server.scoreboard.lock();
+        let mut team = team(&scoreboard, name)?;
+        let message = change(&mut team)?;
+        (message, scoreboard.update_team(team))
+    };
+    context.server.broadcast_scoreboard(&packets);
+    context.send_success(&message, true);
+    context.result = 1;
+    Ok(())
+}
+
+fn set_color(
+    context: &mut CommandContext,
+    name: &str,
+    color: TeamColor,
+) -> Result<(), CommandError> {
+    modify_team(context, name, |team| {
+        if team.color == color {
+            return Err(failed(
+                translations::COMMANDS_TEAM_OPTION_COLOR_UNCHANGED.msg(),
+            ));
+        }
+        team.color = color;
+        Ok(translations::COMMANDS_TEAM_OPTION_COLOR_SUCCESS
+            .message([
+                team.formatted_display_name(),
+                TextComponent::from(color.name()),
+            ])
+            .into())
+    })
+}
+
+fn set_friendly_fire(
+    context: &mut CommandContext,
+    name: &str,
+    allowed: bool,
+) -> Result<(), CommandError> {
+    modify_team(context, name, |team| {
+        if team.allow_friendly_fire == allowed {
+            let unchanged = if allowed {
+                &translations::COMMANDS_TEAM_OPTION_FRIENDLYFIRE_ALREADY_ENABLED
+            } else {
+                &translations::COMMANDS_TEAM_OPTION_FRIENDLYFIRE_ALREADY_DISABLED
+            };
+            return Err(failed(unchanged.msg()));
+        }
+        team.allow_friendly_fire = allowed;
+        let translation = if allowed {
+            &translations::COMMANDS_TEAM_OPTION_FRIENDLYFIRE_ENABLED
+        } else {
+            &translations::COMMANDS_TEAM_OPTION_FRIENDLYFIRE_DISABLED
+        };
+        Ok(translation.message([team.formatted_display_name()]).into())
+    })
+}
+
+fn set_prefix(
+    context: &mut CommandContext,
+    name: &str,
+    prefix: TextComponent,
+) -> Result<(), CommandError> {
+    modify_team(context, name, |team| {
+        team.prefix = prefix.clone();
+        Ok(translations::COMMANDS_TEAM_OPTION_PREFIX_SUCCESS
+            .message([prefix])
+            .into())
+    })
+}
+
+fn set_suffix(
+    context: &mut CommandContext,
+    name: &str,
+    suffix: TextComponent,
+) -> Result<(), CommandError> {
+    modify_team(context, name, |team| {
+        team.suffix = suffix.clone();
+        Ok(translations::COMMANDS_TEAM_OPTION_SUFFIX_SUCCESS
+            .message([suffix])
+            .into())
+    })
+}
+
+fn visibility_name(visibility: NameTagVisibility) -> TextComponent {
+    match visibility {
+        NameTagVisibility::Always => translations::TEAM_VISIBILITY_ALWAYS.msg(),
+        NameTagVisibility::Never => translations::TEAM_VISIBILITY_NEVER.msg(),
+        NameTagVisibility::HideForOtherTeams => {
+            translations::TEAM_VISIBILITY_HIDE_FOR_OTHER_TEAMS.msg()
+        }
+        NameTagVisibility::HideForOwnTeam => translations::TEAM_VISIBILITY_HIDE_FOR_OWN_TEAM.msg(),
+    }
+    .into()
+}
+
+fn collision_rule_name(rule: CollisionRule) -> TextComponent {
+    match rule {
+        CollisionRule::Always => translations::TEAM_COLLISION_ALWAYS.msg(),
+        CollisionRule::Never => translations::TEAM_COLLISION_NEVER.msg(),
+        CollisionRule::PushOtherTeams => translations::TEAM_COLLISION_PUSH_OTHER_TEAMS.msg(),
+        CollisionRule::PushOwnTeam => translations::TEAM_COLLISION_PUSH_OWN_TEAM.msg(),
+    }
+    .into()
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use text_components::TextComponent;
+
+    use super::{create_team, join_holders};
+    use crate::scoreboard::Scoreboard;
+
+    #[test]
+    fn teams_are_created_once() {
+        let mut scoreboard = Scoreboard::default();
+
+        let (team, packets) = create_team(
+            &mut scoreboard,
+            "red".to_owned(),
+            TextComponent::plain("Red"),
+        )
+        .expect("the name is free");
+        assert_eq!(team.name, "red");
+        assert_eq!(packets.len(), 1);
+
+        assert!(
+            create_team(
+                &mut scoreboard,
+                "red".to_owned(),
+                TextComponent::plain("Red")
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn joining_moves_holders_between_teams() {
+        let mut scoreboard = Scoreboard::default();
+        for name in ["red", "blue"] {
+            create_team(&mut scoreboard, name.to_owned(), TextComponent::plain(name))
+                .expect("the name is free");
+        }
+
+        join_holders(&mut scoreboard, "red", &["Steve".to_owned()]).expect("red exists");
+        assert_eq!(
+            scoreboard
+                .holder_team("Steve")
+                .map(|team| team.name.as_str()),
+            Some("red")
+        );
+
+        let (_, packets) =
+            join_holders(&mut scoreboard, "blue", &["Steve".to_owned()]).expect("blue exists");
+        assert_eq!(packets.len(), 2);
+        assert_eq!(
+            scoreboard
+                .holder_team("Steve")
+                .map(|team| team.name.as_str()),
+            Some("blue")
+        );
+        assert!(
+            scoreboard
+                .team("red")
+                .is_some_and(|team| team.players.is_empty())
+        );
+
+        assert!(join_holders(&mut scoreboard, "green", &["Alex".to_owned()]).is_err());
+    }
+}
@@ -0,0 +1,435 @@
+//! Handler for the "teleport" command.
+//! Mirrors `net.minecraft.server.commands.TeleportCommand`.
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_utils::{BlockPos, translations};
+use text_components::TextComponent;
+
+use crate::{
+    command::{
+        LEVEL_GAMEMASTERS,
+        arguments::{
+            anchor::AnchorArgument,
+            entity::EntityArgument,
+            rotation::RotationArgument,
+            vector3::{Coordinates, Vector3Argument},
+        },
+        commands::{
+            CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal,
+            summon::entity_display_name,
+        },
+        context::{CommandContext, EntityAnchor},
+        error::CommandError,
+        suggest_teleport,
+    },
+    entity::{Entity, LivingEntity},
+    world::World,
+};
+
+type Targets = Vec<Arc<dyn LivingEntity + Send + Sync>>;
+type TargetsArgs = ((), Targets);
+type LocationArgs = (TargetsArgs, Coordinates);
+type RotationArgs = (LocationArgs, (f32, f32));
+type DestinationArgs = (TargetsArgs, Targets);
+type FacingEntityArgs = (LocationArgs, Targets);
+type FacingAnchorArgs = (FacingEntityArgs, EntityAnchor);
+type FacingLocationArgs = (LocationArgs, Coordinates);
+
+/// Handler for the "teleport" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["teleport", "tp"],
+        "Teleports entities to a location or another entity.",
+        "minecraft:command.teleport",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(argument("location", Vector3Argument).executes(TeleportExecutor))
+    .then(argument("destination", EntityArgument::one()).executes(TeleportExecutor))
+    .then(
+        argument("targets", EntityArgument::multiple())
+            .then(
+                argument("location", Vector3Argument)
+                    .executes(TeleportExecutor)
+                    .then(argument("rotation", RotationArgument).executes(TeleportExecutor))
+                    .then(
+                        literal("facing")
+                            .then(
+                                literal("entity").then(
+                                    argument("facingEntity", EntityArgument::one())
+                                        .executes(TeleportExecutor)
+                                        .then(
+                                            argument("facingAnchor", AnchorArgument)
+                                                .executes(TeleportExecutor),
+                                        ),
+                                ),
+                            )
+                            .then(
+                                argument("facingLocation", Vector3Argument)
+                                    .executes(TeleportExecutor),
+                            ),
+                    ),
+            )
+            .then(argument("destination", EntityArgument::one()).executes(TeleportExecutor)),
+    )
+}
+
+struct TeleportExecutor;
+
+impl CommandExecutor<((), Coordinates)> for TeleportExecutor {
+    fn execute(
+        &self,
+        ((), location): ((), Coordinates),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let targets = [source_entity(context)?];
+        teleport_to_pos(context, &targets, &location, None, None)
+    }
+}
+
+impl CommandExecutor<TargetsArgs> for TeleportExecutor {
+    fn execute(
+        &self,
+        ((), destination): TargetsArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let targets = [source_entity(context)?];
+        teleport_to_entity(context, &targets, &destination)
+    }
+}
+
+impl CommandExecutor<LocationArgs> for TeleportExecutor {
+    fn execute(
+        &self,
+        (((), targets), location): LocationArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        teleport_to_pos(context, &targets, &location, None, None)
+    }
+}
+
+impl CommandExecutor<RotationArgs> for TeleportExecutor {
+    fn execute(
+        &self,
+        ((((), targets), location), rotation): RotationArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        teleport_to_pos(context, &targets, &location, Some(rotation), None)
+    }
+}
+
+impl CommandExecutor<FacingEntityArgs> for TeleportExecutor {
+    fn execute(
+        &self,
+        ((((), targets), location), facing): FacingEntityArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let look_at = look_at_entity(&facing, &EntityAnchor::Feet)?;
+        teleport_to_pos(context, &targets, &location, None, Some(look_at))
+    }
+}
+
+impl CommandExecutor<FacingAnchorArgs> for TeleportExecutor {
+    fn execute(
+        &self,
+        (((((), targets), location), facing), anchor): FacingAnchorArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let look_at = look_at_entity(&facing, &anchor)?;
+        teleport_to_pos(context, &targets, &location, None, Some(look_at))
+    }
+}
+
+impl CommandExecutor<FacingLocationArgs> for TeleportExecutor {
+    fn execute(
+        &self,
+        ((((), targets), location), facing): FacingLocationArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let look_at = facing.position(context);
+        teleport_to_pos(context, &targets, &location, None, Some(look_at))
+    }
+}
+
+impl CommandExecutor<DestinationArgs> for TeleportExecutor {
+    fn execute(
+        &self,
+        (((), targets), destination): DestinationArgs,
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        teleport_to_entity(context, &targets, &destination)
+    }
+}
+
+/// The entity running the command, vanilla's `getEntityOrException`.
+fn source_entity(
+    context: &CommandContext,
+) -> Result<Arc<dyn LivingEntity + Send + Sync>, CommandError> {
+    match context.player.clone() {
+        Some(player) => Ok(player),
+        None => Err(CommandError::CommandFailed(Box::new(
+            translations::PERMISSIONS_REQUIRES_ENTITY.msg().into(),
+        ))),
+    }
+}
+
+/// The point on `facing` a `facing entity` teleport turns towards.
+fn look_at_entity(
+    facing: &[Arc<dyn LivingEntity + Send + Sync>],
+    anchor: &EntityAnchor,
+) -> Result<DVec3, CommandError> {
+    let Some(facing) = facing.first() else {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_ENTITY_NOTFOUND_ENTITY.msg().into(),
+        )));
+    };
+    Ok(anchor_position(&**facing, anchor))
+}
+
+fn anchor_position<E: Entity + ?Sized>(entity: &E, anchor: &EntityAnchor) -> DVec3 {
+    let position = entity.position();
+    match anchor {
+        EntityAnchor::Feet => position,
+        EntityAnchor::Eyes => DVec3::new(position.x, entity.get_eye_y(), position.z),
+    }
+}
+
+/// Teleports every target to `destination`. Relative coordinates are resolved against each
+/// target, so `/tp @a ~ ~10 ~` lifts everyone by ten blocks.
+fn teleport_to_pos(
+    context: &mut CommandContext,
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
+    destination: &Coordinates,
+    rotation: Option<(f32, f32)>,
+    look_at: Option<DVec3>,
+) -> Result<(), CommandError> {
+    // The feedback names where the last target was sent
+    let mut location = destination.position(context);
+    for target in targets {
+        location = destination.position_for(&**target, &context.anchor);
+        let rotation = match (look_at, rotation) {
+            (Some(look_at), _) => {
+                let eye_offset = match context.anchor {
+                    EntityAnchor::Feet => 0.0,
+                    EntityAnchor::Eyes => target.get_eye_y() - target.position().y,
+                };
+                look_rotation(location + DVec3::new(0.0, eye_offset, 0.0), look_at)
+            }
+            (None, Some(rotation)) => rotation,
+            (None, None) => target.rotation(),
+        };
+        perform_teleport(&**target, location, rotation)?;
+    }
+
+    let [x, y, z] = [location.x, location.y, location.z].map(format_double);
+    let coordinates = [&x, &y, &z].map(|axis| TextComponent::from(axis.clone()));
+    let message = if let [target] = targets {
+        let [cx, cy, cz] = coordinates;
+        translations::COMMANDS_TELEPORT_SUCCESS_LOCATION_SINGLE.message([
+            entity_display_name(&**target),
+            cx,
+            cy,
+            cz,
+        ])
+    } else {
+        let [cx, cy, cz] = coordinates;
+        translations::COMMANDS_TELEPORT_SUCCESS_LOCATION_MULTIPLE.message([
+            TextComponent::from(targets.len().to_string()),
+            cx,
+            cy,
+            cz,
+        ])
+    };
+    let message = suggest_teleport(message.into(), &x, &y, &z);
+    context.send_success(&message, true);
+    context.result = i32::try_from(targets.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn teleport_to_entity(
+    context: &mut CommandContext,
+    targets: &[Arc<dyn LivingEntity + Send + Sync>],
+    destination: &[Arc<dyn LivingEntity + Send + Sync>],
+) -> Result<(), CommandError> {
+    let Some(destination) = destination.first() else {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::ARGUMENT_ENTITY_NOTFOUND_ENTITY.msg().into(),
+        )));
+    };
+
+    // TODO: follow destinations into other worlds
+    let position = destination.position();
+    let rotation = destination.rotation();
+    for target in targets {
+        perform_teleport(&**target, position, rotation)?;
+    }
+
+    let destination_name = entity_display_name(&**destination);
+    let message = if let [target] = targets {
+        translations::COMMANDS_TELEPORT_SUCCESS_ENTITY_SINGLE
+            .message([entity_display_name(&**target), destination_name])
+    } else {
+        translations::COMMANDS_TELEPORT_SUCCESS_ENTITY_MULTIPLE.message([
+            TextComponent::from(targets.len().to_string()),
+            destination_name,
+        ])
+    };
+    context.send_success(&message.into(), true);
+    context.result = i32::try_from(targets.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+/// Vanilla `TeleportCommand.performTeleport` within the target's own world.
+fn perform_teleport(
+    target: &(dyn LivingEntity + Send + Sync),
+    position: DVec3,
+    (yaw, pitch): (f32, f32),
+) -> Result<(), CommandError> {
+    if !World::is_in_spawnable_bounds(BlockPos::from(position)) {
+        return Err(CommandError::CommandFailed(Box::new(
+            translations::COMMANDS_TELEPORT_INVALID_POSITION
+                .msg()
+                .into(),
+        )));
+    }
+
+    let moved = match target.as_player() {
+        Some(player) => player.teleport(position, yaw, pitch).inspect(|()| {
+            player.reset_flying_ticks();
+        }),
+        None => target.try_set_position(position).inspect(|()| {
+            target.set_rotation((yaw, pitch));
+            target.set_y_head_rot(yaw);
+            target.set_old_position_to_current();
+        }),
+    };
+    moved.map_err(|error| {
+        CommandError::CommandFailed(Box::new(TextComponent::plain(format!(
+            "Failed to teleport: {error}"
+        ))))
+    })?;
+
+    if !target.is_fall_flying() {
+        let velocity = target.velocity();
+        target.set_velocity(DVec3::new(velocity.x, 0.0, velocity.z));
+        target.set_on_ground(true);
+    }
+    if let Some(mob) = target.as_mob() {
+        mob.mob_base().navigation().lock().stop();
+    }
+    Ok(())
+}
+
+/// The (yaw, pitch) looking from `from` towards `to`, as in vanilla's `Entity.lookAt`.
+fn look_rotation(from: DVec3, to: DVec3) -> (f32, f32) {
+    let delta = to - from;
+    let horizontal = delta.x.hypot(delta.z);
+    let pitch = -(delta.y.atan2(horizontal) * 180.0 / PI);
+    let yaw = delta.z.atan2(delta.x) * 180.0 / PI - 90.0;
+    (yaw as f32, pitch as f32)
+}
+
+/// Vanilla `TeleportCommand.formatDouble`, a Java `%f`.
+fn format_double(value: f64) -> String {
+    format!("{value:.6}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use glam::DVec3;
+    use text_components::interactivity::ClickEvent;
+
+    use super::{format_double, look_rotation};
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::sender::CommandSender;
+    use crate::entity::Entity;
+    use crate::server::Server;
+    use crate::test_support::server::{TestPlayer, add_player, run_command, test_server};
+
+    fn player_at(server: &Arc<Server>, name: &str, position: DVec3) -> TestPlayer {
+        let player = add_player(server, name);
+        player
+            .player
+            .teleport(position, 90.0, 10.0)
+            .expect("test player should move");
+        player
+    }
+
+    #[test]
+    fn coordinates_are_printed_like_java_percent_f() {
+        assert_eq!(format_double(-12.5), "-12.500000");
+    }
+
+    #[test]
+    fn facing_a_location_turns_towards_it() {
+        let from = DVec3::new(0.0, 64.0, 0.0);
+
+        let (yaw, pitch) = look_rotation(from, DVec3::new(10.0, 64.0, 0.0));
+        assert!((yaw - -90.0).abs() < 1e-4);
+        assert!(pitch.abs() < 1e-4);
+
+        let (yaw, pitch) = look_rotation(from, DVec3::new(0.0, 64.0, 10.0));
+        assert!(yaw.abs() < 1e-4);
+        assert!(pitch.abs() < 1e-4);
+
+        let (_, pitch) = look_rotation(from, DVec3::new(0.0, 54.0, 0.0));
+        assert!((pitch - 90.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn relative_coordinates_resolve_against_each_target() {
+        let server = test_server();
+        let steve = player_at(&server, "Steve", DVec3::new(0.5, 64.0, 0.5));
+        let alex = player_at(&server, "Alex", DVec3::new(10.5, 70.0, -4.5));
+
+        run_command(
+            &server,
+            CommandSender::capture(LEVEL_GAMEMASTERS),
+            "tp @a ~ ~10 ~",
+        );
+
+        assert_eq!(steve.player.position(), DVec3::new(0.5, 74.0, 0.5));
+        assert_eq!(alex.player.position(), DVec3::new(10.5, 80.0, -4.5));
+    }
+
+    #[test]
+    fn absolute_coordinates_are_centered_on_the_block() {
+        let server = test_server();
+        let steve = player_at(&server, "Steve", DVec3::new(0.5, 64.0, 0.5));
+        let sender = CommandSender::capture(LEVEL_GAMEMASTERS);
+
+        run_command(&server, sender.clone(), "tp Steve 3 65 -2");
+
+        assert_eq!(steve.player.position(), DVec3::new(3.5, 65.0, -1.5));
+        assert_eq!(steve.player.rotation(), (90.0, 10.0));
+        let feedback = sender.captured();
+        assert!(matches!(
+            feedback[0].interactions.click,
+            Some(ClickEvent::SuggestCommand { ref command })
+                if command.as_ref() == "/tp @s 3.500000 65.000000 -1.500000"
+        ));
+    }
+
+    #[test]
+    fn entities_are_sent_to_the_destination_entity() {
+        let server = test_server();
+        let steve = player_at(&server, "Steve", DVec3::new(0.5, 64.0, 0.5));
+        let alex = add_player(&server, "Alex");
+        alex.player
+            .teleport(DVec3::new(-20.0, 80.0, 15.0), -45.0, 30.0)
+            .expect("test player should move");
+
+        run_command(
+            &server,
+            CommandSender::capture(LEVEL_GAMEMASTERS),
+            "tp Steve Alex",
+        );
+
+        assert_eq!(steve.player.position(), alex.player.position());
+        assert_eq!(steve.player.rotation(), (-45.0, 30.0));
+    }
+}
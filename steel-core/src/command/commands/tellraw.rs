@@ -1,6 +1,6 @@
 //! Handler for the "tellraw" command.
 use crate::command::arguments::player::PlayerArgument;
-use crate::command::arguments::text_component::TextComponentArgument;
+use crate::command::arguments::text_component::{TextComponentArgument, resolve_selectors};
 use crate::command::commands::{
     CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument,
 };
@@ -37,11 +37,37 @@ impl CommandExecutor<(((), Vec<Arc<Player>>), TextComponent)> for TellrawCommand
             CommandSender::Player(player) => &player.gameprofile.name,
             CommandSender::Console => "Console",
             CommandSender::Rcon => "Rcon",
+            CommandSender::CommandBlock { name, .. } => name,
+            CommandSender::Capture { .. } => "Capture",
         };
         log::info!("{}'s tellraw: {:p}", sender, args.1);
+        let message = resolve_selectors(&args.1, context);
         for player in args.0.1 {
-            player.send_message(&args.1);
+            player.send_message(&message);
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use text_components::TextComponent;
+
+    use crate::command::LEVEL_GAMEMASTERS;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, run_command, test_server};
+
+    #[test]
+    fn capture_senders_run_tellraw() {
+        let server = test_server();
+        let steve = add_player(&server, "Steve");
+        let sender = CommandSender::capture(LEVEL_GAMEMASTERS);
+
+        run_command(&server, sender.clone(), r#"tellraw Steve {text:"hello"}"#);
+        assert!(sender.captured().is_empty());
+        assert!(steve.received_message(&TextComponent::plain("hello")));
+
+        run_command(&server, sender.clone(), r#"tellraw Nobody {text:"hello"}"#);
+        assert!(!sender.captured().is_empty());
+    }
+}
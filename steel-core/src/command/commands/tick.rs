@@ -36,13 +36,13 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         literal("step")
             .executes(TickStepDefaultExecutor)
             .then(literal("stop").executes(TickStepStopExecutor))
-            .then(argument("time", TimeArgument).executes(TickStepExecutor)),
+            .then(argument("time", TimeArgument::with_min(1)).executes(TickStepExecutor)),
     )
     // /tick sprint <time> | /tick sprint stop
     .then(
         literal("sprint")
             .then(literal("stop").executes(TickSprintStopExecutor))
-            .then(argument("time", TimeArgument).executes(TickSprintExecutor)),
+            .then(argument("time", TimeArgument::with_min(1)).executes(TickSprintExecutor)),
     )
 }
 
@@ -1,10 +1,12 @@
 //! Handler for the `time` command
 use steel_protocol::packets::game::CSetTime;
+use steel_registry::game_rules::GameRuleValue;
 use steel_registry::vanilla_game_rules::ADVANCE_TIME;
 use steel_utils::translations;
 use text_components::TextComponent;
 
 use crate::command::{
+    LEVEL_GAMEMASTERS,
     arguments::time::TimeArgument,
     commands::{CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
     context::CommandContext,
@@ -19,6 +21,7 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Allows interacting with the ingame time.",
         "minecraft:command.time",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .then(
         literal("query")
             .then(literal("day").executes(TimeQueryExecutor::Day))
@@ -31,9 +34,9 @@ pub fn command_handler() -> impl CommandHandlerDyn {
             .then(literal("midnight").executes(TimeConstSetExecutor::<18000>))
             .then(literal("night").executes(TimeConstSetExecutor::<13000>))
             .then(literal("noon").executes(TimeConstSetExecutor::<6000>))
-            .then(argument("time", TimeArgument).executes(TimeExecutor::Set)),
+            .then(argument("time", TimeArgument::new()).executes(TimeExecutor::Set)),
     )
-    .then(literal("add").then(argument("time", TimeArgument).executes(TimeExecutor::Add)))
+    .then(literal("add").then(argument("time", TimeArgument::new()).executes(TimeExecutor::Add)))
 }
 
 enum TimeQueryExecutor {
@@ -52,11 +55,13 @@ impl CommandExecutor<()> for TimeQueryExecutor {
                 TimeQueryExecutor::Gametime => lock.game_time(),
             }
         };
-        context.sender.send_message(
+        context.send_success(
             &translations::COMMANDS_TIME_QUERY
                 .message([TextComponent::from(format!("{number}"))])
                 .into(),
+            false,
         );
+        context.result = (number % i64::from(i32::MAX)) as i32;
         Ok(())
     }
 }
@@ -66,75 +71,83 @@ enum TimeExecutor {
     Set,
 }
 
+impl TimeExecutor {
+    /// Returns the day time after applying `time` to `current`. Day time only counts the time
+    /// of day, the day itself comes from the game time.
+    const fn new_day_time(&self, current: i64, time: i32) -> i64 {
+        let time = time as i64;
+        match self {
+            TimeExecutor::Add => (current + time).rem_euclid(24000),
+            TimeExecutor::Set => time.rem_euclid(24000),
+        }
+    }
+}
+
 impl CommandExecutor<((), i32)> for TimeExecutor {
     fn execute(&self, args: ((), i32), context: &mut CommandContext) -> Result<(), CommandError> {
-        let mut day_time_option: Option<i64> = None;
-
-        context.server.worlds.values().for_each(|world| {
-            let (game_time, new_day_time) = {
-                let mut lock = world.level_data.write();
-
-                let game_time = lock.game_time();
-                let new_day_time = match self {
-                    TimeExecutor::Add => (lock.day_time() + i64::from(args.1)) % 24000,
-                    TimeExecutor::Set => i64::from(args.1) % 24000,
-                };
-
-                lock.set_day_time(new_day_time);
-                (game_time, new_day_time)
-            };
-
-            let advance_time = { world.get_game_rule(&ADVANCE_TIME).as_bool().expect("todo") };
-
-            day_time_option = Some(new_day_time);
-
-            let rate = if advance_time { 1.0 } else { 0.0 };
-            world.broadcast_to_all(CSetTime::new(game_time, new_day_time, 0.0, rate));
-        });
-
-        let Some(new_day_time) = day_time_option else {
-            return Err(CommandError::CommandFailed(Box::new(TextComponent::from(
-                "no world to update time on",
-            ))));
-        };
-
-        context.sender.send_message(
-            &translations::COMMANDS_TIME_SET
-                .message([TextComponent::from(format!("{new_day_time}"))])
-                .into(),
-        );
-
-        Ok(())
+        let ((), time) = args;
+        set_day_time(context, |current| self.new_day_time(current, time))
     }
 }
 
-struct TimeConstSetExecutor<const DAYTIME: i64>;
+struct TimeConstSetExecutor<const DAYTIME: i32>;
 
-impl<const DAYTIME: i64> CommandExecutor<()> for TimeConstSetExecutor<DAYTIME> {
+impl<const DAYTIME: i32> CommandExecutor<()> for TimeConstSetExecutor<DAYTIME> {
     fn execute(&self, _args: (), context: &mut CommandContext) -> Result<(), CommandError> {
-        context.server.worlds.values().for_each(|world| {
-            let (game_time, new_day_time) = {
-                let mut lock = world.level_data.write();
+        set_day_time(context, |current| {
+            TimeExecutor::Set.new_day_time(current, DAYTIME)
+        })
+    }
+}
 
-                let game_time = lock.game_time();
-                let new_day_time = DAYTIME;
+/// Updates the day time of every world, like vanilla does, and reports the sender's new time.
+fn set_day_time(
+    context: &mut CommandContext,
+    update: impl Fn(i64) -> i64,
+) -> Result<(), CommandError> {
+    if context.server.worlds.is_empty() {
+        return Err(CommandError::CommandFailed(Box::new(TextComponent::from(
+            "no world to update time on",
+        ))));
+    }
 
-                lock.set_day_time(new_day_time);
-                (game_time, new_day_time)
-            };
+    for world in context.server.worlds.values() {
+        let (game_time, new_day_time) = {
+            let mut lock = world.level_data.write();
+            let new_day_time = update(lock.day_time());
+            lock.set_day_time(new_day_time);
+            (lock.game_time(), new_day_time)
+        };
 
-            let advance_time = { world.get_game_rule(&ADVANCE_TIME).as_bool().expect("todo") };
+        let advance_time = world.get_game_rule(&ADVANCE_TIME) == GameRuleValue::Bool(true);
+        let rate = if advance_time { 1.0 } else { 0.0 };
+        world.broadcast_to_all(CSetTime::new(game_time, new_day_time, 0.0, rate));
+    }
 
-            let rate = if advance_time { 1.0 } else { 0.0 };
-            world.broadcast_to_all(CSetTime::new(game_time, new_day_time, 0.0, rate));
-        });
+    let new_day_time = context.world.level_data.read().day_time();
+    context.send_success(
+        &translations::COMMANDS_TIME_SET
+            .message([TextComponent::from(format!("{new_day_time}"))])
+            .into(),
+        true,
+    );
+    context.result = new_day_time as i32;
+    Ok(())
+}
 
-        context.sender.send_message(
-            &translations::COMMANDS_TIME_SET
-                .message([TextComponent::from(format!("{DAYTIME}"))])
-                .into(),
-        );
+#[cfg(test)]
+mod tests {
+    use super::TimeExecutor;
 
-        Ok(())
+    #[test]
+    fn set_day_replaces_the_time_of_day() {
+        assert_eq!(TimeExecutor::Set.new_day_time(18000, 1000), 1000);
+        assert_eq!(TimeExecutor::Set.new_day_time(0, 25000), 1000);
+    }
+
+    #[test]
+    fn add_wraps_around_midnight() {
+        assert_eq!(TimeExecutor::Add.new_day_time(1000, 100), 1100);
+        assert_eq!(TimeExecutor::Add.new_day_time(23950, 100), 50);
     }
 }
@@ -0,0 +1,178 @@
+//! Handler for the "title" command.
+//! Mirrors `net.minecraft.server.commands.TitleCommand`.
+use crate::command::arguments::player::PlayerArgument;
+use crate::command::arguments::text_component::TextComponentArgument;
+use crate::command::arguments::time::TimeArgument;
+use crate::command::commands::{
+    CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal,
+};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::player::Player;
+use std::sync::Arc;
+use steel_protocol::packets::game::{
+    CClearTitles, CSetActionBarText, CSetSubtitleText, CSetTitleText, CSetTitlesAnimation,
+};
+use steel_utils::translations;
+use text_components::TextComponent;
+use text_components::translation::Translation;
+
+/// Handler for the "title" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["title"],
+        "Manages screen titles.",
+        "minecraft:command.title",
+    )
+    .then(
+        argument("targets", PlayerArgument::multiple())
+            .then(literal("clear").executes(TitleClearExecutor::Clear))
+            .then(literal("reset").executes(TitleClearExecutor::Reset))
+            .then(
+                literal("title").then(
+                    argument("title", TextComponentArgument).executes(TitleShowExecutor::Title),
+                ),
+            )
+            .then(literal("subtitle").then(
+                argument("title", TextComponentArgument).executes(TitleShowExecutor::Subtitle),
+            ))
+            .then(literal("actionbar").then(
+                argument("title", TextComponentArgument).executes(TitleShowExecutor::Actionbar),
+            ))
+            .then(literal("times").then(
+                argument("fadeIn", TimeArgument::new()).then(
+                    argument("stay", TimeArgument::new()).then(
+                        argument("fadeOut", TimeArgument::new()).executes(TitleTimesExecutor),
+                    ),
+                ),
+            )),
+    )
+}
+
+/// Sends the `.single` feedback naming the target, or `.multiple` with the target count.
+fn send_feedback(
+    context: &CommandContext,
+    targets: &[Arc<Player>],
+    single: &Translation<1>,
+    multiple: &Translation<1>,
+) {
+    let message = if let [target] = targets {
+        single.message([TextComponent::plain(target.gameprofile.name.clone())])
+    } else {
+        multiple.message([TextComponent::from(targets.len().to_string())])
+    };
+    context.sender.send_message(&message.into());
+}
+
+enum TitleClearExecutor {
+    Clear,
+    Reset,
+}
+
+impl CommandExecutor<((), Vec<Arc<Player>>)> for TitleClearExecutor {
+    fn execute(
+        &self,
+        args: ((), Vec<Arc<Player>>),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let ((), targets) = args;
+        let reset_times = matches!(self, TitleClearExecutor::Reset);
+
+        for target in &targets {
+            target.send_packet(CClearTitles { reset_times });
+        }
+
+        if reset_times {
+            send_feedback(
+                context,
+                &targets,
+                &translations::COMMANDS_TITLE_RESET_SINGLE,
+                &translations::COMMANDS_TITLE_RESET_MULTIPLE,
+            );
+        } else {
+            send_feedback(
+                context,
+                &targets,
+                &translations::COMMANDS_TITLE_CLEARED_SINGLE,
+                &translations::COMMANDS_TITLE_CLEARED_MULTIPLE,
+            );
+        }
+        Ok(())
+    }
+}
+
+enum TitleShowExecutor {
+    Title,
+    Subtitle,
+    Actionbar,
+}
+
+impl CommandExecutor<(((), Vec<Arc<Player>>), TextComponent)> for TitleShowExecutor {
+    fn execute(
+        &self,
+        args: (((), Vec<Arc<Player>>), TextComponent),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let (((), targets), title) = args;
+
+        for target in &targets {
+            match self {
+                TitleShowExecutor::Title => {
+                    target.send_packet(CSetTitleText::new(&title, target.as_ref()));
+                }
+                TitleShowExecutor::Subtitle => {
+                    target.send_packet(CSetSubtitleText::new(&title, target.as_ref()));
+                }
+                TitleShowExecutor::Actionbar => {
+                    target.send_packet(CSetActionBarText::new(&title, target.as_ref()));
+                }
+            }
+        }
+
+        let (single, multiple) = match self {
+            TitleShowExecutor::Title => (
+                &translations::COMMANDS_TITLE_SHOW_TITLE_SINGLE,
+                &translations::COMMANDS_TITLE_SHOW_TITLE_MULTIPLE,
+            ),
+            TitleShowExecutor::Subtitle => (
+                &translations::COMMANDS_TITLE_SHOW_SUBTITLE_SINGLE,
+                &translations::COMMANDS_TITLE_SHOW_SUBTITLE_MULTIPLE,
+            ),
+            TitleShowExecutor::Actionbar => (
+                &translations::COMMANDS_TITLE_SHOW_ACTIONBAR_SINGLE,
+                &translations::COMMANDS_TITLE_SHOW_ACTIONBAR_MULTIPLE,
+            ),
+        };
+        send_feedback(context, &targets, single, multiple);
+        Ok(())
+    }
+}
+
+struct TitleTimesExecutor;
+
+impl CommandExecutor<(((((), Vec<Arc<Player>>), i32), i32), i32)> for TitleTimesExecutor {
+    fn execute(
+        &self,
+        args: (((((), Vec<Arc<Player>>), i32), i32), i32),
+        context: &mut CommandContext,
+    ) -> Result<(), CommandError> {
+        let (((((), targets), fade_in), stay), fade_out) = args;
+
+        for target in &targets {
+            target.send_packet(CSetTitlesAnimation {
+                fade_in,
+                stay,
+                fade_out,
+            });
+        }
+
+        send_feedback(
+            context,
+            &targets,
+            &translations::COMMANDS_TITLE_TIMES_SINGLE,
+            &translations::COMMANDS_TITLE_TIMES_MULTIPLE,
+        );
+        Ok(())
+    }
+}
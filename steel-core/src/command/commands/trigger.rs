@@ -0,0 +1,141 @@
+//! Handler for the "trigger" command.
+//! Mirrors `net.minecraft.server.commands.TriggerCommand`.
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::{
+    arguments::{integer::IntegerArgument, objective::ObjectiveArgument},
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::scoreboard::{Objective, ObjectiveCriteria, Scoreboard, ScoreboardPacket};
+
+type ObjectiveArgs = ((), String);
+
+/// How `/trigger` changes the score.
+#[derive(Clone, Copy)]
+enum TriggerAction {
+    /// Adds 1.
+    Simple,
+    Add(i32),
+    Set(i32),
+}
+
+/// Handler for the "trigger" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["trigger"],
+        "Changes a trigger objective enabled for you.",
+        "minecraft:command.trigger",
+    )
+    .then(
+        argument("objective", ObjectiveArgument::existing())
+            .executes(|((), name): ObjectiveArgs, context: &mut CommandContext| {
+                run_trigger(context, &name, TriggerAction::Simple)
+            })
+            .then(
+                literal("add").then(argument("value", IntegerArgument::new()).executes(
+                    |(((), name), value): (ObjectiveArgs, i32), context: &mut CommandContext| {
+                        run_trigger(context, &name, TriggerAction::Add(value))
+                    },
+                )),
+            )
+            .then(
+                literal("set").then(argument("value", IntegerArgument::new()).executes(
+                    |(((), name), value): (ObjectiveArgs, i32), context: &mut CommandContext| {
+                        run_trigger(context, &name, TriggerAction::Set(value))
+                    },
+                )),
+            ),
+    )
+}
+
+/// Applies `action` to the score of `holder` in the trigger `name` and disables it again.
+///
+/// Returns the objective, the new score and the packets announcing it.
+fn apply_trigger(
+    scoreboard: &mut Scoreboard,
+    holder: &str,
+    name: &str,
+    action: TriggerAction,
+) -> Result<(Objective, i32, Vec<ScoreboardPacket>), CommandError> {
+    let objective = scoreboard.objective(name).cloned().ok_or_else(|| {
+        failed(translations::ARGUMENTS_OBJECTIVE_NOT_FOUND.message([TextComponent::from(name)]))
+    })?;
+    if objective.criteria != ObjectiveCriteria::Trigger {
+        return Err(failed(translations::COMMANDS_TRIGGER_FAILED_INVALID.msg()));
+    }
+    let (score, packets) = scoreboard
+        .trigger(holder, name, |score| match action {
+            TriggerAction::Simple => score.wrapping_add(1),
+            TriggerAction::Add(value) => score.wrapping_add(value),
+            TriggerAction::Set(value) => value,
+        })
+        .ok_or_else(|| failed(translations::COMMANDS_TRIGGER_FAILED_UNPRIMED.msg()))?;
+    Ok((objective, score, packets))
+}
+
+fn run_trigger(
+    context: &mut CommandContext,
+    name: &str,
+    action: TriggerAction,
+) -> Result<(), CommandError> {
+    let Some(player) = context.player.as_ref() else {
+        return Err(failed(translations::PERMISSIONS_REQUIRES_PLAYER.msg()));
+    };
+    let holder = player.gameprofile.name.clone();
+    let (objective, score, packets) =
+        apply_trigger(&mut context.server.scoreboard.lock(), &holder, name, action)?;
+    context.server.broadcast_scoreboard(&packets);
+
+    let objective = objective.formatted_display_name();
+    let message = match action {
+        TriggerAction::Simple => translations::COMMANDS_TRIGGER_SIMPLE_SUCCESS.message([objective]),
+        TriggerAction::Add(value) => translations::COMMANDS_TRIGGER_ADD_SUCCESS
+            .message([objective, TextComponent::from(value.to_string())]),
+        TriggerAction::Set(value) => translations::COMMANDS_TRIGGER_SET_SUCCESS
+            .message([objective, TextComponent::from(value.to_string())]),
+    };
+    context.send_success(&message.into(), true);
+    context.result = score;
+    Ok(())
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use text_components::TextComponent;
+
+    use super::{TriggerAction, apply_trigger};
+    use crate::scoreboard::{ObjectiveCriteria, Scoreboard};
+
+    #[test]
+    fn only_enabled_triggers_change() {
+        let mut scoreboard = Scoreboard::default();
+        for (name, criteria) in [
+            ("vote", ObjectiveCriteria::Trigger),
+            ("quest", ObjectiveCriteria::Trigger),
+            ("kills", ObjectiveCriteria::Dummy),
+        ] {
+            scoreboard.add_objective(name.to_owned(), criteria, TextComponent::plain(name));
+        }
+        assert!(scoreboard.enable_trigger("Steve", "vote").0);
+
+        let (_, score, _) = apply_trigger(&mut scoreboard, "Steve", "vote", TriggerAction::Simple)
+            .expect("vote is enabled");
+        assert_eq!(score, 1);
+        assert_eq!(scoreboard.score("Steve", "vote"), Some(1));
+
+        // Triggering disables the objective again.
+        assert!(apply_trigger(&mut scoreboard, "Steve", "vote", TriggerAction::Add(2)).is_err());
+        assert!(apply_trigger(&mut scoreboard, "Steve", "quest", TriggerAction::Set(3)).is_err());
+
+        scoreboard.enable_trigger("Steve", "kills");
+        assert!(apply_trigger(&mut scoreboard, "Steve", "kills", TriggerAction::Simple).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 //! Handler for the "weather" command.
+use crate::command::LEVEL_GAMEMASTERS;
 use crate::command::arguments::time::TimeArgument;
 use crate::command::commands::{
     CommandExecutor, CommandHandlerBuilder, CommandHandlerDyn, argument, literal,
@@ -15,19 +16,29 @@ pub fn command_handler() -> impl CommandHandlerDyn {
         "Changes the weather in the current world.",
         "minecraft:command.weather",
     )
+    .requires_level(LEVEL_GAMEMASTERS)
     .then(
         literal("rain")
-            .then(argument("duration", TimeArgument).executes(WeatherCommandExecutor::Rain))
+            .then(
+                argument("duration", TimeArgument::with_min(1))
+                    .executes(WeatherCommandExecutor::Rain),
+            )
             .executes(WeatherCommandExecutor::Rain),
     )
     .then(
         literal("thunder")
-            .then(argument("duration", TimeArgument).executes(WeatherCommandExecutor::Thunder))
+            .then(
+                argument("duration", TimeArgument::with_min(1))
+                    .executes(WeatherCommandExecutor::Thunder),
+            )
             .executes(WeatherCommandExecutor::Thunder),
     )
     .then(
         literal("clear")
-            .then(argument("duration", TimeArgument).executes(WeatherCommandExecutor::Clear))
+            .then(
+                argument("duration", TimeArgument::with_min(1))
+                    .executes(WeatherCommandExecutor::Clear),
+            )
             .executes(WeatherCommandExecutor::Clear),
     )
 }
@@ -50,41 +61,56 @@ impl CommandExecutor<()> for WeatherCommandExecutor {
     }
 }
 
-impl CommandExecutor<((), i32)> for WeatherCommandExecutor {
-    fn execute(&self, args: ((), i32), context: &mut CommandContext) -> Result<(), CommandError> {
-        let ((), duration) = args;
-        let world = &context.world;
-        let mut lock = world.level_data.write();
-        let (clear_weather_time, weather_time, raining, thundering) = match self {
+impl WeatherCommandExecutor {
+    /// Returns the clear weather time, weather time, raining and thundering flags to set, like
+    /// vanilla's `ServerLevel.setWeatherParameters` calls.
+    const fn parameters(&self, duration: i32) -> (i32, i32, bool, bool) {
+        match self {
             WeatherCommandExecutor::Clear => (duration, 0, false, false),
             WeatherCommandExecutor::Rain => (0, duration, true, false),
             WeatherCommandExecutor::Thunder => (0, duration, true, true),
-        };
-
-        lock.set_clear_weather_time(clear_weather_time);
-        lock.set_rain_time(weather_time);
-        lock.set_thunder_time(weather_time);
-        lock.set_raining(raining);
-        lock.set_thundering(thundering);
+        }
+    }
+}
 
-        match self {
-            WeatherCommandExecutor::Clear => {
-                context
-                    .sender
-                    .send_message(&translations::COMMANDS_WEATHER_SET_CLEAR.msg().into());
-            }
-            WeatherCommandExecutor::Rain => {
-                context
-                    .sender
-                    .send_message(&translations::COMMANDS_WEATHER_SET_RAIN.msg().into());
-            }
-            WeatherCommandExecutor::Thunder => {
-                context
-                    .sender
-                    .send_message(&translations::COMMANDS_WEATHER_SET_THUNDER.msg().into());
-            }
+impl CommandExecutor<((), i32)> for WeatherCommandExecutor {
+    fn execute(&self, args: ((), i32), context: &mut CommandContext) -> Result<(), CommandError> {
+        let ((), duration) = args;
+        let (clear_weather_time, weather_time, raining, thundering) = self.parameters(duration);
+        {
+            // The world tick notices the change and sends the weather events to its players.
+            let mut lock = context.world.level_data.write();
+            lock.set_clear_weather_time(clear_weather_time);
+            lock.set_rain_time(weather_time);
+            lock.set_thunder_time(weather_time);
+            lock.set_raining(raining);
+            lock.set_thundering(thundering);
         }
 
+        let message = match self {
+            WeatherCommandExecutor::Clear => &translations::COMMANDS_WEATHER_SET_CLEAR,
+            WeatherCommandExecutor::Rain => &translations::COMMANDS_WEATHER_SET_RAIN,
+            WeatherCommandExecutor::Thunder => &translations::COMMANDS_WEATHER_SET_THUNDER,
+        };
+        context.send_success(&message.msg().into(), true);
+        context.result = duration;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::WeatherCommandExecutor;
+
+    #[test]
+    fn thunder_sets_raining_and_thundering() {
+        assert_eq!(
+            WeatherCommandExecutor::Thunder.parameters(6000),
+            (0, 6000, true, true)
+        );
+        assert_eq!(
+            WeatherCommandExecutor::Clear.parameters(6000),
+            (6000, 0, false, false)
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Handler for the "whitelist" command.
+//! Mirrors `net.minecraft.server.commands.WhitelistCommand`.
+use std::io;
+use std::sync::Arc;
+
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::LEVEL_ADMINS;
+use crate::command::arguments::player::PlayerArgument;
+use crate::command::arguments::text_component::format_list;
+use crate::command::commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal};
+use crate::command::context::CommandContext;
+use crate::command::error::CommandError;
+use crate::player::Player;
+use crate::server::whitelist::WhitelistEntry;
+
+type TargetsArgs = ((), Vec<Arc<Player>>);
+
+/// Handler for the "whitelist" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["whitelist"],
+        "Manages the server whitelist.",
+        "minecraft:command.whitelist",
+    )
+    .requires_level(LEVEL_ADMINS)
+    .then(literal("on").executes(|(), context: &mut CommandContext| set_enabled(context, true)))
+    .then(literal("off").executes(|(), context: &mut CommandContext| set_enabled(context, false)))
+    .then(literal("list").executes(|(), context: &mut CommandContext| list(context)))
+    .then(
+        literal("add").then(argument("targets", PlayerArgument::multiple()).executes(
+            |((), targets): TargetsArgs, context: &mut CommandContext| add(context, &targets),
+        )),
+    )
+    .then(
+        literal("remove").then(argument("targets", PlayerArgument::multiple()).executes(
+            |((), targets): TargetsArgs, context: &mut CommandContext| remove(context, &targets),
+        )),
+    )
+    .then(literal("reload").executes(|(), context: &mut CommandContext| reload(context)))
+}
+
+fn set_enabled(context: &mut CommandContext, enabled: bool) -> Result<(), CommandError> {
+    if !context.server.whitelist.set_enabled(enabled) {
+        let already = if enabled {
+            &translations::COMMANDS_WHITELIST_ALREADY_ON
+        } else {
+            &translations::COMMANDS_WHITELIST_ALREADY_OFF
+        };
+        return Err(failed(already.msg()));
+    }
+    let message = if enabled {
+        context.server.kick_unlisted_players();
+        &translations::COMMANDS_WHITELIST_ENABLED
+    } else {
+        &translations::COMMANDS_WHITELIST_DISABLED
+    };
+    context.send_success(&message.msg().into(), true);
+    context.result = 1;
+    Ok(())
+}
+
+fn list(context: &mut CommandContext) -> Result<(), CommandError> {
+    let names = context.server.whitelist.names();
+    if names.is_empty() {
+        context.send_success(&translations::COMMANDS_WHITELIST_NONE.msg().into(), false);
+    } else {
+        context.send_success(
+            &translations::COMMANDS_WHITELIST_LIST
+                .message([
+                    TextComponent::from(names.len().to_string()),
+                    format_list(
+                        names
+                            .iter()
+                            .map(|name| TextComponent::from(name.as_str()))
+                            .collect(),
+                        &TextComponent::plain(", "),
+                    ),
+                ])
+                .into(),
+            false,
+        );
+    }
+    context.result = i32::try_from(names.len()).unwrap_or(i32::MAX);
+    Ok(())
+}
+
+fn add(context: &mut CommandContext, targets: &[Arc<Player>]) -> Result<(), CommandError> {
+    let mut count = 0;
+    for target in targets {
+        let entry = WhitelistEntry {
+            uuid: target.gameprofile.id,
+            name: target.gameprofile.name.clone(),
+        };
+        if !context
+            .server
+            .whitelist
+            .add(entry)
+            .map_err(|e| save_failed(&e))?
+        {
+            continue;
+        }
+        count += 1;
+        context.send_success(
+            &translations::COMMANDS_WHITELIST_ADD_SUCCESS
+                .message([TextComponent::plain(target.gameprofile.name.clone())])
+                .into(),
+            true,
+        );
+    }
+
+    if count == 0 {
+        return Err(failed(translations::COMMANDS_WHITELIST_ADD_FAILED.msg()));
+    }
+    context.result = count;
+    Ok(())
+}
+
+fn remove(context: &mut CommandContext, targets: &[Arc<Player>]) -> Result<(), CommandError> {
+    let mut count = 0;
+    for target in targets {
+        if !context
+            .server
+            .whitelist
+            .remove(target.gameprofile.id)
+            .map_err(|e| save_failed(&e))?
+        {
+            continue;
+        }
+        count += 1;
+        context.send_success(
+            &translations::COMMANDS_WHITELIST_REMOVE_SUCCESS
+                .message([TextComponent::plain(target.gameprofile.name.clone())])
+                .into(),
+            true,
+        );
+    }
+
+    if count == 0 {
+        return Err(failed(translations::COMMANDS_WHITELIST_REMOVE_FAILED.msg()));
+    }
+    context.server.kick_unlisted_players();
+    context.result = count;
+    Ok(())
+}
+
+fn reload(context: &mut CommandContext) -> Result<(), CommandError> {
+    context.server.whitelist.reload().map_err(|e| {
+        log::error!("Failed to load whitelist.json: {e}");
+        failed(TextComponent::const_plain("Failed to load the whitelist"))
+    })?;
+    context.server.kick_unlisted_players();
+    context.send_success(
+        &translations::COMMANDS_WHITELIST_RELOADED.msg().into(),
+        true,
+    );
+    context.result = 1;
+    Ok(())
+}
+
+fn save_failed(e: &io::Error) -> CommandError {
+    log::error!("Failed to save whitelist.json: {e}");
+    failed(TextComponent::const_plain("Failed to save the whitelist"))
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
@@ -0,0 +1,366 @@
+//! Handler for the "worldborder" command.
+//! Mirrors `net.minecraft.server.commands.WorldBorderCommand`.
+use glam::DVec2;
+use steel_utils::translations;
+use text_components::TextComponent;
+
+use crate::command::{
+    LEVEL_GAMEMASTERS,
+    arguments::{
+        double::DoubleArgument, float::FloatArgument, integer::IntegerArgument, time::TimeArgument,
+        vector2::Vector2Argument,
+    },
+    commands::{CommandHandlerBuilder, CommandHandlerDyn, argument, literal},
+    context::CommandContext,
+    error::CommandError,
+};
+use crate::world::{WorldBorderError, WorldBorderSnapshot};
+
+/// The largest diameter the border can be set to.
+const MAX_SIZE: f64 = 5.999_996_8E7;
+/// The largest absolute center coordinate.
+const MAX_CENTER: f64 = 2.999_998_4E7;
+
+type DistanceArgs = ((), f64);
+type DistanceTimeArgs = (DistanceArgs, i32);
+
+/// Handler for the "worldborder" command.
+#[must_use]
+pub fn command_handler() -> impl CommandHandlerDyn {
+    CommandHandlerBuilder::new(
+        &["worldborder"],
+        "Manages the world border.",
+        "minecraft:command.worldborder",
+    )
+    .requires_level(LEVEL_GAMEMASTERS)
+    .then(
+        literal("add").then(
+            argument(
+                "distance",
+                DoubleArgument::bounded(Some(-MAX_SIZE), Some(MAX_SIZE)),
+            )
+            .executes(
+                |((), distance): DistanceArgs, context: &mut CommandContext| {
+                    let border = context.world.world_border_snapshot();
+                    set_size(context, border.old_size + distance, 0)
+                },
+            )
+            .then(argument("time", TimeArgument::new()).executes(
+                |(((), distance), ticks): DistanceTimeArgs, context: &mut CommandContext| {
+                    let border = context.world.world_border_snapshot();
+                    let ticks = border.lerp_time + i64::from(ticks);
+                    set_size(context, border.old_size + distance, ticks)
+                },
+            )),
+        ),
+    )
+    .then(
+        literal("set").then(
+            argument(
+                "distance",
+                DoubleArgument::bounded(Some(-MAX_SIZE), Some(MAX_SIZE)),
+            )
+            .executes(
+                |((), distance): DistanceArgs, context: &mut CommandContext| {
+                    set_size(context, distance, 0)
+                },
+            )
+            .then(argument("time", TimeArgument::new()).executes(
+                |(((), distance), ticks): DistanceTimeArgs, context: &mut CommandContext| {
+                    set_size(context, distance, i64::from(ticks))
+                },
+            )),
+        ),
+    )
+    .then(literal("center").then(
+        argument("pos", Vector2Argument).executes(
+            |((), pos): ((), DVec2), context: &mut CommandContext| set_center(context, pos),
+        ),
+    ))
+    .then(
+        literal("damage")
+            .then(literal("amount").then(
+                argument("damagePerBlock", FloatArgument::bounded(Some(0.0), None)).executes(
+                    |((), damage): ((), f32), context: &mut CommandContext| {
+                        set_damage_amount(context, damage)
+                    },
+                ),
+            ))
+            .then(literal("buffer").then(
+                argument("distance", FloatArgument::bounded(Some(0.0), None)).executes(
+                    |((), distance): ((), f32), context: &mut CommandContext| {
+                        set_damage_buffer(context, distance)
+                    },
+                ),
+            )),
+    )
+    .then(literal("get").executes(|(), context: &mut CommandContext| {
+        let size = context.world.world_border_snapshot().old_size;
+        context.send_success(
+            &translations::COMMANDS_WORLDBORDER_GET
+                .message([TextComponent::from(format!("{size:.0}"))])
+                .into(),
+            false,
+        );
+        context.result = (size + 0.5).floor() as i32;
+        Ok(())
+    }))
+    .then(
+        literal("warning")
+            .then(literal("distance").then(
+                argument("distance", IntegerArgument::bounded(Some(0), None)).executes(
+                    |((), distance): ((), i32), context: &mut CommandContext| {
+                        set_warning_distance(context, distance)
+                    },
+                ),
+            ))
+            .then(literal("time").then(
+                argument("time", IntegerArgument::bounded(Some(0), None)).executes(
+                    |((), time): ((), i32), context: &mut CommandContext| {
+                        set_warning_time(context, time)
+                    },
+                ),
+            )),
+    )
+}
+
+/// A validated change of the border diameter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Resize {
+    Immediate(f64),
+    Lerp { from: f64, to: f64, ticks: i64 },
+}
+
+/// Vanilla's checks before resizing `border` to `distance` over `ticks`.
+#[expect(
+    clippy::float_cmp,
+    reason = "Vanilla only rejects resizing to exactly the current size."
+)]
+fn resize(border: WorldBorderSnapshot, distance: f64, ticks: i64) -> Result<Resize, CommandError> {
+    let current = border.old_size;
+    if current == distance {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_SET_FAILED_NOCHANGE.msg(),
+        ));
+    }
+    if distance < 1.0 {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_SET_FAILED_SMALL.msg(),
+        ));
+    }
+    if distance > MAX_SIZE {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_SET_FAILED_BIG
+                .message([TextComponent::from(MAX_SIZE.to_string())]),
+        ));
+    }
+    if ticks > 0 {
+        Ok(Resize::Lerp {
+            from: current,
+            to: distance,
+            ticks,
+        })
+    } else {
+        Ok(Resize::Immediate(distance))
+    }
+}
+
+/// Vanilla's checks before moving the center of `border` to `pos`.
+#[expect(
+    clippy::float_cmp,
+    reason = "Vanilla only rejects moving to exactly the current center."
+)]
+fn recenter(border: WorldBorderSnapshot, pos: DVec2) -> Result<(), CommandError> {
+    if border.center_x == pos.x && border.center_z == pos.y {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_CENTER_FAILED.msg(),
+        ));
+    }
+    if pos.x.abs() > MAX_CENTER || pos.y.abs() > MAX_CENTER {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_SET_FAILED_FAR.msg(),
+        ));
+    }
+    Ok(())
+}
+
+fn set_size(context: &mut CommandContext, distance: f64, ticks: i64) -> Result<(), CommandError> {
+    let border = context.world.world_border_snapshot();
+    let size = format!("{distance:.1}");
+    let message = match resize(border, distance, ticks)? {
+        Resize::Immediate(to) => {
+            context
+                .world
+                .set_world_border_size(to)
+                .map_err(border_error)?;
+            translations::COMMANDS_WORLDBORDER_SET_IMMEDIATE.message([TextComponent::from(size)])
+        }
+        Resize::Lerp { from, to, ticks } => {
+            context
+                .world
+                .lerp_world_border_size_between(from, to, ticks)
+                .map_err(border_error)?;
+            let translation = if to > from {
+                &translations::COMMANDS_WORLDBORDER_SET_GROW
+            } else {
+                &translations::COMMANDS_WORLDBORDER_SET_SHRINK
+            };
+            translation.message([
+                TextComponent::from(size),
+                TextComponent::from((ticks / 20).to_string()),
+            ])
+        }
+    };
+    context.send_success(&message.into(), true);
+    context.result = (distance - border.old_size) as i32;
+    Ok(())
+}
+
+fn set_center(context: &mut CommandContext, pos: DVec2) -> Result<(), CommandError> {
+    recenter(context.world.world_border_snapshot(), pos)?;
+    context
+        .world
+        .set_world_border_center(pos.x, pos.y)
+        .map_err(border_error)?;
+    context.send_success(
+        &translations::COMMANDS_WORLDBORDER_CENTER_SUCCESS
+            .message([
+                TextComponent::from(format!("{:.2}", pos.x)),
+                TextComponent::from(format!("{:.2}", pos.y)),
+            ])
+            .into(),
+        true,
+    );
+    Ok(())
+}
+
+#[expect(
+    clippy::float_cmp,
+    reason = "Vanilla only rejects setting exactly the current damage."
+)]
+fn set_damage_amount(context: &mut CommandContext, damage: f32) -> Result<(), CommandError> {
+    let border = context.world.world_border_snapshot();
+    if border.damage_per_block == f64::from(damage) {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_DAMAGE_AMOUNT_FAILED.msg(),
+        ));
+    }
+    context
+        .world
+        .set_world_border_damage_per_block(f64::from(damage))
+        .map_err(border_error)?;
+    context.send_success(
+        &translations::COMMANDS_WORLDBORDER_DAMAGE_AMOUNT_SUCCESS
+            .message([TextComponent::from(format!("{damage:.2}"))])
+            .into(),
+        true,
+    );
+    context.result = damage as i32;
+    Ok(())
+}
+
+#[expect(
+    clippy::float_cmp,
+    reason = "Vanilla only rejects setting exactly the current buffer."
+)]
+fn set_damage_buffer(context: &mut CommandContext, distance: f32) -> Result<(), CommandError> {
+    let border = context.world.world_border_snapshot();
+    if border.safe_zone == f64::from(distance) {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_DAMAGE_BUFFER_FAILED.msg(),
+        ));
+    }
+    context
+        .world
+        .set_world_border_safe_zone(f64::from(distance))
+        .map_err(border_error)?;
+    context.send_success(
+        &translations::COMMANDS_WORLDBORDER_DAMAGE_BUFFER_SUCCESS
+            .message([TextComponent::from(format!("{distance:.2}"))])
+            .into(),
+        true,
+    );
+    context.result = distance as i32;
+    Ok(())
+}
+
+fn set_warning_distance(context: &mut CommandContext, distance: i32) -> Result<(), CommandError> {
+    if context.world.world_border_snapshot().warning_blocks == distance {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_WARNING_DISTANCE_FAILED.msg(),
+        ));
+    }
+    context.world.set_world_border_warning_blocks(distance);
+    context.send_success(
+        &translations::COMMANDS_WORLDBORDER_WARNING_DISTANCE_SUCCESS
+            .message([TextComponent::from(distance.to_string())])
+            .into(),
+        true,
+    );
+    context.result = distance;
+    Ok(())
+}
+
+fn set_warning_time(context: &mut CommandContext, time: i32) -> Result<(), CommandError> {
+    if context.world.world_border_snapshot().warning_time == time {
+        return Err(failed(
+            translations::COMMANDS_WORLDBORDER_WARNING_TIME_FAILED.msg(),
+        ));
+    }
+    context.world.set_world_border_warning_time(time);
+    context.send_success(
+        &translations::COMMANDS_WORLDBORDER_WARNING_TIME_SUCCESS
+            .message([TextComponent::from(time.to_string())])
+            .into(),
+        true,
+    );
+    context.result = time;
+    Ok(())
+}
+
+fn failed(message: impl Into<TextComponent>) -> CommandError {
+    CommandError::CommandFailed(Box::new(message.into()))
+}
+
+fn border_error(error: WorldBorderError) -> CommandError {
+    CommandError::CommandFailed(Box::new(TextComponent::plain(error.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec2;
+
+    use super::{Resize, recenter, resize};
+    use crate::level_data::WorldBorderData;
+    use crate::world::WorldBorder;
+
+    fn border() -> WorldBorder {
+        WorldBorder::new(WorldBorderData::default()).expect("default world border should load")
+    }
+
+    #[test]
+    fn set_changes_the_diameter() {
+        let mut border = border();
+
+        let change = resize(border.snapshot(), 100.0, 0).expect("100 is a valid size");
+        assert_eq!(change, Resize::Immediate(100.0));
+
+        border.set_size(100.0).expect("valid border size");
+        assert!((border.snapshot().new_size - 100.0).abs() < f64::EPSILON);
+        assert!(resize(border.snapshot(), 100.0, 0).is_err());
+    }
+
+    #[test]
+    fn center_moves_the_border_unless_it_is_already_there() {
+        let mut border = border();
+        border.set_center(5.0, 5.0).expect("valid border center");
+
+        assert!(recenter(border.snapshot(), DVec2::ZERO).is_ok());
+
+        border.set_center(0.0, 0.0).expect("valid border center");
+        let snapshot = border.snapshot();
+        assert!(snapshot.center_x.abs() < f64::EPSILON);
+        assert!(snapshot.center_z.abs() < f64::EPSILON);
+        assert!(recenter(snapshot, DVec2::ZERO).is_err());
+    }
+}
@@ -2,6 +2,12 @@
 use std::sync::Arc;
 
 use glam::DVec3;
+use steel_registry::game_rules::{GameRuleRef, GameRuleValue};
+use steel_registry::vanilla_game_rules::{
+    COMMAND_BLOCK_OUTPUT, LOG_ADMIN_COMMANDS, SEND_COMMAND_FEEDBACK,
+};
+use steel_utils::translations;
+use text_components::{Modifier, TextComponent, format::Color};
 
 use crate::command::sender::CommandSender;
 use crate::entity::Entity;
@@ -26,6 +32,11 @@ pub struct CommandContext {
     pub rotation: Option<(f32, f32)>,
     /// The anchor of the command.
     pub anchor: EntityAnchor,
+    /// The value the command returned, read by `/execute store result` and comparators.
+    /// Executors default to `1` on success and can overwrite it.
+    pub result: i32,
+    /// How many times the command succeeded, read by `/execute store success`.
+    pub success_count: u32,
 }
 
 /// The position anchor to use for an entity.
@@ -47,18 +58,23 @@ impl CommandContext {
             .as_ref()
             .map_or(server.overworld().clone(), |p| p.get_world());
         let world_spawn = world.level_data.read().data().spawn.clone();
-        let position = player
-            .as_ref()
-            // TODO: Check this. The default position is the surface of the world center
-            // (Where the compass should point to)
-            .map_or(
-                DVec3::new(
-                    f64::from(world_spawn.x),
-                    f64::from(world_spawn.y),
-                    f64::from(world_spawn.z),
-                ),
-                |p| p.position(),
-            );
+        let position = if let CommandSender::CommandBlock { position, .. } = &sender {
+            let (x, y, z) = position.get_center();
+            DVec3::new(x, y, z)
+        } else {
+            player
+                .as_ref()
+                // TODO: Check this. The default position is the surface of the world center
+                // (Where the compass should point to)
+                .map_or(
+                    DVec3::new(
+                        f64::from(world_spawn.x),
+                        f64::from(world_spawn.y),
+                        f64::from(world_spawn.z),
+                    ),
+                    |p| p.position(),
+                )
+        };
 
         let rotation = player.as_ref().map_or((0.0, 0.0), |p| p.rotation());
 
@@ -70,6 +86,67 @@ impl CommandContext {
             position,
             rotation: Some(rotation),
             anchor: EntityAnchor::default(),
+            result: 0,
+            success_count: 0,
+        }
+    }
+
+    /// Sends command feedback, vanilla's `CommandSourceStack.sendSuccess`.
+    ///
+    /// Players only get feedback while `sendCommandFeedback` is on. With `broadcast_to_ops`,
+    /// other operators also see it as `[Name: message]`, like `/gamemode` and `/op` do.
+    pub fn send_success(&self, message: &TextComponent, broadcast_to_ops: bool) {
+        let send_feedback = self.game_rule_enabled(&SEND_COMMAND_FEEDBACK);
+        let accepts_success = match self.sender {
+            CommandSender::Player(_) => send_feedback,
+            _ => true,
+        };
+        if accepts_success {
+            self.sender.send_message(message);
+        }
+
+        let informs_admins = match self.sender {
+            CommandSender::CommandBlock { .. } => self.game_rule_enabled(&COMMAND_BLOCK_OUTPUT),
+            _ => true,
+        };
+        if broadcast_to_ops && informs_admins {
+            self.broadcast_to_admins(message, send_feedback);
+        }
+    }
+
+    /// Sends an error to the sender in red, vanilla's `CommandSourceStack.sendFailure`.
+    pub fn send_failure(&self, message: &TextComponent) {
+        self.sender.send_message(&message.clone().color(Color::Red));
+    }
+
+    fn broadcast_to_admins(&self, message: &TextComponent, send_feedback: bool) {
+        let message: TextComponent = translations::CHAT_TYPE_ADMIN
+            .message([
+                TextComponent::from(self.sender.to_string()),
+                message.clone(),
+            ])
+            .into();
+        let message = message.color(Color::Gray).italic(true);
+
+        if send_feedback {
+            let source = self.sender.get_player();
+            for player in self.server.get_players() {
+                if self.server.op_list.get(player.gameprofile.id).is_some()
+                    && source.is_none_or(|source| !Arc::ptr_eq(source, &player))
+                {
+                    player.send_message(&message);
+                }
+            }
         }
+
+        if !matches!(self.sender, CommandSender::Console)
+            && self.game_rule_enabled(&LOG_ADMIN_COMMANDS)
+        {
+            log::info!("{message:p}");
+        }
+    }
+
+    fn game_rule_enabled(&self, rule: GameRuleRef) -> bool {
+        self.world.get_game_rule(rule) == GameRuleValue::Bool(true)
     }
 }
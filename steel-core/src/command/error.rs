@@ -12,7 +12,42 @@ pub enum CommandError {
     /// The command could not be executed due to insufficient permissions.
     /// The user attempting to run the command lacks the necessary authorization.
     PermissionDenied,
+    /// The input doesn't match the command's syntax.
+    SyntaxError {
+        /// Byte offset into the command string where parsing stopped.
+        cursor: usize,
+        /// What went wrong at `cursor`.
+        message: Box<TextComponent>,
+    },
     /// A general error occurred during command execution that doesn't fit into
     /// more specific `CommandError` variants.
     CommandFailed(Box<TextComponent>),
 }
+
+/// Returned by a branch of the command tree that doesn't match the arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseFailure {
+    /// How many arguments were left where the branch stopped matching. Counting from the end
+    /// keeps this valid when a redirect only dispatches the tail of the input.
+    pub remaining: usize,
+}
+
+impl ParseFailure {
+    /// A failure at the first of `args`, or at the end of the input if there are none.
+    #[must_use]
+    pub const fn at(args: &[&str]) -> Self {
+        Self {
+            remaining: args.len(),
+        }
+    }
+
+    /// Keeps whichever failure got further into the input, preferring `self` on ties.
+    #[must_use]
+    pub const fn furthest(self, other: Self) -> Self {
+        if other.remaining < self.remaining {
+            other
+        } else {
+            self
+        }
+    }
+}
@@ -8,15 +8,28 @@ pub mod sender;
 use std::sync::Arc;
 
 use steel_protocol::packets::game::{CCommandSuggestions, CCommands, CommandNode, SuggestionEntry};
+use steel_utils::translations;
+use text_components::interactivity::{ClickEvent, HoverEvent};
 use text_components::{Modifier, TextComponent, format::Color};
 
-use crate::command::commands::CommandHandlerDyn;
+use crate::command::commands::{CommandHandlerDyn, ParseResult};
 use crate::command::context::CommandContext;
-use crate::command::error::CommandError;
+use crate::command::error::{CommandError, ParseFailure};
 use crate::command::sender::CommandSender;
 use crate::player::Player;
 use crate::server::Server;
 
+/// The permission level every sender has, vanilla's `Commands.LEVEL_ALL`.
+pub const LEVEL_ALL: u8 = 0;
+/// The permission level that bypasses spawn protection.
+pub const LEVEL_MODERATORS: u8 = 1;
+/// The permission level for cheat commands, and the level of command blocks.
+pub const LEVEL_GAMEMASTERS: u8 = 2;
+/// The permission level for commands managing players, such as `/kick`.
+pub const LEVEL_ADMINS: u8 = 3;
+/// The permission level for commands managing the server, such as `/stop`.
+pub const LEVEL_OWNERS: u8 = 4;
+
 /// A struct that parses and dispatches commands to their appropriate handlers.
 #[derive(Default)]
 pub struct CommandDispatcher {
@@ -29,29 +42,65 @@ impl CommandDispatcher {
     #[must_use]
     pub fn new() -> Self {
         let dispatcher = CommandDispatcher::new_empty();
+        dispatcher.register(commands::ban::command_handler());
+        dispatcher.register(commands::ban_ip::command_handler());
+        dispatcher.register(commands::bossbar::command_handler());
         dispatcher.register(commands::clear::command_handler());
+        dispatcher.register(commands::data::command_handler());
+        dispatcher.register(commands::defaultgamemode::command_handler());
+        dispatcher.register(commands::deop::command_handler());
         dispatcher.register(commands::domain::command_handler());
+        dispatcher.register(commands::effect::command_handler());
         dispatcher.register(commands::enchant::command_handler());
         dispatcher.register(commands::execute::command_handler());
+        dispatcher.register(commands::experience::command_handler());
+        dispatcher.register(commands::fill::command_handler());
+        dispatcher.register(commands::forceload::command_handler());
         dispatcher.register(commands::fly::command_handler());
         dispatcher.register(commands::gamemode::command_handler());
         dispatcher.register(commands::gamerule::command_handler());
+        dispatcher.register(commands::kick::command_handler());
         dispatcher.register(commands::kill::command_handler());
         dispatcher.register(commands::list::command_handler());
         dispatcher.register(commands::locate::command_handler());
         dispatcher.register(commands::give::command_handler());
+        dispatcher.register(commands::help::command_handler());
+        dispatcher.register(commands::me::command_handler());
+        dispatcher.register(commands::msg::command_handler());
+        dispatcher.register(commands::op::command_handler());
+        dispatcher.register(commands::pardon::command_handler());
+        dispatcher.register(commands::pardon_ip::command_handler());
+        dispatcher.register(commands::particle::command_handler());
+        dispatcher.register(commands::playsound::command_handler());
+        dispatcher.register(commands::reload::command_handler());
+        dispatcher.register(commands::reply::command_handler());
+        dispatcher.register(commands::save::save_all_handler());
+        dispatcher.register(commands::save::save_off_handler());
+        dispatcher.register(commands::save::save_on_handler());
+        dispatcher.register(commands::say::command_handler());
+        dispatcher.register(commands::scoreboard::command_handler());
         dispatcher.register(commands::seed::command_handler());
+        dispatcher.register(commands::setblock::command_handler());
+        dispatcher.register(commands::setidletimeout::command_handler());
         dispatcher.register(commands::setworldspawn::command_handler());
+        dispatcher.register(commands::spawnpoint::command_handler());
+        dispatcher.register(commands::spectate::command_handler());
         dispatcher.register(commands::stop::command_handler());
+        dispatcher.register(commands::stopsound::command_handler());
         dispatcher.register(commands::summon::command_handler());
+        dispatcher.register(commands::tag::command_handler());
+        dispatcher.register(commands::team::command_handler());
+        dispatcher.register(commands::teleport::command_handler());
         dispatcher.register(commands::tellraw::command_handler());
         dispatcher.register(commands::tick::command_handler());
         dispatcher.register(commands::time::command_handler());
-        dispatcher.register(commands::tp::command_handler());
+        dispatcher.register(commands::title::command_handler());
+        dispatcher.register(commands::trigger::command_handler());
         dispatcher.register(commands::weather::command_handler());
+        dispatcher.register(commands::whitelist::command_handler());
+        dispatcher.register(commands::worldborder::command_handler());
         dispatcher.register(commands::difficulty::command_handler());
         dispatcher.register(commands::steel::command_handler());
-        dispatcher.register(commands::xp::command_handler());
         dispatcher
     }
 
@@ -65,11 +114,12 @@ impl CommandDispatcher {
 
     /// Executes a command.
     pub fn handle_command(&self, sender: CommandSender, command: String, server: &Arc<Server>) {
-        let mut context = CommandContext::new(sender.clone(), server.clone());
+        let mut context = CommandContext::new(sender, server.clone());
 
-        if let Err(error) = Self::split_command(&command)
-            .and_then(|(command, args)| self.execute(command, &args, &mut context, server))
-        {
+        if let Err(error) = Self::split_command(&command).and_then(|(name, args)| {
+            self.execute(name, &args, &mut context, server)
+                .unwrap_or_else(|failure| Err(syntax_error(&command, args.len() + 1, failure)))
+        }) {
             let text = match error {
                 CommandError::InvalidConsumption(s) => {
                     log::error!(
@@ -89,11 +139,19 @@ impl CommandDispatcher {
                         "I'm sorry, but you do not have permission to perform this command. Please contact the server administrator if you believe this is an error.",
                     )
                 }
+                CommandError::SyntaxError { cursor, message } => {
+                    context.send_failure(&message);
+                    // The context is already colored, so it must not be turned red
+                    context
+                        .sender
+                        .send_message(&syntax_error_context(&command, cursor));
+                    return;
+                }
                 CommandError::CommandFailed(text_component) => *text_component,
             };
 
             // TODO: Use vanilla error messages
-            sender.send_message(&text.color(Color::Red));
+            context.send_failure(&text);
         }
     }
 
@@ -104,23 +162,50 @@ impl CommandDispatcher {
         command_args: &[&str],
         context: &mut CommandContext,
         server: &Arc<Server>,
-    ) -> Result<(), CommandError> {
-        let Some(handler) = self.handlers.read_sync(command, |_, v| v.clone()) else {
-            return Err(CommandError::CommandFailed(Box::new(
-                format!("Command {command} does not exist").into(),
-            )));
+    ) -> ParseResult {
+        let case_insensitive = server.config.case_insensitive_commands;
+        let Some(handler) = self.find_handler(command, case_insensitive) else {
+            return Err(ParseFailure {
+                remaining: command_args.len() + 1,
+            });
         };
 
-        // TODO: Implement permission checking logic here
-        // if let CommandSender::Player(&player) = sender
-        //     && !server.player_has_permission(player, &handler.permission)
-        // {
-        //     return Err(PermissionDenied);
-        // };
+        // TODO: Also check `handler.permission` once there is a permission system
+        if context.sender.permission_level() < handler.permission_level() {
+            return Ok(Err(CommandError::PermissionDenied));
+        }
 
         handler.execute(command_args, context, server)
     }
 
+    /// Looks up a handler by name. Registered names are lowercase, so ignoring case only needs
+    /// a second lookup.
+    fn find_handler(
+        &self,
+        name: &str,
+        case_insensitive: bool,
+    ) -> Option<Arc<dyn CommandHandlerDyn + Send + Sync>> {
+        self.handlers.read_sync(name, |_, v| v.clone()).or_else(|| {
+            case_insensitive
+                .then(|| name.to_ascii_lowercase())
+                .and_then(|name| self.handlers.read_sync(name.as_str(), |_, v| v.clone()))
+        })
+    }
+
+    /// Returns the handlers a sender with permission `level` can run, sorted by primary name.
+    #[must_use]
+    pub fn usable_handlers(&self, level: u8) -> Vec<Arc<dyn CommandHandlerDyn + Send + Sync>> {
+        let mut handlers = Vec::with_capacity(self.handlers.len());
+        self.handlers.iter_sync(|command, handler| {
+            if *command == handler.names()[0] && level >= handler.permission_level() {
+                handlers.push(handler.clone());
+            }
+            true
+        });
+        handlers.sort_unstable_by_key(|handler| handler.names()[0]);
+        handlers
+    }
+
     /// Parses a command string into its components.
     fn split_command(command: &str) -> Result<(&str, Box<[&str]>), CommandError> {
         let command = command.trim();
@@ -140,19 +225,18 @@ impl CommandDispatcher {
         Ok((command, command_args.split_whitespace().collect()))
     }
 
-    /// Generates the `CCommands` packet, containing the usage information of every registered commands.
-    pub fn get_commands(&self) -> CCommands {
+    /// Generates the `CCommands` packet, containing the usage information of every command a
+    /// sender with permission `level` can run.
+    pub fn get_commands(&self, level: u8) -> CCommands {
         let mut nodes = Vec::with_capacity(self.handlers.len() + 1);
         nodes.push(CommandNode::new_root());
 
         let mut root_children = Vec::with_capacity(self.handlers.len());
         self.handlers.iter_sync(|command, handler| {
-            if *command != handler.names()[0] {
+            if *command != handler.names()[0] || level < handler.permission_level() {
                 return true;
             }
 
-            // TODO: Implement permission checking logic here
-
             handler.usage(&mut nodes, &mut root_children);
             true
         });
@@ -181,6 +265,15 @@ impl CommandDispatcher {
         }
     }
 
+    /// Copies the handlers of `previous` over under the names this dispatcher doesn't use,
+    /// so a rebuilt dispatcher keeps what was registered besides the built-in commands.
+    pub fn carry_over(&self, previous: &CommandDispatcher) {
+        previous.handlers.iter_sync(|name, handler| {
+            let _ = self.handlers.insert_sync(name, handler.clone());
+            true
+        });
+    }
+
     /// Handles a command suggestion request from a player.
     pub fn handle_player_suggestions(
         &self,
@@ -214,20 +307,26 @@ impl CommandDispatcher {
             parts.push("");
         }
 
+        let level = sender.permission_level();
+
         // If empty or typing command name, suggest command names
         if parts.is_empty() || (parts.len() == 1 && !has_trailing_space) {
             let prefix = parts.first().copied().unwrap_or("");
-            let suggestions = self.get_command_suggestions(prefix);
+            let suggestions = self.get_command_suggestions(prefix, level);
             // Start position is 1 (after the slash)
             return (suggestions, 1, prefix.len() as i32);
         }
 
         // Get the command handler
         let command_name = parts[0];
-        let Some(handler) = self.handlers.read_sync(command_name, |_, v| v.clone()) else {
+        let case_insensitive = server.config.case_insensitive_commands;
+        let Some(handler) = self.find_handler(command_name, case_insensitive) else {
             // Unknown command - no suggestions
             return (vec![], 0, 0);
         };
+        if level < handler.permission_level() {
+            return (vec![], 0, 0);
+        }
 
         // Calculate where args start (after "command_name ")
         let args_start_pos = command_name.len() + 1; // +1 for space
@@ -248,14 +347,18 @@ impl CommandDispatcher {
         }
     }
 
-    /// Gets command name suggestions matching the given prefix.
-    fn get_command_suggestions(&self, prefix: &str) -> Vec<SuggestionEntry> {
+    /// Gets the names of commands a sender with permission `level` can run that match the given
+    /// prefix.
+    fn get_command_suggestions(&self, prefix: &str, level: u8) -> Vec<SuggestionEntry> {
         let mut suggestions = Vec::new();
         let prefix_lower = prefix.to_lowercase();
 
         self.handlers.iter_sync(|name, handler| {
             // Only include primary command names (not aliases)
-            if *name == handler.names()[0] && name.to_lowercase().starts_with(&prefix_lower) {
+            if *name == handler.names()[0]
+                && level >= handler.permission_level()
+                && name.to_lowercase().starts_with(&prefix_lower)
+            {
                 suggestions.push(SuggestionEntry::new(*name));
             }
             true
@@ -265,3 +368,315 @@ impl CommandDispatcher {
         suggestions
     }
 }
+
+/// Turns a parse failure into an error pointing at the token it happened at. `tokens` is how many
+/// whitespace separated tokens `input` has, including the command name.
+fn syntax_error(input: &str, tokens: usize, failure: ParseFailure) -> CommandError {
+    let index = tokens.saturating_sub(failure.remaining);
+    let message = if index == 0 || failure.remaining == 0 {
+        translations::COMMAND_UNKNOWN_COMMAND.msg()
+    } else {
+        translations::COMMAND_UNKNOWN_ARGUMENT.msg()
+    };
+    CommandError::SyntaxError {
+        cursor: token_start(input, index),
+        message: Box::new(message.into()),
+    }
+}
+
+/// Returns the byte offset of the `index`th whitespace separated token of `input`, or of the end
+/// of the input if there aren't that many.
+fn token_start(input: &str, index: usize) -> usize {
+    let mut offset = 0;
+    let mut rest = input;
+    for _ in 0..index {
+        let trimmed = rest.trim_start();
+        let len = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        offset += rest.len() - trimmed.len() + len;
+        rest = &trimmed[len..];
+    }
+    if rest.trim().is_empty() {
+        return input.trim_end().len();
+    }
+    offset + rest.len() - rest.trim_start().len()
+}
+
+/// Builds a green `[x, y, z]` that suggests `/tp @s x y z` when clicked, the coordinate
+/// feedback vanilla commands like `/locate` give.
+pub(crate) fn clickable_position(x: i32, y: i32, z: i32) -> TextComponent {
+    clickable_coordinates(&x.to_string(), &y.to_string(), &z.to_string())
+}
+
+/// Like [`clickable_position`], for coordinates that may be relative such as a `~` height.
+pub(crate) fn clickable_coordinates(x: &str, y: &str, z: &str) -> TextComponent {
+    let coordinates = TextComponent::plain("[")
+        .add_child(
+            translations::CHAT_COORDINATES
+                .message([
+                    TextComponent::from(x.to_owned()),
+                    TextComponent::from(y.to_owned()),
+                    TextComponent::from(z.to_owned()),
+                ])
+                .component(),
+        )
+        .add_child(TextComponent::plain("]"))
+        .color(Color::Green);
+    suggest_teleport(coordinates, x, y, z)
+}
+
+/// Makes `component` suggest `/tp @s x y z` when clicked, with the same tooltip as
+/// [`clickable_coordinates`]. For feedback that already spells out the coordinates.
+pub(crate) fn suggest_teleport(
+    component: TextComponent,
+    x: &str,
+    y: &str,
+    z: &str,
+) -> TextComponent {
+    component
+        .hover_event(HoverEvent::show_text(
+            &translations::CHAT_COORDINATES_TOOLTIP,
+        ))
+        .click_event(ClickEvent::suggest_command(format!("/tp @s {x} {y} {z}")))
+}
+
+/// Shows the input up to `cursor` followed by a `<--[HERE]` marker, like vanilla's
+/// `Commands.performCommand`. Clicking it puts the command back into the chat box.
+fn syntax_error_context(input: &str, cursor: usize) -> TextComponent {
+    let input = input.trim_end();
+    let cursor = cursor.min(input.len());
+    let (before, after) = input.split_at(cursor);
+
+    // Only the last 10 characters before the cursor are shown
+    let start = before.char_indices().rev().nth(9).map_or(0, |(i, _)| i);
+    let mut component = TextComponent::plain("")
+        .color(Color::Gray)
+        .click_event(ClickEvent::suggest_command(format!("/{input}")));
+    if start > 0 {
+        component = component.add_child(TextComponent::plain("..."));
+    }
+    component = component.add_child(TextComponent::plain(before[start..].to_string()));
+    if !after.is_empty() {
+        component = component.add_child(
+            TextComponent::plain(after.to_string())
+                .color(Color::Red)
+                .underlined(true),
+        );
+    }
+    component.add_child(
+        TextComponent::from(translations::COMMAND_CONTEXT_HERE.msg())
+            .color(Color::Red)
+            .italic(true),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use steel_protocol::packets::game::CommandNode;
+    use steel_utils::translations;
+    use text_components::TextComponent;
+    use text_components::format::Color;
+    use text_components::interactivity::{ClickEvent, HoverEvent};
+
+    use super::{
+        CommandDispatcher, LEVEL_ALL, LEVEL_GAMEMASTERS, LEVEL_OWNERS, clickable_position,
+        syntax_error_context, token_start,
+    };
+    use crate::command::commands::CommandHandlerBuilder;
+    use crate::command::context::CommandContext;
+    use crate::command::error::CommandError;
+    use crate::command::sender::CommandSender;
+    use crate::test_support::server::{add_player, run_command, test_server};
+
+    #[test]
+    fn clickable_positions_suggest_a_teleport() {
+        let component = clickable_position(12, 64, -34);
+
+        assert_eq!(component.format.color, Some(Color::Green));
+        assert!(matches!(
+            component.interactions.click,
+            Some(ClickEvent::SuggestCommand { ref command })
+                if command.as_ref() == "/tp @s 12 64 -34"
+        ));
+        assert!(matches!(
+            component.interactions.hover,
+            Some(HoverEvent::ShowText { .. })
+        ));
+    }
+
+    #[test]
+    fn aliases_share_the_primary_handler() {
+        let dispatcher = CommandDispatcher::new_empty();
+        dispatcher.register(
+            CommandHandlerBuilder::new(
+                &["teleport", "tp"],
+                "Teleports.",
+                "minecraft:command.teleport",
+            )
+            .executes(|(), _context: &mut CommandContext| Ok(())),
+        );
+
+        let primary = dispatcher
+            .handlers
+            .read_sync("teleport", |_, handler| handler.clone())
+            .expect("primary name is registered");
+        let alias = dispatcher
+            .handlers
+            .read_sync("tp", |_, handler| handler.clone())
+            .expect("alias is registered");
+        assert!(Arc::ptr_eq(&primary, &alias));
+
+        let suggestions = dispatcher.get_command_suggestions("t", LEVEL_ALL);
+        let names: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(names, ["teleport"]);
+    }
+
+    #[test]
+    fn command_tree_hides_commands_above_the_senders_level() {
+        let dispatcher = CommandDispatcher::new_empty();
+        dispatcher.register(
+            CommandHandlerBuilder::new(&["list"], "Lists players.", "minecraft:command.list")
+                .executes(|(), _context: &mut CommandContext| Ok(())),
+        );
+        dispatcher.register(
+            CommandHandlerBuilder::new(&["stop"], "Stops the server.", "minecraft:command.stop")
+                .requires_level(LEVEL_OWNERS)
+                .executes(|(), _context: &mut CommandContext| Ok(())),
+        );
+
+        let root_names = |level| {
+            let commands = dispatcher.get_commands(level);
+            let CommandNode::Root { children } = &commands.nodes[0] else {
+                panic!("the first node is the root");
+            };
+            let mut names: Vec<String> = children
+                .iter()
+                .filter_map(|&child| match &commands.nodes[child as usize] {
+                    CommandNode::Literal { name, .. } => Some(name.to_string()),
+                    _ => None,
+                })
+                .collect();
+            names.sort_unstable();
+            names
+        };
+        assert_eq!(root_names(LEVEL_ALL), ["list"]);
+        assert_eq!(root_names(LEVEL_OWNERS), ["list", "stop"]);
+
+        let suggestions = dispatcher.get_command_suggestions("", LEVEL_ALL);
+        let names: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(names, ["list"]);
+    }
+
+    #[test]
+    fn executors_report_their_result_to_the_caller() {
+        let server = test_server();
+        let dispatcher = CommandDispatcher::new_empty();
+        dispatcher.register(
+            CommandHandlerBuilder::new(&["five"], "Returns 5.", "steel:command.five").executes(
+                |(), context: &mut CommandContext| {
+                    context.result = 5;
+                    Ok(())
+                },
+            ),
+        );
+        dispatcher.register(
+            CommandHandlerBuilder::new(&["fails"], "Always fails.", "steel:command.fails")
+                .executes(|(), _context: &mut CommandContext| {
+                    Err(CommandError::CommandFailed(Box::new(TextComponent::plain(
+                        "no",
+                    ))))
+                }),
+        );
+
+        let mut context = CommandContext::new(CommandSender::capture(LEVEL_OWNERS), server.clone());
+        assert!(matches!(
+            dispatcher.execute("five", &[], &mut context, &server),
+            Ok(Ok(()))
+        ));
+        assert_eq!((context.result, context.success_count), (5, 1));
+
+        assert!(matches!(
+            dispatcher.execute("fails", &[], &mut context, &server),
+            Ok(Err(_))
+        ));
+        assert_eq!((context.result, context.success_count), (0, 0));
+    }
+
+    #[test]
+    fn malformed_integer_reports_where_it_started() {
+        let server = test_server();
+        add_player(&server, "Steve");
+        let sender = CommandSender::capture(LEVEL_GAMEMASTERS);
+
+        run_command(&server, sender.clone(), "xp add Steve abc levels");
+
+        // `abc` is where `<amount>` failed, and the context is sent with its own colors
+        let captured: Vec<_> = sender
+            .captured()
+            .iter()
+            .map(TextComponent::to_nbt_tag)
+            .collect();
+        assert_eq!(
+            captured,
+            [
+                TextComponent::from(translations::COMMAND_UNKNOWN_ARGUMENT.msg())
+                    .color(Color::Red)
+                    .to_nbt_tag(),
+                syntax_error_context("xp add Steve abc levels", 13).to_nbt_tag()
+            ]
+        );
+    }
+
+    #[test]
+    fn token_start_skips_repeated_whitespace() {
+        assert_eq!(token_start("  say  hi", 0), 2);
+        assert_eq!(token_start("  say  hi", 1), 7);
+        // Missing arguments point at the end of the input
+        assert_eq!(token_start("time set ", 2), 8);
+    }
+
+    #[test]
+    fn command_names_ignore_case_only_when_enabled() {
+        let dispatcher = CommandDispatcher::new_empty();
+        dispatcher.register(
+            CommandHandlerBuilder::new(
+                &["gamemode"],
+                "Sets the game mode.",
+                "minecraft:command.gamemode",
+            )
+            .executes(|(), _context: &mut CommandContext| Ok(())),
+        );
+
+        assert!(dispatcher.find_handler("gamemode", false).is_some());
+        assert!(dispatcher.find_handler("GAMEMODE", false).is_none());
+        assert!(dispatcher.find_handler("gamemode", true).is_some());
+        assert!(dispatcher.find_handler("GameMode", true).is_some());
+    }
+
+    #[test]
+    fn rebuilt_dispatchers_keep_other_registrations() {
+        let previous = CommandDispatcher::new_empty();
+        previous.register(
+            CommandHandlerBuilder::new(&["list"], "Old version.", "minecraft:command.list")
+                .executes(|(), _context: &mut CommandContext| Ok(())),
+        );
+        previous.register(
+            CommandHandlerBuilder::new(&["added", "new"], "Shows up.", "steel:command.added")
+                .executes(|(), _context: &mut CommandContext| Ok(())),
+        );
+
+        let rebuilt = CommandDispatcher::new_empty();
+        let list = CommandHandlerBuilder::new(&["list"], "New version.", "minecraft:command.list")
+            .executes(|(), _context: &mut CommandContext| Ok(()));
+        rebuilt.register(list);
+        rebuilt.carry_over(&previous);
+
+        assert!(rebuilt.find_handler("added", false).is_some());
+        assert!(rebuilt.find_handler("new", false).is_some());
+        let list = rebuilt
+            .find_handler("list", false)
+            .expect("list is registered");
+        assert_eq!(list.description(), "New version.");
+    }
+}
@@ -1,7 +1,9 @@
 //! Module defining the sender of a command.
 use std::{fmt, sync::Arc};
+use steel_utils::{BlockPos, locks::SyncMutex, text::render_ansi};
 use text_components::TextComponent;
 
+use crate::command::{LEVEL_GAMEMASTERS, LEVEL_OWNERS};
 use crate::player::Player;
 
 /// The sender of a command.
@@ -13,9 +15,42 @@ pub enum CommandSender {
     Console,
     /// The command was sent via Rcon.
     Rcon,
+    /// The command was run by a command block.
+    CommandBlock {
+        /// The position of the command block.
+        position: BlockPos,
+        /// The custom name of the command block, `@` by default.
+        name: String,
+    },
+    /// The command was run programmatically, for example by a plugin. Messages are collected
+    /// instead of being shown to anyone.
+    Capture {
+        /// The messages sent to the sender so far.
+        output: Arc<SyncMutex<Vec<TextComponent>>>,
+        /// The permission level the command runs with.
+        permission_level: u8,
+    },
 }
 
 impl CommandSender {
+    /// Creates a sender that collects its output, see [`Self::captured`].
+    #[must_use]
+    pub fn capture(permission_level: u8) -> Self {
+        Self::Capture {
+            output: Arc::new(SyncMutex::new(Vec::new())),
+            permission_level,
+        }
+    }
+
+    /// Returns the messages a [`Self::Capture`] sender has received, or nothing for other senders.
+    #[must_use]
+    pub fn captured(&self) -> Vec<TextComponent> {
+        match self {
+            Self::Capture { output, .. } => output.lock().clone(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Returns the player if the sender is a player.
     #[must_use]
     pub const fn get_player(&self) -> Option<&Arc<Player>> {
@@ -25,13 +60,32 @@ impl CommandSender {
         }
     }
 
+    /// Returns the permission level of the sender, from [`LEVEL_ALL`](crate::command::LEVEL_ALL)
+    /// to [`LEVEL_OWNERS`].
+    #[must_use]
+    pub fn permission_level(&self) -> u8 {
+        match self {
+            Self::Player(player) => player.permission_level(),
+            Self::Console | Self::Rcon => LEVEL_OWNERS,
+            Self::CommandBlock { .. } => LEVEL_GAMEMASTERS,
+            Self::Capture {
+                permission_level, ..
+            } => *permission_level,
+        }
+    }
+
     /// Sends a system message to the command sender.
     pub fn send_message(&self, text: &TextComponent) {
         match self {
             Self::Player(player) => player.send_message(text),
-            Self::Console => log::info!("{:p}", *text),
-            // TODO: Implement Rcon message sending
-            Self::Rcon => unimplemented!(),
+            Self::Console => log::info!("{}", render_ansi(text)),
+            // TODO: Send as the RCON response once there is an RCON listener
+            Self::Rcon => log::info!("[Rcon] {}", render_ansi(text)),
+            // TODO: Store as the command block's last output once there are command block entities
+            Self::CommandBlock { position, name } => {
+                log::debug!("[{name} at {position:?}] {:p}", *text);
+            }
+            Self::Capture { output, .. } => output.lock().push(text.clone()),
         }
     }
 }
@@ -45,7 +99,58 @@ impl fmt::Display for CommandSender {
                 Self::Player(p) => &p.gameprofile.name,
                 Self::Console => "Server",
                 Self::Rcon => "Rcon",
+                Self::CommandBlock { name, .. } => name,
+                Self::Capture { .. } => "Server",
             }
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::BlockPos;
+    use text_components::TextComponent;
+
+    use super::CommandSender;
+    use crate::command::{LEVEL_GAMEMASTERS, LEVEL_OWNERS};
+
+    #[test]
+    fn console_and_rcon_have_owner_level() {
+        assert_eq!(CommandSender::Console.permission_level(), LEVEL_OWNERS);
+        assert_eq!(CommandSender::Rcon.permission_level(), LEVEL_OWNERS);
+    }
+
+    #[test]
+    fn command_blocks_have_gamemaster_level() {
+        let sender = CommandSender::CommandBlock {
+            position: BlockPos::new(1, 64, -3),
+            name: "@".to_string(),
+        };
+
+        assert_eq!(sender.permission_level(), LEVEL_GAMEMASTERS);
+        assert_eq!(sender.to_string(), "@");
+    }
+
+    #[test]
+    fn capture_collects_messages_in_order() {
+        let sender = CommandSender::capture(LEVEL_GAMEMASTERS);
+        let clone = sender.clone();
+        sender.send_message(&TextComponent::plain("first"));
+        clone.send_message(&TextComponent::plain("second"));
+
+        assert_eq!(sender.permission_level(), LEVEL_GAMEMASTERS);
+        let captured: Vec<_> = sender
+            .captured()
+            .iter()
+            .map(TextComponent::to_nbt_tag)
+            .collect();
+        assert_eq!(
+            captured,
+            [
+                TextComponent::plain("first").to_nbt_tag(),
+                TextComponent::plain("second").to_nbt_tag()
+            ]
+        );
+        assert!(CommandSender::Console.captured().is_empty());
+    }
+}
@@ -52,6 +52,12 @@ pub struct RuntimeConfig {
     pub chat_spam_threshold_seconds: i32,
     /// Vanilla command spam threshold window in seconds
     pub command_spam_threshold_seconds: i32,
+    /// Whether command names and literals match regardless of case.
+    pub case_insensitive_commands: bool,
+    /// Whether the whitelist is enforced when the server starts.
+    pub white_list: bool,
+    /// Minutes a player may stay idle before being kicked when the server starts, 0 to never kick.
+    pub player_idle_timeout: u32,
     /// The compression settings for the server.
     pub compression: Option<CompressionInfo>,
     /// All settings and configurations for server links.
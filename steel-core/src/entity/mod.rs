@@ -4931,6 +4931,15 @@ pub trait LivingEntity: Entity {
         self.living_base().remove_mob_effect(effect)
     }
 
+    /// Returns vanilla `LivingEntity.removeAllEffects()`, which is whether anything was removed.
+    fn remove_all_mob_effects(&self) -> bool {
+        let effects = self.active_mob_effects();
+        for effect in &effects {
+            self.remove_mob_effect(effect.effect());
+        }
+        !effects.is_empty()
+    }
+
     /// Ticks vanilla mob-effect durations.
     fn tick_mob_effects(&self) {
         self.living_base().tick_mob_effects();
@@ -9,12 +9,12 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use steel_registry::REGISTRY;
 use steel_registry::game_rules::{GameRuleValue, GameRuleValues};
-use steel_utils::types::Difficulty;
-use steel_utils::{BlockPos, GlobalPos, Identifier};
+use steel_utils::types::{Difficulty, GameType};
+use steel_utils::{BlockPos, ChunkPos, GlobalPos, Identifier};
 use tokio::fs;
 
 /// Persistent world border data stored with Steel level data.
@@ -82,6 +82,12 @@ pub struct LevelData {
     /// Whether the difficulty is locked.
     #[serde(default)]
     pub difficulty_locked: bool,
+    /// Default game mode set by `/defaultgamemode`, overriding the configured one.
+    #[serde(default)]
+    pub default_gamemode: Option<GameType>,
+    /// Chunks kept loaded by `/forceload`, as chunk `(x, z)` coordinates.
+    #[serde(default)]
+    pub forced_chunks: FxHashSet<(i32, i32)>,
     /// Game rules (stored as name -> value pairs for serialization).
     pub game_rules: FxHashMap<String, GameRuleValue>,
     /// Runtime game rule values (not serialized, loaded from `game_rules`).
@@ -311,6 +317,8 @@ impl LevelData {
             world_border: WorldBorderData::default(),
             difficulty,
             difficulty_locked: false,
+            default_gamemode: None,
+            forced_chunks: FxHashSet::default(),
             game_rules: FxHashMap::default(),
             game_rules_values: GameRuleValues::new(&REGISTRY.game_rules),
             initialized: false,
@@ -318,6 +326,22 @@ impl LevelData {
         }
     }
 
+    /// Whether `/forceload` keeps the chunk at `pos` loaded.
+    #[must_use]
+    pub fn is_chunk_forced(&self, pos: ChunkPos) -> bool {
+        self.forced_chunks.contains(&(pos.0.x, pos.0.y))
+    }
+
+    /// Adds or removes a force-loaded chunk, returning whether anything changed.
+    pub fn set_chunk_forced(&mut self, pos: ChunkPos, forced: bool) -> bool {
+        let key = (pos.0.x, pos.0.y);
+        if forced {
+            self.forced_chunks.insert(key)
+        } else {
+            self.forced_chunks.remove(&key)
+        }
+    }
+
     /// Verifies saved generator metadata against the current config.
     ///
     /// Returns whether missing metadata was adopted and should be saved.
@@ -386,6 +410,31 @@ impl LevelData {
     pub fn set_respawn_data(&mut self, respawn_data: RespawnData) {
         self.respawn = Some(respawn_data);
     }
+
+    /// Moves this world's own spawn point and angle to `respawn_data`.
+    pub const fn set_local_spawn(&mut self, respawn_data: &RespawnData) {
+        self.set_spawn_pos(respawn_data.pos());
+        self.spawn.angle = respawn_data.yaw;
+    }
+}
+
+/// Serialized level data waiting to be written to `level.toml`.
+pub struct PendingLevelDataSave {
+    path: PathBuf,
+    content: String,
+}
+
+impl PendingLevelDataSave {
+    /// Writes the data to disk.
+    pub async fn write(self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(&self.path, self.content).await?;
+
+        log::debug!("Saved level data to {}", self.path.display());
+        Ok(())
+    }
 }
 
 /// Manages level data persistence for a world.
@@ -490,28 +539,37 @@ impl LevelDataManager {
 
     /// Saves the level data to disk if it has been modified.
     pub async fn save(&mut self) -> io::Result<()> {
-        if !self.dirty {
+        let Some(pending) = self.take_pending_save()? else {
             return Ok(());
+        };
+        if let Err(e) = pending.write().await {
+            self.dirty = true;
+            return Err(e);
         }
+        Ok(())
+    }
 
-        let Some(world_path) = &self.path else {
+    /// Serializes the level data if it has been modified and marks it clean.
+    ///
+    /// This lets the file be written without holding the world's lock on the data.
+    /// Callers should [`mark_dirty`](Self::mark_dirty) again if the write fails.
+    pub fn take_pending_save(&mut self) -> io::Result<Option<PendingLevelDataSave>> {
+        if !self.dirty {
+            return Ok(None);
+        }
+
+        let Some(path) = self.path.clone() else {
             self.dirty = false;
-            return Ok(());
+            return Ok(None);
         };
-        if let Some(parent) = world_path.parent() {
-            fs::create_dir_all(parent).await?;
-        }
 
         // Export runtime game rules to serializable format before saving
         self.data.save_game_rules();
 
         let content = toml::to_string_pretty(&self.data)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        fs::write(world_path, content).await?;
         self.dirty = false;
-
-        log::debug!("Saved level data to {}", world_path.display());
-        Ok(())
+        Ok(Some(PendingLevelDataSave { path, content }))
     }
 
     /// Gets the seed.
@@ -742,6 +800,36 @@ mod tests {
         assert_eq!(deserialized.pitch.to_bits(), (-90.0_f32).to_bits());
     }
 
+    #[test]
+    fn default_gamemode_override_is_persisted() {
+        init_test_registry();
+        let mut data = LevelData::new_with_seed(1);
+        assert_eq!(data.default_gamemode, None);
+
+        data.default_gamemode = Some(GameType::Creative);
+
+        let serialized = toml::to_string(&data).expect("level data should serialize");
+        let deserialized: LevelData =
+            toml::from_str(&serialized).expect("level data should deserialize");
+        assert_eq!(deserialized.default_gamemode, Some(GameType::Creative));
+    }
+
+    #[test]
+    fn world_spawn_moves_to_an_absolute_position() {
+        init_test_registry();
+        let mut data = LevelData::new_with_seed(1);
+        let overworld = Identifier::vanilla_static("overworld");
+        let respawn_data =
+            RespawnData::of(overworld.clone(), BlockPos::new(120, 72, -45), 90.0, 0.0);
+
+        data.set_local_spawn(&respawn_data);
+        data.set_respawn_data(respawn_data.clone());
+
+        assert_eq!(data.spawn_pos(), BlockPos::new(120, 72, -45));
+        assert_eq!(data.spawn.angle.to_bits(), 90.0_f32.to_bits());
+        assert_eq!(data.respawn_data_or_local(&overworld), respawn_data);
+    }
+
     #[test]
     fn level_data_uses_legacy_spawn_as_respawn_default() {
         init_test_registry();
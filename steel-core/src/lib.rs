@@ -6,6 +6,7 @@ use crate::chunk::chunk_map::ChunkMap;
 
 pub mod behavior;
 pub mod block_entity;
+pub mod boss_bar;
 pub mod chunk;
 pub mod chunk_saver;
 pub mod command;
@@ -19,6 +20,7 @@ pub mod physics;
 pub mod player;
 pub mod poi;
 pub(crate) mod portal;
+pub mod scoreboard;
 pub mod server;
 #[cfg(test)]
 #[path = "../tests/support/mod.rs"]
@@ -3,6 +3,8 @@
 //! The trait is object-safe to allow using `dyn PlayerConnection` for both real network
 //! connections (`JavaConnection`) and test connections (`FlintConnection`).
 
+use std::net::{IpAddr, SocketAddr};
+
 use enum_dispatch::enum_dispatch;
 use steel_protocol::packet_traits::{ClientPacket, CompressionInfo, EncodedPacket};
 use steel_protocol::packets::common::SClientInformation;
@@ -57,6 +59,11 @@ pub trait NetworkConnection: Send + Sync {
 
     /// Returns whether the connection is closed.
     fn closed(&self) -> bool;
+
+    /// Returns the address the client connected from, `None` for connections without a socket.
+    fn address(&self) -> Option<SocketAddr> {
+        None
+    }
 }
 
 impl NetworkConnection for Box<dyn NetworkConnection> {
@@ -91,6 +98,10 @@ impl NetworkConnection for Box<dyn NetworkConnection> {
     fn closed(&self) -> bool {
         (**self).closed()
     }
+
+    fn address(&self) -> Option<SocketAddr> {
+        (**self).address()
+    }
 }
 
 impl Player {
@@ -129,6 +140,14 @@ impl Player {
         }
     }
 
+    /// Returns the IP address the player connected from, vanilla's `ServerPlayer.getIpAddress`.
+    #[must_use]
+    pub fn ip_address(&self) -> Option<IpAddr> {
+        self.connection
+            .address()
+            .map(|address| address.ip().to_canonical())
+    }
+
     /// Disconnects the player with a reason message.
     pub fn disconnect(&self, reason: impl Into<TextComponent>) {
         self.connection.disconnect_with_reason(reason.into());
@@ -22,7 +22,6 @@ use steel_registry::item_stack::ItemStack;
 use steel_registry::sound_event::{SoundEventHolder, SoundEventRef};
 use steel_registry::{REGISTRY, vanilla_attributes, vanilla_damage_types, vanilla_entities};
 use steel_utils::entity_events::EntityStatus;
-use steel_utils::translations;
 use steel_utils::types::{Difficulty, GameType, InteractionHand};
 use steel_utils::{BlockPos, Identifier, WorldAabb};
 use text_components::TextComponent;
@@ -34,7 +33,6 @@ use crate::behavior::{
 };
 use crate::block_entity::BlockEntity;
 use crate::block_entity::entities::SignBlockEntity;
-use crate::command::commands::gamemode::get_gamemode_translation;
 use crate::enchantment_helper::{self, EnchantmentDamageContext, EnchantmentPostAttackContext};
 use crate::entity::attribute::{AttributeModifier, AttributeModifierOperation};
 use crate::entity::damage::DamageSource;
@@ -1002,12 +1000,6 @@ impl Player {
             self.reset_current_impulse_context();
         }
 
-        self.send_message(
-            &translations::COMMANDS_GAMEMODE_SUCCESS_SELF
-                .message([get_gamemode_translation(gamemode)])
-                .into(),
-        );
-
         true
     }
 
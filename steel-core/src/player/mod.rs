@@ -55,6 +55,7 @@ use enum_dispatch::enum_dispatch;
 use game_mode_state::PlayerGameModeState;
 pub use game_profile::{GameProfile, GameProfileAction};
 use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
 use steel_macros::entity_impl;
 use steel_protocol::packets::game::{
     AttributeSnapshot, CEntityEvent, CPlayerCombatKill, CRespawn, CSetDefaultSpawnPosition,
@@ -81,6 +82,7 @@ use steel_utils::entity_events::EntityStatus;
 
 use arc_swap::ArcSwap;
 use steel_utils::locks::SyncMutex;
+use steel_utils::translations;
 use steel_utils::types::{Difficulty, GameType};
 use text_components::TextComponent;
 use text_components::resolving::TextResolutor;
@@ -88,6 +90,7 @@ use text_components::translation::TranslatedMessage;
 use text_components::{content::Resolvable, custom::CustomData};
 
 use crate::chunk::chunk_request::{ChunkRequestHandle, ChunkRequestState};
+use crate::command::{LEVEL_ADMINS, LEVEL_ALL, LEVEL_GAMEMASTERS, LEVEL_MODERATORS};
 use crate::config::RuntimeConfig;
 use crate::enchantment_helper;
 use crate::entity::damage::DamageSource;
@@ -208,6 +211,9 @@ pub struct Player {
     /// Client lifecycle flags.
     lifecycle: SyncMutex<PlayerLifecycleState>,
 
+    /// When the player last acted, checked against the server's idle timeout.
+    last_action_time: SyncMutex<Instant>,
+
     /// Movement tracking state
     pub(crate) movement: SyncMutex<MovementState>,
 
@@ -256,6 +262,9 @@ pub struct Player {
     /// Player abilities (flight, invulnerability, build permissions, speeds, etc.)
     pub abilities: SyncMutex<Abilities>,
 
+    /// The operator permission level, from `LEVEL_ALL` to `LEVEL_OWNERS`.
+    permission_level: SyncMutex<u8>,
+
     /// Block breaking state machine.
     pub block_breaking: SyncMutex<BlockBreakingManager>,
 
@@ -278,6 +287,29 @@ pub struct Player {
 
     /// Persisted `RootVehicle` payload awaiting live entity restoration.
     pending_root_vehicle: SyncMutex<Option<PendingRootVehicleRestore>>,
+
+    /// The personal respawn point, used instead of the world spawn when set.
+    respawn_config: SyncMutex<Option<RespawnConfig>>,
+}
+
+/// Vanilla `ServerPlayer.RespawnConfig`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RespawnConfig {
+    /// Where the player respawns.
+    pub respawn_data: RespawnData,
+    /// Whether the player respawns here without a bed or respawn anchor, like `/spawnpoint`.
+    pub forced: bool,
+}
+
+/// The entity event the client reads its permission level from.
+const fn permission_level_status(level: u8) -> EntityStatus {
+    match level {
+        LEVEL_ALL => EntityStatus::PermissionLevelAll,
+        LEVEL_MODERATORS => EntityStatus::PermissionLevelModerators,
+        LEVEL_GAMEMASTERS => EntityStatus::PermissionLevelGamemasters,
+        LEVEL_ADMINS => EntityStatus::PermissionLevelAdmins,
+        _ => EntityStatus::PermissionLevelOwners,
+    }
 }
 
 #[derive(Clone)]
@@ -318,7 +350,7 @@ impl PlayerRespawnJob {
         let search = PlayerSpawnSearch::new(
             &target_world,
             respawn_data.pos(),
-            target_world.default_gamemode,
+            target_world.default_gamemode(),
         )?;
         Ok(Self {
             player,
@@ -329,6 +361,23 @@ impl PlayerRespawnJob {
         })
     }
 
+    /// Respawns at a fixed position, skipping the world spawn search.
+    fn at(
+        player: Arc<Player>,
+        source_world: Arc<World>,
+        target_world: Arc<World>,
+        spawn: DeathRespawnSpawn,
+    ) -> Self {
+        let request = target_world.request_player_spawn_chunks(spawn.position);
+        Self {
+            player,
+            source_world,
+            target_world,
+            rotation: spawn.rotation,
+            phase: PlayerRespawnJobPhase::LoadingSpawnChunks { spawn, request },
+        }
+    }
+
     fn still_valid(&self) -> bool {
         !self.player.connection.closed()
             && Arc::ptr_eq(&self.player.get_world(), &self.source_world)
@@ -434,6 +483,49 @@ impl Player {
         self.game_modes.lock().change_current(game_mode)
     }
 
+    /// Returns the player's operator permission level.
+    #[must_use]
+    pub fn permission_level(&self) -> u8 {
+        *self.permission_level.lock()
+    }
+
+    /// Marks the player as active, vanilla's `ServerPlayer.resetLastActionTime`.
+    pub fn reset_last_action_time(&self) {
+        *self.last_action_time.lock() = Instant::now();
+    }
+
+    /// Disconnects the player once they have been idle past the server's idle timeout.
+    fn disconnect_if_idle_too_long(&self) -> bool {
+        let Some(server) = self.server.upgrade() else {
+            return false;
+        };
+        let idle = self.last_action_time.lock().elapsed();
+        if !idle_timeout_exceeded(idle, server.player_idle_timeout()) {
+            return false;
+        }
+        self.disconnect(translations::MULTIPLAYER_DISCONNECT_IDLING.msg());
+        true
+    }
+
+    /// Sets the player's operator permission level and resends what depends on it.
+    pub fn set_permission_level(&self, level: u8) {
+        *self.permission_level.lock() = level;
+        self.send_permission_level();
+        if let Some(server) = self.server.upgrade() {
+            let commands = server.command_dispatcher.read().get_commands(level);
+            self.send_packet(commands);
+        }
+    }
+
+    /// Tells the client its permission level, which unlocks operator features such as the
+    /// game mode switcher. Vanilla: `PlayerList.sendPlayerPermissionLevel`.
+    pub(crate) fn send_permission_level(&self) {
+        self.send_packet(CEntityEvent {
+            entity_id: self.id(),
+            event: permission_level_status(self.permission_level()),
+        });
+    }
+
     /// Creates a new player.
     #[expect(clippy::too_many_arguments, reason = "Player::new is complex")]
     pub fn new(
@@ -453,6 +545,9 @@ impl Player {
 
         let living_base = LivingEntityBase::new(&vanilla_entities::PLAYER);
         let player_uuid = gameprofile.id;
+        let permission_level = server
+            .upgrade()
+            .map_or(LEVEL_ALL, |server| server.op_list.level(player_uuid));
         let world_ref = Arc::downgrade(&world);
         let chat_spam_threshold_seconds = config.chat_spam_threshold_seconds;
         let command_spam_threshold_seconds = config.command_spam_threshold_seconds;
@@ -472,6 +567,7 @@ impl Player {
                 world_ref,
             ),
             lifecycle: SyncMutex::new(PlayerLifecycleState::default()),
+            last_action_time: SyncMutex::new(Instant::now()),
             movement: SyncMutex::new(MovementState::new()),
             entity_data: SyncMutex::new({
                 let mut data = PlayerEntityData::new();
@@ -495,6 +591,7 @@ impl Player {
             teleport_state: SyncMutex::new(TeleportState::new()),
             tick_state: SyncMutex::new(PlayerTickState::new()),
             abilities: SyncMutex::new(Abilities::default()),
+            permission_level: SyncMutex::new(permission_level),
             block_breaking: SyncMutex::new(BlockBreakingManager::new()),
             living_base,
             food_data: SyncMutex::new(FoodData::new()),
@@ -502,6 +599,7 @@ impl Player {
             experience: SyncMutex::new(Experience::default()),
             chunk_send_epoch: SyncMutex::new(0),
             pending_root_vehicle: SyncMutex::new(None),
+            respawn_config: SyncMutex::new(None),
         }
     }
 
@@ -581,6 +679,9 @@ impl Player {
         if self.disconnect_if_vehicle_floating_too_long() {
             return;
         }
+        if self.disconnect_if_idle_too_long() {
+            return;
+        }
 
         self.tick_living_state();
 
@@ -895,6 +996,11 @@ impl Player {
             );
             return;
         };
+        if let Some(job) = self.personal_respawn_job(&server, &player_arc, &source_world) {
+            server.jobs.spawn(job);
+            return;
+        }
+
         let (target_world, respawn_data) =
             match server.respawn_world_and_data_for_domain(source_world.domain()) {
                 Ok(resolved) => resolved,
@@ -920,6 +1026,46 @@ impl Player {
         }
     }
 
+    /// Returns the personal respawn point set for this player, if any.
+    pub fn respawn_config(&self) -> Option<RespawnConfig> {
+        self.respawn_config.lock().clone()
+    }
+
+    /// Vanilla `ServerPlayer.setRespawnPosition`.
+    pub fn set_respawn_position(&self, config: Option<RespawnConfig>) {
+        *self.respawn_config.lock() = config;
+    }
+
+    /// Builds a respawn at the forced personal respawn point when it is in this domain.
+    // TODO: respawn blocks, and falling back when the point is obstructed
+    fn personal_respawn_job(
+        &self,
+        server: &Server,
+        player: &Arc<Player>,
+        source_world: &Arc<World>,
+    ) -> Option<PlayerRespawnJob> {
+        let config = self.respawn_config().filter(|config| config.forced)?;
+        let target_world = server
+            .worlds
+            .get(config.respawn_data.dimension())
+            .filter(|world| world.domain() == source_world.domain())?;
+        let pos = config.respawn_data.pos();
+        let spawn = DeathRespawnSpawn {
+            position: DVec3::new(
+                f64::from(pos.x()) + 0.5,
+                f64::from(pos.y()) + 0.1,
+                f64::from(pos.z()) + 0.5,
+            ),
+            rotation: (config.respawn_data.yaw, config.respawn_data.pitch),
+        };
+        Some(PlayerRespawnJob::at(
+            player.clone(),
+            source_world.clone(),
+            target_world.clone(),
+            spawn,
+        ))
+    }
+
     fn finish_death_respawn(
         self: &Arc<Self>,
         source_world: &Arc<World>,
@@ -1385,6 +1531,10 @@ impl Player {
 
         // Resend client context that is not fully covered by CLogin/CRespawn.
         self.server().resend_player_context(self);
+        if reason == ResetReason::InitialJoin {
+            self.server().send_scoreboard_to_player(self);
+            self.server().send_boss_bars_to_player(self);
+        }
 
         // Add to world / re-enter chunk tracking
         match reason {
@@ -2017,16 +2167,62 @@ impl TextResolutor for Player {
     }
 }
 
+/// Whether a player idle for `idle` has outlasted a timeout of `timeout_minutes`, where 0
+/// never times out.
+fn idle_timeout_exceeded(idle: Duration, timeout_minutes: u32) -> bool {
+    timeout_minutes > 0 && idle > Duration::from_secs(u64::from(timeout_minutes) * 60)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::{Duration, Instant};
+
+    use steel_protocol::packets::game::SPlayerInput;
     use steel_registry::{
         test_support::init_test_registry, vanilla_damage_types, vanilla_game_rules,
     };
     use steel_utils::types::GameType;
 
     use crate::entity::damage::DamageSource;
+    use crate::test_support::server::{add_player, test_server};
 
-    use super::{Player, nullable_game_mode_id};
+    use super::{Player, idle_timeout_exceeded, nullable_game_mode_id};
+
+    #[test]
+    fn idle_timeout_kicks_only_after_the_configured_minutes() {
+        let five_minutes = Duration::from_secs(5 * 60);
+        assert!(!idle_timeout_exceeded(five_minutes, 5));
+        assert!(idle_timeout_exceeded(
+            five_minutes + Duration::from_secs(1),
+            5
+        ));
+        assert!(!idle_timeout_exceeded(
+            Duration::from_secs(7 * 24 * 60 * 60),
+            0
+        ));
+    }
+
+    #[test]
+    fn repeated_identical_input_does_not_keep_a_player_active() {
+        let server = test_server();
+        server.set_player_idle_timeout(1);
+        let steve = add_player(&server, "Steve");
+        let player = &steve.player;
+        player.set_client_loaded(true);
+        let two_minutes_ago = Instant::now() - Duration::from_secs(2 * 60);
+
+        player.handle_player_input(SPlayerInput { flags: 0x01 });
+        *player.last_action_time.lock() = two_minutes_ago;
+        player.handle_player_input(SPlayerInput { flags: 0x01 });
+        assert!(player.last_action_time.lock().elapsed() > Duration::from_secs(60));
+
+        player.handle_player_input(SPlayerInput { flags: 0x00 });
+        assert!(player.last_action_time.lock().elapsed() < Duration::from_secs(60));
+
+        *player.last_action_time.lock() = two_minutes_ago;
+        player.handle_player_input(SPlayerInput { flags: 0x00 });
+        assert!(player.disconnect_if_idle_too_long());
+    }
 
     #[test]
     fn respawn_request_is_allowed_after_dead_reconnect() {
@@ -805,14 +805,22 @@ impl Player {
     pub fn handle_player_input(&self, packet: SPlayerInput) {
         // Vanilla stores the input unconditionally before the guard check.
         let input = PlayerInput::from_flags(packet.flags);
-        self.movement.lock().set_last_client_input(input);
+        let previous = {
+            let mut movement = self.movement.lock();
+            let previous = movement.last_client_input();
+            movement.set_last_client_input(input);
+            previous
+        };
 
         if !self.has_client_loaded() {
             return;
         }
 
-        // TODO: Vanilla calls this.player.resetLastActionTime() here which sets
-        // lastActionTime = Util.getMillis(), preventing idle-kick. Add when idle-kick system is implemented.
+        // Vanilla resets the idle timer on every input packet. Only a change counts here, so a
+        // client resending the same keys still times out.
+        if input != previous {
+            self.reset_last_action_time();
+        }
 
         self.set_crouching(input.shift());
     }
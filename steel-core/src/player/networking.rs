@@ -1,5 +1,6 @@
 //! This module contains the `JavaConnection` struct, which is used to represent a connection to a Java client.
 use std::io::Cursor;
+use std::net::SocketAddr;
 use std::sync::{Arc, Weak};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -34,6 +35,8 @@ use tokio::select;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, error::TryRecvError};
 use tokio_util::sync::CancellationToken;
 
+use crate::command::LEVEL_GAMEMASTERS;
+use crate::command::commands::gamemode;
 use crate::command::sender::CommandSender;
 use crate::player::Player;
 use crate::player::connection::NetworkConnection;
@@ -102,6 +105,7 @@ pub struct JavaConnection {
     compression: Option<CompressionInfo>,
     network_writer: JavaNetworkWriter,
     id: u64,
+    address: SocketAddr,
 
     player: Weak<Player>,
     keep_alive_tracker: SyncMutex<KeepAliveTracker>,
@@ -116,6 +120,7 @@ impl JavaConnection {
         compression: Option<CompressionInfo>,
         network_writer: JavaNetworkWriter,
         id: u64,
+        address: SocketAddr,
         player: Weak<Player>,
     ) -> Self {
         Self {
@@ -124,6 +129,7 @@ impl JavaConnection {
             compression,
             network_writer,
             id,
+            address,
             player,
             keep_alive_tracker: SyncMutex::new(KeepAliveTracker {
                 alive_time: 0,
@@ -314,6 +320,25 @@ impl JavaConnection {
             return Ok(());
         }
 
+        // Vanilla's handlers for these reset the idle timer
+        if matches!(
+            packet.id,
+            play::S_ATTACK
+                | play::S_INTERACT
+                | play::S_CHAT
+                | play::S_CHAT_COMMAND
+                | play::S_CONTAINER_BUTTON_CLICK
+                | play::S_CONTAINER_CLICK
+                | play::S_PLAYER_ACTION
+                | play::S_PLAYER_COMMAND
+                | play::S_SET_CARRIED_ITEM
+                | play::S_SWING
+                | play::S_USE_ITEM
+                | play::S_USE_ITEM_ON
+        ) {
+            player.reset_last_action_time();
+        }
+
         match packet.id {
             play::S_ACCEPT_TELEPORTATION => {
                 player.handle_accept_teleportation(SAcceptTeleportation::read_packet(data)?);
@@ -457,9 +482,15 @@ impl JavaConnection {
                 player.send_packet(CPongResponse::new(packet.time));
             }
             play::S_CHANGE_GAME_MODE => {
-                // TODO: Check player permission level (Or gamemode permission)
                 let packet = SChangeGameMode::read_packet(data)?;
-                player.set_game_mode(packet.gamemode);
+                if player.permission_level() < LEVEL_GAMEMASTERS {
+                    log::warn!(
+                        "Player {} tried to change game mode without permission",
+                        player.gameprofile.name
+                    );
+                } else {
+                    gamemode::set_game_mode(&player, server.clone(), packet.gamemode);
+                }
             }
             play::S_CHANGE_DIFFICULTY => {
                 let packet = SChangeDifficulty::read_packet(data)?;
@@ -637,6 +668,10 @@ impl NetworkConnection for JavaConnection {
     fn closed(&self) -> bool {
         self.cancel_token.is_cancelled()
     }
+
+    fn address(&self) -> Option<SocketAddr> {
+        Some(self.address)
+    }
 }
 
 #[cfg(test)]
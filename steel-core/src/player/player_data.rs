@@ -11,7 +11,7 @@ use crate::{
     inventory::container::Container,
 };
 
-use super::{Player, abilities::Abilities};
+use super::{Player, RespawnConfig, abilities::Abilities};
 
 /// Current data version for player saves.
 /// Increment when making breaking changes to the format.
@@ -105,6 +105,9 @@ pub struct PersistentPlayerData {
 
     /// Vanilla one-player root vehicle tree stored with the player instead of chunk data.
     pub root_vehicle: Option<PersistentRootVehicle>,
+
+    /// The personal respawn point, vanilla's `respawn` tag.
+    pub respawn: Option<RespawnConfig>,
 }
 
 /// A vanilla `RootVehicle` tree persisted with player data.
@@ -221,6 +224,7 @@ impl PersistentPlayerData {
             experience_total,
             score,
             root_vehicle,
+            respawn: player.respawn_config(),
         }
     }
 
@@ -331,6 +335,9 @@ impl PersistentPlayerData {
         // Health
         player.set_health(self.health);
 
+        // Personal respawn point
+        player.set_respawn_position(self.respawn.clone());
+
         // Game mode
         player.restore_game_modes(
             self.game_mode.into(),
@@ -18,14 +18,15 @@ use super::player_data::{
 };
 use crate::chunk_saver::PersistentEntity;
 use crate::config::StorageSelection;
-use crate::player::Player;
+use crate::level_data::RespawnData;
+use crate::player::{Player, RespawnConfig};
 use steel_registry::item_stack::ItemStack;
-use steel_utils::Identifier;
 use steel_utils::locks::{AsyncMutex, SyncMutex};
+use steel_utils::{BlockPos, Identifier};
 
 const PLAYER_MAGIC: [u8; 4] = *b"STLP";
 const GLOBAL_MAGIC: [u8; 4] = *b"STLG";
-const PLAYER_STORAGE_VERSION: u16 = 6;
+const PLAYER_STORAGE_VERSION: u16 = 7;
 const GLOBAL_STORAGE_VERSION: u16 = 1;
 const GLOBAL_PLAYER_DATA_VERSION: i32 = 1;
 
@@ -79,6 +80,7 @@ struct PlayerDataFile {
     experience_total: i32,
     score: i32,
     root_vehicle: Option<RootVehicleFile>,
+    respawn: Option<RespawnFile>,
 }
 
 #[derive(SchemaWrite, SchemaRead)]
@@ -87,6 +89,16 @@ struct RootVehicleFile {
     entity: PersistentEntity,
 }
 
+/// Vanilla's `respawn` tag.
+#[derive(SchemaWrite, SchemaRead)]
+struct RespawnFile {
+    dimension: String,
+    pos: [i32; 3],
+    yaw: f32,
+    pitch: f32,
+    forced: bool,
+}
+
 #[derive(SchemaWrite, SchemaRead)]
 struct AbilitiesFile {
     invulnerable: bool,
@@ -367,6 +379,7 @@ impl PlayerDataFile {
                     attach: root_vehicle.attach,
                     entity: root_vehicle.entity,
                 }),
+            respawn: data.respawn.as_ref().map(RespawnFile::from_config),
         })
     }
 
@@ -428,6 +441,34 @@ impl PlayerDataFile {
                 attach: root_vehicle.attach,
                 entity: root_vehicle.entity,
             }),
+            respawn: self.respawn.map(RespawnFile::into_config).transpose()?,
+        })
+    }
+}
+
+impl RespawnFile {
+    fn from_config(config: &RespawnConfig) -> Self {
+        let pos = config.respawn_data.pos();
+        Self {
+            dimension: config.respawn_data.dimension().to_string(),
+            pos: [pos.x(), pos.y(), pos.z()],
+            yaw: config.respawn_data.yaw,
+            pitch: config.respawn_data.pitch,
+            forced: config.forced,
+        }
+    }
+
+    fn into_config(self) -> io::Result<RespawnConfig> {
+        let dimension = self.dimension.parse::<Identifier>().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid respawn dimension {}", self.dimension),
+            )
+        })?;
+        let [x, y, z] = self.pos;
+        Ok(RespawnConfig {
+            respawn_data: RespawnData::of(dimension, BlockPos::new(x, y, z), self.yaw, self.pitch),
+            forced: self.forced,
         })
     }
 }
@@ -568,6 +609,7 @@ mod tests {
             experience_total: 32,
             score: 9,
             root_vehicle: None,
+            respawn: None,
         }
     }
 
@@ -677,6 +719,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn player_file_roundtrip_preserves_respawn_point() {
+        let mut persistent = sample_player_file(PLAYER_DATA_VERSION)
+            .into_persistent()
+            .expect("player file should convert");
+        let respawn = RespawnConfig {
+            respawn_data: RespawnData::of(
+                Identifier::vanilla_static("the_nether"),
+                BlockPos::new(-12, 70, 48),
+                90.0,
+                -15.0,
+            ),
+            forced: true,
+        };
+        persistent.respawn = Some(respawn.clone());
+
+        let file = PlayerDataFile::from_persistent(&persistent).expect("player data should save");
+        let encoded = encode_player_file(&file).expect("player file should encode");
+        let decoded = decode_player_file(&encoded)
+            .expect("player file should decode")
+            .into_persistent()
+            .expect("player file should convert");
+
+        assert_eq!(decoded.respawn, Some(respawn));
+    }
+
     #[test]
     fn stale_player_payload_version_is_rejected() {
         let file = sample_player_file(PLAYER_DATA_VERSION - 1);
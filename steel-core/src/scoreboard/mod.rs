@@ -0,0 +1,525 @@
+//! The server scoreboard, vanilla's `ServerScoreboard`.
+//!
+//! Objectives are only sent to clients while they are shown in a display slot.
+mod objective;
+mod team;
+
+pub use objective::{DisplaySlot, Objective, ObjectiveCriteria};
+pub use team::{PlayerTeam, TeamColor};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use steel_protocol::packets::game::{
+    CResetScore, CSetDisplayObjective, CSetObjective, CSetPlayerTeam, CSetScore, ObjectiveAction,
+    TeamAction,
+};
+use text_components::TextComponent;
+
+use crate::player::Player;
+use crate::world::World;
+
+/// A packet a scoreboard change sends to every player.
+#[derive(Clone, Debug)]
+pub enum ScoreboardPacket {
+    Objective(CSetObjective),
+    Display(CSetDisplayObjective),
+    Score(CSetScore),
+    Reset(CResetScore),
+    Team(CSetPlayerTeam),
+}
+
+impl ScoreboardPacket {
+    /// Sends the packet to one player.
+    pub fn send(&self, player: &Player) {
+        match self {
+            Self::Objective(packet) => player.send_packet(packet.clone()),
+            Self::Display(packet) => player.send_packet(packet.clone()),
+            Self::Score(packet) => player.send_packet(packet.clone()),
+            Self::Reset(packet) => player.send_packet(packet.clone()),
+            Self::Team(packet) => player.send_packet(packet.clone()),
+        }
+    }
+
+    /// Sends the packet to every player in `world`.
+    pub fn broadcast(&self, world: &World) {
+        match self {
+            Self::Objective(packet) => world.broadcast_to_all(packet.clone()),
+            Self::Display(packet) => world.broadcast_to_all(packet.clone()),
+            Self::Score(packet) => world.broadcast_to_all(packet.clone()),
+            Self::Reset(packet) => world.broadcast_to_all(packet.clone()),
+            Self::Team(packet) => world.broadcast_to_all(packet.clone()),
+        }
+    }
+}
+
+/// The objectives, scores and teams shared by every world.
+// TODO: persist the scoreboard to scoreboard.dat
+#[derive(Default)]
+pub struct Scoreboard {
+    objectives: FxHashMap<String, Objective>,
+    /// Scores by holder, then by objective name.
+    scores: FxHashMap<String, FxHashMap<String, i32>>,
+    display_slots: FxHashMap<DisplaySlot, String>,
+    /// Objectives clients currently know about.
+    tracked: FxHashSet<String>,
+    teams: FxHashMap<String, PlayerTeam>,
+    /// The team of each holder in one.
+    teams_by_holder: FxHashMap<String, String>,
+    /// The trigger objectives each holder may use `/trigger` on, vanilla's unlocked scores.
+    enabled_triggers: FxHashMap<String, FxHashSet<String>>,
+}
+
+impl Scoreboard {
+    /// The objective named `name`.
+    #[must_use]
+    pub fn objective(&self, name: &str) -> Option<&Objective> {
+        self.objectives.get(name)
+    }
+
+    /// Every objective, sorted by name.
+    #[must_use]
+    pub fn objectives(&self) -> Vec<&Objective> {
+        let mut objectives: Vec<_> = self.objectives.values().collect();
+        objectives.sort_by(|a, b| a.name.cmp(&b.name));
+        objectives
+    }
+
+    /// Adds an objective. Returns `false` if one with the same name exists.
+    pub fn add_objective(
+        &mut self,
+        name: String,
+        criteria: ObjectiveCriteria,
+        display_name: TextComponent,
+    ) -> bool {
+        if self.objectives.contains_key(&name) {
+            return false;
+        }
+        let objective = Objective {
+            name: name.clone(),
+            criteria,
+            display_name,
+            render_type: criteria.default_render_type(),
+        };
+        self.objectives.insert(name, objective);
+        true
+    }
+
+    /// Removes an objective with its scores and display slots.
+    pub fn remove_objective(&mut self, name: &str) -> Vec<ScoreboardPacket> {
+        let mut packets = Vec::new();
+        let Some(objective) = self.objectives.get(name).cloned() else {
+            return packets;
+        };
+        self.display_slots.retain(|_, displayed| displayed != name);
+        if self.tracked.remove(name) {
+            packets.extend(self.stop_tracking_packets(&objective));
+        }
+        self.objectives.remove(name);
+        for scores in self.scores.values_mut() {
+            scores.remove(name);
+        }
+        self.scores.retain(|_, scores| !scores.is_empty());
+        for triggers in self.enabled_triggers.values_mut() {
+            triggers.remove(name);
+        }
+        self.enabled_triggers
+            .retain(|_, triggers| !triggers.is_empty());
+        packets
+    }
+
+    /// The objective shown in `slot`.
+    #[must_use]
+    pub fn display_objective(&self, slot: DisplaySlot) -> Option<&Objective> {
+        self.display_slots
+            .get(&slot)
+            .and_then(|name| self.objectives.get(name))
+    }
+
+    /// Shows `objective` in `slot`, or clears it, vanilla's `ServerScoreboard.setDisplayObjective`.
+    pub fn set_display_objective(
+        &mut self,
+        slot: DisplaySlot,
+        objective: Option<&str>,
+    ) -> Vec<ScoreboardPacket> {
+        let mut packets = Vec::new();
+        let objective = objective.and_then(|name| self.objectives.get(name).cloned());
+        let old = self.display_objective(slot).cloned();
+        match &objective {
+            Some(objective) => {
+                self.display_slots.insert(slot, objective.name.clone());
+            }
+            None => {
+                self.display_slots.remove(&slot);
+            }
+        }
+
+        if let Some(old) =
+            old.filter(|old| objective.as_ref().is_none_or(|new| new.name != old.name))
+        {
+            if self.display_slots.values().any(|name| *name == old.name) {
+                packets.push(display_packet(slot, objective.as_ref()));
+            } else if self.tracked.remove(&old.name) {
+                packets.extend(self.stop_tracking_packets(&old));
+            }
+        }
+        if let Some(objective) = objective {
+            if self.tracked.contains(&objective.name) {
+                packets.push(display_packet(slot, Some(&objective)));
+            } else {
+                self.tracked.insert(objective.name.clone());
+                packets.extend(self.start_tracking_packets(&objective));
+            }
+        }
+        packets
+    }
+
+    /// The score of `holder` in `objective`.
+    #[must_use]
+    pub fn score(&self, holder: &str, objective: &str) -> Option<i32> {
+        self.scores.get(holder)?.get(objective).copied()
+    }
+
+    /// The scores of `holder`, sorted by objective name.
+    #[must_use]
+    pub fn holder_scores(&self, holder: &str) -> Vec<(&Objective, i32)> {
+        let mut scores: Vec<_> = self
+            .scores
+            .get(holder)
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, &score)| Some((self.objectives.get(name)?, score)))
+            .collect();
+        scores.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+        scores
+    }
+
+    /// Every holder with a score, sorted by name.
+    #[must_use]
+    pub fn holders(&self) -> Vec<&str> {
+        let mut holders: Vec<_> = self.scores.keys().map(String::as_str).collect();
+        holders.sort_unstable();
+        holders
+    }
+
+    /// Sets the score of `holder` in `objective`.
+    pub fn set_score(
+        &mut self,
+        holder: &str,
+        objective: &str,
+        score: i32,
+    ) -> Vec<ScoreboardPacket> {
+        if !self.objectives.contains_key(objective) {
+            return Vec::new();
+        }
+        self.scores
+            .entry(holder.to_owned())
+            .or_default()
+            .insert(objective.to_owned(), score);
+        if !self.tracked.contains(objective) {
+            return Vec::new();
+        }
+        vec![score_packet(holder, objective, score)]
+    }
+
+    /// Removes the scores of `holder`, only the one in `objective` when given.
+    ///
+    /// Returns whether any score was removed, and the packets to send.
+    pub fn reset_scores(
+        &mut self,
+        holder: &str,
+        objective: Option<&str>,
+    ) -> (bool, Vec<ScoreboardPacket>) {
+        let Some(objective) = objective else {
+            self.enabled_triggers.remove(holder);
+            let removed = self.scores.remove(holder).is_some();
+            let packet = ScoreboardPacket::Reset(CResetScore {
+                owner: holder.to_owned(),
+                objective_name: None,
+            });
+            return (removed, vec![packet]);
+        };
+
+        let removed = self
+            .scores
+            .get_mut(holder)
+            .is_some_and(|scores| scores.remove(objective).is_some());
+        if self.scores.get(holder).is_some_and(FxHashMap::is_empty) {
+            self.scores.remove(holder);
+        }
+        self.lock_trigger(holder, objective);
+        let mut packets = Vec::new();
+        if removed && self.tracked.contains(objective) {
+            packets.push(ScoreboardPacket::Reset(CResetScore {
+                owner: holder.to_owned(),
+                objective_name: Some(objective.to_owned()),
+            }));
+        }
+        (removed, packets)
+    }
+
+    /// Lets `holder` use `/trigger` on `objective` once, creating the score at 0 if unset,
+    /// vanilla's `ScoreAccess.unlock`.
+    ///
+    /// Returns whether the trigger was disabled before, and the packets to send.
+    pub fn enable_trigger(
+        &mut self,
+        holder: &str,
+        objective: &str,
+    ) -> (bool, Vec<ScoreboardPacket>) {
+        if !self.objectives.contains_key(objective) {
+            return (false, Vec::new());
+        }
+        let packets = if self.score(holder, objective).is_none() {
+            self.set_score(holder, objective, 0)
+        } else {
+            Vec::new()
+        };
+        let enabled = self
+            .enabled_triggers
+            .entry(holder.to_owned())
+            .or_default()
+            .insert(objective.to_owned());
+        (enabled, packets)
+    }
+
+    /// Replaces the score of an enabled trigger with `change` of it and disables the trigger
+    /// again, as vanilla's `TriggerCommand` does.
+    ///
+    /// Returns the new score and the packets to send, or `None` if the trigger isn't enabled.
+    pub fn trigger(
+        &mut self,
+        holder: &str,
+        objective: &str,
+        change: impl FnOnce(i32) -> i32,
+    ) -> Option<(i32, Vec<ScoreboardPacket>)> {
+        if !self.lock_trigger(holder, objective) {
+            return None;
+        }
+        let score = change(self.score(holder, objective).unwrap_or(0));
+        Some((score, self.set_score(holder, objective, score)))
+    }
+
+    /// Stops `holder` from using `/trigger` on `objective`, returning whether they could.
+    fn lock_trigger(&mut self, holder: &str, objective: &str) -> bool {
+        let Some(triggers) = self.enabled_triggers.get_mut(holder) else {
+            return false;
+        };
+        let locked = triggers.remove(objective);
+        if triggers.is_empty() {
+            self.enabled_triggers.remove(holder);
+        }
+        locked
+    }
+
+    /// The team named `name`.
+    #[must_use]
+    pub fn team(&self, name: &str) -> Option<&PlayerTeam> {
+        self.teams.get(name)
+    }
+
+    /// Every team, sorted by name.
+    #[must_use]
+    pub fn teams(&self) -> Vec<&PlayerTeam> {
+        let mut teams: Vec<_> = self.teams.values().collect();
+        teams.sort_by(|a, b| a.name.cmp(&b.name));
+        teams
+    }
+
+    /// The team `holder` is in.
+    #[must_use]
+    pub fn holder_team(&self, holder: &str) -> Option<&PlayerTeam> {
+        self.teams_by_holder
+            .get(holder)
+            .and_then(|name| self.teams.get(name))
+    }
+
+    /// Adds a team. Returns `None` if one with the same name exists.
+    pub fn add_team(
+        &mut self,
+        name: String,
+        display_name: TextComponent,
+    ) -> Option<Vec<ScoreboardPacket>> {
+        if self.teams.contains_key(&name) {
+            return None;
+        }
+        let team = PlayerTeam::new(name.clone(), display_name);
+        let packet = team_packet(
+            &team,
+            TeamAction::Add {
+                parameters: team.parameters(),
+                players: Vec::new(),
+            },
+        );
+        self.teams.insert(name, team);
+        Some(vec![packet])
+    }
+
+    /// Removes a team, leaving its members without one.
+    pub fn remove_team(&mut self, name: &str) -> Vec<ScoreboardPacket> {
+        let Some(team) = self.teams.remove(name) else {
+            return Vec::new();
+        };
+        for player in &team.players {
+            self.teams_by_holder.remove(player);
+        }
+        vec![team_packet(&team, TeamAction::Remove)]
+    }
+
+    /// Replaces the options of the team with the same name, keeping its members.
+    pub fn update_team(&mut self, mut team: PlayerTeam) -> Vec<ScoreboardPacket> {
+        let Some(stored) = self.teams.get_mut(&team.name) else {
+            return Vec::new();
+        };
+        team.players = std::mem::take(&mut stored.players);
+        *stored = team;
+        vec![team_packet(stored, TeamAction::Change(stored.parameters()))]
+    }
+
+    /// Moves `holder` into the team named `team`, leaving its previous team.
+    pub fn add_holder_to_team(&mut self, holder: &str, team: &str) -> Vec<ScoreboardPacket> {
+        if !self.teams.contains_key(team) {
+            return Vec::new();
+        }
+        let mut packets = self.remove_holder_from_team(holder).unwrap_or_default();
+        if let Some(team) = self.teams.get_mut(team) {
+            team.players.insert(holder.to_owned());
+            self.teams_by_holder
+                .insert(holder.to_owned(), team.name.clone());
+            packets.push(team_packet(team, TeamAction::Join(vec![holder.to_owned()])));
+        }
+        packets
+    }
+
+    /// Removes `holder` from its team. Returns `None` if it isn't in one.
+    pub fn remove_holder_from_team(&mut self, holder: &str) -> Option<Vec<ScoreboardPacket>> {
+        let name = self.teams_by_holder.remove(holder)?;
+        let team = self.teams.get_mut(&name)?;
+        team.players.remove(holder);
+        Some(vec![team_packet(
+            team,
+            TeamAction::Leave(vec![holder.to_owned()]),
+        )])
+    }
+
+    /// Everything a joining player needs to see the teams and displayed objectives.
+    #[must_use]
+    pub fn join_packets(&self) -> Vec<ScoreboardPacket> {
+        let mut packets: Vec<_> = self
+            .teams()
+            .into_iter()
+            .map(|team| {
+                team_packet(
+                    team,
+                    TeamAction::Add {
+                        parameters: team.parameters(),
+                        players: team.sorted_players(),
+                    },
+                )
+            })
+            .collect();
+        let mut tracked: Vec<_> = self
+            .tracked
+            .iter()
+            .filter_map(|name| self.objectives.get(name))
+            .collect();
+        tracked.sort_by(|a, b| a.name.cmp(&b.name));
+        packets.extend(
+            tracked
+                .into_iter()
+                .flat_map(|objective| self.start_tracking_packets(objective)),
+        );
+        packets
+    }
+
+    fn slots_showing<'a>(&'a self, objective: &'a str) -> impl Iterator<Item = DisplaySlot> + 'a {
+        DisplaySlot::all().filter(move |slot| {
+            self.display_slots
+                .get(slot)
+                .is_some_and(|name| name == objective)
+        })
+    }
+
+    fn start_tracking_packets(&self, objective: &Objective) -> Vec<ScoreboardPacket> {
+        let mut packets = vec![ScoreboardPacket::Objective(CSetObjective {
+            objective_name: objective.name.clone(),
+            action: ObjectiveAction::Add {
+                display_name: objective.display_name.clone(),
+                render_type: objective.render_type,
+            },
+        })];
+        packets.extend(
+            self.slots_showing(&objective.name)
+                .map(|slot| display_packet(slot, Some(objective))),
+        );
+        for holder in self.holders() {
+            if let Some(score) = self.score(holder, &objective.name) {
+                packets.push(score_packet(holder, &objective.name, score));
+            }
+        }
+        packets
+    }
+
+    fn stop_tracking_packets(&self, objective: &Objective) -> Vec<ScoreboardPacket> {
+        let mut packets = vec![ScoreboardPacket::Objective(CSetObjective {
+            objective_name: objective.name.clone(),
+            action: ObjectiveAction::Remove,
+        })];
+        packets.extend(
+            self.slots_showing(&objective.name)
+                .map(|slot| display_packet(slot, Some(objective))),
+        );
+        packets
+    }
+}
+
+fn display_packet(slot: DisplaySlot, objective: Option<&Objective>) -> ScoreboardPacket {
+    ScoreboardPacket::Display(CSetDisplayObjective {
+        slot: slot.id(),
+        objective_name: objective
+            .map(|objective| objective.name.clone())
+            .unwrap_or_default(),
+    })
+}
+
+fn team_packet(team: &PlayerTeam, action: TeamAction) -> ScoreboardPacket {
+    ScoreboardPacket::Team(CSetPlayerTeam {
+        name: team.name.clone(),
+        action,
+    })
+}
+
+fn score_packet(holder: &str, objective: &str, score: i32) -> ScoreboardPacket {
+    ScoreboardPacket::Score(CSetScore {
+        owner: holder.to_owned(),
+        objective_name: objective.to_owned(),
+        score,
+        display: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use text_components::TextComponent;
+
+    use super::{DisplaySlot, ObjectiveCriteria, Scoreboard, ScoreboardPacket};
+
+    #[test]
+    fn displaying_an_objective_sends_its_scores() {
+        let mut scoreboard = Scoreboard::default();
+        assert!(scoreboard.add_objective(
+            "kills".to_owned(),
+            ObjectiveCriteria::Dummy,
+            TextComponent::plain("Kills"),
+        ));
+        assert!(scoreboard.set_score("Steve", "kills", 3).is_empty());
+
+        let packets = scoreboard.set_display_objective(DisplaySlot::Sidebar, Some("kills"));
+        assert!(matches!(packets[0], ScoreboardPacket::Objective(_)));
+        assert!(matches!(packets[1], ScoreboardPacket::Display(ref packet) if packet.slot == 1));
+        assert!(matches!(packets[2], ScoreboardPacket::Score(ref packet) if packet.score == 3));
+
+        let packets = scoreboard.remove_objective("kills");
+        assert_eq!(packets.len(), 1);
+        assert!(scoreboard.display_objective(DisplaySlot::Sidebar).is_none());
+        assert_eq!(scoreboard.score("Steve", "kills"), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! Scoreboard objectives, their criteria and display slots.
+use steel_protocol::packets::game::ObjectiveRenderType;
+use text_components::TextComponent;
+
+/// What updates the scores of an objective, vanilla's `ObjectiveCriteria`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectiveCriteria {
+    Dummy,
+    Trigger,
+    DeathCount,
+    PlayerKillCount,
+    TotalKillCount,
+    Health,
+    Xp,
+    Level,
+    Food,
+    Air,
+    Armor,
+}
+
+impl ObjectiveCriteria {
+    /// Every criteria that isn't tied to a statistic or team color.
+    // TODO: support statistic and team color criteria
+    pub const ALL: [Self; 11] = [
+        Self::Dummy,
+        Self::Trigger,
+        Self::DeathCount,
+        Self::PlayerKillCount,
+        Self::TotalKillCount,
+        Self::Health,
+        Self::Xp,
+        Self::Level,
+        Self::Food,
+        Self::Air,
+        Self::Armor,
+    ];
+
+    /// The vanilla criteria name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Dummy => "dummy",
+            Self::Trigger => "trigger",
+            Self::DeathCount => "deathCount",
+            Self::PlayerKillCount => "playerKillCount",
+            Self::TotalKillCount => "totalKillCount",
+            Self::Health => "health",
+            Self::Xp => "xp",
+            Self::Level => "level",
+            Self::Food => "food",
+            Self::Air => "air",
+            Self::Armor => "armor",
+        }
+    }
+
+    /// The criteria named `name`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|criteria| criteria.name() == name)
+    }
+
+    /// Whether scores are only changed by the game, never by commands.
+    #[must_use]
+    pub const fn is_read_only(self) -> bool {
+        matches!(
+            self,
+            Self::Health | Self::Xp | Self::Level | Self::Food | Self::Air | Self::Armor
+        )
+    }
+
+    /// The render type new objectives with this criteria use.
+    #[must_use]
+    pub const fn default_render_type(self) -> ObjectiveRenderType {
+        match self {
+            Self::Health => ObjectiveRenderType::Hearts,
+            _ => ObjectiveRenderType::Integer,
+        }
+    }
+}
+
+/// A named set of scores.
+#[derive(Clone, Debug)]
+pub struct Objective {
+    pub name: String,
+    pub criteria: ObjectiveCriteria,
+    pub display_name: TextComponent,
+    pub render_type: ObjectiveRenderType,
+}
+
+impl Objective {
+    /// The display name in square brackets, as command feedback shows it.
+    #[must_use]
+    pub fn formatted_display_name(&self) -> TextComponent {
+        TextComponent::plain("[")
+            .add_child(self.display_name.clone())
+            .add_child(TextComponent::plain("]"))
+    }
+}
+
+/// The team colors a sidebar can be shown to, in vanilla order.
+const TEAM_COLORS: [&str; 16] = [
+    "black",
+    "dark_blue",
+    "dark_green",
+    "dark_aqua",
+    "dark_red",
+    "dark_purple",
+    "gold",
+    "gray",
+    "dark_gray",
+    "blue",
+    "green",
+    "aqua",
+    "red",
+    "light_purple",
+    "yellow",
+    "white",
+];
+
+/// Where an objective is shown on the client, vanilla's `DisplaySlot`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DisplaySlot {
+    List,
+    Sidebar,
+    BelowName,
+    /// The sidebar shown to members of a team, by index into the team colors.
+    TeamSidebar(u8),
+}
+
+impl DisplaySlot {
+    /// Every display slot, in id order.
+    pub fn all() -> impl Iterator<Item = Self> {
+        [Self::List, Self::Sidebar, Self::BelowName]
+            .into_iter()
+            .chain((0..TEAM_COLORS.len() as u8).map(Self::TeamSidebar))
+    }
+
+    /// The vanilla protocol id.
+    #[must_use]
+    pub const fn id(self) -> i32 {
+        match self {
+            Self::List => 0,
+            Self::Sidebar => 1,
+            Self::BelowName => 2,
+            Self::TeamSidebar(color) => 3 + color as i32,
+        }
+    }
+
+    /// The vanilla serialized name.
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::List => "list".to_owned(),
+            Self::Sidebar => "sidebar".to_owned(),
+            Self::BelowName => "below_name".to_owned(),
+            Self::TeamSidebar(color) => format!("sidebar.team.{}", TEAM_COLORS[color as usize]),
+        }
+    }
+
+    /// The slot named `name`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().find(|slot| slot.name() == name)
+    }
+}
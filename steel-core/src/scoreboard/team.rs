@@ -0,0 +1,182 @@
+//! Teams of score holders and their options.
+use rustc_hash::FxHashSet;
+use steel_protocol::packets::game::{CollisionRule, NameTagVisibility, TeamParameters};
+use text_components::{Modifier, TextComponent, format::Color};
+
+/// The chat color of a team, the colors of vanilla's `ChatFormatting` plus `reset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TeamColor {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+    Reset,
+}
+
+impl TeamColor {
+    /// Every color, in `ChatFormatting` order.
+    pub const ALL: [Self; 17] = [
+        Self::Black,
+        Self::DarkBlue,
+        Self::DarkGreen,
+        Self::DarkAqua,
+        Self::DarkRed,
+        Self::DarkPurple,
+        Self::Gold,
+        Self::Gray,
+        Self::DarkGray,
+        Self::Blue,
+        Self::Green,
+        Self::Aqua,
+        Self::Red,
+        Self::LightPurple,
+        Self::Yellow,
+        Self::White,
+        Self::Reset,
+    ];
+
+    /// The vanilla serialized name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Black => "black",
+            Self::DarkBlue => "dark_blue",
+            Self::DarkGreen => "dark_green",
+            Self::DarkAqua => "dark_aqua",
+            Self::DarkRed => "dark_red",
+            Self::DarkPurple => "dark_purple",
+            Self::Gold => "gold",
+            Self::Gray => "gray",
+            Self::DarkGray => "dark_gray",
+            Self::Blue => "blue",
+            Self::Green => "green",
+            Self::Aqua => "aqua",
+            Self::Red => "red",
+            Self::LightPurple => "light_purple",
+            Self::Yellow => "yellow",
+            Self::White => "white",
+            Self::Reset => "reset",
+        }
+    }
+
+    /// The color named `name`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|color| color.name() == name)
+    }
+
+    /// The `ChatFormatting` id the protocol uses.
+    #[must_use]
+    pub const fn id(self) -> i32 {
+        match self {
+            // The formatting codes between white and reset aren't colors
+            Self::Reset => 21,
+            color => color as i32,
+        }
+    }
+
+    /// The text color, or `None` for `reset`.
+    #[must_use]
+    pub const fn text_color(self) -> Option<Color> {
+        Some(match self {
+            Self::Black => Color::Black,
+            Self::DarkBlue => Color::DarkBlue,
+            Self::DarkGreen => Color::DarkGreen,
+            Self::DarkAqua => Color::DarkAqua,
+            Self::DarkRed => Color::DarkRed,
+            Self::DarkPurple => Color::DarkPurple,
+            Self::Gold => Color::Gold,
+            Self::Gray => Color::Gray,
+            Self::DarkGray => Color::DarkGray,
+            Self::Blue => Color::Blue,
+            Self::Green => Color::Green,
+            Self::Aqua => Color::Aqua,
+            Self::Red => Color::Red,
+            Self::LightPurple => Color::LightPurple,
+            Self::Yellow => Color::Yellow,
+            Self::White => Color::White,
+            Self::Reset => return None,
+        })
+    }
+}
+
+/// A team of score holders, vanilla's `PlayerTeam`.
+#[derive(Clone, Debug)]
+pub struct PlayerTeam {
+    pub name: String,
+    pub display_name: TextComponent,
+    pub color: TeamColor,
+    pub prefix: TextComponent,
+    pub suffix: TextComponent,
+    pub allow_friendly_fire: bool,
+    pub see_friendly_invisibles: bool,
+    pub nametag_visibility: NameTagVisibility,
+    pub collision_rule: CollisionRule,
+    pub players: FxHashSet<String>,
+}
+
+impl PlayerTeam {
+    /// A team with vanilla's default options.
+    #[must_use]
+    pub fn new(name: String, display_name: TextComponent) -> Self {
+        Self {
+            name,
+            display_name,
+            color: TeamColor::Reset,
+            prefix: TextComponent::new(),
+            suffix: TextComponent::new(),
+            allow_friendly_fire: true,
+            see_friendly_invisibles: true,
+            nametag_visibility: NameTagVisibility::Always,
+            collision_rule: CollisionRule::Always,
+            players: FxHashSet::default(),
+        }
+    }
+
+    /// The display name in square brackets and the team color, as command feedback shows it.
+    #[must_use]
+    pub fn formatted_display_name(&self) -> TextComponent {
+        let name = TextComponent::plain("[")
+            .add_child(self.display_name.clone())
+            .add_child(TextComponent::plain("]"));
+        match self.color.text_color() {
+            Some(color) => name.color(color),
+            None => name,
+        }
+    }
+
+    /// The members, sorted by name.
+    #[must_use]
+    pub fn sorted_players(&self) -> Vec<String> {
+        let mut players: Vec<_> = self.players.iter().cloned().collect();
+        players.sort_unstable();
+        players
+    }
+
+    /// The options the client renders.
+    #[must_use]
+    pub fn parameters(&self) -> TeamParameters {
+        TeamParameters {
+            display_name: self.display_name.clone(),
+            allow_friendly_fire: self.allow_friendly_fire,
+            see_friendly_invisibles: self.see_friendly_invisibles,
+            nametag_visibility: self.nametag_visibility,
+            collision_rule: self.collision_rule,
+            color: self.color.id(),
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! When the game tick saves the server on its own, vanilla's autosave interval.
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Ticks between autosaves, vanilla's default of five minutes.
+pub const AUTOSAVE_INTERVAL: u32 = 6000;
+
+/// Counts down to the next autosave. Worlds that `/save-off` stopped skip it, see
+/// [`World::no_save`](crate::world::World::no_save).
+pub struct Autosave {
+    ticks_until_save: AtomicU32,
+}
+
+impl Default for Autosave {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Autosave {
+    /// A full interval until the first save.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ticks_until_save: AtomicU32::new(AUTOSAVE_INTERVAL),
+        }
+    }
+
+    /// Counts down one game tick. Returns whether an autosave is due.
+    pub fn tick(&self) -> bool {
+        let remaining = self.ticks_until_save.load(Ordering::Relaxed);
+        if remaining > 1 {
+            self.ticks_until_save
+                .store(remaining - 1, Ordering::Relaxed);
+            return false;
+        }
+        self.ticks_until_save
+            .store(AUTOSAVE_INTERVAL, Ordering::Relaxed);
+        true
+    }
+}
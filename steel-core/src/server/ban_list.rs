@@ -0,0 +1,196 @@
+//! The player and IP ban lists, vanilla's `UserBanList` and `IpBanList`, persisted to
+//! `banned-players.json` and `banned-ips.json`.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use text_components::TextComponent;
+use text_components::translation::Translation;
+use uuid::Uuid;
+
+use crate::server::stored_list::{ListEntry, StoredList};
+
+/// The format of the `created` and `expires` fields, vanilla's `BanListEntry.DATE_FORMAT`.
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+/// How disconnect messages show when a ban ends, vanilla's `PlayerList.BAN_DATE_FORMAT`.
+const BAN_DATE_FORMAT: &str = "%Y-%m-%d at %H:%M:%S %Z";
+/// The `expires` value of a ban that never ends.
+const FOREVER: &str = "forever";
+/// The reason given when a ban has none, vanilla's `BanListEntry` default.
+pub const DEFAULT_REASON: &str = "Banned by an operator.";
+
+/// When, by whom and why a ban was made, shared by both ban lists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BanDetails {
+    /// When the ban was made, like `2024-01-31 12:00:00 +0000`.
+    pub created: String,
+    /// Who made the ban.
+    pub source: String,
+    /// When the ban ends in the same format, or `forever`.
+    pub expires: String,
+    /// Why the player was banned.
+    pub reason: String,
+}
+
+impl BanDetails {
+    /// A permanent ban made now by `source`, with the default reason when none is given.
+    #[must_use]
+    pub fn new(source: String, reason: Option<String>) -> Self {
+        Self {
+            created: Utc::now().format(DATE_FORMAT).to_string(),
+            source,
+            expires: FOREVER.to_owned(),
+            reason: reason.unwrap_or_else(|| DEFAULT_REASON.to_owned()),
+        }
+    }
+
+    /// When the ban ends, or `None` if it never does.
+    #[must_use]
+    pub fn expiration(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_str(&self.expires, DATE_FORMAT)
+            .ok()
+            .map(|time| time.with_timezone(&Utc))
+    }
+
+    /// Whether the ban has ended.
+    #[must_use]
+    pub fn has_expired(&self) -> bool {
+        self.expiration()
+            .is_some_and(|expires| expires < Utc::now())
+    }
+
+    /// The message refusing a banned player, with the reason and when the ban ends.
+    #[must_use]
+    pub fn disconnect_message(
+        &self,
+        reason: &Translation<1>,
+        expiration: &Translation<1>,
+    ) -> TextComponent {
+        let message: TextComponent = reason
+            .message([TextComponent::from(self.reason.clone())])
+            .into();
+        match self.expiration() {
+            Some(expires) => message.add_child(
+                expiration
+                    .message([TextComponent::from(
+                        expires.format(BAN_DATE_FORMAT).to_string(),
+                    )])
+                    .into(),
+            ),
+            None => message,
+        }
+    }
+}
+
+/// A banned player, stored in the same shape as vanilla's `banned-players.json` entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserBanEntry {
+    /// The player's UUID.
+    pub uuid: Uuid,
+    /// The player's name when they were banned.
+    pub name: String,
+    /// The details of the ban.
+    #[serde(flatten)]
+    pub details: BanDetails,
+}
+
+/// A banned address, stored in the same shape as vanilla's `banned-ips.json` entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IpBanEntry {
+    /// The banned address.
+    pub ip: String,
+    /// The details of the ban.
+    #[serde(flatten)]
+    pub details: BanDetails,
+}
+
+impl ListEntry for UserBanEntry {
+    type Key = Uuid;
+
+    fn key(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    fn is_active(&self) -> bool {
+        !self.details.has_expired()
+    }
+}
+
+impl ListEntry for IpBanEntry {
+    type Key = str;
+
+    fn key(&self) -> &str {
+        &self.ip
+    }
+
+    fn is_active(&self) -> bool {
+        !self.details.has_expired()
+    }
+}
+
+/// The banned players, vanilla's `UserBanList`.
+pub type UserBanList = StoredList<UserBanEntry>;
+/// The banned addresses, vanilla's `IpBanList`.
+pub type IpBanList = StoredList<IpBanEntry>;
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use uuid::Uuid;
+
+    use super::{BanDetails, IpBanEntry, IpBanList, UserBanEntry, UserBanList};
+    use crate::test_support::temp_path;
+
+    #[test]
+    fn player_bans_are_persisted_in_vanilla_format() {
+        let path = temp_path("banned-players");
+        let uuid = Uuid::from_u128(1);
+
+        let bans = UserBanList::load(path.clone()).expect("missing file is an empty list");
+        assert!(bans.get(&uuid).is_none());
+        let entry = UserBanEntry {
+            uuid,
+            name: "Steve".to_owned(),
+            details: BanDetails::new("Server".to_owned(), None),
+        };
+        assert!(bans.add(entry.clone()).expect("bans should save"));
+        assert!(!bans.add(entry).expect("bans should save"));
+
+        let json = fs::read_to_string(&path).expect("banned-players.json should be written");
+        assert!(json.contains("\"expires\": \"forever\""));
+        assert!(json.contains("\"reason\": \"Banned by an operator.\""));
+        let reloaded = UserBanList::load(path.clone()).expect("banned-players.json should load");
+        assert_eq!(
+            reloaded.get(&uuid).map(|ban| ban.name),
+            Some("Steve".to_owned())
+        );
+
+        assert!(reloaded.remove(&uuid).expect("bans should save"));
+        assert!(!reloaded.remove(&uuid).expect("bans should save"));
+        assert!(reloaded.get(&uuid).is_none());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn expired_ip_bans_are_ignored() {
+        let path = temp_path("banned-ips");
+        let bans = IpBanList::load(path.clone()).expect("missing file is an empty list");
+        let mut details = BanDetails::new("Server".to_owned(), Some("Griefing".to_owned()));
+        details.expires = "2000-01-01 00:00:00 +0000".to_owned();
+        let expired = IpBanEntry {
+            ip: "127.0.0.1".to_owned(),
+            details,
+        };
+        assert!(bans.add(expired).expect("bans should save"));
+        assert!(bans.get("127.0.0.1").is_none());
+        assert!(!bans.remove("127.0.0.1").expect("bans should save"));
+
+        let entry = IpBanEntry {
+            ip: "127.0.0.1".to_owned(),
+            details: BanDetails::new("Server".to_owned(), None),
+        };
+        assert!(bans.add(entry).expect("bans should save"));
+        assert!(bans.get("127.0.0.1").is_some());
+        assert!(bans.remove("127.0.0.1").expect("bans should save"));
+        let _ = fs::remove_file(&path);
+    }
+}
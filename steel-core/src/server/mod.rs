@@ -1,16 +1,22 @@
 //! This module contains the `Server` struct, which is the main entry point for the server.
+pub mod autosave;
+pub mod ban_list;
 /// Tick-polled server jobs.
 pub mod jobs;
+pub mod op_list;
 mod pregen;
 /// The registry cache for the server.
 pub mod registry_cache;
+pub mod stored_list;
 /// The tick rate manager for the server.
 pub mod tick_rate_manager;
+pub mod whitelist;
 /// Domain-aware loaded world map.
 pub mod worlds;
 
 use crate::behavior::init_behaviors;
 use crate::block_entity::init_block_entities;
+use crate::boss_bar::BossBars;
 use crate::chunk::{
     chunk_access::ChunkStatus,
     chunk_request::{ChunkRequestHandle, ChunkRequestState, ChunkTicketKind},
@@ -27,32 +33,44 @@ use crate::player::player_data::{PersistentPlayerData, PersistentRootVehicle};
 use crate::player::player_data_storage::{GlobalPlayerData, PlayerDataStorage};
 use crate::player::{Player, ResetReason};
 use crate::portal::{TeleportTransition, WorldChangeRequest};
+use crate::scoreboard::{Scoreboard, ScoreboardPacket};
+use crate::server::autosave::Autosave;
+use crate::server::ban_list::{IpBanList, UserBanList};
 use crate::server::jobs::{JobPoll, ServerJob, ServerJobContext, ServerJobQueue};
+use crate::server::op_list::OpList;
 use crate::server::registry_cache::RegistryCache;
+use crate::server::whitelist::Whitelist;
 use crate::server::worlds::WorldMap;
 use crate::world::{World, WorldConfig, WorldGameTickTimings};
 use crate::worldgen::WorldGeneratorRegistry;
 use crate::worldgen::registry::GeneratorOutput;
 use glam::DVec3;
 use rayon::{ThreadPool, ThreadPoolBuilder};
+use rustc_hash::FxHashMap;
+use simdnbt::owned::NbtCompound;
 use std::{
     mem,
+    net::IpAddr,
     num::NonZero,
     path::Path,
-    sync::{Arc, mpsc},
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+        mpsc,
+    },
     thread,
     time::{Duration, Instant},
 };
 use steel_crypto::key_store::KeyStore;
 use steel_protocol::packets::game::{
-    CEntityEvent, CGameEvent, CLogin, CSetDefaultSpawnPosition, CSystemChat, CTabList,
-    CTickingState, CTickingStep, CommonPlayerSpawnInfo, GameEventType,
+    CBossEvent, CGameEvent, CLogin, CSetDefaultSpawnPosition, CSystemChat, CTabList, CTickingState,
+    CTickingStep, CommonPlayerSpawnInfo, GameEventType,
 };
 use steel_registry::game_rules::GameRuleValue;
 use steel_registry::vanilla_game_rules::{IMMEDIATE_RESPAWN, LIMITED_CRAFTING, REDUCED_DEBUG_INFO};
 use steel_registry::{REGISTRY, Registry, RegistryEntry};
 use steel_utils::locks::SyncMutex;
-use steel_utils::{ChunkPos, Identifier, entity_events::EntityStatus, locks::SyncRwLock};
+use steel_utils::{ChunkPos, Identifier, locks::SyncRwLock};
 use text_components::{Modifier, TextComponent, format::Color};
 use tick_rate_manager::{SprintReport, TickRateManager};
 use tokio::{runtime::Runtime, task::spawn_blocking, time::sleep};
@@ -110,11 +128,11 @@ struct PreparedSpawn {
 fn apply_default_spawn(player: &Arc<Player>, world: &Arc<World>, spawn: PreparedSpawn) {
     player.base().set_position_local(spawn.position);
     player.set_rotation(spawn.rotation);
-    player.restore_game_modes(world.default_gamemode, None);
+    player.restore_game_modes(world.default_gamemode(), None);
     player
         .abilities
         .lock()
-        .update_for_game_mode(world.default_gamemode);
+        .update_for_game_mode(world.default_gamemode());
 }
 
 fn world_spawn_transition(world: Arc<World>) -> TeleportTransition {
@@ -363,6 +381,30 @@ fn discard_restored_entities(entities: &[SharedEntity]) {
     }
 }
 
+/// The operator, whitelist and ban lists kept next to the worlds.
+pub(crate) struct PlayerLists {
+    op_list: OpList,
+    whitelist: Whitelist,
+    banned_players: UserBanList,
+    banned_ips: IpBanList,
+}
+
+impl PlayerLists {
+    /// Loads every list from its file in `dir`. Missing files start out as empty lists.
+    pub(crate) fn load(dir: &Path, white_list: bool) -> Result<Self, String> {
+        Ok(Self {
+            op_list: OpList::load(dir.join("ops.json"))
+                .map_err(|e| format!("failed to load ops.json: {e}"))?,
+            whitelist: Whitelist::load(dir.join("whitelist.json"), white_list)
+                .map_err(|e| format!("failed to load whitelist.json: {e}"))?,
+            banned_players: UserBanList::load(dir.join("banned-players.json"))
+                .map_err(|e| format!("failed to load banned-players.json: {e}"))?,
+            banned_ips: IpBanList::load(dir.join("banned-ips.json"))
+                .map_err(|e| format!("failed to load banned-ips.json: {e}"))?,
+        })
+    }
+}
+
 /// The main server struct.
 pub struct Server {
     /// Runtime configuration (view distance, compression, etc.).
@@ -381,8 +423,29 @@ pub struct Server {
     pub command_dispatcher: SyncRwLock<CommandDispatcher>,
     /// Jobs resumed from a known point in the server game tick.
     pub jobs: ServerJobQueue,
+    /// The autosave countdown.
+    pub autosave: Autosave,
+    /// Minutes a player may stay idle before being kicked, 0 to never kick.
+    player_idle_timeout: AtomicU32,
     /// Player data storage for saving/loading player state.
     pub player_data_storage: PlayerDataStorage,
+    /// The server operators, loaded from `ops.json`.
+    pub op_list: OpList,
+    /// The whitelisted players, loaded from `whitelist.json`.
+    pub whitelist: Whitelist,
+    /// The banned players, loaded from `banned-players.json`.
+    pub banned_players: UserBanList,
+    /// The banned addresses, loaded from `banned-ips.json`.
+    pub banned_ips: IpBanList,
+    /// The objectives and scores shared by every world.
+    pub scoreboard: SyncMutex<Scoreboard>,
+    /// Compounds `/data` keeps under namespaced ids, vanilla's `CommandStorage`.
+    // TODO: persist to `data/command_storage_*.dat`
+    pub command_storage: SyncMutex<FxHashMap<Identifier, NbtCompound>>,
+    /// The boss bars made with `/bossbar`.
+    pub boss_bars: SyncMutex<BossBars>,
+    /// Who each player last whispered with, the player their `/r` answers.
+    pub whisper_partners: SyncMutex<FxHashMap<Uuid, Uuid>>,
     /// Player joins prepared by async I/O and finalized at the game tick safe point.
     pending_player_joins: PlayerJoinQueue,
     /// Queued world changes to process after the tick.
@@ -423,8 +486,6 @@ impl Server {
             "SteelMC is not affiliated with Mojang or Microsoft. Use is subject to the Minecraft EULA: https://aka.ms/MinecraftEULA"
         );
 
-        let registry_cache = RegistryCache::new(config.compression);
-
         let (generator_registry, storage_registry) = world_config_registries()?;
         let resolved_worlds = worlds_config
             .validate_and_resolve(&generator_registry, &storage_registry)
@@ -452,6 +513,7 @@ impl Server {
         )
         .await
         .map_err(|e| format!("failed to create player data storage: {e}"))?;
+        let lists = PlayerLists::load(Path::new(""), config.white_list)?;
         let mut worlds = WorldMap::new(
             resolved_worlds.default_domain.clone(),
             &resolved_worlds.domains,
@@ -516,7 +578,27 @@ impl Server {
             worlds.insert(world_entry.key.clone(), world);
         }
 
-        Ok(Server {
+        Ok(Self::assemble(
+            config,
+            cancel_token,
+            worlds,
+            player_data_storage,
+            lists,
+        ))
+    }
+
+    /// Puts a server together around loaded worlds and player lists, with every other piece of
+    /// runtime state starting out empty.
+    pub(crate) fn assemble(
+        config: Arc<RuntimeConfig>,
+        cancel_token: CancellationToken,
+        worlds: WorldMap,
+        player_data_storage: PlayerDataStorage,
+        lists: PlayerLists,
+    ) -> Self {
+        let registry_cache = RegistryCache::new(config.compression);
+        let player_idle_timeout = AtomicU32::new(config.player_idle_timeout);
+        Server {
             config,
             cancel_token,
             key_store: KeyStore::create(),
@@ -525,11 +607,21 @@ impl Server {
             tick_rate_manager: SyncRwLock::new(TickRateManager::new()),
             command_dispatcher: SyncRwLock::new(CommandDispatcher::new()),
             jobs: ServerJobQueue::new(),
+            autosave: Autosave::new(),
+            player_idle_timeout,
             player_data_storage,
+            op_list: lists.op_list,
+            whitelist: lists.whitelist,
+            banned_players: lists.banned_players,
+            banned_ips: lists.banned_ips,
+            scoreboard: SyncMutex::new(Scoreboard::default()),
+            command_storage: SyncMutex::new(FxHashMap::default()),
+            boss_bars: SyncMutex::new(BossBars::default()),
+            whisper_partners: SyncMutex::new(FxHashMap::default()),
             pending_player_joins: PlayerJoinQueue::new(),
             pending_world_changes: SyncMutex::new(vec![]),
             pending_domain_switches: SyncMutex::new(vec![]),
-        })
+        }
     }
 
     /// Queues initial player join work.
@@ -734,7 +826,7 @@ impl Server {
             )
         };
         let position = world
-            .find_adjusted_shared_spawn_pos(spawn_pos, world.default_gamemode)
+            .find_adjusted_shared_spawn_pos(spawn_pos, world.default_gamemode())
             .await?;
         Ok(PreparedSpawn {
             position,
@@ -747,7 +839,7 @@ impl Server {
         respawn_data: &RespawnData,
     ) -> Result<PreparedSpawn, String> {
         let position = world
-            .find_adjusted_shared_spawn_pos(respawn_data.pos(), world.default_gamemode)
+            .find_adjusted_shared_spawn_pos(respawn_data.pos(), world.default_gamemode())
             .await?;
         Ok(PreparedSpawn {
             position,
@@ -882,6 +974,101 @@ impl Server {
         self.worlds.iter().map(|w| w.1.players.len()).sum()
     }
 
+    /// Starts a graceful shutdown, like vanilla's `MinecraftServer.halt`.
+    ///
+    /// Players are disconnected, which saves their data, and the main loop stops. Worlds are
+    /// saved once it has.
+    pub fn stop(&self) {
+        for player in self.get_players() {
+            player.disconnect(translations::MULTIPLAYER_DISCONNECT_SERVER_SHUTDOWN.msg());
+        }
+        self.cancel_token.cancel();
+    }
+
+    /// Whether `id` may join, vanilla's `PlayerList.isWhiteListed`. Operators always may.
+    #[must_use]
+    pub fn is_whitelisted(&self, id: Uuid) -> bool {
+        !self.whitelist.is_enabled()
+            || self.op_list.get(id).is_some()
+            || self.whitelist.contains(id)
+    }
+
+    /// Why `id` connecting from `address` may not join, vanilla's `PlayerList.canPlayerLogin`.
+    #[must_use]
+    pub fn login_rejection(&self, id: Uuid, address: IpAddr) -> Option<TextComponent> {
+        if let Some(ban) = self.banned_players.get(&id) {
+            return Some(ban.details.disconnect_message(
+                &translations::MULTIPLAYER_DISCONNECT_BANNED_REASON,
+                &translations::MULTIPLAYER_DISCONNECT_BANNED_EXPIRATION,
+            ));
+        }
+        if !self.is_whitelisted(id) {
+            return Some(
+                translations::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED
+                    .msg()
+                    .into(),
+            );
+        }
+        if let Some(ban) = self.banned_ips.get(&address.to_string()) {
+            return Some(ban.details.disconnect_message(
+                &translations::MULTIPLAYER_DISCONNECT_BANNED_IP_REASON,
+                &translations::MULTIPLAYER_DISCONNECT_BANNED_IP_EXPIRATION,
+            ));
+        }
+        None
+    }
+
+    /// Minutes a player may stay idle before being kicked, 0 to never kick.
+    #[must_use]
+    pub fn player_idle_timeout(&self) -> u32 {
+        self.player_idle_timeout.load(Ordering::Relaxed)
+    }
+
+    /// Changes the idle timeout, vanilla's `MinecraftServer.setPlayerIdleTimeout`.
+    ///
+    /// The change only lasts until the server stops. Unlike vanilla, the config file is not
+    /// rewritten, so `player_idle_timeout` there applies again on the next start.
+    pub fn set_player_idle_timeout(&self, minutes: u32) {
+        self.player_idle_timeout.store(minutes, Ordering::Relaxed);
+    }
+
+    /// Saves every player and world, vanilla's `MinecraftServer.saveEverything`.
+    ///
+    /// With `flush`, region headers are written too so the save is complete on disk. With
+    /// `force`, worlds are saved even if `/save-off` turned their saving off.
+    /// Returns `false` if anything failed to save.
+    pub async fn save_everything(&self, flush: bool, force: bool) -> bool {
+        let mut saved = true;
+        if let Err(e) = self.player_data_storage.save_all(&self.get_players()).await {
+            log::error!("Failed to save player data: {e}");
+            saved = false;
+        }
+        for world in self.worlds.values() {
+            if let Err(e) = world.save(flush, force).await {
+                log::error!("Failed to save world {}: {e}", world.key);
+                saved = false;
+            }
+        }
+        saved
+    }
+
+    /// Disconnects the players who are no longer whitelisted, vanilla's
+    /// `MinecraftServer.kickUnlistedPlayers`.
+    pub fn kick_unlisted_players(&self) {
+        for player in self.get_players() {
+            if !self.is_whitelisted(player.gameprofile.id) {
+                player.disconnect(translations::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED.msg());
+            }
+        }
+    }
+
+    /// Sends a system chat message to every player on the server.
+    pub fn broadcast_system_message(&self, message: &TextComponent) {
+        for world in self.worlds.values() {
+            world.broadcast_to_all_with(|player| CSystemChat::new(message, false, player));
+        }
+    }
+
     /// Returns a sample of up to 12 online players for the server list ping.
     #[must_use]
     pub fn player_sample(&self) -> Vec<(String, String)> {
@@ -990,8 +1177,7 @@ impl Server {
         if Arc::ptr_eq(&default_world, &target_world) {
             let mut level_data = default_world.level_data.write();
             let data = level_data.data_mut();
-            data.set_spawn_pos(respawn_data.pos());
-            data.spawn.angle = respawn_data.yaw;
+            data.set_local_spawn(&respawn_data);
             data.set_respawn_data(respawn_data.clone());
         } else {
             default_world
@@ -1000,10 +1186,11 @@ impl Server {
                 .data_mut()
                 .set_respawn_data(respawn_data.clone());
 
-            let mut level_data = target_world.level_data.write();
-            let data = level_data.data_mut();
-            data.set_spawn_pos(respawn_data.pos());
-            data.spawn.angle = respawn_data.yaw;
+            target_world
+                .level_data
+                .write()
+                .data_mut()
+                .set_local_spawn(&respawn_data);
         }
 
         let packet = CSetDefaultSpawnPosition {
@@ -1108,6 +1295,11 @@ impl Server {
 
             self.tick_worlds_game(tick_count, runs_normally).await;
             self.tick_jobs(tick_count, runs_normally);
+            if self.autosave.tick() {
+                log::info!("Autosave started");
+                self.save_everything(false, false).await;
+                log::info!("Autosave finished");
+            }
             self.process_player_joins();
 
             {
@@ -1559,9 +1751,7 @@ impl Server {
             ])
             .into();
 
-        for world in self.worlds.values() {
-            world.broadcast_to_all_with(|player| CSystemChat::new(&message, false, player));
-        }
+        self.broadcast_system_message(&message);
     }
 
     /// Broadcasts the current tick rate and frozen state to all clients.
@@ -1600,19 +1790,77 @@ impl Server {
         player.send_packet(step_packet);
     }
 
+    /// Sends scoreboard changes to every player.
+    pub fn broadcast_scoreboard(&self, packets: &[ScoreboardPacket]) {
+        for world in self.worlds.values() {
+            for packet in packets {
+                packet.broadcast(world);
+            }
+        }
+    }
+
+    /// Sends the displayed objectives to a joining player.
+    pub fn send_scoreboard_to_player(&self, player: &Player) {
+        let packets = self.scoreboard.lock().join_packets();
+        for packet in &packets {
+            packet.send(player);
+        }
+    }
+
+    /// Shows a joining player the boss bars they were added to.
+    pub fn send_boss_bars_to_player(&self, player: &Player) {
+        let packets = self.boss_bars.lock().join_packets(player.gameprofile.id);
+        for packet in packets {
+            player.send_packet(packet);
+        }
+    }
+
+    /// Sends each boss bar packet to its player, if they are online.
+    pub fn send_boss_bar_packets(&self, packets: Vec<(Uuid, CBossEvent)>) {
+        if packets.is_empty() {
+            return;
+        }
+        let players = self.get_players();
+        for (id, packet) in packets {
+            if let Some(player) = players.iter().find(|player| player.gameprofile.id == id) {
+                player.send_packet(packet);
+            }
+        }
+    }
+
+    /// Rebuilds the command tree and resends it to every player, what `/reload` refreshes of
+    /// vanilla's `MinecraftServer.reloadResources`.
+    ///
+    /// The built-in commands are built from scratch and everything else registered on the old
+    /// tree is carried over, then the new tree replaces the old one in a single step. This takes
+    /// the dispatcher's write lock, so it must not be called from inside a command.
+    // TODO: re-read the datapack directory and rebuild the registries once packs load from disk
+    pub fn reload_resources(&self) {
+        let rebuilt = CommandDispatcher::new();
+        {
+            let mut dispatcher = self.command_dispatcher.write();
+            rebuilt.carry_over(&dispatcher);
+            *dispatcher = rebuilt;
+        }
+
+        let dispatcher = self.command_dispatcher.read();
+        for player in self.get_players() {
+            player.send_packet(dispatcher.get_commands(player.permission_level()));
+        }
+    }
+
     /// Resends client state that is not fully covered by `CRespawn`.
     pub fn resend_player_context(&self, player: &Player) {
         player.send_difficulty();
         player.send_inventory_to_remote();
 
-        let commands = self.command_dispatcher.read().get_commands();
+        let commands = self
+            .command_dispatcher
+            .read()
+            .get_commands(player.permission_level());
         player.send_packet(commands);
 
-        // TODO: Set permissions level to match player's level.
-        player.send_packet(CEntityEvent {
-            entity_id: player.id(),
-            event: EntityStatus::PermissionLevelOwners,
-        });
+        player.send_permission_level();
 
         self.send_ticking_state_to_player(player);
 
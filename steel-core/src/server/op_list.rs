@@ -0,0 +1,106 @@
+//! The server operator list, vanilla's `ServerOpList`, persisted to `ops.json`.
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::command::LEVEL_ALL;
+use crate::server::stored_list::{ListEntry, StoredList};
+
+/// An operator, stored in the same shape as vanilla's `ops.json` entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpEntry {
+    /// The operator's UUID.
+    pub uuid: Uuid,
+    /// The operator's name when they were made an operator.
+    pub name: String,
+    /// The permission level the operator has.
+    pub level: u8,
+    /// Whether the operator can join when the server is full.
+    #[serde(default)]
+    pub bypasses_player_limit: bool,
+}
+
+impl ListEntry for OpEntry {
+    type Key = Uuid;
+
+    fn key(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+/// The operators of the server.
+pub struct OpList {
+    entries: StoredList<OpEntry>,
+}
+
+impl OpList {
+    /// Loads the list from `path`. A missing file is an empty list.
+    pub fn load(path: PathBuf) -> io::Result<Self> {
+        Ok(Self {
+            entries: StoredList::load(path)?,
+        })
+    }
+
+    /// Returns the operator entry for `uuid`, if there is one.
+    #[must_use]
+    pub fn get(&self, uuid: Uuid) -> Option<OpEntry> {
+        self.entries.get(&uuid)
+    }
+
+    /// Returns the permission level of `uuid`, [`LEVEL_ALL`] for non operators.
+    #[must_use]
+    pub fn level(&self, uuid: Uuid) -> u8 {
+        self.get(uuid).map_or(LEVEL_ALL, |entry| entry.level)
+    }
+
+    /// Adds an operator and saves the list. Returns `false` if they already were one.
+    pub fn add(&self, entry: OpEntry) -> io::Result<bool> {
+        self.entries.add(entry)
+    }
+
+    /// Removes an operator and saves the list. Returns `false` if they weren't one.
+    pub fn remove(&self, uuid: Uuid) -> io::Result<bool> {
+        self.entries.remove(&uuid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use uuid::Uuid;
+
+    use super::{OpEntry, OpList};
+    use crate::command::{LEVEL_ALL, LEVEL_OWNERS};
+    use crate::test_support::temp_path;
+
+    #[test]
+    fn ops_are_persisted_in_vanilla_format() {
+        let path = temp_path("ops");
+        let uuid = Uuid::from_u128(1);
+
+        let ops = OpList::load(path.clone()).expect("missing file is an empty list");
+        assert_eq!(ops.level(uuid), LEVEL_ALL);
+        let entry = OpEntry {
+            uuid,
+            name: "Steve".to_owned(),
+            level: LEVEL_OWNERS,
+            bypasses_player_limit: false,
+        };
+        assert!(ops.add(entry.clone()).expect("ops should save"));
+        assert!(!ops.add(entry).expect("ops should save"));
+
+        let json = fs::read_to_string(&path).expect("ops.json should be written");
+        assert!(json.contains("\"bypassesPlayerLimit\": false"));
+        let reloaded = OpList::load(path.clone()).expect("ops.json should load");
+        assert_eq!(reloaded.level(uuid), LEVEL_OWNERS);
+
+        assert!(reloaded.remove(uuid).expect("ops should save"));
+        assert!(!reloaded.remove(uuid).expect("ops should save"));
+        assert_eq!(reloaded.level(uuid), LEVEL_ALL);
+        let _ = fs::remove_file(&path);
+    }
+}
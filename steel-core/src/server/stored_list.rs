@@ -0,0 +1,137 @@
+//! The JSON list store behind the ban lists, the whitelist and the operator list.
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use steel_utils::locks::SyncRwLock;
+
+/// An entry of a [`StoredList`], found by its key.
+pub trait ListEntry: Clone + Serialize + DeserializeOwned {
+    /// What the entry is found by.
+    type Key: PartialEq + ?Sized;
+
+    /// The player or address the entry is about.
+    fn key(&self) -> &Self::Key;
+
+    /// Whether the entry still applies. Entries that don't stay in the file, but are otherwise
+    /// treated as missing.
+    fn is_active(&self) -> bool {
+        true
+    }
+}
+
+/// A list of entries saved as a JSON array, vanilla's `StoredUserList`.
+pub struct StoredList<T> {
+    path: PathBuf,
+    entries: SyncRwLock<Vec<T>>,
+}
+
+impl<T: ListEntry> StoredList<T> {
+    /// Loads the list from `path`. A missing file is an empty list.
+    pub fn load(path: PathBuf) -> io::Result<Self> {
+        let entries = read_entries(&path)?;
+        Ok(Self {
+            path,
+            entries: SyncRwLock::new(entries),
+        })
+    }
+
+    /// Returns the active entry of `key`, if there is one.
+    #[must_use]
+    pub fn get(&self, key: &T::Key) -> Option<T> {
+        self.entries
+            .read()
+            .iter()
+            .find(|e| e.key() == key && e.is_active())
+            .cloned()
+    }
+
+    /// Every active entry, in the order they were added.
+    #[must_use]
+    pub fn entries(&self) -> Vec<T> {
+        self.entries
+            .read()
+            .iter()
+            .filter(|e| e.is_active())
+            .cloned()
+            .collect()
+    }
+
+    /// Adds an entry and saves the list. Returns `false` if there already was an active one.
+    pub fn add(&self, entry: T) -> io::Result<bool> {
+        let mut entries = self.entries.write();
+        if entries
+            .iter()
+            .any(|e| e.key() == entry.key() && e.is_active())
+        {
+            return Ok(false);
+        }
+        entries.retain(|e| e.key() != entry.key());
+        entries.push(entry);
+        self.save(&entries)?;
+        Ok(true)
+    }
+
+    /// Removes an entry and saves the list. Returns `false` if there was no active one.
+    pub fn remove(&self, key: &T::Key) -> io::Result<bool> {
+        let mut entries = self.entries.write();
+        if !entries.iter().any(|e| e.key() == key && e.is_active()) {
+            return Ok(false);
+        }
+        entries.retain(|e| e.key() != key);
+        self.save(&entries)?;
+        Ok(true)
+    }
+
+    /// Rereads the list from disk.
+    pub fn reload(&self) -> io::Result<()> {
+        let entries = read_entries(&self.path)?;
+        *self.entries.write() = entries;
+        Ok(())
+    }
+
+    fn save(&self, entries: &[T]) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(entries)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&self.path, json)
+    }
+}
+
+fn read_entries<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+    match fs::read_to_string(path) {
+        Ok(json) => {
+            serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::StoredList;
+    use crate::server::ban_list::{BanDetails, IpBanEntry};
+    use crate::test_support::temp_path;
+
+    #[test]
+    fn removing_a_missing_entry_changes_nothing() {
+        let path = temp_path("stored-list");
+        let list = StoredList::load(path.clone()).expect("missing file is an empty list");
+        let mut details = BanDetails::new("Server".to_owned(), None);
+        details.expires = "2000-01-01 00:00:00 +0000".to_owned();
+        let expired = IpBanEntry {
+            ip: "127.0.0.1".to_owned(),
+            details,
+        };
+        assert!(list.add(expired).expect("list should save"));
+
+        assert!(!list.remove("127.0.0.1").expect("list should save"));
+        assert_eq!(list.entries.read().len(), 1);
+        list.reload().expect("list should reload");
+        assert_eq!(list.entries.read().len(), 1);
+        let _ = fs::remove_file(&path);
+    }
+}
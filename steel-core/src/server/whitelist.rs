@@ -0,0 +1,133 @@
+//! The server whitelist, vanilla's `UserWhiteList`, persisted to `whitelist.json`.
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::server::stored_list::{ListEntry, StoredList};
+
+/// A whitelisted player, stored in the same shape as vanilla's `whitelist.json` entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WhitelistEntry {
+    /// The player's UUID.
+    pub uuid: Uuid,
+    /// The player's name when they were whitelisted.
+    pub name: String,
+}
+
+impl ListEntry for WhitelistEntry {
+    type Key = Uuid;
+
+    fn key(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+/// The players allowed to join while the whitelist is enforced.
+pub struct Whitelist {
+    entries: StoredList<WhitelistEntry>,
+    enabled: AtomicBool,
+}
+
+impl Whitelist {
+    /// Loads the list from `path`. A missing file is an empty list.
+    pub fn load(path: PathBuf, enabled: bool) -> io::Result<Self> {
+        Ok(Self {
+            entries: StoredList::load(path)?,
+            enabled: AtomicBool::new(enabled),
+        })
+    }
+
+    /// Whether only whitelisted players and operators may join.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Turns enforcement on or off. Returns `false` if it already was.
+    // TODO: write the state back to the config like vanilla's `white-list` property
+    pub fn set_enabled(&self, enabled: bool) -> bool {
+        self.enabled.swap(enabled, Ordering::Relaxed) != enabled
+    }
+
+    /// Whether `uuid` is on the list.
+    #[must_use]
+    pub fn contains(&self, uuid: Uuid) -> bool {
+        self.entries.get(&uuid).is_some()
+    }
+
+    /// The names of the whitelisted players, in the order they were added.
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.entries.entries().into_iter().map(|e| e.name).collect()
+    }
+
+    /// Adds a player and saves the list. Returns `false` if they already were on it.
+    pub fn add(&self, entry: WhitelistEntry) -> io::Result<bool> {
+        self.entries.add(entry)
+    }
+
+    /// Removes a player and saves the list. Returns `false` if they weren't on it.
+    pub fn remove(&self, uuid: Uuid) -> io::Result<bool> {
+        self.entries.remove(&uuid)
+    }
+
+    /// Rereads the list from disk.
+    pub fn reload(&self) -> io::Result<()> {
+        self.entries.reload()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use uuid::Uuid;
+
+    use super::{Whitelist, WhitelistEntry};
+    use crate::test_support::temp_path;
+
+    #[test]
+    fn players_are_persisted_in_vanilla_format() {
+        let path = temp_path("whitelist");
+        let uuid = Uuid::from_u128(1);
+
+        let whitelist =
+            Whitelist::load(path.clone(), false).expect("missing file is an empty list");
+        assert!(!whitelist.contains(uuid));
+        let entry = WhitelistEntry {
+            uuid,
+            name: "Steve".to_owned(),
+        };
+        assert!(whitelist.add(entry.clone()).expect("whitelist should save"));
+        assert!(!whitelist.add(entry).expect("whitelist should save"));
+
+        let json = fs::read_to_string(&path).expect("whitelist.json should be written");
+        assert!(json.contains("\"name\": \"Steve\""));
+        let reloaded = Whitelist::load(path.clone(), false).expect("whitelist.json should load");
+        assert_eq!(reloaded.names(), ["Steve"]);
+
+        assert!(reloaded.remove(uuid).expect("whitelist should save"));
+        assert!(!reloaded.remove(uuid).expect("whitelist should save"));
+        assert!(!reloaded.contains(uuid));
+        whitelist.reload().expect("whitelist.json should load");
+        assert!(!whitelist.contains(uuid));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn enforcement_only_changes_once() {
+        let whitelist =
+            Whitelist::load(temp_path("whitelist"), false).expect("missing file is an empty list");
+        assert!(!whitelist.is_enabled());
+
+        assert!(whitelist.set_enabled(true));
+        assert!(!whitelist.set_enabled(true));
+        assert!(whitelist.is_enabled());
+
+        assert!(whitelist.set_enabled(false));
+        assert!(!whitelist.is_enabled());
+    }
+}
@@ -11,6 +11,7 @@ use std::{
 };
 
 use crate::chunk::chunk_access::{ChunkAccess, ChunkStatus};
+use crate::chunk::chunk_ticket_manager::ChunkTicket;
 use crate::chunk::light::{
     LightLayer, LightSectionEmptinessChange, MAX_LIGHT_LEVEL, has_different_light_properties,
 };
@@ -184,7 +185,7 @@ pub use crate::config::WorldStorageConfig;
 use crate::worldgen::generators::vanilla::fuzzed_biome_at_block;
 use crate::worldgen::{ChunkGenerator, ChunkGeneratorType};
 pub use border::WorldBorderError;
-use border::{WorldBorder, WorldBorderSnapshot};
+pub(crate) use border::{WorldBorder, WorldBorderSnapshot};
 pub use level_reader::{LevelAccessor, LevelReader, ScheduledTickAccess};
 pub use player_area_map::PlayerAreaMap;
 pub use player_map::PlayerMap;
@@ -198,6 +199,9 @@ fn triangle_random(mode: f64, deviation: f64) -> f64 {
     mode + deviation * (rand::random::<f64>() - rand::random::<f64>())
 }
 
+/// Keeps a `/forceload` chunk loaded and simulated, like vanilla's `TicketType.FORCED`.
+const FORCED_CHUNK_TICKET: ChunkTicket = ChunkTicket::simulated_full_chunks(0);
+
 const fn initialize_border_packet(snapshot: WorldBorderSnapshot) -> CInitializeBorder {
     CInitializeBorder {
         new_center_x: snapshot.center_x,
@@ -325,11 +329,13 @@ pub struct World {
     pub is_flat: bool,
     /// Sea level sent in login/respawn packets.
     pub sea_level: i32,
-    /// Default game mode for first-visit player data.
-    pub default_gamemode: GameType,
+    /// Configured default game mode, used until `/defaultgamemode` overrides it.
+    configured_gamemode: GameType,
     /// Whether the tick rate is running normally (not frozen/paused).
     /// When false, movement validation checks are skipped.
     tick_runs_normally: AtomicBool,
+    /// Whether `/save-off` stopped this world from being saved, vanilla's `ServerLevel.noSave`.
+    no_save: AtomicBool,
     /// Central runtime entity ownership and lookup.
     entity_manager: WorldEntityManager,
     /// Entity tracker for managing which players can see which entities.
@@ -424,6 +430,12 @@ impl World {
                 generation_pool,
             ));
             chunk_map.start_generation_refill_loop();
+            {
+                let mut tickets = chunk_map.chunk_tickets.lock();
+                for &(x, z) in &level_data.data().forced_chunks {
+                    tickets.add_ticket(ChunkPos::new(x, z), FORCED_CHUNK_TICKET);
+                }
+            }
 
             Self {
                 chunk_map,
@@ -438,8 +450,9 @@ impl World {
                 compression,
                 is_flat,
                 sea_level,
-                default_gamemode,
+                configured_gamemode: default_gamemode,
                 tick_runs_normally: AtomicBool::new(true),
+                no_save: AtomicBool::new(false),
                 entity_manager: WorldEntityManager::new(),
                 entity_tracker: EntityTracker::new(),
                 navigating_mobs: NavigatingMobTracker::new(),
@@ -469,6 +482,31 @@ impl World {
         }
     }
 
+    /// Saves the level data and every dirty chunk while the world keeps running.
+    ///
+    /// With `flush`, region headers are written too so the save is complete on disk. Nothing is
+    /// saved while [`Self::no_save`] is set unless `force` is given, as for `/save-all`.
+    /// Returns the number of chunks saved.
+    pub async fn save(&self, flush: bool, force: bool) -> io::Result<usize> {
+        if self.no_save() && !force {
+            return Ok(0);
+        }
+        self.sync_world_border_to_level_data();
+        let pending = self.level_data.write().take_pending_save()?;
+        if let Some(pending) = pending
+            && let Err(e) = pending.write().await
+        {
+            self.level_data.write().mark_dirty();
+            return Err(e);
+        }
+
+        let saved = self.chunk_map.save_dirty_chunks().await?;
+        if flush {
+            self.chunk_map.storage.flush_all().await?;
+        }
+        Ok(saved)
+    }
+
     /// Returns the domain this loaded world belongs to.
     #[must_use]
     pub fn domain(&self) -> &str {
@@ -626,6 +664,45 @@ impl World {
         }
     }
 
+    /// Whether `/forceload` keeps the chunk at `pos` loaded.
+    #[must_use]
+    pub fn is_chunk_forced(&self, pos: ChunkPos) -> bool {
+        self.level_data.read().data().is_chunk_forced(pos)
+    }
+
+    /// Every chunk kept loaded by `/forceload`.
+    #[must_use]
+    pub fn forced_chunks(&self) -> Vec<ChunkPos> {
+        self.level_data
+            .read()
+            .data()
+            .forced_chunks
+            .iter()
+            .map(|&(x, z)| ChunkPos::new(x, z))
+            .collect()
+    }
+
+    /// Force loads or releases the chunk at `pos`, vanilla's `ServerLevel.setChunkForced`.
+    ///
+    /// Returns whether the chunk's forced state changed.
+    pub fn set_chunk_forced(&self, pos: ChunkPos, forced: bool) -> bool {
+        {
+            let mut level_data = self.level_data.write();
+            if level_data.data().is_chunk_forced(pos) == forced {
+                return false;
+            }
+            level_data.data_mut().set_chunk_forced(pos, forced);
+        }
+
+        let mut tickets = self.chunk_map.chunk_tickets.lock();
+        if forced {
+            tickets.add_ticket(pos, FORCED_CHUNK_TICKET);
+        } else {
+            tickets.remove_ticket(pos, FORCED_CHUNK_TICKET);
+        }
+        true
+    }
+
     fn set_game_time(&self, tick_count: u64) {
         let mut level_data = self.level_data.write();
         level_data.data_mut().game_time = tick_count as i64;
@@ -641,6 +718,20 @@ impl World {
         self.level_data.read().data().difficulty
     }
 
+    /// Returns the game mode given to players on their first visit.
+    pub fn default_gamemode(&self) -> GameType {
+        self.level_data
+            .read()
+            .data()
+            .default_gamemode
+            .unwrap_or(self.configured_gamemode)
+    }
+
+    /// Overrides the configured default game mode, persisting it in the level data.
+    pub fn set_default_gamemode(&self, game_type: GameType) {
+        self.level_data.write().data_mut().default_gamemode = Some(game_type);
+    }
+
     /// Returns the total height of the world in blocks.
     pub const fn get_height(&self) -> i32 {
         self.dimension_type.height
@@ -934,6 +1025,18 @@ impl World {
             .store(runs_normally, Ordering::Relaxed);
     }
 
+    /// Whether `/save-off` stopped this world from being saved. Chunks are then neither
+    /// unloaded nor written by autosaves.
+    #[must_use]
+    pub fn no_save(&self) -> bool {
+        self.no_save.load(Ordering::Relaxed)
+    }
+
+    /// Stops or resumes saving this world, for `/save-off` and `/save-on`.
+    pub fn set_no_save(&self, no_save: bool) {
+        self.no_save.store(no_save, Ordering::Relaxed);
+    }
+
     /// Gets the value of a game rule.
     /// WARNING: this function acquires a read lock on the level data.
     /// if you already have a write lock on level data, this will DEADLOCK
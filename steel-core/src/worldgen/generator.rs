@@ -2,6 +2,7 @@
 
 use enum_dispatch::enum_dispatch;
 use glam::IVec3;
+use steel_registry::biome::BiomeRef;
 use steel_utils::random::{
     PositionalRandom as _, Random as _, RandomSource, RandomSplitter, name_hash::NameHash,
     xoroshiro::Xoroshiro,
@@ -42,6 +43,11 @@ pub trait ChunkGenerator: Send + Sync {
         None
     }
 
+    /// Returns a sampler of the biome generated at a quart position, used by biome locate queries.
+    fn biome_sampler(&self) -> Option<Box<dyn FnMut(IVec3) -> BiomeRef + '_>> {
+        None
+    }
+
     /// Creates the structures in a chunk.
     fn create_structures(&self, chunk: &ChunkAccess);
 
@@ -222,6 +222,10 @@ impl ChunkGenerator for FlatChunkGenerator {
         self.structure_generator.as_ref()
     }
 
+    fn biome_sampler(&self) -> Option<Box<dyn FnMut(IVec3) -> BiomeRef + '_>> {
+        Some(Box::new(|_| &vanilla_biomes::PLAINS))
+    }
+
     fn create_structures(&self, chunk: &ChunkAccess) {
         let Some(structure_generator) = &self.structure_generator else {
             return;
@@ -194,6 +194,13 @@ impl<N: DimensionNoises> ChunkGenerator for VanillaGenerator<N> {
         Some(&self.structure_generator)
     }
 
+    fn biome_sampler(&self) -> Option<Box<dyn FnMut(IVec3) -> BiomeRef + '_>> {
+        let mut sampler = self.biome_source.chunk_sampler();
+        Some(Box::new(move |quart| {
+            sampler.sample(quart.x, quart.y, quart.z)
+        }))
+    }
+
     fn create_structures(&self, chunk: &ChunkAccess) {
         let pos = chunk.pos();
         let chunk_x = pos.0.x;
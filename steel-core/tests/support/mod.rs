@@ -1,4 +1,9 @@
+pub(crate) mod server;
+
 use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, process};
 
 use steel_registry::blocks::BlockRef;
 use steel_registry::fluid::FluidRef;
@@ -11,6 +16,15 @@ use steel_utils::{BlockPos, BlockStateId};
 use crate::world::game_event_context::GameEventContext;
 use crate::world::{LevelAccessor, LevelReader, ScheduledTickAccess};
 
+/// A path in the temp directory no other test uses, for a list file named `name`.
+pub(crate) fn temp_path(name: &str) -> PathBuf {
+    let unique = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    env::temp_dir().join(format!("steel-{name}-{}-{unique}.json", process::id()))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct PlacedBlockState {
     pub(crate) pos: BlockPos,
@@ -0,0 +1,260 @@
+//! A server with one empty, in-memory world, for tests that build a `CommandContext` or run
+//! commands end to end.
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use steel_protocol::packet_traits::{CompressionInfo, EncodedPacket};
+use steel_protocol::packets::game::CSystemChatMessage;
+use steel_protocol::utils::ConnectionProtocol;
+use steel_registry::test_support::init_test_registry;
+use steel_registry::vanilla_dimension_types::OVERWORLD;
+use steel_utils::Identifier;
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::{Difficulty, GameType};
+use text_components::TextComponent;
+use tokio::runtime::{Builder, EnterGuard, Runtime};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::behavior::init_behaviors;
+use crate::command::sender::CommandSender;
+use crate::config::{ResolvedDomainConfig, RuntimeConfig, StorageSelection};
+use crate::entity::next_entity_id;
+use crate::level_data::WorldGenerationSettings;
+use crate::player::connection::NetworkConnection;
+use crate::player::player_data_storage::PlayerDataStorage;
+use crate::player::{ClientInformation, GameProfile, Player, PlayerConnection};
+use crate::server::worlds::WorldMap;
+use crate::server::{PlayerLists, Server};
+use crate::world::{World, WorldConfig, WorldStorageConfig};
+use crate::worldgen::{ChunkGeneratorType, EmptyChunkGenerator};
+
+const DOMAIN: &str = "test";
+
+static RUNTIME: OnceLock<Arc<Runtime>> = OnceLock::new();
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+/// The runtime every test server's chunk tasks run on.
+fn runtime() -> &'static Arc<Runtime> {
+    RUNTIME.get_or_init(|| {
+        Arc::new(
+            Builder::new_multi_thread()
+                .worker_threads(1)
+                .enable_all()
+                .build()
+                .expect("test runtime should initialize"),
+        )
+    })
+}
+
+/// Enters the test runtime, for commands that spawn tasks.
+pub(crate) fn enter_runtime() -> EnterGuard<'static> {
+    runtime().enter()
+}
+
+fn test_config() -> RuntimeConfig {
+    RuntimeConfig {
+        max_players: 20,
+        view_distance: 2,
+        simulation_distance: 2,
+        online_mode: false,
+        auth_server: None,
+        encryption: false,
+        allow_flight: false,
+        motd: String::new(),
+        use_favicon: false,
+        favicon: String::new(),
+        enforce_secure_chat: false,
+        chat_spam_threshold_seconds: 10,
+        command_spam_threshold_seconds: 10,
+        case_insensitive_commands: false,
+        white_list: false,
+        player_idle_timeout: 0,
+        compression: None,
+        server_links: None,
+        chunk_generation_threads: Some(1),
+    }
+}
+
+fn temp_server_dir() -> PathBuf {
+    let path = env::temp_dir().join(format!(
+        "steel-test-server-{}-{}",
+        process::id(),
+        NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&path).expect("temp server dir should be created");
+    path
+}
+
+/// Creates a server with no players and a single empty overworld. Its player lists and player
+/// data live in a fresh temporary directory.
+pub(crate) fn test_server() -> Arc<Server> {
+    init_test_registry();
+    init_behaviors();
+    let runtime = runtime();
+    let dir = temp_server_dir();
+    let key = Identifier::new_static(DOMAIN, "overworld");
+
+    let world = runtime
+        .block_on(World::new_with_config(
+            runtime.clone(),
+            key.clone(),
+            &OVERWORLD,
+            0,
+            WorldConfig {
+                storage: WorldStorageConfig::RamOnly,
+                level_data_path: None,
+                generator: Arc::new(ChunkGeneratorType::Empty(EmptyChunkGenerator::new())),
+                generation_settings: WorldGenerationSettings {
+                    generator: Identifier::new_static("steel", "empty"),
+                    config: toml::Value::Table(toml::map::Map::new()),
+                    dimension_type: OVERWORLD.key.clone(),
+                    min_y: OVERWORLD.min_y,
+                    height: OVERWORLD.height,
+                },
+                view_distance: 2,
+                simulation_distance: 2,
+                compression: None,
+                is_flat: false,
+                sea_level: 63,
+                default_gamemode: GameType::Survival,
+                difficulty: Difficulty::default(),
+            },
+            Arc::new(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(1)
+                    .build()
+                    .expect("test generation pool should initialize"),
+            ),
+        ))
+        .expect("test world should initialize");
+    let mut worlds = WorldMap::new(
+        DOMAIN.to_owned(),
+        &[ResolvedDomainConfig {
+            name: DOMAIN.to_owned(),
+            default_world: key.clone(),
+            worlds: vec![key.clone()],
+        }],
+    );
+    worlds.insert(key, world);
+
+    let player_data_storage = runtime
+        .block_on(PlayerDataStorage::new(
+            dir.clone(),
+            StorageSelection {
+                kind: Identifier::new_static("steel", "file"),
+                config: None,
+            },
+        ))
+        .expect("test player data storage should initialize");
+    let lists = PlayerLists::load(&dir, false).expect("empty player lists should load");
+
+    Arc::new(Server::assemble(
+        Arc::new(test_config()),
+        CancellationToken::new(),
+        worlds,
+        player_data_storage,
+        lists,
+    ))
+}
+
+/// Runs `command` on `server` the way a chat or console command is run.
+pub(crate) fn run_command(server: &Arc<Server>, sender: CommandSender, command: &str) {
+    server
+        .command_dispatcher
+        .read()
+        .handle_command(sender, command.to_owned(), server);
+}
+
+/// A player in a test server's overworld, whose packets are recorded instead of sent.
+pub(crate) struct TestPlayer {
+    /// The player.
+    pub(crate) player: Arc<Player>,
+    sent: Arc<SyncMutex<Vec<EncodedPacket>>>,
+}
+
+impl TestPlayer {
+    /// Whether the player was sent `text` as a system chat message.
+    pub(crate) fn received_message(&self, text: &TextComponent) -> bool {
+        let expected = EncodedPacket::from_bare(
+            CSystemChatMessage::new(text, self.player.as_ref(), false),
+            None,
+            ConnectionProtocol::Play,
+        )
+        .expect("system chat message should encode");
+        self.sent
+            .lock()
+            .iter()
+            .any(|packet| packet.encoded_data[..] == expected.encoded_data[..])
+    }
+}
+
+/// Adds a player named `name` to the overworld of `server`.
+pub(crate) fn add_player(server: &Arc<Server>, name: &str) -> TestPlayer {
+    let sent = Arc::new(SyncMutex::new(Vec::new()));
+    let connection = TestConnection {
+        sent: sent.clone(),
+        closed: AtomicBool::new(false),
+    };
+    let world = server.overworld().clone();
+    let entity_id = next_entity_id();
+    let player = Arc::new_cyclic(|weak| {
+        Player::new(
+            GameProfile {
+                id: Uuid::from_u128(u128::from(entity_id.unsigned_abs())),
+                name: name.to_owned(),
+                properties: Vec::new(),
+                profile_actions: None,
+            },
+            Arc::new(PlayerConnection::Other(Box::new(connection))),
+            world.clone(),
+            Arc::downgrade(server),
+            server.config.clone(),
+            entity_id,
+            weak,
+            ClientInformation::default(),
+        )
+    });
+    assert!(world.players.insert(player.clone()), "{name} joined twice");
+    TestPlayer { player, sent }
+}
+
+struct TestConnection {
+    sent: Arc<SyncMutex<Vec<EncodedPacket>>>,
+    closed: AtomicBool,
+}
+
+impl NetworkConnection for TestConnection {
+    fn compression(&self) -> Option<CompressionInfo> {
+        None
+    }
+
+    fn send_encoded(&self, packet: EncodedPacket) {
+        self.sent.lock().push(packet);
+    }
+
+    fn send_encoded_bundle(&self, packets: Vec<EncodedPacket>) {
+        self.sent.lock().extend(packets);
+    }
+
+    fn disconnect_with_reason(&self, _reason: TextComponent) {
+        self.close();
+    }
+
+    fn tick(&self) {}
+
+    fn latency(&self) -> i32 {
+        0
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+    }
+
+    fn closed(&self) -> bool {
+        self.closed.load(Ordering::Relaxed)
+    }
+}
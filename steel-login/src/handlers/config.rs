@@ -113,6 +113,7 @@ impl JavaTcpClient {
                 self.compression.load(),
                 self.network_writer.clone(),
                 self.id,
+                self.address,
                 player_weak.clone(),
             );
             let connection = Arc::new(PlayerConnection::Java(java_connection));
@@ -171,11 +171,20 @@ impl JavaTcpClient {
             .with_reader_encryption(secret_key)
     }
 
-    /// Finishes the login process and transitions to the configuration state.
+    /// Finishes the login process and transitions to the configuration state, or kicks players
+    /// who are banned or not whitelisted.
     ///
     /// # Panics
     /// This function will panic if the compression threshold cannot be converted to an i32.
     pub(crate) async fn finish_login(&self, profile: &GameProfile) -> ConnectionAction {
+        if let Some(reason) = self
+            .server
+            .login_rejection(profile.id, self.address.ip().to_canonical())
+        {
+            self.kick(reason).await;
+            return ConnectionAction::none();
+        }
+
         let mut action = ConnectionAction::none();
         if let Some(compression) = self.server.config.compression {
             self.send_bare_packet_now(CLoginCompression::new(
@@ -0,0 +1,128 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_BOSS_EVENT;
+use steel_utils::{codec::VarInt, serial::WriteTo};
+use text_components::TextComponent;
+use uuid::Uuid;
+
+/// The color of a boss bar, vanilla's `BossEvent.BossBarColor`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BossBarColor {
+    Pink,
+    Blue,
+    Red,
+    Green,
+    Yellow,
+    Purple,
+    White,
+}
+
+impl BossBarColor {
+    /// The vanilla serialized name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Pink => "pink",
+            Self::Blue => "blue",
+            Self::Red => "red",
+            Self::Green => "green",
+            Self::Yellow => "yellow",
+            Self::Purple => "purple",
+            Self::White => "white",
+        }
+    }
+}
+
+/// How a boss bar is divided, vanilla's `BossEvent.BossBarOverlay`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BossBarOverlay {
+    Progress,
+    Notched6,
+    Notched10,
+    Notched12,
+    Notched20,
+}
+
+impl BossBarOverlay {
+    /// The vanilla serialized name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Progress => "progress",
+            Self::Notched6 => "notched_6",
+            Self::Notched10 => "notched_10",
+            Self::Notched12 => "notched_12",
+            Self::Notched20 => "notched_20",
+        }
+    }
+}
+
+/// The change a [`CBossEvent`] makes to the client's boss bar.
+#[derive(Clone, Debug)]
+pub enum BossEventAction {
+    Add {
+        name: TextComponent,
+        progress: f32,
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+        /// Whether to darken the sky (1), play boss music (2) and create fog (4).
+        flags: u8,
+    },
+    Remove,
+    UpdateProgress(f32),
+    UpdateName(TextComponent),
+    UpdateStyle {
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    UpdateProperties(u8),
+}
+
+/// Shows, hides or updates a boss bar on the client.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_BOSS_EVENT)]
+pub struct CBossEvent {
+    pub id: Uuid,
+    pub action: BossEventAction,
+}
+
+impl WriteTo for CBossEvent {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.id.write(writer)?;
+        match &self.action {
+            BossEventAction::Add {
+                name,
+                progress,
+                color,
+                overlay,
+                flags,
+            } => {
+                VarInt(0).write(writer)?;
+                name.write(writer)?;
+                progress.write(writer)?;
+                VarInt(*color as i32).write(writer)?;
+                VarInt(*overlay as i32).write(writer)?;
+                flags.write(writer)
+            }
+            BossEventAction::Remove => VarInt(1).write(writer),
+            BossEventAction::UpdateProgress(progress) => {
+                VarInt(2).write(writer)?;
+                progress.write(writer)
+            }
+            BossEventAction::UpdateName(name) => {
+                VarInt(3).write(writer)?;
+                name.write(writer)
+            }
+            BossEventAction::UpdateStyle { color, overlay } => {
+                VarInt(4).write(writer)?;
+                VarInt(*color as i32).write(writer)?;
+                VarInt(*overlay as i32).write(writer)
+            }
+            BossEventAction::UpdateProperties(flags) => {
+                VarInt(5).write(writer)?;
+                flags.write(writer)
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_CLEAR_TITLES;
+
+/// Packet to hide the current title and subtitle.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_CLEAR_TITLES)]
+pub struct CClearTitles {
+    /// Whether the title animation times are also reset to their defaults.
+    pub reset_times: bool,
+}
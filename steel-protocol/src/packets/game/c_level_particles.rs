@@ -0,0 +1,24 @@
+use glam::{DVec3, Vec3};
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_LEVEL_PARTICLES;
+
+/// Spawns particles around a position on the client.
+///
+/// A count of 0 spawns a single particle moving along `delta` at `max_speed`.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_LEVEL_PARTICLES)]
+pub struct CLevelParticles {
+    /// Ignores the client's particle limit and distance culling.
+    pub override_limiter: bool,
+    /// Shows the particles even when the client's particle setting is minimal.
+    pub always_show: bool,
+    pub pos: DVec3,
+    /// The spread of the particles, or their direction when `count` is 0.
+    pub delta: Vec3,
+    pub max_speed: f32,
+    pub count: i32,
+    /// The particle type registry id. Only types without particle options can be sent.
+    // TODO: write the type-specific particle options
+    #[write(as = VarInt)]
+    pub particle_id: i32,
+}
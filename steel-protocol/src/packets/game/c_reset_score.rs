@@ -0,0 +1,13 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_RESET_SCORE;
+
+/// Removes a score holder's scores on the client.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_RESET_SCORE)]
+pub struct CResetScore {
+    #[write(as = Prefixed(VarInt))]
+    pub owner: String,
+    /// Only remove the score in this objective, or every score when `None`.
+    #[write(as = Prefixed(VarInt))]
+    pub objective_name: Option<String>,
+}
@@ -0,0 +1,21 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_SET_ACTION_BAR_TEXT;
+use text_components::{TextComponent, resolving::TextResolutor};
+
+/// Packet to set the text shown above the hotbar.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_SET_ACTION_BAR_TEXT)]
+pub struct CSetActionBarText {
+    /// The text to display.
+    pub text: TextComponent,
+}
+
+impl CSetActionBarText {
+    /// Creates the packet, resolving `text` for `player`.
+    #[must_use]
+    pub fn new<T: TextResolutor>(text: &TextComponent, player: &T) -> Self {
+        Self {
+            text: text.resolve(player),
+        }
+    }
+}
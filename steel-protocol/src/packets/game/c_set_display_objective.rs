@@ -0,0 +1,14 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_SET_DISPLAY_OBJECTIVE;
+
+/// Shows an objective in a display slot on the client.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_SET_DISPLAY_OBJECTIVE)]
+pub struct CSetDisplayObjective {
+    /// The vanilla `DisplaySlot` id.
+    #[write(as = VarInt)]
+    pub slot: i32,
+    /// The objective shown, or empty to clear the slot.
+    #[write(as = Prefixed(VarInt))]
+    pub objective_name: String,
+}
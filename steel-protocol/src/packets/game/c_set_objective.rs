@@ -0,0 +1,98 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_SET_OBJECTIVE;
+use steel_utils::{
+    codec::VarInt,
+    serial::{PrefixedWrite, WriteTo},
+};
+use text_components::TextComponent;
+
+/// How the client renders an objective's scores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectiveRenderType {
+    Integer,
+    Hearts,
+}
+
+impl ObjectiveRenderType {
+    /// The vanilla serialized name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Integer => "integer",
+            Self::Hearts => "hearts",
+        }
+    }
+}
+
+/// The change a [`CSetObjective`] makes to the client's objective.
+#[derive(Clone, Debug)]
+pub enum ObjectiveAction {
+    Add {
+        display_name: TextComponent,
+        render_type: ObjectiveRenderType,
+    },
+    Remove,
+    Change {
+        display_name: TextComponent,
+        render_type: ObjectiveRenderType,
+    },
+}
+
+/// Adds, removes or updates a scoreboard objective on the client.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_SET_OBJECTIVE)]
+pub struct CSetObjective {
+    pub objective_name: String,
+    pub action: ObjectiveAction,
+}
+
+impl WriteTo for CSetObjective {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.objective_name.write_prefixed::<VarInt>(writer)?;
+        match &self.action {
+            ObjectiveAction::Add {
+                display_name,
+                render_type,
+            }
+            | ObjectiveAction::Change {
+                display_name,
+                render_type,
+            } => {
+                let method: u8 = if matches!(self.action, ObjectiveAction::Add { .. }) {
+                    0
+                } else {
+                    2
+                };
+                method.write(writer)?;
+                display_name.write(writer)?;
+                VarInt(*render_type as i32).write(writer)?;
+                // TODO: send the objective's number format
+                false.write(writer)
+            }
+            ObjectiveAction::Remove => 1u8.write(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::serial::WriteTo;
+
+    use super::{CSetObjective, ObjectiveAction};
+
+    #[test]
+    fn removing_writes_only_the_name_and_method() {
+        let packet = CSetObjective {
+            objective_name: "kills".to_owned(),
+            action: ObjectiveAction::Remove,
+        };
+        let mut bytes = Vec::new();
+        packet
+            .write(&mut bytes)
+            .expect("writing to a vec should not fail");
+
+        assert_eq!(bytes, [5, b'k', b'i', b'l', b'l', b's', 1]);
+    }
+}
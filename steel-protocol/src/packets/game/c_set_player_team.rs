@@ -0,0 +1,163 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_SET_PLAYER_TEAM;
+use steel_utils::{
+    codec::VarInt,
+    serial::{PrefixedWrite, WriteTo},
+};
+use text_components::TextComponent;
+
+/// Who sees the name tags of a team's members, vanilla's `Team.Visibility`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NameTagVisibility {
+    Always,
+    Never,
+    HideForOtherTeams,
+    HideForOwnTeam,
+}
+
+impl NameTagVisibility {
+    /// The vanilla serialized name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Never => "never",
+            Self::HideForOtherTeams => "hideForOtherTeams",
+            Self::HideForOwnTeam => "hideForOwnTeam",
+        }
+    }
+}
+
+/// Who pushes a team's members, vanilla's `Team.CollisionRule`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollisionRule {
+    Always,
+    Never,
+    PushOtherTeams,
+    PushOwnTeam,
+}
+
+impl CollisionRule {
+    /// The vanilla serialized name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Never => "never",
+            Self::PushOtherTeams => "pushOtherTeams",
+            Self::PushOwnTeam => "pushOwnTeam",
+        }
+    }
+}
+
+/// The options of a team the client renders.
+#[derive(Clone, Debug)]
+pub struct TeamParameters {
+    pub display_name: TextComponent,
+    pub allow_friendly_fire: bool,
+    pub see_friendly_invisibles: bool,
+    pub nametag_visibility: NameTagVisibility,
+    pub collision_rule: CollisionRule,
+    /// The `ChatFormatting` id of the team color.
+    pub color: i32,
+    pub prefix: TextComponent,
+    pub suffix: TextComponent,
+}
+
+impl WriteTo for TeamParameters {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.display_name.write(writer)?;
+        let options =
+            u8::from(self.allow_friendly_fire) | (u8::from(self.see_friendly_invisibles) << 1);
+        options.write(writer)?;
+        VarInt(self.nametag_visibility as i32).write(writer)?;
+        VarInt(self.collision_rule as i32).write(writer)?;
+        VarInt(self.color).write(writer)?;
+        self.prefix.write(writer)?;
+        self.suffix.write(writer)
+    }
+}
+
+/// The change a [`CSetPlayerTeam`] makes to the client's team.
+#[derive(Clone, Debug)]
+pub enum TeamAction {
+    Add {
+        parameters: TeamParameters,
+        players: Vec<String>,
+    },
+    Remove,
+    Change(TeamParameters),
+    Join(Vec<String>),
+    Leave(Vec<String>),
+}
+
+/// Adds, removes or updates a team on the client, or changes its members.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_SET_PLAYER_TEAM)]
+pub struct CSetPlayerTeam {
+    pub name: String,
+    pub action: TeamAction,
+}
+
+impl WriteTo for CSetPlayerTeam {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.name.write_prefixed::<VarInt>(writer)?;
+        match &self.action {
+            TeamAction::Add {
+                parameters,
+                players,
+            } => {
+                0u8.write(writer)?;
+                parameters.write(writer)?;
+                write_players(players, writer)
+            }
+            TeamAction::Remove => 1u8.write(writer),
+            TeamAction::Change(parameters) => {
+                2u8.write(writer)?;
+                parameters.write(writer)
+            }
+            TeamAction::Join(players) => {
+                3u8.write(writer)?;
+                write_players(players, writer)
+            }
+            TeamAction::Leave(players) => {
+                4u8.write(writer)?;
+                write_players(players, writer)
+            }
+        }
+    }
+}
+
+fn write_players(players: &[String], writer: &mut impl Write) -> Result<()> {
+    VarInt(i32::try_from(players.len()).unwrap_or(i32::MAX)).write(writer)?;
+    for player in players {
+        player.write_prefixed::<VarInt>(writer)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::serial::WriteTo;
+
+    use super::{CSetPlayerTeam, TeamAction};
+
+    #[test]
+    fn joining_writes_the_member_names() {
+        let packet = CSetPlayerTeam {
+            name: "red".to_owned(),
+            action: TeamAction::Join(vec!["Steve".to_owned()]),
+        };
+        let mut bytes = Vec::new();
+        packet
+            .write(&mut bytes)
+            .expect("writing to a vec should not fail");
+
+        assert_eq!(
+            bytes,
+            [3, b'r', b'e', b'd', 3, 1, 5, b'S', b't', b'e', b'v', b'e']
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_SET_SCORE;
+use steel_utils::{
+    codec::VarInt,
+    serial::{PrefixedWrite, WriteTo},
+};
+use text_components::TextComponent;
+
+/// Sets a score holder's score in an objective on the client.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_SET_SCORE)]
+pub struct CSetScore {
+    pub owner: String,
+    pub objective_name: String,
+    pub score: i32,
+    /// Replaces the owner's name in the objective's display.
+    pub display: Option<TextComponent>,
+}
+
+impl WriteTo for CSetScore {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.owner.write_prefixed::<VarInt>(writer)?;
+        self.objective_name.write_prefixed::<VarInt>(writer)?;
+        VarInt(self.score).write(writer)?;
+        self.display.write(writer)?;
+        // TODO: send the score's number format
+        false.write(writer)
+    }
+}
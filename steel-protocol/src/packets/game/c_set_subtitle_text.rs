@@ -0,0 +1,21 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_SET_SUBTITLE_TEXT;
+use text_components::{TextComponent, resolving::TextResolutor};
+
+/// Packet to set the subtitle shown below the title.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_SET_SUBTITLE_TEXT)]
+pub struct CSetSubtitleText {
+    /// The text to display.
+    pub text: TextComponent,
+}
+
+impl CSetSubtitleText {
+    /// Creates the packet, resolving `text` for `player`.
+    #[must_use]
+    pub fn new<T: TextResolutor>(text: &TextComponent, player: &T) -> Self {
+        Self {
+            text: text.resolve(player),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_SET_TITLE_TEXT;
+use text_components::{TextComponent, resolving::TextResolutor};
+
+/// Packet to set the title shown in the middle of the screen.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_SET_TITLE_TEXT)]
+pub struct CSetTitleText {
+    /// The text to display.
+    pub text: TextComponent,
+}
+
+impl CSetTitleText {
+    /// Creates the packet, resolving `text` for `player`.
+    #[must_use]
+    pub fn new<T: TextResolutor>(text: &TextComponent, player: &T) -> Self {
+        Self {
+            text: text.resolve(player),
+        }
+    }
+}
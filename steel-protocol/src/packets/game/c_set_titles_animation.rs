@@ -0,0 +1,14 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_SET_TITLES_ANIMATION;
+
+/// Packet to set how long titles take to fade in, stay and fade out, in ticks.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_SET_TITLES_ANIMATION)]
+pub struct CSetTitlesAnimation {
+    /// Ticks spent fading in.
+    pub fade_in: i32,
+    /// Ticks the title stays fully visible.
+    pub stay: i32,
+    /// Ticks spent fading out.
+    pub fade_out: i32,
+}
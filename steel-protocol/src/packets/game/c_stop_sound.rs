@@ -0,0 +1,88 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_STOP_SOUND;
+use steel_utils::{Identifier, codec::VarInt, serial::WriteTo};
+
+use super::SoundSource;
+
+/// Stops sounds playing on the client.
+///
+/// Without a source or name every sound stops; each given one narrows the sounds stopped.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_STOP_SOUND)]
+pub struct CStopSound {
+    /// Only stop sounds in this category.
+    pub source: Option<SoundSource>,
+    /// Only stop sounds with this id.
+    pub name: Option<Identifier>,
+}
+
+impl CStopSound {
+    const HAS_SOURCE: u8 = 1;
+    const HAS_NAME: u8 = 2;
+
+    /// The flags byte vanilla writes before the optional fields.
+    #[must_use]
+    pub const fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.source.is_some() {
+            flags |= Self::HAS_SOURCE;
+        }
+        if self.name.is_some() {
+            flags |= Self::HAS_NAME;
+        }
+        flags
+    }
+}
+
+impl WriteTo for CStopSound {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.flags().write(writer)?;
+        if let Some(source) = self.source {
+            VarInt(source.as_varint()).write(writer)?;
+        }
+        if let Some(name) = &self.name {
+            name.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::{Identifier, serial::WriteTo};
+
+    use super::CStopSound;
+    use crate::packets::game::SoundSource;
+
+    fn encode(packet: &CStopSound) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        packet
+            .write(&mut bytes)
+            .expect("writing to a vec should not fail");
+        bytes
+    }
+
+    #[test]
+    fn a_source_alone_is_written_after_its_flag() {
+        let packet = CStopSound {
+            source: Some(SoundSource::Blocks),
+            name: None,
+        };
+
+        assert_eq!(encode(&packet), [1, 4]);
+    }
+
+    #[test]
+    fn a_specific_sound_sets_both_flags() {
+        let packet = CStopSound {
+            source: Some(SoundSource::Master),
+            name: Some(Identifier::vanilla_static("ui.button.click")),
+        };
+
+        let bytes = encode(&packet);
+        assert_eq!(&bytes[..2], [3, 0]);
+        assert_eq!(bytes.len(), 2 + 1 + "minecraft:ui.button.click".len());
+    }
+}
@@ -5,10 +5,12 @@ mod c_block_destruction;
 mod c_block_entity_data;
 mod c_block_event;
 mod c_block_update;
+mod c_boss_event;
 mod c_bundle_delimiter;
 mod c_change_difficulty;
 mod c_chunk_batch_finished;
 mod c_chunk_batch_start;
+mod c_clear_titles;
 mod c_command_suggestions;
 mod c_commands;
 mod c_container_close;
@@ -25,6 +27,7 @@ mod c_hurt_animation;
 mod c_initialize_border;
 mod c_level_chunk_with_light;
 mod c_level_event;
+mod c_level_particles;
 mod c_light_update;
 mod c_login;
 mod c_move_entity;
@@ -39,9 +42,11 @@ mod c_player_position;
 mod c_remove_entities;
 mod c_remove_mob_effect;
 mod c_remove_player_info;
+mod c_reset_score;
 mod c_respawn;
 mod c_rotate_head;
 mod c_section_blocks_update;
+mod c_set_action_bar_text;
 mod c_set_border_center;
 mod c_set_border_lerp_size;
 mod c_set_border_size;
@@ -52,6 +57,7 @@ mod c_set_chunk_cache_radius;
 mod c_set_chunk_center;
 mod c_set_cursor_item;
 mod c_set_default_spawn_position;
+mod c_set_display_objective;
 mod c_set_entity_data;
 mod c_set_entity_link;
 mod c_set_entity_motion;
@@ -59,9 +65,16 @@ mod c_set_equipment;
 mod c_set_experience;
 mod c_set_health;
 mod c_set_held_slot;
+mod c_set_objective;
 mod c_set_passengers;
+mod c_set_player_team;
+mod c_set_score;
+mod c_set_subtitle_text;
 mod c_set_time;
+mod c_set_title_text;
+mod c_set_titles_animation;
 mod c_sound;
+mod c_stop_sound;
 mod c_system_chat;
 mod c_system_chat_message;
 mod c_tab_list;
@@ -113,12 +126,17 @@ pub use c_block_destruction::CBlockDestruction;
 pub use c_block_entity_data::CBlockEntityData;
 pub use c_block_event::CBlockEvent;
 pub use c_block_update::CBlockUpdate;
+pub use c_boss_event::{BossBarColor, BossBarOverlay, BossEventAction, CBossEvent};
 pub use c_bundle_delimiter::CBundleDelimiter;
 pub use c_change_difficulty::CChangeDifficulty;
 pub use c_chunk_batch_finished::CChunkBatchFinished;
 pub use c_chunk_batch_start::CChunkBatchStart;
+pub use c_clear_titles::CClearTitles;
 pub use c_command_suggestions::{CCommandSuggestions, SuggestionEntry};
-pub use c_commands::{ArgumentType, CCommands, CommandNode, CommandNodeInfo, SuggestionType};
+pub use c_commands::{
+    ArgumentStringTypeBehavior, ArgumentType, CCommands, CommandNode, CommandNodeInfo,
+    SuggestionType,
+};
 pub use c_container_close::CContainerClose;
 pub use c_container_set_content::CContainerSetContent;
 pub use c_container_set_data::CContainerSetData;
@@ -137,6 +155,7 @@ pub use c_level_chunk_with_light::{
     LightUpdatePacketData,
 };
 pub use c_level_event::CLevelEvent;
+pub use c_level_particles::CLevelParticles;
 pub use c_light_update::CLightUpdate;
 pub use c_login::CLogin;
 pub use c_login::CommonPlayerSpawnInfo;
@@ -156,9 +175,11 @@ pub use c_player_position::{CPlayerPosition, RelativeMovement};
 pub use c_remove_entities::CRemoveEntities;
 pub use c_remove_mob_effect::CRemoveMobEffect;
 pub use c_remove_player_info::CRemovePlayerInfo;
+pub use c_reset_score::CResetScore;
 pub use c_respawn::CRespawn;
 pub use c_rotate_head::CRotateHead;
 pub use c_section_blocks_update::{BlockChange, CSectionBlocksUpdate};
+pub use c_set_action_bar_text::CSetActionBarText;
 pub use c_set_border_center::CSetBorderCenter;
 pub use c_set_border_lerp_size::CSetBorderLerpSize;
 pub use c_set_border_size::CSetBorderSize;
@@ -169,6 +190,7 @@ pub use c_set_chunk_cache_radius::CSetChunkCacheRadius;
 pub use c_set_chunk_center::CSetChunkCenter;
 pub use c_set_cursor_item::CSetCursorItem;
 pub use c_set_default_spawn_position::CSetDefaultSpawnPosition;
+pub use c_set_display_objective::CSetDisplayObjective;
 pub use c_set_entity_data::CSetEntityData;
 pub use c_set_entity_link::CSetEntityLink;
 pub use c_set_entity_motion::CSetEntityMotion;
@@ -176,9 +198,18 @@ pub use c_set_equipment::{CSetEquipment, EquipmentSlotItem};
 pub use c_set_experience::CSetExperience;
 pub use c_set_health::CSetHealth;
 pub use c_set_held_slot::CSetHeldSlot;
+pub use c_set_objective::{CSetObjective, ObjectiveAction, ObjectiveRenderType};
 pub use c_set_passengers::CSetPassengers;
+pub use c_set_player_team::{
+    CSetPlayerTeam, CollisionRule, NameTagVisibility, TeamAction, TeamParameters,
+};
+pub use c_set_score::CSetScore;
+pub use c_set_subtitle_text::CSetSubtitleText;
 pub use c_set_time::CSetTime;
+pub use c_set_title_text::CSetTitleText;
+pub use c_set_titles_animation::CSetTitlesAnimation;
 pub use c_sound::{CSound, SoundSource};
+pub use c_stop_sound::CStopSound;
 pub use c_system_chat::CSystemChat;
 pub use c_system_chat_message::CSystemChatMessage;
 pub use c_tab_list::CTabList;
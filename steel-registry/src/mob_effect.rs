@@ -1,6 +1,7 @@
 use std::hash::{Hash, Hasher};
 
 use crate::attribute::{AttributeModifierOperation, AttributeRef};
+use crate::vanilla_mob_effects::{INSTANT_DAMAGE, INSTANT_HEALTH, SATURATION};
 use rustc_hash::FxHashMap;
 use steel_utils::Identifier;
 
@@ -35,6 +36,14 @@ impl MobEffect {
         debug_assert!(id <= i32::MAX as usize);
         id as i32
     }
+
+    /// Returns vanilla `MobEffect.isInstantenous()`, true for effects applied all at once.
+    #[must_use]
+    pub fn is_instantaneous(&self) -> bool {
+        [INSTANT_HEALTH, INSTANT_DAMAGE, SATURATION]
+            .iter()
+            .any(|effect| effect.key == self.key)
+    }
 }
 
 impl Hash for MobEffect {
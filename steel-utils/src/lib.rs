@@ -28,6 +28,7 @@ pub mod random;
 pub mod registry;
 pub mod rotation;
 pub mod serial;
+pub mod snbt;
 pub mod text;
 /// A module for common types.
 pub mod types;
@@ -5,11 +5,11 @@
 use std::{
     collections::HashMap,
     hash::BuildHasher,
-    io::{Result, Write},
+    io::{Error, ErrorKind, Result, Write},
 };
 
 use simdnbt::{
-    ToNbtTag,
+    Mutf8String, ToNbtTag,
     owned::{NbtCompound, NbtTag},
 };
 use text_components::TextComponent;
@@ -213,3 +213,49 @@ impl From<Option<NbtCompound>> for OptionalNbt {
         Self(opt)
     }
 }
+
+/// Writes a compound in the NBT file format: the compound tag id, the root `name`, then the
+/// compound body.
+///
+/// This is different from `WriteTo for NbtCompound`, which writes the nameless network format.
+pub fn write_named_nbt_compound<W: Write>(
+    writer: &mut W,
+    name: &str,
+    compound: &NbtCompound,
+) -> Result<()> {
+    let name = Mutf8String::from(name);
+    let name_length = u16::try_from(name.len()).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            "NBT root name is longer than 65535 bytes",
+        )
+    })?;
+
+    writer.write_all(&[0x0A])?;
+    name_length.write(writer)?;
+    writer.write_all(name.as_bytes())?;
+    WriteTo::write(compound, writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_compound_writes_root_name_before_body() {
+        let mut compound = NbtCompound::new();
+        compound.insert("x", 1_i32);
+
+        let mut bytes = Vec::new();
+        write_named_nbt_compound(&mut bytes, "hi", &compound).expect("write failed");
+
+        assert_eq!(
+            bytes,
+            [
+                0x0A, 0x00, 0x02, b'h', b'i', // root compound named "hi"
+                0x03, 0x00, 0x01, b'x', 0x00, 0x00, 0x00, 0x01, // int "x" = 1
+                0x00, // end
+            ]
+        );
+    }
+}
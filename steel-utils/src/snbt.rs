@@ -0,0 +1,435 @@
+//! Parsing of SNBT (stringified NBT), mirroring vanilla's `TagParser`.
+//!
+//! [`crate::text::to_snbt`] writes the format this module reads.
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+
+/// Parses a string that is exactly one SNBT compound, such as `{Count:1b}`.
+#[must_use]
+pub fn parse_compound(input: &str) -> Option<NbtCompound> {
+    let (compound, consumed) = parse_compound_prefix(input)?;
+    input[consumed..].trim().is_empty().then_some(compound)
+}
+
+/// Parses the SNBT compound at the start of `input`, returning it together with the number of
+/// bytes it spans. Anything after the closing brace is left for the caller.
+#[must_use]
+pub fn parse_compound_prefix(input: &str) -> Option<(NbtCompound, usize)> {
+    let mut parser = TagParser::new(input);
+    parser.skip_whitespace();
+    let compound = parser.read_compound()?;
+    Some((compound, parser.pos))
+}
+
+/// Parses the SNBT value at the start of `input`, such as `5b`, `"text"` or `[1,2]`, returning it
+/// together with the number of bytes it spans.
+#[must_use]
+pub fn parse_tag_prefix(input: &str) -> Option<(NbtTag, usize)> {
+    let mut parser = TagParser::new(input);
+    let tag = parser.read_value()?;
+    Some((tag, parser.pos))
+}
+
+/// How deeply compounds and lists may nest, like vanilla's `TagParser.MAX_DEPTH`.
+const MAX_DEPTH: usize = 512;
+
+struct TagParser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// The number of compounds and lists currently being read.
+    depth: usize,
+}
+
+/// Characters allowed in unquoted keys and values.
+const fn is_allowed_in_unquoted(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+impl<'a> TagParser<'a> {
+    const fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, c: char) -> Option<()> {
+        self.skip_whitespace();
+        if self.peek() != Some(c) {
+            return None;
+        }
+        self.pos += c.len_utf8();
+        Some(())
+    }
+
+    /// Consumes `c` if it is the next non-whitespace character.
+    fn accept(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Runs `read` one nesting level deeper, failing once [`MAX_DEPTH`] is exceeded so that
+    /// input like `[[[[...` can't overflow the stack.
+    fn nested<T>(&mut self, read: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        if self.depth >= MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let result = read(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn read_compound(&mut self) -> Option<NbtCompound> {
+        self.nested(Self::read_compound_entries)
+    }
+
+    fn read_compound_entries(&mut self) -> Option<NbtCompound> {
+        self.expect('{')?;
+        let mut compound = NbtCompound::new();
+        if self.accept('}') {
+            return Some(compound);
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.read_string()?;
+            if key.is_empty() {
+                return None;
+            }
+            self.expect(':')?;
+            let value = self.read_value()?;
+            compound.insert(key, value);
+            if self.accept('}') {
+                return Some(compound);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn read_value(&mut self) -> Option<NbtTag> {
+        self.skip_whitespace();
+        match self.peek()? {
+            '{' => self.read_compound().map(NbtTag::Compound),
+            '[' => self.read_list_or_array(),
+            '"' | '\'' => self.read_quoted().map(|s| NbtTag::String(s.into())),
+            _ => {
+                let token = self.read_unquoted();
+                if token.is_empty() {
+                    return None;
+                }
+                Some(infer_type(token))
+            }
+        }
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        match self.peek()? {
+            '"' | '\'' => self.read_quoted(),
+            _ => Some(self.read_unquoted().to_owned()),
+        }
+    }
+
+    fn read_unquoted(&mut self) -> &str {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = rest
+            .find(|c: char| !is_allowed_in_unquoted(c))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        &self.input[start..self.pos]
+    }
+
+    fn read_quoted(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        self.pos += 1;
+        let mut result = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    let (_, escaped) = chars.next()?;
+                    if escaped != quote && escaped != '\\' {
+                        return None;
+                    }
+                    result.push(escaped);
+                }
+                c if c == quote => {
+                    self.pos += i + 1;
+                    return Some(result);
+                }
+                c => result.push(c),
+            }
+        }
+        None
+    }
+
+    fn read_list_or_array(&mut self) -> Option<NbtTag> {
+        self.nested(Self::read_list_or_array_entries)
+    }
+
+    fn read_list_or_array_entries(&mut self) -> Option<NbtTag> {
+        self.expect('[')?;
+        let rest = &self.input[self.pos..];
+        let array_prefix = rest
+            .strip_prefix(['B', 'I', 'L'])
+            .filter(|after| after.starts_with(';'));
+        if array_prefix.is_some() {
+            let kind = self.peek()?;
+            self.pos += 2;
+            return self.read_array(kind);
+        }
+
+        let mut tags = Vec::new();
+        if !self.accept(']') {
+            loop {
+                tags.push(self.read_value()?);
+                if self.accept(']') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+        list_from_tags(tags).map(NbtTag::List)
+    }
+
+    fn read_array(&mut self, kind: char) -> Option<NbtTag> {
+        let mut values = Vec::new();
+        if !self.accept(']') {
+            loop {
+                values.push(self.read_value()?);
+                if self.accept(']') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+
+        let values = values.into_iter();
+        Some(match kind {
+            'B' => NbtTag::ByteArray(
+                values
+                    .map(|tag| match tag {
+                        NbtTag::Byte(v) => Some(v.cast_unsigned()),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            'I' => NbtTag::IntArray(
+                values
+                    .map(|tag| match tag {
+                        NbtTag::Byte(v) => Some(i32::from(v)),
+                        NbtTag::Short(v) => Some(i32::from(v)),
+                        NbtTag::Int(v) => Some(v),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            _ => NbtTag::LongArray(
+                values
+                    .map(|tag| match tag {
+                        NbtTag::Byte(v) => Some(i64::from(v)),
+                        NbtTag::Short(v) => Some(i64::from(v)),
+                        NbtTag::Int(v) => Some(i64::from(v)),
+                        NbtTag::Long(v) => Some(v),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+        })
+    }
+}
+
+/// Builds a list from elements, which must all be of the same type.
+#[must_use]
+pub fn list_from_tags(tags: Vec<NbtTag>) -> Option<NbtList> {
+    macro_rules! collect {
+        ($variant:ident) => {
+            NbtList::$variant(
+                tags.into_iter()
+                    .map(|tag| match tag {
+                        NbtTag::$variant(v) => Some(v),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            )
+        };
+    }
+
+    Some(match tags.first() {
+        None => NbtList::Empty,
+        Some(NbtTag::Byte(_)) => collect!(Byte),
+        Some(NbtTag::Short(_)) => collect!(Short),
+        Some(NbtTag::Int(_)) => collect!(Int),
+        Some(NbtTag::Long(_)) => collect!(Long),
+        Some(NbtTag::Float(_)) => collect!(Float),
+        Some(NbtTag::Double(_)) => collect!(Double),
+        Some(NbtTag::ByteArray(_)) => collect!(ByteArray),
+        Some(NbtTag::String(_)) => collect!(String),
+        Some(NbtTag::List(_)) => collect!(List),
+        Some(NbtTag::Compound(_)) => collect!(Compound),
+        Some(NbtTag::IntArray(_)) => collect!(IntArray),
+        Some(NbtTag::LongArray(_)) => collect!(LongArray),
+    })
+}
+
+/// Splits a list into its elements, the inverse of [`list_from_tags`].
+#[must_use]
+pub fn tags_from_list(list: NbtList) -> Vec<NbtTag> {
+    macro_rules! split {
+        ($v:ident, $variant:ident) => {
+            $v.into_iter().map(NbtTag::$variant).collect()
+        };
+    }
+
+    match list {
+        NbtList::Empty => Vec::new(),
+        NbtList::Byte(v) => split!(v, Byte),
+        NbtList::Short(v) => split!(v, Short),
+        NbtList::Int(v) => split!(v, Int),
+        NbtList::Long(v) => split!(v, Long),
+        NbtList::Float(v) => split!(v, Float),
+        NbtList::Double(v) => split!(v, Double),
+        NbtList::ByteArray(v) => split!(v, ByteArray),
+        NbtList::String(v) => split!(v, String),
+        NbtList::List(v) => split!(v, List),
+        NbtList::Compound(v) => split!(v, Compound),
+        NbtList::IntArray(v) => split!(v, IntArray),
+        NbtList::LongArray(v) => split!(v, LongArray),
+    }
+}
+
+/// Works out the type of an unquoted value from its suffix, like vanilla does. Values that
+/// aren't valid numbers, including out of range ones, are strings.
+fn infer_type(token: &str) -> NbtTag {
+    match token {
+        "true" => return NbtTag::Byte(1),
+        "false" => return NbtTag::Byte(0),
+        _ => {}
+    }
+
+    let (body, suffix) = match token.char_indices().last() {
+        Some((i, c)) if c.is_ascii_alphabetic() => (&token[..i], Some(c.to_ascii_lowercase())),
+        _ => (token, None),
+    };
+
+    let number = match suffix {
+        Some('b') if is_integer(body) => body.parse().ok().map(NbtTag::Byte),
+        Some('s') if is_integer(body) => body.parse().ok().map(NbtTag::Short),
+        Some('l') if is_integer(body) => body.parse().ok().map(NbtTag::Long),
+        Some('f') if is_decimal(body) => body.parse().ok().map(NbtTag::Float),
+        Some('d') if is_decimal(body) => body.parse().ok().map(NbtTag::Double),
+        None if is_integer(body) => body.parse().ok().map(NbtTag::Int),
+        None if is_decimal(body) && body.contains('.') => body.parse().ok().map(NbtTag::Double),
+        _ => None,
+    };
+    number.unwrap_or_else(|| NbtTag::String(token.into()))
+}
+
+fn is_integer(s: &str) -> bool {
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_decimal(s: &str) -> bool {
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    digits.bytes().any(|b| b.is_ascii_digit())
+        && digits
+            .bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'-' | b'+'))
+}
+
+#[cfg(test)]
+mod tests {
+    use simdnbt::owned::{NbtList, NbtTag};
+
+    use super::{MAX_DEPTH, parse_compound, parse_compound_prefix, parse_tag_prefix};
+    use crate::text::to_snbt;
+
+    #[test]
+    fn parses_nested_compounds() {
+        let compound = parse_compound("{Count:1b,tag:{display:{Name:\"x\"}}}").expect("valid snbt");
+
+        assert_eq!(compound.get("Count"), Some(&NbtTag::Byte(1)));
+        let name = compound
+            .compound("tag")
+            .and_then(|tag| tag.compound("display"))
+            .and_then(|display| display.string("Name"))
+            .expect("nested name");
+        assert_eq!(name.to_str(), "x");
+    }
+
+    #[test]
+    fn infers_number_types_from_suffixes() {
+        let compound = parse_compound(
+            "{a:1,b:2s,c:3L,d:1.5f,e:2.5,f:true,g:'it\\'s',h:300b,i:[1,2],j:[I;1,2],k:[]}",
+        )
+        .expect("valid snbt");
+        assert_eq!(compound.get("a"), Some(&NbtTag::Int(1)));
+        assert_eq!(compound.get("b"), Some(&NbtTag::Short(2)));
+        assert_eq!(compound.get("c"), Some(&NbtTag::Long(3)));
+        assert_eq!(compound.get("d"), Some(&NbtTag::Float(1.5)));
+        assert_eq!(compound.get("e"), Some(&NbtTag::Double(2.5)));
+        assert_eq!(compound.get("f"), Some(&NbtTag::Byte(1)));
+        assert_eq!(compound.get("g"), Some(&NbtTag::String("it's".into())));
+        assert_eq!(compound.get("h"), Some(&NbtTag::String("300b".into())));
+        assert_eq!(
+            compound.get("i"),
+            Some(&NbtTag::List(NbtList::Int(vec![1, 2])))
+        );
+        assert_eq!(compound.get("j"), Some(&NbtTag::IntArray(vec![1, 2])));
+        assert_eq!(compound.get("k"), Some(&NbtTag::List(NbtList::Empty)));
+    }
+
+    #[test]
+    fn prefix_stops_after_closing_brace() {
+        let (compound, consumed) = parse_compound_prefix("{a:1} 5").expect("valid snbt");
+
+        assert_eq!(compound.get("a"), Some(&NbtTag::Int(1)));
+        assert_eq!(consumed, 5);
+        assert!(parse_compound("{a:1} 5").is_none());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(parse_compound("{a:1").is_none());
+        assert!(parse_compound("{:1}").is_none());
+        assert!(parse_compound("{a:[1,2b]}").is_none());
+        assert!(parse_compound("{a:\"open}").is_none());
+        assert!(parse_compound("[1,2]").is_none());
+    }
+
+    #[test]
+    fn rejects_nesting_past_the_depth_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse_tag_prefix(&nested(MAX_DEPTH)).is_some());
+        assert!(parse_tag_prefix(&nested(MAX_DEPTH + 1)).is_none());
+        assert!(parse_tag_prefix(&"[".repeat(100_000)).is_none());
+
+        let compounds =
+            |depth: usize| format!("{}{{}}{}", "{a:".repeat(depth - 1), "}".repeat(depth - 1));
+        assert!(parse_compound(&compounds(MAX_DEPTH)).is_some());
+        assert!(parse_compound(&compounds(MAX_DEPTH + 1)).is_none());
+    }
+
+    #[test]
+    fn round_trips_through_to_snbt() {
+        let input = "{list:[{x:1.0d}],bytes:[B;1B,2B],name:\"a b\"}";
+        let compound = parse_compound(input).expect("valid snbt");
+
+        assert_eq!(parse_compound(&to_snbt(&compound)), Some(compound));
+    }
+}
@@ -0,0 +1,157 @@
+//! Conversion of legacy `§`-code formatted strings into text components.
+use text_components::{Modifier, TextComponent, format::Color};
+
+#[derive(Clone, Copy, Default)]
+struct LegacyStyle {
+    color: Option<Color>,
+    bold: bool,
+    italic: bool,
+    underlined: bool,
+    strikethrough: bool,
+    obfuscated: bool,
+}
+
+impl LegacyStyle {
+    fn apply(self, text: String) -> TextComponent {
+        let mut component = TextComponent::plain(text);
+        if let Some(color) = self.color {
+            component = component.color(color);
+        }
+        if self.bold {
+            component = component.bold(true);
+        }
+        if self.italic {
+            component = component.italic(true);
+        }
+        if self.underlined {
+            component = component.underlined(true);
+        }
+        if self.strikethrough {
+            component = component.strikethrough(true);
+        }
+        if self.obfuscated {
+            component = component.obfuscated(true);
+        }
+        component
+    }
+}
+
+const fn legacy_color(code: char) -> Option<Color> {
+    Some(match code {
+        '0' => Color::Black,
+        '1' => Color::DarkBlue,
+        '2' => Color::DarkGreen,
+        '3' => Color::DarkAqua,
+        '4' => Color::DarkRed,
+        '5' => Color::DarkPurple,
+        '6' => Color::Gold,
+        '7' => Color::Gray,
+        '8' => Color::DarkGray,
+        '9' => Color::Blue,
+        'a' => Color::Green,
+        'b' => Color::Aqua,
+        'c' => Color::Red,
+        'd' => Color::LightPurple,
+        'e' => Color::Yellow,
+        'f' => Color::White,
+        _ => return None,
+    })
+}
+
+/// Converts a string using legacy formatting codes (`§c`, `§l`, ...) into a [`TextComponent`].
+///
+/// `formatting_char` is normally `§`, but config files commonly use `&`. As in vanilla, a color code
+/// clears the active formatting flags and `§r` resets everything. Unknown codes are kept as literal
+/// text and a trailing formatting char is dropped.
+#[must_use]
+pub fn from_legacy(input: &str, formatting_char: char) -> TextComponent {
+    let mut spans = Vec::new();
+    let mut style = LegacyStyle::default();
+    let mut text = String::new();
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        if c != formatting_char {
+            text.push(c);
+            continue;
+        }
+        let Some(code) = chars.next() else {
+            break;
+        };
+
+        let mut next = style;
+        match code.to_ascii_lowercase() {
+            'k' => next.obfuscated = true,
+            'l' => next.bold = true,
+            'm' => next.strikethrough = true,
+            'n' => next.underlined = true,
+            'o' => next.italic = true,
+            'r' => next = LegacyStyle::default(),
+            lower => {
+                if let Some(color) = legacy_color(lower) {
+                    next = LegacyStyle {
+                        color: Some(color),
+                        ..LegacyStyle::default()
+                    };
+                } else {
+                    text.push(c);
+                    text.push(code);
+                    continue;
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            spans.push(style.apply(std::mem::take(&mut text)));
+        }
+        style = next;
+    }
+
+    if !text.is_empty() {
+        spans.push(style.apply(text));
+    }
+
+    if spans.len() == 1 {
+        spans.pop().unwrap_or_default()
+    } else {
+        TextComponent::plain("").add_children(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_and_format_codes_build_spans() {
+        let component = from_legacy("§cRed §lbold§r plain", '§');
+        let expected = TextComponent::plain("").add_children(vec![
+            TextComponent::plain("Red ").color(Color::Red),
+            TextComponent::plain("bold").color(Color::Red).bold(true),
+            TextComponent::plain(" plain"),
+        ]);
+
+        assert_eq!(component.to_nbt_tag(), expected.to_nbt_tag());
+    }
+
+    #[test]
+    fn color_code_clears_formatting() {
+        let component = from_legacy("&l&kx&ay", '&');
+        let expected = TextComponent::plain("").add_children(vec![
+            TextComponent::plain("x").bold(true).obfuscated(true),
+            TextComponent::plain("y").color(Color::Green),
+        ]);
+
+        assert_eq!(component.to_nbt_tag(), expected.to_nbt_tag());
+    }
+
+    #[test]
+    fn unknown_codes_are_literal_and_trailing_char_is_dropped() {
+        let component = from_legacy("50§z off§", '§');
+
+        assert_eq!(
+            component.to_nbt_tag(),
+            TextComponent::plain("50§z off").to_nbt_tag()
+        );
+    }
+}
@@ -1,11 +1,18 @@
 //! This module contains everything related to text components.
+pub mod legacy;
+pub mod render;
+
+pub use legacy::from_legacy;
+pub use render::{render_ansi, to_plain_string, visible_len};
+
 use crate::{
     hash::{ComponentHasher, HashComponent, HashEntry, sort_map_entries},
-    serial::ReadFrom,
+    serial::{ReadFrom, write::write_named_nbt_compound},
     translations_registry::TRANSLATIONS,
 };
-use simdnbt::owned::read_tag;
-use std::io::{self, Cursor};
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use simdnbt::owned::{Nbt, NbtCompound, NbtList, NbtTag, read as read_named_nbt, read_tag};
+use std::io::{self, Cursor, Read, Write};
 use text_components::{
     TextComponent,
     content::{Content, NbtSource, Object, Resolvable},
@@ -46,15 +53,315 @@ impl ReadFrom for TextComponent {
             return Ok(Self::new());
         }
 
-        // Read exactly one NBT tag using simdnbt
-        let nbt_tag =
-            read_tag(data).map_err(|e| io::Error::other(format!("Failed to read NBT: {e:?}")))?;
+        read_text_component_tag(data)
+    }
+}
+
+/// Decodes a [`TextComponent`] from a nameless network NBT tag (tag id followed by its payload),
+/// as produced by `WriteTo for TextComponent`.
+///
+/// Truncated buffers and unknown tag ids are reported as errors.
+pub fn decode_text_component(bytes: &[u8]) -> io::Result<TextComponent> {
+    read_text_component_tag(&mut Cursor::new(bytes))
+}
+
+/// Reads exactly one NBT tag and builds a [`TextComponent`] from it.
+fn read_text_component_tag(data: &mut Cursor<&[u8]>) -> io::Result<TextComponent> {
+    let nbt_tag =
+        read_tag(data).map_err(|e| io::Error::other(format!("Failed to read NBT: {e:?}")))?;
+
+    TextComponent::from_nbt(&nbt_tag)
+        .ok_or_else(|| io::Error::other("Failed to parse TextComponent from NBT"))
+}
+
+/// Encodes a [`TextComponent`] as a gzip-compressed NBT file with an empty root name, the way
+/// vanilla writes `.dat` files.
+///
+/// Components that don't serialize to a compound are stored as `{text: "...", extra: [...]}`.
+pub fn encode_text_component_gzip(component: &TextComponent) -> io::Result<Vec<u8>> {
+    let root = component_root_compound(component)?;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    write_named_nbt_compound(&mut encoder, "", &root)?;
+    encoder.finish()
+}
+
+/// Builds the compound form of a component, expanding the collapsed string and list forms.
+fn component_root_compound(component: &TextComponent) -> io::Result<NbtCompound> {
+    match component.to_nbt_tag() {
+        NbtTag::Compound(compound) => Ok(compound),
+        NbtTag::String(text) => {
+            let mut compound = NbtCompound::new();
+            compound.insert("text", text);
+            Ok(compound)
+        }
+        NbtTag::List(extra) => {
+            let mut compound = NbtCompound::new();
+            compound.insert("text", "");
+            compound.insert("extra", extra);
+            Ok(compound)
+        }
+        _ => Err(io::Error::other(
+            "TextComponent did not serialize to a string, list or compound",
+        )),
+    }
+}
+
+/// Encodes a [`TextComponent`] as the JSON chat format used by clients before 1.20.3.
+///
+/// This renders the same compound that is sent as NBT, so both formats stay in sync. Component
+/// booleans are stored as bytes in NBT and come out as JSON `true`/`false`.
+pub fn encode_text_component_json(component: &TextComponent) -> io::Result<String> {
+    let root = component_root_compound(component)?;
+    serde_json::to_string(&nbt_compound_to_json(&root))
+        .map_err(|e| io::Error::other(format!("Failed to serialize TextComponent: {e}")))
+}
+
+fn nbt_compound_to_json(compound: &NbtCompound) -> serde_json::Value {
+    compound
+        .iter()
+        .map(|(key, value)| {
+            let key = key.to_str().into_owned();
+            let value = match value {
+                NbtTag::String(color) if key == "color" => {
+                    serde_json::Value::String(color.to_str().to_lowercase())
+                }
+                value => nbt_tag_to_json(value),
+            };
+            (key, value)
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
 
-        Self::from_nbt(&nbt_tag)
-            .ok_or_else(|| io::Error::other("Failed to parse TextComponent from NBT"))
+fn nbt_tag_to_json(tag: &NbtTag) -> serde_json::Value {
+    use serde_json::Value;
+
+    match tag {
+        NbtTag::Byte(v) => Value::Bool(*v != 0),
+        NbtTag::Short(v) => (*v).into(),
+        NbtTag::Int(v) => (*v).into(),
+        NbtTag::Long(v) => (*v).into(),
+        NbtTag::Float(v) => (*v).into(),
+        NbtTag::Double(v) => (*v).into(),
+        NbtTag::ByteArray(v) => v.iter().map(|b| b.cast_signed()).collect(),
+        NbtTag::String(v) => v.to_str().into_owned().into(),
+        NbtTag::List(list) => nbt_list_to_json(list),
+        NbtTag::Compound(compound) => nbt_compound_to_json(compound),
+        NbtTag::IntArray(v) => v.iter().copied().collect(),
+        NbtTag::LongArray(v) => v.iter().copied().collect(),
     }
 }
 
+fn nbt_list_to_json(list: &NbtList) -> serde_json::Value {
+    use serde_json::Value;
+
+    match list {
+        NbtList::Empty => Value::Array(Vec::new()),
+        NbtList::Byte(v) => v.iter().map(|b| Value::Bool(*b != 0)).collect(),
+        NbtList::Short(v) => v.iter().copied().collect(),
+        NbtList::Int(v) => v.iter().copied().collect(),
+        NbtList::Long(v) => v.iter().copied().collect(),
+        NbtList::Float(v) => v.iter().copied().collect(),
+        NbtList::Double(v) => v.iter().copied().collect(),
+        NbtList::ByteArray(v) => v
+            .iter()
+            .map(|bytes| bytes.iter().map(|b| b.cast_signed()).collect())
+            .collect(),
+        NbtList::String(v) => v
+            .iter()
+            .map(|s| Value::from(s.to_str().into_owned()))
+            .collect(),
+        NbtList::List(v) => v.iter().map(nbt_list_to_json).collect(),
+        NbtList::Compound(v) => v.iter().map(nbt_compound_to_json).collect(),
+        NbtList::IntArray(v) => v
+            .iter()
+            .map(|ints| ints.iter().copied().collect())
+            .collect(),
+        NbtList::LongArray(v) => v
+            .iter()
+            .map(|longs| longs.iter().copied().collect())
+            .collect(),
+    }
+}
+
+/// Decodes a [`TextComponent`] from a gzip-compressed NBT file, as written by
+/// [`encode_text_component_gzip`].
+pub fn decode_text_component_gzip(bytes: &[u8]) -> io::Result<TextComponent> {
+    let mut data = Vec::new();
+    GzDecoder::new(bytes).read_to_end(&mut data)?;
+
+    let nbt = read_named_nbt(&mut Cursor::new(&data))
+        .map_err(|e| io::Error::other(format!("Failed to read NBT: {e:?}")))?;
+    let Nbt::Some(root) = nbt else {
+        return Err(io::Error::other("NBT file has no root compound"));
+    };
+
+    TextComponent::from_nbt(&NbtTag::Compound(root.as_compound().clone()))
+        .ok_or_else(|| io::Error::other("Failed to parse TextComponent from NBT"))
+}
+
+/// Returns a copy of `component` with every color, style flag and click/hover event removed.
+///
+/// The tree itself is kept, including translation keys and their arguments.
+#[must_use]
+pub fn strip_formatting(component: &TextComponent) -> TextComponent {
+    let content = match &component.content {
+        Content::Translate(message) => {
+            let mut message = message.clone();
+            if let Some(args) = &mut message.args {
+                for arg in args.iter_mut() {
+                    *arg = strip_formatting(arg);
+                }
+            }
+            Content::Translate(message)
+        }
+        content => content.clone(),
+    };
+
+    TextComponent {
+        content,
+        children: component.children.iter().map(strip_formatting).collect(),
+        ..Default::default()
+    }
+}
+
+/// Renders a compound as SNBT, matching vanilla's `StringTagVisitor` output.
+///
+/// Numbers keep their type suffix, so the result parses back to the same tag types.
+#[must_use]
+pub fn to_snbt(compound: &NbtCompound) -> String {
+    let mut out = String::new();
+    write_snbt_compound(&mut out, compound);
+    out
+}
+
+/// Renders any tag as SNBT, like [`to_snbt`] does for compounds.
+#[must_use]
+pub fn tag_to_snbt(tag: &NbtTag) -> String {
+    let mut out = String::new();
+    write_snbt_tag(&mut out, tag);
+    out
+}
+
+fn write_snbt_compound(out: &mut String, compound: &NbtCompound) {
+    out.push('{');
+    for (i, (key, value)) in compound.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_snbt_key(out, &key.to_str());
+        out.push(':');
+        write_snbt_tag(out, value);
+    }
+    out.push('}');
+}
+
+fn write_snbt_tag(out: &mut String, tag: &NbtTag) {
+    match tag {
+        NbtTag::Byte(v) => write_snbt_number(out, v, "b"),
+        NbtTag::Short(v) => write_snbt_number(out, v, "s"),
+        NbtTag::Int(v) => write_snbt_number(out, v, ""),
+        NbtTag::Long(v) => write_snbt_number(out, v, "L"),
+        NbtTag::Float(v) => write_snbt_number(out, &format!("{v:?}"), "f"),
+        NbtTag::Double(v) => write_snbt_number(out, &format!("{v:?}"), "d"),
+        NbtTag::ByteArray(v) => write_snbt_array(out, "B", v.iter().map(|b| b.cast_signed()), "B"),
+        NbtTag::String(v) => write_snbt_string(out, &v.to_str()),
+        NbtTag::List(list) => write_snbt_list(out, list),
+        NbtTag::Compound(compound) => write_snbt_compound(out, compound),
+        NbtTag::IntArray(v) => write_snbt_array(out, "I", v.iter(), ""),
+        NbtTag::LongArray(v) => write_snbt_array(out, "L", v.iter(), "L"),
+    }
+}
+
+fn write_snbt_list(out: &mut String, list: &NbtList) {
+    fn elements<T>(out: &mut String, items: &[T], mut write: impl FnMut(&mut String, &T)) {
+        out.push('[');
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write(out, item);
+        }
+        out.push(']');
+    }
+
+    match list {
+        NbtList::Empty => out.push_str("[]"),
+        NbtList::Byte(v) => elements(out, v, |out, v| write_snbt_number(out, v, "b")),
+        NbtList::Short(v) => elements(out, v, |out, v| write_snbt_number(out, v, "s")),
+        NbtList::Int(v) => elements(out, v, |out, v| write_snbt_number(out, v, "")),
+        NbtList::Long(v) => elements(out, v, |out, v| write_snbt_number(out, v, "L")),
+        NbtList::Float(v) => elements(out, v, |out, v| {
+            write_snbt_number(out, &format!("{v:?}"), "f");
+        }),
+        NbtList::Double(v) => elements(out, v, |out, v| {
+            write_snbt_number(out, &format!("{v:?}"), "d");
+        }),
+        NbtList::ByteArray(v) => elements(out, v, |out, v| {
+            write_snbt_array(out, "B", v.iter().map(|b| b.cast_signed()), "B");
+        }),
+        NbtList::String(v) => elements(out, v, |out, v| write_snbt_string(out, &v.to_str())),
+        NbtList::List(v) => elements(out, v, write_snbt_list),
+        NbtList::Compound(v) => elements(out, v, write_snbt_compound),
+        NbtList::IntArray(v) => elements(out, v, |out, v| write_snbt_array(out, "I", v.iter(), "")),
+        NbtList::LongArray(v) => {
+            elements(out, v, |out, v| write_snbt_array(out, "L", v.iter(), "L"));
+        }
+    }
+}
+
+fn write_snbt_number(out: &mut String, value: &impl ToString, suffix: &str) {
+    out.push_str(&value.to_string());
+    out.push_str(suffix);
+}
+
+fn write_snbt_array<T: ToString>(
+    out: &mut String,
+    prefix: &str,
+    values: impl Iterator<Item = T>,
+    suffix: &str,
+) {
+    out.push('[');
+    out.push_str(prefix);
+    out.push(';');
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_snbt_number(out, &value, suffix);
+    }
+    out.push(']');
+}
+
+fn write_snbt_key(out: &mut String, key: &str) {
+    let is_simple = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+'));
+    if is_simple {
+        out.push_str(key);
+    } else {
+        write_snbt_string(out, key);
+    }
+}
+
+/// Quotes with `"` unless the string contains `"` and no `'`, like vanilla's `quoteAndEscape`.
+fn write_snbt_string(out: &mut String, value: &str) {
+    let quote = if value.contains('"') && !value.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+    out.push(quote);
+    for c in value.chars() {
+        if c == '\\' || c == quote {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push(quote);
+}
+
 impl HashComponent for TextComponent {
     fn hash_component(&self, hasher: &mut ComponentHasher) {
         // Minecraft's CODEC for Component uses an Either:
@@ -731,3 +1038,197 @@ fn hash_click_fields(event: &ClickEvent, hasher: &mut ComponentHasher) {
     }
     hasher.end_map();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serial::WriteTo;
+    use text_components::{Modifier, format::Color};
+
+    fn encode(component: &TextComponent) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        WriteTo::write(component, &mut bytes).expect("writing to a buffer should not fail");
+        bytes
+    }
+
+    #[test]
+    fn decode_round_trips_encoded_component() {
+        let component = TextComponent::plain("hello");
+        let decoded = decode_text_component(&encode(&component)).expect("decode failed");
+
+        assert_eq!(decoded.to_nbt_tag(), component.to_nbt_tag());
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|window| window == needle)
+    }
+
+    #[test]
+    fn strings_are_encoded_as_modified_utf8() {
+        // Matches Java's `DataOutputStream.writeUTF`: NUL is two bytes and supplementary
+        // characters are written as a surrogate pair of three-byte sequences.
+        let nul = encode(&TextComponent::plain("a\0b"));
+        assert!(contains(&nul, &[0x00, 0x04, b'a', 0xC0, 0x80, b'b']));
+
+        let gem = encode(&TextComponent::plain("💎"));
+        assert!(contains(
+            &gem,
+            &[0x00, 0x06, 0xED, 0xA0, 0xBD, 0xED, 0xB2, 0x8E]
+        ));
+    }
+
+    #[test]
+    fn gzip_round_trips_component() {
+        let component = TextComponent::plain("saved motd");
+        let bytes = encode_text_component_gzip(&component).expect("encode failed");
+
+        assert_eq!(bytes[..2], [0x1F, 0x8B], "missing gzip magic");
+
+        let mut data = Vec::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_end(&mut data)
+            .expect("gunzip failed");
+        assert_eq!(
+            data[..3],
+            [0x0A, 0x00, 0x00],
+            "root must be an unnamed compound"
+        );
+
+        let decoded = decode_text_component_gzip(&bytes).expect("decode failed");
+        assert_eq!(
+            decoded.to_nbt_tag(),
+            TextComponent::from_nbt(&NbtTag::Compound({
+                let mut compound = NbtCompound::new();
+                compound.insert("text", "saved motd");
+                compound
+            }))
+            .expect("valid component")
+            .to_nbt_tag()
+        );
+    }
+
+    #[test]
+    fn snbt_renders_every_tag_type() {
+        let mut inner = NbtCompound::new();
+        inner.insert("a", 1_i8);
+
+        let mut compound = NbtCompound::new();
+        compound.insert("byte", 1_i8);
+        compound.insert("short", 2_i16);
+        compound.insert("int", 3_i32);
+        compound.insert("long", 4_i64);
+        compound.insert("float", 0.5_f32);
+        compound.insert("double", 1.0_f64);
+        compound.insert("bytes", NbtTag::ByteArray(vec![1, 0xFF]));
+        compound.insert("string", "say \"hi\"");
+        compound.insert("list", NbtTag::List(NbtList::Int(vec![1, 2])));
+        compound.insert("empty", NbtTag::List(NbtList::Empty));
+        compound.insert("compound", inner);
+        compound.insert("ints", NbtTag::IntArray(vec![1, -2]));
+        compound.insert("longs", NbtTag::LongArray(vec![3]));
+        compound.insert("needs quotes", "it's");
+
+        assert_eq!(
+            to_snbt(&compound),
+            concat!(
+                r#"{byte:1b,short:2s,int:3,long:4L,float:0.5f,double:1.0d,bytes:[B;1B,-1B],"#,
+                r#"string:'say "hi"',list:[1,2],empty:[],compound:{a:1b},ints:[I;1,-2],"#,
+                r#"longs:[L;3L],"needs quotes":"it's"}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn snbt_escapes_quotes_and_backslashes() {
+        let mut compound = NbtCompound::new();
+        compound.insert("s", r#"a\b"'c"#);
+
+        assert_eq!(to_snbt(&compound), r#"{s:"a\\b\"'c"}"#);
+    }
+
+    #[test]
+    fn snbt_round_trips_through_from_snbt() {
+        let mut compound = NbtCompound::new();
+        compound.insert("text", "hi");
+        compound.insert("bold", 1_i8);
+
+        let component = TextComponent::from_snbt(&to_snbt(&compound)).expect("valid snbt");
+        let expected =
+            TextComponent::from_nbt(&NbtTag::Compound(compound)).expect("valid component");
+        assert_eq!(component.to_nbt_tag(), expected.to_nbt_tag());
+    }
+
+    #[test]
+    fn json_encodes_empty_component_as_text_object() {
+        let json = encode_text_component_json(&TextComponent::plain("")).expect("encode failed");
+
+        assert_eq!(json, r#"{"text":""}"#);
+    }
+
+    #[test]
+    fn json_uses_booleans_and_lowercase_colors() {
+        let component = TextComponent::plain("hi")
+            .color(Color::DarkRed)
+            .bold(true)
+            .add_children(vec![TextComponent::plain("!")]);
+        let json: serde_json::Value =
+            serde_json::from_str(&encode_text_component_json(&component).expect("encode failed"))
+                .expect("valid json");
+
+        assert_eq!(json["text"], "hi");
+        assert_eq!(json["bold"], true);
+        assert_eq!(json["color"], "dark_red");
+        assert!(json["extra"].is_array());
+    }
+
+    #[test]
+    fn strip_formatting_removes_style_recursively() {
+        let component = TextComponent::plain("hi")
+            .color(Color::Red)
+            .bold(true)
+            .click_event(ClickEvent::CopyToClipboard { value: "hi".into() })
+            .add_children(vec![TextComponent::plain("!").color(Color::Blue)]);
+
+        let stripped = strip_formatting(&component);
+
+        assert!(stripped.format.is_none());
+        assert!(stripped.interactions.is_none());
+        assert_eq!(
+            stripped.to_nbt_tag(),
+            TextComponent::plain("hi")
+                .add_children(vec![TextComponent::plain("!")])
+                .to_nbt_tag()
+        );
+    }
+
+    #[test]
+    fn decode_rejects_truncated_buffer() {
+        let bytes = encode(&TextComponent::plain("hello"));
+
+        assert!(decode_text_component(&bytes[..bytes.len() - 1]).is_err());
+        assert!(decode_text_component(&[]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_unknown_tag_id() {
+        assert!(decode_text_component(&[0x42, 0x00]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_deeply_nested_lists() {
+        const DEPTH: usize = 600;
+        const TAG_END: u8 = 0x00;
+        const TAG_LIST: u8 = 0x09;
+
+        // A list of lists, each containing exactly one element, nested past vanilla's 512 limit
+        let mut bytes = vec![TAG_LIST];
+        for _ in 0..DEPTH {
+            bytes.push(TAG_LIST);
+            bytes.extend_from_slice(&1i32.to_be_bytes());
+        }
+        bytes.push(TAG_END);
+        bytes.extend_from_slice(&0i32.to_be_bytes());
+
+        assert!(decode_text_component(&bytes).is_err());
+    }
+}
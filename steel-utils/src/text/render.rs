@@ -0,0 +1,348 @@
+//! Rendering of text components into flat strings, for the console and logs.
+use std::fmt::Write;
+
+use text_components::{TextComponent, content::Content, format::Format, resolving::TextResolutor};
+
+use super::DisplayResolutor;
+
+/// Renders a component for a terminal, turning colors and style flags into ANSI escape sequences.
+///
+/// Children inherit their parent's style like they do on the client, and every styled span is
+/// followed by a reset.
+#[must_use]
+pub fn render_ansi(component: &TextComponent) -> String {
+    let mut out = String::new();
+    visit_segments(
+        component,
+        &DisplayResolutor,
+        &Format::default(),
+        &mut |text, format| {
+            let codes = ansi_codes(format);
+            if codes.is_empty() {
+                out.push_str(text);
+            } else {
+                let _ = write!(out, "\x1b[{codes}m{text}\x1b[0m");
+            }
+        },
+    );
+    out
+}
+
+/// Flattens a component to its unformatted text, resolving translations through `resolutor`.
+///
+/// Translation keys the resolutor doesn't know fall back to the component's `fallback`, then to
+/// the key itself. Styling and click/hover events are ignored.
+#[must_use]
+pub fn to_plain_string(component: &TextComponent, resolutor: &impl TextResolutor) -> String {
+    let mut out = String::new();
+    visit_segments(component, resolutor, &Format::default(), &mut |text, _| {
+        out.push_str(text);
+    });
+    out
+}
+
+/// Counts the characters a component displays once resolved, ignoring formatting.
+///
+/// Used to enforce limits such as sign line and book page lengths.
+#[must_use]
+pub fn visible_len(component: &TextComponent, resolutor: &impl TextResolutor) -> usize {
+    let mut len = 0;
+    visit_segments(component, resolutor, &Format::default(), &mut |text, _| {
+        len += text.chars().count();
+    });
+    len
+}
+
+/// Walks `component` in display order, calling `visit` with every non-empty text segment and the
+/// style it ends up with after inheriting from `parent`.
+pub(crate) fn visit_segments(
+    component: &TextComponent,
+    resolutor: &impl TextResolutor,
+    parent: &Format,
+    visit: &mut impl FnMut(&str, &Format),
+) {
+    let format = inherit_format(parent, &component.format);
+
+    match &component.content {
+        Content::Text { text } => visit_text(text, &format, visit),
+        Content::Keybind { keybind } => visit_text(keybind, &format, visit),
+        Content::Translate(message) => {
+            let template = resolutor
+                .translate(&message.key)
+                .or_else(|| message.fallback.as_deref().map(ToString::to_string))
+                .unwrap_or_else(|| message.key.to_string());
+            let args = message.args.as_deref().unwrap_or_default();
+            visit_translation(&template, args, resolutor, &format, visit);
+        }
+        Content::Resolvable(resolvable) => {
+            let resolved = resolutor.resolve_content(resolvable);
+            // Resolutors that can't resolve hand the resolvable back, which must not loop.
+            if !matches!(resolved.content, Content::Resolvable(_)) {
+                visit_segments(&resolved, resolutor, &format, visit);
+            }
+        }
+        Content::Custom(data) => {
+            if let Some(resolved) = resolutor.resolve_custom(data) {
+                visit_segments(&resolved, resolutor, &format, visit);
+            }
+        }
+        Content::Object(_) => {}
+    }
+
+    for child in &component.children {
+        visit_segments(child, resolutor, &format, visit);
+    }
+}
+
+fn visit_text(text: &str, format: &Format, visit: &mut impl FnMut(&str, &Format)) {
+    if !text.is_empty() {
+        visit(text, format);
+    }
+}
+
+/// Fills `%s`, `%n$s` and `%%` in a translation template, the way the client's
+/// `TranslatableContents` does. Placeholders without a matching argument are kept as-is.
+fn visit_translation(
+    template: &str,
+    args: &[TextComponent],
+    resolutor: &impl TextResolutor,
+    format: &Format,
+    visit: &mut impl FnMut(&str, &Format),
+) {
+    let mut next_arg = 0;
+    let mut rest = template;
+
+    while let Some(start) = rest.find('%') {
+        visit_text(&rest[..start], format, visit);
+        let after = &rest[start + 1..];
+
+        if let Some(tail) = after.strip_prefix('%') {
+            visit_text("%", format, visit);
+            rest = tail;
+            continue;
+        }
+
+        let (index, consumed) = if let Some(tail) = after.strip_prefix('s') {
+            next_arg += 1;
+            (Some(next_arg - 1), after.len() - tail.len())
+        } else {
+            let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+            match after[digits..].strip_prefix("$s") {
+                Some(_) if digits > 0 => (
+                    after[..digits]
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|n| n.checked_sub(1)),
+                    digits + 2,
+                ),
+                _ => (None, 0),
+            }
+        };
+
+        match index.and_then(|i| args.get(i)) {
+            Some(arg) => visit_segments(arg, resolutor, format, visit),
+            None => visit_text(&rest[start..=start + consumed], format, visit),
+        }
+        rest = &after[consumed..];
+    }
+
+    visit_text(rest, format, visit);
+}
+
+fn inherit_format(parent: &Format, own: &Format) -> Format {
+    let mut format = parent.clone();
+    if own.color.is_some() {
+        format.color.clone_from(&own.color);
+    }
+    if own.shadow_color.is_some() {
+        format.shadow_color = own.shadow_color;
+    }
+    if own.font.is_some() {
+        format.font.clone_from(&own.font);
+    }
+    format.bold = own.bold.or(parent.bold);
+    format.italic = own.italic.or(parent.italic);
+    format.underlined = own.underlined.or(parent.underlined);
+    format.strikethrough = own.strikethrough.or(parent.strikethrough);
+    format.obfuscated = own.obfuscated.or(parent.obfuscated);
+    format
+}
+
+/// Builds the `;`-separated SGR parameters for a style, or an empty string for no style.
+fn ansi_codes(format: &Format) -> String {
+    let mut codes = Vec::new();
+    for (enabled, code) in [
+        (format.bold, "1"),
+        (format.italic, "3"),
+        (format.underlined, "4"),
+        (format.obfuscated, "5"),
+        (format.strikethrough, "9"),
+    ] {
+        if enabled == Some(true) {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some((r, g, b)) = format
+        .color
+        .as_ref()
+        .and_then(|c| color_rgb(&c.to_string()))
+    {
+        codes.push(format!("38;2;{r};{g};{b}"));
+    }
+    codes.join(";")
+}
+
+/// Resolves a serialized color (a vanilla color name or `#RRGGBB`) to its RGB value.
+fn color_rgb(color: &str) -> Option<(u8, u8, u8)> {
+    let rgb = match color {
+        "black" => 0x00_0000,
+        "dark_blue" => 0x00_00AA,
+        "dark_green" => 0x00_AA00,
+        "dark_aqua" => 0x00_AAAA,
+        "dark_red" => 0xAA_0000,
+        "dark_purple" => 0xAA_00AA,
+        "gold" => 0xFF_AA00,
+        "gray" => 0xAA_AAAA,
+        "dark_gray" => 0x55_5555,
+        "blue" => 0x55_55FF,
+        "green" => 0x55_FF55,
+        "aqua" => 0x55_FFFF,
+        "red" => 0xFF_5555,
+        "light_purple" => 0xFF_55FF,
+        "yellow" => 0xFF_FF55,
+        "white" => 0xFF_FFFF,
+        hex => u32::from_str_radix(hex.strip_prefix('#')?, 16).ok()?,
+    };
+    Some(((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use text_components::{
+        Modifier, content::Resolvable, custom::CustomData, format::Color,
+        translation::TranslatedMessage,
+    };
+
+    #[test]
+    fn red_bold_text_is_wrapped_in_sgr_and_reset() {
+        let component = TextComponent::plain("hello").color(Color::Red).bold(true);
+
+        assert_eq!(
+            render_ansi(&component),
+            "\x1b[1;38;2;255;85;85mhello\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn children_inherit_parent_style() {
+        let component = TextComponent::plain("a")
+            .bold(true)
+            .add_children(vec![TextComponent::plain("b").color(Color::Aqua)]);
+
+        assert_eq!(
+            render_ansi(&component),
+            "\x1b[1ma\x1b[0m\x1b[1;38;2;85;255;255mb\x1b[0m"
+        );
+    }
+
+    struct TestResolutor;
+    impl TextResolutor for TestResolutor {
+        fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+            TextComponent {
+                content: Content::Resolvable(resolvable.clone()),
+                ..Default::default()
+            }
+        }
+
+        fn resolve_custom(&self, _data: &CustomData) -> Option<TextComponent> {
+            None
+        }
+
+        fn translate(&self, key: &str) -> Option<String> {
+            match key {
+                "chat.type.text" => Some("<%s> %s".to_string()),
+                "swapped" => Some("%2$s before %1$s, 100%%".to_string()),
+                _ => None,
+            }
+        }
+    }
+
+    fn translated<const N: usize>(key: &'static str, args: [TextComponent; N]) -> TextComponent {
+        TranslatedMessage {
+            key: Cow::Borrowed(key),
+            fallback: None,
+            args: Some(Box::new(args)),
+        }
+        .component()
+    }
+
+    #[test]
+    fn plain_string_interpolates_translation_args() {
+        let component = translated(
+            "chat.type.text",
+            [
+                TextComponent::plain("Steve").color(Color::Gold),
+                TextComponent::plain("hi ").add_children(vec![TextComponent::plain("all")]),
+            ],
+        );
+
+        assert_eq!(
+            to_plain_string(&component, &TestResolutor),
+            "<Steve> hi all"
+        );
+    }
+
+    #[test]
+    fn plain_string_supports_indexed_placeholders_and_escapes() {
+        let component = translated(
+            "swapped",
+            [TextComponent::plain("a"), TextComponent::plain("b")],
+        );
+
+        assert_eq!(
+            to_plain_string(&component, &TestResolutor),
+            "b before a, 100%"
+        );
+    }
+
+    #[test]
+    fn plain_string_falls_back_to_key() {
+        let component = translated("unknown.key", []);
+
+        assert_eq!(to_plain_string(&component, &TestResolutor), "unknown.key");
+    }
+
+    #[test]
+    fn console_rendering_fills_translation_args() {
+        let component = translated(
+            "chat.type.text",
+            [TextComponent::plain("Steve"), TextComponent::plain("hello")],
+        );
+
+        assert_eq!(render_ansi(&component), "<Steve> hello");
+    }
+
+    #[test]
+    fn visible_len_counts_nested_translations() {
+        let component = TextComponent::plain("» ").add_children(vec![translated(
+            "chat.type.text",
+            [
+                TextComponent::plain("Alex").bold(true),
+                translated(
+                    "swapped",
+                    [TextComponent::plain("x"), TextComponent::plain("y")],
+                ),
+            ],
+        )]);
+
+        // "» " + "<Alex> " + "y before x, 100%"
+        assert_eq!(visible_len(&component, &TestResolutor), 2 + 7 + 16);
+    }
+
+    #[test]
+    fn unstyled_text_has_no_escapes() {
+        assert_eq!(render_ansi(&TextComponent::plain("plain")), "plain");
+    }
+}
@@ -1174,6 +1174,25 @@ impl From<f32> for GameType {
     }
 }
 
+impl Serialize for GameType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i32(i32::from(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for GameType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let id = i32::deserialize(deserializer)?;
+        Ok(Self::from(id))
+    }
+}
+
 /// World difficulty level.
 ///
 /// Controls starvation damage thresholds, mob spawning behavior,
@@ -100,6 +100,16 @@ pub struct ServerConfig {
     /// Vanilla command spam threshold window in seconds
     #[serde(default = "default_spam_threshold_seconds")]
     pub command_spam_threshold_seconds: i32,
+    /// Whether command names and literals match regardless of case. Vanilla is case-sensitive.
+    #[serde(default)]
+    pub case_insensitive_commands: bool,
+    /// Whether only whitelisted players and operators may join, vanilla's `white-list`.
+    #[serde(default)]
+    pub white_list: bool,
+    /// Minutes a player may stay idle before being kicked, or 0 to never kick.
+    /// Vanilla's `player-idle-timeout`.
+    #[serde(default)]
+    pub player_idle_timeout: u32,
     /// The compression settings for the server.
     pub compression: Option<CompressionInfo>,
     /// All settings and configurations for server links.
@@ -127,6 +137,9 @@ impl ServerConfig {
             enforce_secure_chat: self.enforce_secure_chat,
             chat_spam_threshold_seconds: self.chat_spam_threshold_seconds,
             command_spam_threshold_seconds: self.command_spam_threshold_seconds,
+            case_insensitive_commands: self.case_insensitive_commands,
+            white_list: self.white_list,
+            player_idle_timeout: self.player_idle_timeout,
             compression: self.compression,
             server_links: self.server_links,
             chunk_generation_threads: self.threads.chunk_generation,
@@ -351,6 +364,7 @@ mod tests {
         assert!(!config.server.allow_flight);
         assert_eq!(config.server.chat_spam_threshold_seconds, 10);
         assert_eq!(config.server.command_spam_threshold_seconds, 10);
+        assert!(!config.server.case_insensitive_commands);
         validate(&config.server).expect("default config validates");
         let worlds: WorldsConfig = toml::from_str(DEFAULT_WORLDS).expect("default worlds parses");
         assert!(!worlds.domains.is_empty());